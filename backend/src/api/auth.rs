@@ -1,50 +1,133 @@
 use axum::{
     extract::State,
-    http::StatusCode,
+    http::{header::COOKIE, Extensions, HeaderMap, Method, StatusCode},
+    middleware,
     response::{IntoResponse, Response},
     routing::post,
     Json, Router,
 };
 
-use crate::dto::auth::{AuthError, LoginRequest};
+use crate::dto::auth::{AuthError, LoginRequest, LoginResponse};
+use crate::middleware::{
+    audit, client_ip, decode_json, login_email_key, rate_limit, AuditDetails, RateLimitKey,
+    RateLimitRule, RestAuditRule, RestAuditRules, RestCall,
+};
 use crate::state::AppState;
-use crate::utils::{clear_auth_cookies, set_auth_cookies};
+use crate::utils::{clear_auth_cookies, parse_cookie, set_auth_cookies, REFRESH_TOKEN_COOKIE};
 
-pub fn router() -> Router<AppState> {
+/// Limits sharing buckets with the gRPC AuthService's, so logins are limited
+/// per client and per targeted account whichever API they go through
+static LOGIN_PER_IP: RateLimitRule = RateLimitRule {
+    method: None,
+    name: "login-ip",
+    key: RateLimitKey::ClientIp,
+    limit: |config| config.login_rate_limit_per_ip,
+};
+static LOGIN_PER_EMAIL: RateLimitRule = RateLimitRule {
+    method: None,
+    name: "login-email",
+    key: RateLimitKey::Message(login_email),
+    limit: |config| config.login_rate_limit_per_email,
+};
+static REFRESH_PER_IP: RateLimitRule = RateLimitRule {
+    method: None,
+    name: "refresh-ip",
+    key: RateLimitKey::ClientIp,
+    limit: |config| config.refresh_rate_limit_per_ip,
+};
+
+/// Login attempts recorded in the audit log, like `AUTH_AUDIT` records the
+/// Login RPC. The password is never recorded.
+const AUTH_AUDIT: RestAuditRules = &[RestAuditRule {
+    method: Method::POST,
+    path: "/auth/login",
+    action: "Login",
+    describe: describe_login,
+}];
+
+fn login_email(body: &[u8]) -> Option<String> {
+    decode_json::<LoginRequest>(body).and_then(|req| login_email_key(&req.email))
+}
+
+fn describe_login(call: &RestCall) -> AuditDetails {
+    AuditDetails {
+        actor_email: decode_json::<LoginRequest>(call.request).map(|req| req.email),
+        ..Default::default()
+    }
+}
+
+pub fn router(state: AppState) -> Router<AppState> {
+    // Rate limits come first, so floods never reach the audit log
     Router::new()
-        .route("/auth/login", post(login))
+        .route(
+            "/auth/login",
+            post(login)
+                .route_layer(middleware::from_fn_with_state(
+                    (state.clone(), AUTH_AUDIT),
+                    audit,
+                ))
+                .route_layer(middleware::from_fn_with_state(
+                    (state.clone(), &LOGIN_PER_EMAIL),
+                    rate_limit,
+                ))
+                .route_layer(middleware::from_fn_with_state(
+                    (state.clone(), &LOGIN_PER_IP),
+                    rate_limit,
+                )),
+        )
         .route("/auth/logout", post(logout))
+        .route(
+            "/auth/refresh",
+            post(refresh).route_layer(middleware::from_fn_with_state(
+                (state, &REFRESH_PER_IP),
+                rate_limit,
+            )),
+        )
 }
 
-async fn login(State(state): State<AppState>, Json(payload): Json<LoginRequest>) -> Response {
-    match state
-        .firebase_auth
-        .login(payload.email, payload.password)
-        .await
-    {
-        Ok(auth_response) => {
-            let expires_in = auth_response.expires_in.parse().unwrap_or(3600);
-            let mut response = StatusCode::OK.into_response();
+async fn login(
+    State(state): State<AppState>,
+    extensions: Extensions,
+    Json(payload): Json<LoginRequest>,
+) -> Response {
+    let session = state
+        .identity
+        .login(payload.email.clone(), payload.password)
+        .await;
+    if let Err(AuthError::InvalidCredentials) = session {
+        let client_ip = client_ip(&extensions);
+        state.notifier.login_failed(&payload.email, client_ip).await;
+    }
+
+    match session {
+        Ok(session) => {
+            let mut response = Json(LoginResponse {
+                uid: session.uid,
+                email: session.email,
+            })
+            .into_response();
             set_auth_cookies(
                 &mut response,
-                &auth_response.id_token,
-                &auth_response.refresh_token,
-                expires_in,
+                &session.tokens.id_token,
+                &session.tokens.refresh_token,
+                session.tokens.expires_in,
             );
             response
         }
-        Err(err) => {
-            let status = match err {
-                AuthError::InvalidCredentials => StatusCode::UNAUTHORIZED,
-                AuthError::UserDisabled => StatusCode::FORBIDDEN,
-                AuthError::TooManyAttempts => StatusCode::TOO_MANY_REQUESTS,
-                AuthError::NetworkError | AuthError::InvalidResponse => {
-                    StatusCode::INTERNAL_SERVER_ERROR
-                }
-                _ => StatusCode::BAD_REQUEST,
-            };
-            (status, format!("{:?}", err)).into_response()
+        Err(err) => match err {
+            AuthError::InvalidCredentials => (StatusCode::UNAUTHORIZED, "Invalid credentials"),
+            AuthError::UserDisabled => (StatusCode::FORBIDDEN, "User disabled"),
+            AuthError::TooManyAttempts => (
+                StatusCode::TOO_MANY_REQUESTS,
+                "Too many attempts, try later",
+            ),
+            AuthError::NetworkError | AuthError::InvalidResponse => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Authentication service error",
+            ),
+            _ => (StatusCode::INTERNAL_SERVER_ERROR, "Authentication failed"),
         }
+        .into_response(),
     }
 }
 
@@ -53,3 +136,32 @@ async fn logout() -> Response {
     clear_auth_cookies(&mut response);
     response
 }
+
+async fn refresh(State(state): State<AppState>, headers: HeaderMap) -> Response {
+    let refresh_token = headers
+        .get(COOKIE)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|cookies| parse_cookie(cookies, REFRESH_TOKEN_COOKIE));
+    let Some(refresh_token) = refresh_token else {
+        return (StatusCode::UNAUTHORIZED, "Missing refresh token").into_response();
+    };
+
+    match state.identity.refresh(&refresh_token).await {
+        Ok(refreshed) => {
+            let mut response = StatusCode::OK.into_response();
+            set_auth_cookies(
+                &mut response,
+                &refreshed.id_token,
+                &refreshed.refresh_token,
+                refreshed.expires_in,
+            );
+            response
+        }
+        Err(err) => match err {
+            AuthError::InvalidRefreshToken => (StatusCode::UNAUTHORIZED, "Invalid refresh token"),
+            AuthError::ExpiredToken => (StatusCode::UNAUTHORIZED, "Refresh token expired"),
+            _ => (StatusCode::INTERNAL_SERVER_ERROR, "Token refresh failed"),
+        }
+        .into_response(),
+    }
+}
@@ -1,143 +1,351 @@
 use axum::{
     extract::{Path, Query, State},
-    http::StatusCode,
+    http::{Method, StatusCode},
+    middleware,
     routing::{delete, get, patch, post},
-    Json, Router,
-};
-use proto::blog::{
-    BlogPost, BlogPostSummary, CreateBlogPostRequest, CreateBlogPostResponse,
-    DeleteBlogPostResponse, GetBlogPostResponse, GetBlogPostsRequest, GetBlogPostsResponse,
-    RestoreBlogPostResponse, UpdateBlogPostRequest, UpdateBlogPostResponse,
+    Extension, Json, Router,
 };
+use serde_json::{json, Map, Value};
 
+use crate::dao::blog::{BlogPost, BlogPostUpdate, PageStart};
+use crate::dto::auth::{Claims, Role};
+use crate::dto::blog::{
+    BlogPostListResponse, CreateBlogPostRequest, CreateBlogPostResponse, ListBlogPostsQuery,
+    UnpublishBlogPostQuery, UpdateBlogPostRequest,
+};
+use crate::middleware::{
+    audit, decode_json, rate_limit, require_role, AuditDetails, RateLimitKey, RateLimitRule,
+    RestAuditRule, RestAuditRules, RestCall,
+};
+use crate::services::blog::{decode_page_token, encode_page_token, parse_search_query};
 use crate::state::AppState;
+use crate::utils::slugify;
 
-pub fn public_router() -> Router<AppState> {
+/// Status code with a plain-text message, like a gRPC status
+type ApiError = (StatusCode, String);
+
+fn internal(e: sqlx::Error) -> ApiError {
+    (StatusCode::INTERNAL_SERVER_ERROR, e.to_string())
+}
+
+fn not_found() -> ApiError {
+    (StatusCode::NOT_FOUND, "Blog post not found".to_string())
+}
+
+fn found(found: bool) -> Result<StatusCode, ApiError> {
+    if found {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(not_found())
+    }
+}
+
+/// Per-IP limit, sharing buckets with the gRPC BlogService's
+static PUBLIC_PER_IP: RateLimitRule = RateLimitRule {
+    method: None,
+    name: "public-ip",
+    key: RateLimitKey::ClientIp,
+    limit: |config| config.public_rate_limit_per_ip,
+};
+
+pub fn public_router(state: AppState) -> Router<AppState> {
     Router::new()
-        .route("/blog", post(list_posts))
+        .route("/blog", get(list_posts))
         .route("/blog/{id}", get(get_post))
+        .route_layer(middleware::from_fn_with_state(
+            (state, &PUBLIC_PER_IP),
+            rate_limit,
+        ))
 }
 
-pub fn protected_router() -> Router<AppState> {
-    Router::new()
+/// Write routes, with the same roles as `BLOG_ADMIN_PERMISSIONS` gives the
+/// matching RPCs. Every one of them is audited.
+pub fn protected_router(state: AppState) -> Router<AppState> {
+    let editor = Router::new()
         .route("/blog", post(create_post))
         .route("/blog/{id}", patch(update_post))
+        .route("/blog/{id}/publish", post(publish_post))
+        .route("/blog/{id}/unpublish", post(unpublish_post))
+        .route_layer(middleware::from_fn_with_state(
+            (state.clone(), BLOG_AUDIT),
+            audit,
+        ))
+        .route_layer(middleware::from_fn_with_state(Role::Editor, require_role));
+
+    let admin = Router::new()
         .route("/blog/{id}", delete(delete_post))
         .route("/blog/{id}/restore", post(restore_post))
+        .route_layer(middleware::from_fn_with_state((state, BLOG_AUDIT), audit))
+        .route_layer(middleware::from_fn_with_state(Role::Admin, require_role));
+
+    editor.merge(admin)
+}
+
+/// Write routes recorded in the audit log, under the names `BLOG_ADMIN_AUDIT`
+/// records the matching RPCs with
+const BLOG_AUDIT: RestAuditRules = &[
+    RestAuditRule {
+        method: Method::POST,
+        path: "/blog",
+        action: "CreateBlogPost",
+        describe: describe_create,
+    },
+    RestAuditRule {
+        method: Method::PATCH,
+        path: "/blog/{id}",
+        action: "UpdateBlogPost",
+        describe: describe_update,
+    },
+    RestAuditRule {
+        method: Method::DELETE,
+        path: "/blog/{id}",
+        action: "DeleteBlogPost",
+        describe: describe_post,
+    },
+    RestAuditRule {
+        method: Method::POST,
+        path: "/blog/{id}/restore",
+        action: "RestoreBlogPost",
+        describe: describe_post,
+    },
+    RestAuditRule {
+        method: Method::POST,
+        path: "/blog/{id}/publish",
+        action: "PublishBlogPost",
+        describe: describe_post,
+    },
+    RestAuditRule {
+        method: Method::POST,
+        path: "/blog/{id}/unpublish",
+        action: "UnpublishBlogPost",
+        describe: describe_unpublish,
+    },
+];
+
+fn describe_create(call: &RestCall) -> AuditDetails {
+    let changes = decode_json::<CreateBlogPostRequest>(call.request).map(|req| {
+        json!({
+            "title": req.title,
+            "description": req.description,
+            "body_length": req.body.len(),
+            "tags": req.tags,
+        })
+    });
+    AuditDetails {
+        target_id: call
+            .response
+            .and_then(decode_json::<Value>)
+            .and_then(|res| res["id"].as_i64()),
+        changes: changes.unwrap_or_default(),
+        ..Default::default()
+    }
+}
+
+fn describe_update(call: &RestCall) -> AuditDetails {
+    let mut changes = Map::new();
+    if let Some(req) = decode_json::<UpdateBlogPostRequest>(call.request) {
+        if let Some(title) = req.title {
+            changes.insert("title".to_string(), title.into());
+        }
+        if let Some(description) = req.description {
+            changes.insert("description".to_string(), description.into());
+        }
+        if let Some(body) = req.body {
+            changes.insert("body_length".to_string(), body.len().into());
+        }
+        if let Some(tags) = req.tags {
+            changes.insert("tags".to_string(), tags.into());
+        }
+        if let Some(slug) = req.slug {
+            changes.insert("slug".to_string(), slug.into());
+        }
+    }
+    AuditDetails {
+        target_id: call.id,
+        changes: changes.into(),
+        ..Default::default()
+    }
+}
+
+fn describe_post(call: &RestCall) -> AuditDetails {
+    AuditDetails {
+        target_id: call.id,
+        ..Default::default()
+    }
+}
+
+fn describe_unpublish(call: &RestCall) -> AuditDetails {
+    let archive = Query::<UnpublishBlogPostQuery>::try_from_uri(call.uri)
+        .is_ok_and(|Query(query)| query.archive);
+    AuditDetails {
+        changes: json!({ "archive": archive }),
+        ..describe_post(call)
+    }
 }
 
 async fn list_posts(
     State(state): State<AppState>,
-    Json(req): Json<GetBlogPostsRequest>,
-) -> Result<Json<GetBlogPostsResponse>, StatusCode> {
-    let (posts, total) = state
+    Query(req): Query<ListBlogPostsQuery>,
+) -> Result<Json<BlogPostListResponse>, ApiError> {
+    let query = req
+        .query
+        .as_deref()
+        .map(parse_search_query)
+        .transpose()
+        .map_err(|e| (StatusCode::BAD_REQUEST, e.to_string()))?
+        .flatten();
+
+    let start = match req.page_token.as_deref().filter(|t| !t.is_empty()) {
+        Some(token) => decode_page_token(token, req.sort, query.is_some())
+            .map(PageStart::After)
+            .map_err(|e| (StatusCode::BAD_REQUEST, e.to_string()))?,
+        None => PageStart::Offset(req.offset),
+    };
+
+    let page = state
         .blog_service
-        .list(req.query.as_deref(), req.limit, req.offset, req.sort.into())
+        .list(
+            query.as_deref(),
+            &req.tags(),
+            req.markers(),
+            req.limit,
+            start,
+            req.sort,
+        )
         .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
-
-    let posts = posts
-        .into_iter()
-        .map(|p| BlogPostSummary {
-            id: p.id,
-            title: p.title,
-            description: p.description,
-            created_at: p.created_at,
-            updated_at: p.updated_at,
-        })
-        .collect();
+        .map_err(internal)?;
 
-    Ok(Json(GetBlogPostsResponse { posts, total }))
+    Ok(Json(BlogPostListResponse {
+        posts: page.posts,
+        total: page.total,
+        next_page_token: page.next.as_ref().map(encode_page_token),
+    }))
 }
 
 async fn get_post(
     State(state): State<AppState>,
     Path(id): Path<i64>,
-) -> Result<Json<GetBlogPostResponse>, StatusCode> {
-    let post = state
-        .blog_service
-        .get(id)
-        .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
-
-    let post = post.map(|p| BlogPost {
-        id: p.id,
-        title: p.title,
-        description: p.description,
-        body: p.body,
-        created_at: p.created_at,
-        updated_at: p.updated_at,
-    });
+) -> Result<Json<BlogPost>, ApiError> {
+    let post = state.blog_service.get(id).await.map_err(internal)?;
 
-    Ok(Json(GetBlogPostResponse { post }))
+    post.map(Json).ok_or_else(not_found)
 }
 
 async fn create_post(
     State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
     Json(req): Json<CreateBlogPostRequest>,
-) -> Result<Json<CreateBlogPostResponse>, StatusCode> {
+) -> Result<(StatusCode, Json<CreateBlogPostResponse>), ApiError> {
     let id = state
         .blog_service
-        .create(&req.title, &req.description, &req.body)
+        .create(
+            &req.title,
+            &req.description,
+            &req.body,
+            &req.tags,
+            Some(&claims.sub),
+        )
         .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        .map_err(internal)?;
 
-    Ok(Json(CreateBlogPostResponse { id }))
+    Ok((StatusCode::CREATED, Json(CreateBlogPostResponse { id })))
 }
 
 async fn update_post(
     State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
     Path(id): Path<i64>,
     Json(req): Json<UpdateBlogPostRequest>,
-) -> Result<Json<UpdateBlogPostResponse>, StatusCode> {
+) -> Result<StatusCode, ApiError> {
+    let slug = req.slug.as_deref().map(slugify);
+    if let Some(slug) = &slug {
+        if slug.is_empty() {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                "Slug must contain letters or digits".to_string(),
+            ));
+        }
+
+        let taken = state
+            .blog_service
+            .slug_taken(slug, id)
+            .await
+            .map_err(internal)?;
+        if taken {
+            return Err((StatusCode::CONFLICT, "Slug is already in use".to_string()));
+        }
+    }
+
+    let changes = BlogPostUpdate {
+        title: req.title.as_deref(),
+        description: req.description.as_deref(),
+        body: req.body.as_deref(),
+        tags: req.tags.as_deref(),
+        slug: slug.as_deref(),
+    };
+
     let updated = state
         .blog_service
-        .update(
-            id,
-            req.title.as_deref(),
-            req.description.as_deref(),
-            req.body.as_deref(),
-        )
+        .update(id, changes, Some(&claims.sub))
         .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        .map_err(internal)?;
 
-    if updated {
-        Ok(Json(UpdateBlogPostResponse {}))
-    } else {
-        Err(StatusCode::NOT_FOUND)
-    }
+    found(updated)
 }
 
 async fn delete_post(
     State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
     Path(id): Path<i64>,
-) -> Result<Json<DeleteBlogPostResponse>, StatusCode> {
+) -> Result<StatusCode, ApiError> {
     let deleted = state
         .blog_service
-        .delete(id)
+        .delete(id, Some(&claims.sub))
         .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        .map_err(internal)?;
 
-    if deleted {
-        Ok(Json(DeleteBlogPostResponse {}))
-    } else {
-        Err(StatusCode::NOT_FOUND)
-    }
+    found(deleted)
 }
 
 async fn restore_post(
     State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
     Path(id): Path<i64>,
-) -> Result<Json<RestoreBlogPostResponse>, StatusCode> {
+) -> Result<StatusCode, ApiError> {
     let restored = state
         .blog_service
-        .restore(id)
+        .restore(id, Some(&claims.sub))
         .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        .map_err(internal)?;
 
-    if restored {
-        Ok(Json(RestoreBlogPostResponse {}))
-    } else {
-        Err(StatusCode::NOT_FOUND)
-    }
+    found(restored)
+}
+
+async fn publish_post(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<i64>,
+) -> Result<StatusCode, ApiError> {
+    let published = state
+        .blog_service
+        .publish(id, Some(&claims.sub))
+        .await
+        .map_err(internal)?;
+
+    found(published)
+}
+
+async fn unpublish_post(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<i64>,
+    Query(query): Query<UnpublishBlogPostQuery>,
+) -> Result<StatusCode, ApiError> {
+    let unpublished = state
+        .blog_service
+        .unpublish(id, query.archive, Some(&claims.sub))
+        .await
+        .map_err(internal)?;
+
+    found(unpublished)
 }
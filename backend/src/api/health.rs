@@ -1,11 +1,25 @@
-use axum::{extract::State, routing::get, Router};
+use axum::{extract::State, http::StatusCode, routing::get, Json, Router};
 
+use crate::dto::auth::KeyCacheStatus;
 use crate::state::AppState;
 
 pub fn router() -> Router<AppState> {
-    Router::new().route("/health", get(health_check))
+    Router::new()
+        .route("/health", get(health_check))
+        .route("/health/keys", get(key_cache))
 }
 
 async fn health_check(State(_state): State<AppState>) -> &'static str {
     "ok"
 }
+
+/// Public key cache state, for monitoring key rotation. Not found when the
+/// identity provider doesn't fetch keys.
+async fn key_cache(State(state): State<AppState>) -> Result<Json<KeyCacheStatus>, StatusCode> {
+    state
+        .identity
+        .key_cache_status()
+        .await
+        .map(Json)
+        .ok_or(StatusCode::NOT_FOUND)
+}
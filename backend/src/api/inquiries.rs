@@ -1,12 +1,239 @@
-use axum::{http::StatusCode, routing::get, Router};
+use axum::{
+    extract::{Path, Query, State},
+    http::{Extensions, Method, StatusCode},
+    middleware,
+    routing::{delete, get, post},
+    Extension, Json, Router,
+};
 
+use crate::dao::inquiry::Inquiry;
+use crate::dto::auth::{Claims, Role};
+use crate::dto::inquiry::{
+    FormTokenResponse, InquiryListResponse, ListInquiriesQuery, MarkInquirySpamRequest,
+    SubmitInquiryRequest,
+};
+use crate::middleware::{
+    audit, client_ip, decode_json, rate_limit, require_role, AuditDetails, RateLimitKey,
+    RateLimitRule, RestAuditRule, RestAuditRules, RestCall,
+};
+use crate::services::inquiry::validate_inquiry;
+use crate::services::spam::Submission;
 use crate::state::AppState;
 
-pub fn router() -> Router<AppState> {
-    Router::new().route("/inquiries", get(get_inquiries))
+/// Status code with a plain-text message, like a gRPC status
+type ApiError = (StatusCode, String);
+
+/// Per-IP limit, sharing buckets with the gRPC InquiryService's
+static INQUIRY_PER_IP: RateLimitRule = RateLimitRule {
+    method: None,
+    name: "inquiry-ip",
+    key: RateLimitKey::ClientIp,
+    limit: |config| config.inquiry_rate_limit_per_ip,
+};
+
+fn internal(e: sqlx::Error) -> ApiError {
+    (StatusCode::INTERNAL_SERVER_ERROR, e.to_string())
+}
+
+fn not_found() -> ApiError {
+    (StatusCode::NOT_FOUND, "Inquiry not found".to_string())
+}
+
+fn found(found: bool) -> Result<StatusCode, ApiError> {
+    if found {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(not_found())
+    }
+}
+
+/// The contact form
+pub fn public_router(state: AppState) -> Router<AppState> {
+    Router::new()
+        .route("/inquiries/form-token", get(form_token))
+        .route(
+            "/inquiries",
+            post(submit_inquiry).route_layer(middleware::from_fn_with_state(
+                (state, &INQUIRY_PER_IP),
+                rate_limit,
+            )),
+        )
+}
+
+/// The inbox, with the same roles as `INQUIRY_ADMIN_PERMISSIONS` gives the
+/// matching RPCs. Changes to inquiries are audited.
+pub fn protected_router(state: AppState) -> Router<AppState> {
+    let editor = Router::new()
+        .route("/inquiries", get(list_inquiries))
+        .route("/inquiries/{id}", get(get_inquiry))
+        .route("/inquiries/{id}/handled", post(mark_handled))
+        .route("/inquiries/{id}/archive", post(archive_inquiry))
+        .route("/inquiries/{id}/spam", post(mark_spam))
+        .route_layer(middleware::from_fn_with_state(
+            (state.clone(), INQUIRY_AUDIT),
+            audit,
+        ))
+        .route_layer(middleware::from_fn_with_state(Role::Editor, require_role));
+
+    let admin = Router::new()
+        .route("/inquiries/{id}", delete(delete_inquiry))
+        .route_layer(middleware::from_fn_with_state(
+            (state, INQUIRY_AUDIT),
+            audit,
+        ))
+        .route_layer(middleware::from_fn_with_state(Role::Admin, require_role));
+
+    editor.merge(admin)
+}
+
+/// Inbox routes recorded in the audit log, under the names
+/// `INQUIRY_ADMIN_AUDIT` records the matching RPCs with
+const INQUIRY_AUDIT: RestAuditRules = &[
+    RestAuditRule {
+        method: Method::POST,
+        path: "/inquiries/{id}/handled",
+        action: "MarkInquiryHandled",
+        describe: describe_inquiry,
+    },
+    RestAuditRule {
+        method: Method::POST,
+        path: "/inquiries/{id}/archive",
+        action: "ArchiveInquiry",
+        describe: describe_inquiry,
+    },
+    RestAuditRule {
+        method: Method::POST,
+        path: "/inquiries/{id}/spam",
+        action: "MarkInquirySpam",
+        describe: describe_mark_spam,
+    },
+    RestAuditRule {
+        method: Method::DELETE,
+        path: "/inquiries/{id}",
+        action: "DeleteInquiry",
+        describe: describe_inquiry,
+    },
+];
+
+fn describe_inquiry(call: &RestCall) -> AuditDetails {
+    AuditDetails {
+        target_id: call.id,
+        ..Default::default()
+    }
+}
+
+fn describe_mark_spam(call: &RestCall) -> AuditDetails {
+    let spam = decode_json::<MarkInquirySpamRequest>(call.request).map(|req| req.spam);
+    AuditDetails {
+        changes: serde_json::json!({ "spam": spam }),
+        ..describe_inquiry(call)
+    }
+}
+
+async fn form_token(State(state): State<AppState>) -> Json<FormTokenResponse> {
+    Json(FormTokenResponse {
+        form_token: state.spam_filter.form_token(),
+    })
+}
+
+async fn submit_inquiry(
+    State(state): State<AppState>,
+    extensions: Extensions,
+    Json(req): Json<SubmitInquiryRequest>,
+) -> Result<StatusCode, ApiError> {
+    let inquiry = validate_inquiry(&req.name, &req.email, &req.subject, &req.message)
+        .map_err(|e| (StatusCode::BAD_REQUEST, e.to_string()))?;
+
+    let spam = state
+        .spam_filter
+        .check(&Submission {
+            client_ip: client_ip(&extensions),
+            honeypot: &req.website,
+            form_token: &req.form_token,
+            text: &[
+                &inquiry.name,
+                &inquiry.email,
+                &inquiry.subject,
+                &inquiry.message,
+            ],
+        })
+        .await
+        .map_err(internal)?;
+
+    // Same response either way, so spammers can't tell they were caught
+    state
+        .inquiry_service
+        .submit(&inquiry, &spam)
+        .await
+        .map_err(internal)?;
+
+    Ok(StatusCode::ACCEPTED)
+}
+
+async fn list_inquiries(
+    State(state): State<AppState>,
+    Query(req): Query<ListInquiriesQuery>,
+) -> Result<Json<InquiryListResponse>, ApiError> {
+    let (inquiries, total) = state
+        .inquiry_service
+        .list(req.status, req.limit, req.offset)
+        .await
+        .map_err(internal)?;
+
+    Ok(Json(InquiryListResponse { inquiries, total }))
+}
+
+async fn get_inquiry(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Json<Inquiry>, ApiError> {
+    let inquiry = state.inquiry_service.get(id).await.map_err(internal)?;
+
+    inquiry.map(Json).ok_or_else(not_found)
 }
 
-// TODO: Implement actual inquiry fetching from database
-async fn get_inquiries() -> StatusCode {
-    StatusCode::OK
+async fn mark_handled(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<i64>,
+) -> Result<StatusCode, ApiError> {
+    let handled = state
+        .inquiry_service
+        .mark_handled(id, Some(&claims.sub))
+        .await
+        .map_err(internal)?;
+
+    found(handled)
+}
+
+async fn archive_inquiry(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<StatusCode, ApiError> {
+    let archived = state.inquiry_service.archive(id).await.map_err(internal)?;
+
+    found(archived)
+}
+
+async fn mark_spam(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Json(req): Json<MarkInquirySpamRequest>,
+) -> Result<StatusCode, ApiError> {
+    let marked = state
+        .inquiry_service
+        .mark_spam(id, req.spam)
+        .await
+        .map_err(internal)?;
+
+    found(marked)
+}
+
+async fn delete_inquiry(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<StatusCode, ApiError> {
+    let deleted = state.inquiry_service.delete(id).await.map_err(internal)?;
+
+    found(deleted)
 }
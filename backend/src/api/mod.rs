@@ -4,9 +4,7 @@ mod health;
 mod inquiries;
 
 use axum::{middleware, Router};
-use http::header::{HeaderName, AUTHORIZATION, CONTENT_TYPE};
-use http::Method;
-use tower_http::cors::CorsLayer;
+use http::header::HeaderName;
 use tower_http::request_id::{MakeRequestUuid, PropagateRequestIdLayer, SetRequestIdLayer};
 use tower_http::trace::{DefaultMakeSpan, DefaultOnResponse, TraceLayer};
 use tracing::Level;
@@ -16,47 +14,26 @@ use crate::{middleware::require_auth, state::AppState};
 /// Header name for request correlation ID
 const X_REQUEST_ID: &str = "x-request-id";
 
+/// JSON REST API for clients that don't speak gRPC-Web, such as curl and
+/// scripts. CORS is handled by the server-wide layer in `main`.
 pub fn router(state: AppState) -> Router {
-    let cors = CorsLayer::new()
-        .allow_origin(
-            state
-                .config
-                .cors_origin
-                .parse::<http::HeaderValue>()
-                .unwrap(),
-        )
-        .allow_methods([
-            Method::GET,
-            Method::POST,
-            Method::PUT,
-            Method::PATCH,
-            Method::DELETE,
-            Method::OPTIONS,
-        ])
-        .allow_headers([AUTHORIZATION, CONTENT_TYPE])
-        .allow_credentials(true);
-
     let x_request_id = HeaderName::from_static(X_REQUEST_ID);
 
     let trace_layer = TraceLayer::new_for_http()
-        .make_span_with(
-            DefaultMakeSpan::new()
-                .level(Level::INFO)
-                .include_headers(true),
-        )
+        .make_span_with(DefaultMakeSpan::new().level(Level::INFO))
         .on_response(DefaultOnResponse::new().level(Level::INFO));
 
     let protected = Router::new()
-        .merge(inquiries::router())
-        .merge(blog::protected_router())
+        .merge(inquiries::protected_router(state.clone()))
+        .merge(blog::protected_router(state.clone()))
         .route_layer(middleware::from_fn_with_state(state.clone(), require_auth));
 
     Router::new()
-        .merge(auth::router())
+        .merge(auth::router(state.clone()))
         .merge(health::router())
-        .merge(blog::public_router())
+        .merge(blog::public_router(state.clone()))
+        .merge(inquiries::public_router(state.clone()))
         .merge(protected)
-        .layer(cors)
         .layer(trace_layer)
         .layer(PropagateRequestIdLayer::new(x_request_id.clone()))
         .layer(SetRequestIdLayer::new(x_request_id, MakeRequestUuid))
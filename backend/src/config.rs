@@ -1,30 +1,252 @@
 use std::env;
 use std::net::{IpAddr, SocketAddr};
+use std::num::NonZeroU64;
 use std::str::FromStr;
 
+use ipnet::IpNet;
+
+/// Where admin accounts live and who signs their tokens
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdentityProviderKind {
+    Firebase,
+    /// Credentials in SQLite and self-signed tokens; works offline
+    Local,
+}
+
+impl FromStr for IdentityProviderKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "firebase" => Ok(Self::Firebase),
+            "local" => Ok(Self::Local),
+            _ => Err(format!("unknown identity provider '{s}', expected firebase or local")),
+        }
+    }
+}
+
+/// How the SMTP connection is secured
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SmtpTls {
+    /// Plain text throughout; only for local SMTP sinks
+    None,
+    /// Upgrade a plain connection with `STARTTLS`, usually on port 587
+    StartTls,
+    /// TLS from the start, usually on port 465
+    Tls,
+}
+
+impl FromStr for SmtpTls {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "none" => Ok(Self::None),
+            "starttls" => Ok(Self::StartTls),
+            "tls" => Ok(Self::Tls),
+            _ => Err(format!("unknown SMTP TLS mode '{s}', expected none, starttls or tls")),
+        }
+    }
+}
+
+/// A token bucket allowing `capacity` requests in a burst, refilled at
+/// `capacity` tokens every `period_secs`. Written as `capacity/period_secs`,
+/// e.g. `5/300` for five requests per five minutes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimit {
+    pub capacity: u32,
+    pub period_secs: u64,
+}
+
+impl RateLimit {
+    /// Tokens added back per second
+    pub fn refill_rate(&self) -> f64 {
+        self.capacity as f64 / self.period_secs as f64
+    }
+}
+
+impl FromStr for RateLimit {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("invalid rate limit '{s}', expected capacity/seconds");
+        let (capacity, period_secs) = s.split_once('/').ok_or_else(invalid)?;
+        let capacity: u32 = capacity.trim().parse().map_err(|_| invalid())?;
+        let period_secs: u64 = period_secs.trim().parse().map_err(|_| invalid())?;
+        if capacity == 0 || period_secs == 0 {
+            return Err(invalid());
+        }
+        Ok(Self {
+            capacity,
+            period_secs,
+        })
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Config {
     pub host: IpAddr,
     pub port: u16,
     pub cors_origin: String,
+    /// Reverse proxies whose `X-Forwarded-For` header is believed; clients
+    /// connecting directly can't spoof their address with it
+    pub trusted_proxies: Vec<IpNet>,
     pub database_path: String,
+    pub identity_provider: IdentityProviderKind,
     pub firebase_project_id: String,
     pub firebase_api_key: String,
+    /// Identity Toolkit base URL, for password sign-in
+    pub firebase_auth_url: String,
+    /// Secure Token base URL, for refreshing ID tokens
+    pub firebase_token_url: String,
+    /// Where the X.509 certificates that sign ID tokens are published
+    pub firebase_public_keys_url: String,
+    /// Accept unsigned (`alg: none`) ID tokens, as issued by the Firebase Auth
+    /// Emulator. Never enable against real Firebase.
+    pub firebase_allow_unsigned_tokens: bool,
+    /// Admin account the local provider creates or updates at startup
+    pub local_admin_email: Option<String>,
+    pub local_admin_password: Option<String>,
+    /// How often scheduled blog posts are checked for publication
+    pub publish_interval_secs: u64,
+    /// How often dependencies are probed for the gRPC health service
+    pub health_check_interval_secs: u64,
+    /// Login attempts allowed from one client IP
+    pub login_rate_limit_per_ip: RateLimit,
+    /// Login attempts allowed against one email address, from any IP
+    pub login_rate_limit_per_email: RateLimit,
+    /// Token refreshes allowed from one client IP
+    pub refresh_rate_limit_per_ip: RateLimit,
+    /// Public BlogService calls allowed from one client IP
+    pub public_rate_limit_per_ip: RateLimit,
+    /// Contact form submissions allowed from one client IP
+    pub inquiry_rate_limit_per_ip: RateLimit,
+    /// Save rate limit buckets to SQLite so limits survive restarts
+    pub rate_limit_persist: bool,
+    /// Spam score from which public submissions are quarantined
+    pub spam_threshold: f64,
+    /// Submissions sent sooner after loading the form are taken for bots
+    pub spam_min_submit_secs: u64,
+    pub spam_form_token_max_age_secs: u64,
+    /// Key form tokens are signed with; random per process if unset
+    pub spam_form_secret: Option<String>,
+    /// Public submissions from one client IP before they count as suspicious
+    pub spam_submissions_per_ip: RateLimit,
+    /// Links a submission may contain before it counts as suspicious
+    pub spam_max_links: usize,
+    /// Words or domains that mark a submission as spam, case-insensitively
+    pub spam_blocklist: Vec<String>,
+    /// Also score submissions with the classifier trained from admin verdicts
+    pub spam_classifier: bool,
+    /// SMTP server notification emails are sent through; none are sent if unset
+    pub smtp_host: Option<String>,
+    pub smtp_port: u16,
+    pub smtp_tls: SmtpTls,
+    pub smtp_username: Option<String>,
+    pub smtp_password: Option<String>,
+    /// Sender of notification emails
+    pub email_from: String,
+    /// Recipient of notification emails, usually the site owner; none are
+    /// sent if unset
+    pub email_to: Option<String>,
+    /// How often the outbox is checked for emails due to be sent
+    pub email_outbox_interval_secs: u64,
+    /// Failed logins, across all accounts, that trigger a notification when
+    /// they happen within the period, as count/seconds
+    pub notify_failed_logins: RateLimit,
+    /// How often the webhook queue is checked for deliveries due to be sent
+    pub webhook_delivery_interval_secs: u64,
+    /// Longest a webhook endpoint may take to respond before the attempt
+    /// counts as failed
+    pub webhook_timeout_secs: u64,
+    /// Let webhooks reach loopback, private and link-local addresses, which
+    /// are refused by default so subscriptions can't probe the internal network
+    pub webhook_allow_private_urls: bool,
+    /// Public URL of the frontend, used for links to posts
+    pub site_url: String,
+    /// Public URL of this backend, used for feed self links
+    pub public_url: String,
+    pub site_title: String,
 }
 
 impl Config {
     pub fn from_env() -> Self {
         dotenvy::dotenv().ok();
 
+        let identity_provider = env_parse("IDENTITY_PROVIDER", "firebase");
+        let firebase_var = |key: &str| {
+            if identity_provider == IdentityProviderKind::Firebase {
+                env::var(key).unwrap_or_else(|_| panic!("{key} must be set"))
+            } else {
+                env_var(key, "")
+            }
+        };
+
         Self {
             host: env_parse("HOST", "0.0.0.0"),
             port: env_parse("PORT", "3000"),
             cors_origin: env_var("CORS_ORIGIN", "http://localhost:5173"),
+            trusted_proxies: env_var("TRUSTED_PROXIES", "")
+                .split(',')
+                .map(str::trim)
+                .filter(|proxy| !proxy.is_empty())
+                .map(parse_proxy)
+                .collect(),
             database_path: "sqlite:data/portfolio.db".to_string(),
-            firebase_project_id: env::var("FIREBASE_PROJECT_ID")
-                .expect("FIREBASE_PROJECT_ID must be set"),
-            firebase_api_key: env::var("FIREBASE_API_KEY")
-                .expect("FIREBASE_API_KEY must be set"),
+            identity_provider,
+            firebase_project_id: firebase_var("FIREBASE_PROJECT_ID"),
+            firebase_api_key: firebase_var("FIREBASE_API_KEY"),
+            firebase_auth_url: trim_url(env_var(
+                "FIREBASE_AUTH_URL",
+                "https://identitytoolkit.googleapis.com",
+            )),
+            firebase_token_url: trim_url(env_var(
+                "FIREBASE_TOKEN_URL",
+                "https://securetoken.googleapis.com",
+            )),
+            firebase_public_keys_url: env_var(
+                "FIREBASE_PUBLIC_KEYS_URL",
+                "https://www.googleapis.com/robot/v1/metadata/x509/securetoken@system.gserviceaccount.com",
+            ),
+            firebase_allow_unsigned_tokens: env_parse("FIREBASE_ALLOW_UNSIGNED_TOKENS", "false"),
+            local_admin_email: env::var("LOCAL_ADMIN_EMAIL").ok(),
+            local_admin_password: env::var("LOCAL_ADMIN_PASSWORD").ok(),
+            publish_interval_secs: env_interval("PUBLISH_INTERVAL_SECS", "60"),
+            health_check_interval_secs: env_interval("HEALTH_CHECK_INTERVAL_SECS", "15"),
+            login_rate_limit_per_ip: env_parse("LOGIN_RATE_LIMIT_PER_IP", "20/300"),
+            login_rate_limit_per_email: env_parse("LOGIN_RATE_LIMIT_PER_EMAIL", "5/300"),
+            refresh_rate_limit_per_ip: env_parse("REFRESH_RATE_LIMIT_PER_IP", "60/300"),
+            public_rate_limit_per_ip: env_parse("PUBLIC_RATE_LIMIT_PER_IP", "600/60"),
+            inquiry_rate_limit_per_ip: env_parse("INQUIRY_RATE_LIMIT_PER_IP", "5/3600"),
+            rate_limit_persist: env_parse("RATE_LIMIT_PERSIST", "false"),
+            spam_threshold: env_parse("SPAM_THRESHOLD", "1.0"),
+            spam_min_submit_secs: env_parse("SPAM_MIN_SUBMIT_SECS", "3"),
+            spam_form_token_max_age_secs: env_parse("SPAM_FORM_TOKEN_MAX_AGE_SECS", "86400"),
+            spam_form_secret: env::var("SPAM_FORM_SECRET").ok(),
+            spam_submissions_per_ip: env_parse("SPAM_SUBMISSIONS_PER_IP", "3/3600"),
+            spam_max_links: env_parse("SPAM_MAX_LINKS", "2"),
+            spam_blocklist: env_var("SPAM_BLOCKLIST", "")
+                .split(',')
+                .map(str::trim)
+                .filter(|term| !term.is_empty())
+                .map(str::to_string)
+                .collect(),
+            spam_classifier: env_parse("SPAM_CLASSIFIER", "false"),
+            smtp_host: env::var("SMTP_HOST").ok(),
+            smtp_port: env_parse("SMTP_PORT", "587"),
+            smtp_tls: env_parse("SMTP_TLS", "starttls"),
+            smtp_username: env::var("SMTP_USERNAME").ok(),
+            smtp_password: env::var("SMTP_PASSWORD").ok(),
+            email_from: env_var("EMAIL_FROM", "blog@localhost"),
+            email_to: env::var("EMAIL_TO").ok(),
+            email_outbox_interval_secs: env_interval("EMAIL_OUTBOX_INTERVAL_SECS", "30"),
+            notify_failed_logins: env_parse("NOTIFY_FAILED_LOGINS", "10/3600"),
+            webhook_delivery_interval_secs: env_interval("WEBHOOK_DELIVERY_INTERVAL_SECS", "10"),
+            webhook_timeout_secs: env_parse("WEBHOOK_TIMEOUT_SECS", "10"),
+            webhook_allow_private_urls: env_parse("WEBHOOK_ALLOW_PRIVATE_URLS", "false"),
+            site_url: trim_url(env_var("SITE_URL", "http://localhost:5173")),
+            public_url: trim_url(env_var("PUBLIC_URL", "http://localhost:3000")),
+            site_title: env_var("SITE_TITLE", "Blog"),
         }
     }
 
@@ -37,6 +259,24 @@ fn env_var(key: &str, default: &str) -> String {
     env::var(key).unwrap_or_else(|_| default.to_string())
 }
 
+fn trim_url(url: String) -> String {
+    url.trim_end_matches('/').to_string()
+}
+
+/// A proxy's network, or a single address
+fn parse_proxy(proxy: &str) -> IpNet {
+    proxy
+        .parse()
+        .or_else(|_| proxy.parse::<IpAddr>().map(IpNet::from))
+        .unwrap_or_else(|e| panic!("Failed to parse TRUSTED_PROXIES entry {proxy}: {e:?}"))
+}
+
+/// Seconds between runs of a background task. Zero is refused, since
+/// `tokio::time::interval` panics on it.
+fn env_interval(key: &str, default: &str) -> u64 {
+    env_parse::<NonZeroU64>(key, default).get()
+}
+
 fn env_parse<T: FromStr>(key: &str, default: &str) -> T
 where
     T::Err: std::fmt::Debug,
@@ -0,0 +1,20 @@
+use sqlx::types::Json;
+use sqlx::FromRow;
+
+/// A recorded admin RPC call or login attempt
+#[derive(Debug, Clone, FromRow)]
+pub struct AuditEvent {
+    pub id: i64,
+    pub created_at: i64,
+    pub actor_sub: Option<String>,
+    pub actor_email: Option<String>,
+    /// RPC method name, e.g. `DeleteBlogPost`
+    pub action: String,
+    pub target_id: Option<i64>,
+    /// gRPC status code the call finished with
+    pub status_code: i32,
+    pub request_id: Option<String>,
+    pub client_ip: Option<String>,
+    /// Fields the request set, as a JSON object
+    pub changes: Json<serde_json::Value>,
+}
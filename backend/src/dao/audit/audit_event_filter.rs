@@ -0,0 +1,11 @@
+/// Conditions applied to an audit event listing. Unset fields match everything.
+#[derive(Debug, Clone, Default)]
+pub struct AuditEventFilter {
+    /// Caller `sub` or email
+    pub actor: Option<String>,
+    /// RPC method name
+    pub action: Option<String>,
+    /// Inclusive bounds on `created_at`
+    pub since: Option<i64>,
+    pub until: Option<i64>,
+}
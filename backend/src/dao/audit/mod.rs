@@ -0,0 +1,7 @@
+mod audit_event;
+mod audit_event_filter;
+mod new_audit_event;
+
+pub use audit_event::AuditEvent;
+pub use audit_event_filter::AuditEventFilter;
+pub use new_audit_event::NewAuditEvent;
@@ -0,0 +1,12 @@
+/// An audit event about to be recorded
+#[derive(Debug, Clone)]
+pub struct NewAuditEvent {
+    pub actor_sub: Option<String>,
+    pub actor_email: Option<String>,
+    pub action: String,
+    pub target_id: Option<i64>,
+    pub status_code: i32,
+    pub request_id: Option<String>,
+    pub client_ip: Option<String>,
+    pub changes: serde_json::Value,
+}
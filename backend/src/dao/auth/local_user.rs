@@ -0,0 +1,15 @@
+use sqlx::FromRow;
+
+/// An admin account of the local identity provider
+#[derive(Debug, Clone, FromRow)]
+pub struct LocalUser {
+    pub id: i64,
+    pub email: String,
+    /// Argon2 PHC string
+    pub password_hash: String,
+    pub disabled: bool,
+    /// Granted through the `roles` claim, e.g. `admin`
+    pub role: String,
+    #[allow(dead_code)]
+    pub created_at: i64,
+}
@@ -0,0 +1,3 @@
+mod local_user;
+
+pub use local_user::LocalUser;
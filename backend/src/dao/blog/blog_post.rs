@@ -1,13 +1,36 @@
+use serde::Serialize;
+use sqlx::types::Json;
 use sqlx::FromRow;
 
-#[derive(Debug, Clone, FromRow)]
+use super::{BlogPostStatus, TocEntry};
+
+#[derive(Debug, Clone, FromRow, Serialize)]
 pub struct BlogPost {
     pub id: i64,
     pub title: String,
+    pub slug: String,
     pub description: String,
     pub body: String,
+    /// Sanitized HTML rendered from `body`
+    pub body_html: String,
+    pub toc: Json<Vec<TocEntry>>,
+    pub reading_minutes: i32,
     pub created_at: i64,
     pub updated_at: i64,
+    pub status: BlogPostStatus,
+    pub published_at: Option<i64>,
+    #[sqlx(skip)]
+    pub tags: Vec<String>,
     #[allow(dead_code)] // Soft delete support
+    #[serde(skip)]
     pub deleted_at: Option<i64>,
+    /// `sub` of whoever created the post; admin views only
+    #[serde(skip)]
+    pub created_by: Option<String>,
+    /// `sub` of whoever last changed the post; admin views only
+    #[serde(skip)]
+    pub updated_by: Option<String>,
+    /// `sub` of whoever deleted the post; admin views only
+    #[serde(skip)]
+    pub deleted_by: Option<String>,
 }
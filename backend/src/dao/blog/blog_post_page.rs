@@ -0,0 +1,11 @@
+use super::{BlogPostCursor, BlogPostSummary};
+
+/// One page of a blog post listing.
+#[derive(Debug, Clone)]
+pub struct BlogPostPage {
+    pub posts: Vec<BlogPostSummary>,
+    /// Number of posts matching the filter across all pages
+    pub total: i32,
+    /// Set when more posts follow this page
+    pub next: Option<BlogPostCursor>,
+}
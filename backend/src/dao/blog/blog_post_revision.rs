@@ -0,0 +1,13 @@
+use sqlx::FromRow;
+
+/// A snapshot of a post's content, recorded on every create and update.
+#[derive(Debug, Clone, FromRow)]
+pub struct BlogPostRevision {
+    pub post_id: i64,
+    pub revision: i32,
+    pub title: String,
+    pub description: String,
+    pub body: String,
+    pub author_sub: Option<String>,
+    pub created_at: i64,
+}
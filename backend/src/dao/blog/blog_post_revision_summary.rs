@@ -0,0 +1,10 @@
+use sqlx::FromRow;
+
+#[derive(Debug, Clone, FromRow)]
+pub struct BlogPostRevisionSummary {
+    pub post_id: i64,
+    pub revision: i32,
+    pub title: String,
+    pub author_sub: Option<String>,
+    pub created_at: i64,
+}
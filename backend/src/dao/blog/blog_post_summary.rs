@@ -1,10 +1,27 @@
+use serde::Serialize;
 use sqlx::FromRow;
 
-#[derive(Debug, Clone, FromRow)]
+use super::BlogPostStatus;
+
+#[derive(Debug, Clone, FromRow, Serialize)]
 pub struct BlogPostSummary {
     pub id: i64,
     pub title: String,
+    pub slug: String,
     pub description: String,
     pub created_at: i64,
     pub updated_at: i64,
+    pub status: BlogPostStatus,
+    pub published_at: Option<i64>,
+    #[sqlx(skip)]
+    pub tags: Vec<String>,
+    /// Best-matching body fragment; only set when searching
+    pub snippet: Option<String>,
+    /// Title with matched terms marked; only set when searching
+    pub highlighted_title: Option<String>,
+    /// Description with matched terms marked; only set when searching
+    pub highlighted_description: Option<String>,
+    /// FTS5 rank, only set when searching; used for relevance page cursors
+    #[serde(skip)]
+    pub rank: Option<f64>,
 }
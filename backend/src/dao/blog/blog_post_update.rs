@@ -0,0 +1,11 @@
+/// Fields to change on a post. `None` leaves the current value untouched.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BlogPostUpdate<'a> {
+    pub title: Option<&'a str>,
+    pub description: Option<&'a str>,
+    pub body: Option<&'a str>,
+    /// Replaces the full tag set
+    pub tags: Option<&'a [String]>,
+    /// Must already be a valid slug; the old one is kept in the slug history
+    pub slug: Option<&'a str>,
+}
@@ -0,0 +1,25 @@
+use super::BlogPostSort;
+
+/// Sort column value of the last row on a page.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CursorKey {
+    /// `created_at` or `updated_at`, depending on the sort
+    Timestamp(i64),
+    /// FTS5 rank of a search result (lower is better)
+    Rank(f64),
+}
+
+/// Keyset position in a listing: the row after which the next page starts.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BlogPostCursor {
+    pub sort: BlogPostSort,
+    pub key: CursorKey,
+    pub id: i64,
+}
+
+/// Where a page of a listing starts.
+#[derive(Debug, Clone, Copy)]
+pub enum PageStart {
+    Offset(i32),
+    After(BlogPostCursor),
+}
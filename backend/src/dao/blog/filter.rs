@@ -0,0 +1,15 @@
+use super::{BlogPostStatus, BlogPostVisibility, HighlightMarkers};
+
+/// Conditions applied to a blog post listing.
+#[derive(Debug, Clone)]
+pub struct BlogPostFilter {
+    /// FTS5 match expression; empty or absent means no search
+    pub query: Option<String>,
+    /// Only posts carrying every one of these tags
+    pub tags: Vec<String>,
+    pub visibility: BlogPostVisibility,
+    /// Only posts in this status, within what `visibility` allows
+    pub status: Option<BlogPostStatus>,
+    /// Used for snippets and highlights when searching
+    pub markers: HighlightMarkers,
+}
@@ -0,0 +1,16 @@
+/// Strings wrapped around matched terms in search snippets and highlights.
+/// They're inserted as given into otherwise HTML-escaped text.
+#[derive(Debug, Clone)]
+pub struct HighlightMarkers {
+    pub open: String,
+    pub close: String,
+}
+
+impl Default for HighlightMarkers {
+    fn default() -> Self {
+        Self {
+            open: "<mark>".to_string(),
+            close: "</mark>".to_string(),
+        }
+    }
+}
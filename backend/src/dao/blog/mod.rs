@@ -1,7 +1,29 @@
 mod blog_post;
+mod blog_post_page;
+mod blog_post_revision;
+mod blog_post_revision_summary;
 mod blog_post_summary;
+mod blog_post_update;
+mod cursor;
+mod filter;
+mod highlight;
 mod sort;
+mod status;
+mod tag_count;
+mod toc_entry;
+mod visibility;
 
 pub use blog_post::BlogPost;
+pub use blog_post_page::BlogPostPage;
+pub use blog_post_revision::BlogPostRevision;
+pub use blog_post_revision_summary::BlogPostRevisionSummary;
 pub use blog_post_summary::BlogPostSummary;
+pub use blog_post_update::BlogPostUpdate;
+pub use cursor::{BlogPostCursor, CursorKey, PageStart};
+pub use filter::BlogPostFilter;
+pub use highlight::HighlightMarkers;
 pub use sort::BlogPostSort;
+pub use status::BlogPostStatus;
+pub use tag_count::TagCount;
+pub use toc_entry::TocEntry;
+pub use visibility::BlogPostVisibility;
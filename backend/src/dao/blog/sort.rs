@@ -1,6 +1,8 @@
 use proto::blog::get_blog_posts_request::Sort;
+use serde::Deserialize;
 
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum BlogPostSort {
     #[default]
     Relevance,
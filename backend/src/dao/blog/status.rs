@@ -0,0 +1,37 @@
+use proto::blog::BlogPostStatus as ProtoBlogPostStatus;
+use serde::Serialize;
+
+/// Publication state of a blog post, stored as lowercase text in `blog_posts.status`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, sqlx::Type)]
+#[serde(rename_all = "lowercase")]
+#[sqlx(rename_all = "lowercase")]
+pub enum BlogPostStatus {
+    Draft,
+    Scheduled,
+    Published,
+    Archived,
+}
+
+impl From<BlogPostStatus> for ProtoBlogPostStatus {
+    fn from(value: BlogPostStatus) -> Self {
+        match value {
+            BlogPostStatus::Draft => Self::Draft,
+            BlogPostStatus::Scheduled => Self::Scheduled,
+            BlogPostStatus::Published => Self::Published,
+            BlogPostStatus::Archived => Self::Archived,
+        }
+    }
+}
+
+impl BlogPostStatus {
+    /// The status a request asked for; unspecified or unknown values mean none.
+    pub fn from_proto(value: i32) -> Option<Self> {
+        match ProtoBlogPostStatus::try_from(value).ok()? {
+            ProtoBlogPostStatus::Unspecified => None,
+            ProtoBlogPostStatus::Draft => Some(Self::Draft),
+            ProtoBlogPostStatus::Scheduled => Some(Self::Scheduled),
+            ProtoBlogPostStatus::Published => Some(Self::Published),
+            ProtoBlogPostStatus::Archived => Some(Self::Archived),
+        }
+    }
+}
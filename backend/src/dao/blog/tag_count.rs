@@ -0,0 +1,7 @@
+use sqlx::FromRow;
+
+#[derive(Debug, Clone, FromRow)]
+pub struct TagCount {
+    pub name: String,
+    pub post_count: i32,
+}
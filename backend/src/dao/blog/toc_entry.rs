@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+/// A heading in a rendered post body, linked to by its anchor id.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TocEntry {
+    pub level: i32,
+    pub anchor: String,
+    pub title: String,
+}
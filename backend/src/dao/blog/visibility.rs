@@ -0,0 +1,10 @@
+/// Which posts a read query may return.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlogPostVisibility {
+    /// Published posts whose `published_at` has passed (public readers)
+    Public,
+    /// Every non-deleted post regardless of status (admin views)
+    All,
+    /// Every post, soft-deleted ones included (admin single-post view)
+    WithDeleted,
+}
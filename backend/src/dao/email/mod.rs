@@ -0,0 +1,7 @@
+mod new_email;
+mod outbox_email;
+mod outbox_status;
+
+pub use new_email::NewEmail;
+pub use outbox_email::OutboxEmail;
+pub use outbox_status::OutboxStatus;
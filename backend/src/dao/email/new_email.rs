@@ -0,0 +1,10 @@
+/// A notification email about to be queued
+#[derive(Debug, Clone)]
+pub struct NewEmail {
+    /// What it's about, e.g. `new_inquiry`
+    pub event: String,
+    pub recipient: String,
+    pub subject: String,
+    pub text_body: String,
+    pub html_body: String,
+}
@@ -0,0 +1,21 @@
+use sqlx::FromRow;
+
+use super::OutboxStatus;
+
+/// A queued notification email
+#[derive(Debug, Clone, FromRow)]
+pub struct OutboxEmail {
+    pub id: i64,
+    pub event: String,
+    pub recipient: String,
+    pub subject: String,
+    pub text_body: String,
+    pub html_body: String,
+    pub status: OutboxStatus,
+    /// Failed attempts so far
+    pub attempts: i32,
+    pub next_attempt_at: i64,
+    pub last_error: Option<String>,
+    pub created_at: i64,
+    pub sent_at: Option<i64>,
+}
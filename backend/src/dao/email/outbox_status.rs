@@ -0,0 +1,10 @@
+/// Delivery state of a queued email, stored as lowercase text in
+/// `email_outbox.status`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, sqlx::Type)]
+#[sqlx(rename_all = "lowercase")]
+pub enum OutboxStatus {
+    Pending,
+    Sent,
+    /// Gave up after too many attempts
+    Failed,
+}
@@ -0,0 +1,27 @@
+use serde::Serialize;
+use sqlx::types::Json;
+use sqlx::FromRow;
+
+use super::InquiryStatus;
+
+/// A message sent through the contact form
+#[derive(Debug, Clone, FromRow, Serialize)]
+pub struct Inquiry {
+    pub id: i64,
+    pub name: String,
+    pub email: String,
+    pub subject: String,
+    pub message: String,
+    pub status: InquiryStatus,
+    pub created_at: i64,
+    pub handled_at: Option<i64>,
+    /// Caller `sub` of whoever marked it handled
+    pub handled_by: Option<String>,
+    pub archived_at: Option<i64>,
+    /// Spam filter score at submission
+    pub spam_score: f64,
+    /// What the spam filter objected to, if anything
+    pub spam_reasons: Json<Vec<String>>,
+    /// Admin verdict, which the classifier was trained with
+    pub marked_spam: Option<bool>,
+}
@@ -0,0 +1,7 @@
+mod inquiry_record;
+mod new_inquiry;
+mod status;
+
+pub use inquiry_record::Inquiry;
+pub use new_inquiry::NewInquiry;
+pub use status::InquiryStatus;
@@ -0,0 +1,8 @@
+/// A contact form submission about to be stored
+#[derive(Debug, Clone)]
+pub struct NewInquiry {
+    pub name: String,
+    pub email: String,
+    pub subject: String,
+    pub message: String,
+}
@@ -0,0 +1,38 @@
+use proto::inquiry::InquiryStatus as ProtoInquiryStatus;
+use serde::{Deserialize, Serialize};
+
+/// Where an inquiry is in the inbox, stored as lowercase text in `inquiries.status`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[serde(rename_all = "lowercase")]
+#[sqlx(rename_all = "lowercase")]
+pub enum InquiryStatus {
+    New,
+    Handled,
+    Archived,
+    /// Held back from the inbox as likely spam
+    Quarantined,
+}
+
+impl From<InquiryStatus> for ProtoInquiryStatus {
+    fn from(value: InquiryStatus) -> Self {
+        match value {
+            InquiryStatus::New => Self::New,
+            InquiryStatus::Handled => Self::Handled,
+            InquiryStatus::Archived => Self::Archived,
+            InquiryStatus::Quarantined => Self::Quarantined,
+        }
+    }
+}
+
+impl InquiryStatus {
+    /// The status a request asked for; unspecified or unknown values mean none.
+    pub fn from_proto(value: i32) -> Option<Self> {
+        match ProtoInquiryStatus::try_from(value).ok()? {
+            ProtoInquiryStatus::Unspecified => None,
+            ProtoInquiryStatus::New => Some(Self::New),
+            ProtoInquiryStatus::Handled => Some(Self::Handled),
+            ProtoInquiryStatus::Archived => Some(Self::Archived),
+            ProtoInquiryStatus::Quarantined => Some(Self::Quarantined),
+        }
+    }
+}
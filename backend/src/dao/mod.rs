@@ -1 +1,8 @@
+pub mod audit;
+pub mod auth;
 pub mod blog;
+pub mod email;
+pub mod inquiry;
+pub mod rate_limit;
+pub mod spam;
+pub mod webhook;
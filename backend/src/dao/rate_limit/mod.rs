@@ -0,0 +1,3 @@
+mod rate_limit_bucket;
+
+pub use rate_limit_bucket::RateLimitBucket;
@@ -0,0 +1,13 @@
+use sqlx::FromRow;
+
+/// Saved state of a rate limit token bucket
+#[derive(Debug, Clone, FromRow)]
+pub struct RateLimitBucket {
+    /// Limit name and the client it applies to, e.g. `login-ip:203.0.113.7`
+    pub key: String,
+    pub tokens: f64,
+    /// Unix time (fractional seconds) `tokens` was last updated
+    pub updated_at: f64,
+    /// When the bucket will have refilled, after which it can be forgotten
+    pub full_at: f64,
+}
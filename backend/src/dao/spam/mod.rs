@@ -0,0 +1,5 @@
+mod spam_label;
+mod spam_token;
+
+pub use spam_label::SpamLabel;
+pub use spam_token::SpamToken;
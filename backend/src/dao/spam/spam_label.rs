@@ -0,0 +1,18 @@
+/// Verdict a classifier training document was marked with, stored as
+/// lowercase text in `spam_documents.label`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, sqlx::Type)]
+#[sqlx(rename_all = "lowercase")]
+pub enum SpamLabel {
+    Spam,
+    Ham,
+}
+
+impl SpamLabel {
+    pub fn new(spam: bool) -> Self {
+        if spam {
+            Self::Spam
+        } else {
+            Self::Ham
+        }
+    }
+}
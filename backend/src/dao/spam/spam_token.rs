@@ -0,0 +1,9 @@
+use sqlx::FromRow;
+
+/// How many marked submissions of each kind contained a word
+#[derive(Debug, Clone, FromRow)]
+pub struct SpamToken {
+    pub token: String,
+    pub spam_count: i64,
+    pub ham_count: i64,
+}
@@ -0,0 +1,34 @@
+use proto::webhook::WebhookDeliveryStatus as ProtoDeliveryStatus;
+
+/// Where a webhook delivery is in the queue, stored as lowercase text in
+/// `webhook_deliveries.status`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, sqlx::Type)]
+#[sqlx(rename_all = "lowercase")]
+pub enum DeliveryStatus {
+    Pending,
+    Delivered,
+    /// Gave up after too many attempts
+    Failed,
+}
+
+impl From<DeliveryStatus> for ProtoDeliveryStatus {
+    fn from(value: DeliveryStatus) -> Self {
+        match value {
+            DeliveryStatus::Pending => Self::Pending,
+            DeliveryStatus::Delivered => Self::Delivered,
+            DeliveryStatus::Failed => Self::Failed,
+        }
+    }
+}
+
+impl DeliveryStatus {
+    /// The status a request asked for; unspecified or unknown values mean none.
+    pub fn from_proto(value: i32) -> Option<Self> {
+        match ProtoDeliveryStatus::try_from(value).ok()? {
+            ProtoDeliveryStatus::Unspecified => None,
+            ProtoDeliveryStatus::Pending => Some(Self::Pending),
+            ProtoDeliveryStatus::Delivered => Some(Self::Delivered),
+            ProtoDeliveryStatus::Failed => Some(Self::Failed),
+        }
+    }
+}
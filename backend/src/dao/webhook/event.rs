@@ -0,0 +1,59 @@
+use proto::webhook::WebhookEvent as ProtoWebhookEvent;
+use serde::{Deserialize, Serialize};
+
+/// Blog content change a webhook can subscribe to, stored by name, e.g.
+/// `post.created`, in `webhooks.events` and `webhook_deliveries.event`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+pub enum WebhookEvent {
+    #[serde(rename = "post.created")]
+    #[sqlx(rename = "post.created")]
+    PostCreated,
+    #[serde(rename = "post.updated")]
+    #[sqlx(rename = "post.updated")]
+    PostUpdated,
+    #[serde(rename = "post.deleted")]
+    #[sqlx(rename = "post.deleted")]
+    PostDeleted,
+    #[serde(rename = "post.restored")]
+    #[sqlx(rename = "post.restored")]
+    PostRestored,
+    #[serde(rename = "post.published")]
+    #[sqlx(rename = "post.published")]
+    PostPublished,
+}
+
+impl WebhookEvent {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::PostCreated => "post.created",
+            Self::PostUpdated => "post.updated",
+            Self::PostDeleted => "post.deleted",
+            Self::PostRestored => "post.restored",
+            Self::PostPublished => "post.published",
+        }
+    }
+
+    /// The event a request named; unspecified or unknown values mean none.
+    pub fn from_proto(value: i32) -> Option<Self> {
+        match ProtoWebhookEvent::try_from(value).ok()? {
+            ProtoWebhookEvent::Unspecified => None,
+            ProtoWebhookEvent::PostCreated => Some(Self::PostCreated),
+            ProtoWebhookEvent::PostUpdated => Some(Self::PostUpdated),
+            ProtoWebhookEvent::PostDeleted => Some(Self::PostDeleted),
+            ProtoWebhookEvent::PostRestored => Some(Self::PostRestored),
+            ProtoWebhookEvent::PostPublished => Some(Self::PostPublished),
+        }
+    }
+}
+
+impl From<WebhookEvent> for ProtoWebhookEvent {
+    fn from(value: WebhookEvent) -> Self {
+        match value {
+            WebhookEvent::PostCreated => Self::PostCreated,
+            WebhookEvent::PostUpdated => Self::PostUpdated,
+            WebhookEvent::PostDeleted => Self::PostDeleted,
+            WebhookEvent::PostRestored => Self::PostRestored,
+            WebhookEvent::PostPublished => Self::PostPublished,
+        }
+    }
+}
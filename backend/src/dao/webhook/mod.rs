@@ -0,0 +1,13 @@
+mod delivery_status;
+mod event;
+mod new_webhook;
+mod webhook_delivery;
+mod webhook_record;
+mod webhook_update;
+
+pub use delivery_status::DeliveryStatus;
+pub use event::WebhookEvent;
+pub use new_webhook::NewWebhook;
+pub use webhook_delivery::WebhookDelivery;
+pub use webhook_record::Webhook;
+pub use webhook_update::WebhookUpdate;
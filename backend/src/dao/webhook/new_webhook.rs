@@ -0,0 +1,9 @@
+use super::WebhookEvent;
+
+/// A webhook subscription about to be stored
+#[derive(Debug, Clone)]
+pub struct NewWebhook {
+    pub url: String,
+    pub secret: String,
+    pub events: Vec<WebhookEvent>,
+}
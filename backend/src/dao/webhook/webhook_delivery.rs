@@ -0,0 +1,24 @@
+use sqlx::FromRow;
+
+use super::{DeliveryStatus, WebhookEvent};
+
+/// A payload queued for, or sent to, a webhook
+#[derive(Debug, Clone, FromRow)]
+pub struct WebhookDelivery {
+    pub id: i64,
+    pub webhook_id: i64,
+    pub event: WebhookEvent,
+    /// JSON body, signed and sent as is
+    pub payload: String,
+    pub status: DeliveryStatus,
+    /// Attempts made so far
+    pub attempts: i32,
+    pub next_attempt_at: i64,
+    /// HTTP status of the last response, if the endpoint answered
+    pub response_status: Option<i32>,
+    pub last_error: Option<String>,
+    pub created_at: i64,
+    pub delivered_at: Option<i64>,
+    /// Delivery whose payload this one resends
+    pub redelivery_of: Option<i64>,
+}
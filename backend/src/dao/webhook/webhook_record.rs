@@ -0,0 +1,25 @@
+use sqlx::types::Json;
+use sqlx::FromRow;
+
+use super::WebhookEvent;
+
+#[derive(Debug, Clone, FromRow)]
+pub struct Webhook {
+    pub id: i64,
+    pub url: String,
+    /// Key for the HMAC-SHA256 payload signatures
+    pub secret: String,
+    /// Events it subscribes to; empty means every event
+    pub events: Json<Vec<WebhookEvent>>,
+    pub active: bool,
+    pub created_at: i64,
+    pub updated_at: i64,
+    /// `sub` of the admin who created it
+    pub created_by: Option<String>,
+}
+
+impl Webhook {
+    pub fn subscribes_to(&self, event: WebhookEvent) -> bool {
+        self.events.is_empty() || self.events.contains(&event)
+    }
+}
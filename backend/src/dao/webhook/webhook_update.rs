@@ -0,0 +1,11 @@
+use super::WebhookEvent;
+
+/// Fields to change on a webhook. `None` leaves the current value untouched.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WebhookUpdate<'a> {
+    pub url: Option<&'a str>,
+    pub secret: Option<&'a str>,
+    /// Replaces the event filter
+    pub events: Option<&'a [WebhookEvent]>,
+    pub active: Option<bool>,
+}
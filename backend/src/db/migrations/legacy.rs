@@ -0,0 +1,96 @@
+//! Adoption of databases from the first release, which created its one table
+//! without recording any migration history. Such a database is brought to the
+//! schema of migration 1 by moving its posts aside, running that migration and
+//! copying them back; later migrations then apply as usual.
+
+use sqlx::types::Json;
+use sqlx::SqliteConnection;
+
+use super::Migration;
+use crate::repositories::blog::unique_slug;
+use crate::utils::{render_markdown, slugify};
+
+/// The migration whose schema an adopted database ends up with
+pub const BASELINE_VERSION: i64 = 1;
+
+/// Columns of `blog_posts` as the first release created it
+const LEGACY_COLUMNS: [&str; 7] = [
+    "id",
+    "title",
+    "description",
+    "body",
+    "created_at",
+    "updated_at",
+    "deleted_at",
+];
+
+/// Whether `blog_posts` has exactly the first release's columns
+pub async fn is_legacy(conn: &mut SqliteConnection) -> Result<bool, sqlx::Error> {
+    let columns: Vec<String> =
+        sqlx::query_scalar("SELECT name FROM pragma_table_info('blog_posts') ORDER BY cid")
+            .fetch_all(conn)
+            .await?;
+    Ok(columns == LEGACY_COLUMNS)
+}
+
+/// Replace the first release's schema with `baseline`'s, keeping the posts.
+/// Posts from then had no publication workflow, so they stay published.
+pub async fn upgrade(conn: &mut SqliteConnection, baseline: &Migration) -> Result<(), sqlx::Error> {
+    sqlx::raw_sql(
+        r#"
+        DROP TRIGGER IF EXISTS blog_posts_ai;
+        DROP TRIGGER IF EXISTS blog_posts_ad;
+        DROP TRIGGER IF EXISTS blog_posts_au;
+        DROP TABLE IF EXISTS blog_posts_fts;
+        DROP INDEX IF EXISTS idx_blog_posts_deleted_at;
+        ALTER TABLE blog_posts RENAME TO legacy_blog_posts;
+        "#,
+    )
+    .execute(&mut *conn)
+    .await?;
+
+    sqlx::raw_sql(baseline.sql).execute(&mut *conn).await?;
+
+    // The insert trigger fills both full-text indexes
+    sqlx::raw_sql(
+        r#"
+        INSERT INTO blog_posts
+            (id, title, description, body, created_at, updated_at, deleted_at,
+             status, published_at)
+        SELECT id, title, description, body, created_at, updated_at, deleted_at,
+               'published', created_at
+        FROM legacy_blog_posts;
+
+        INSERT INTO blog_post_revisions
+            (post_id, revision, title, description, body, author_sub, created_at)
+        SELECT id, 1, title, description, body, NULL, updated_at
+        FROM blog_posts;
+
+        DROP TABLE legacy_blog_posts;
+        "#,
+    )
+    .execute(&mut *conn)
+    .await?;
+
+    let posts: Vec<(i64, String, String)> =
+        sqlx::query_as("SELECT id, title, body FROM blog_posts ORDER BY id")
+            .fetch_all(&mut *conn)
+            .await?;
+    for (id, title, body) in posts {
+        let slug = unique_slug(&mut *conn, &slugify(&title), Some(id)).await?;
+        let rendered = render_markdown(&body);
+        sqlx::query(
+            "UPDATE blog_posts SET slug = ?, body_html = ?, toc = ?, reading_minutes = ? \
+             WHERE id = ?",
+        )
+        .bind(&slug)
+        .bind(&rendered.html)
+        .bind(Json(&rendered.toc))
+        .bind(rendered.reading_minutes)
+        .bind(id)
+        .execute(&mut *conn)
+        .await?;
+    }
+
+    Ok(())
+}
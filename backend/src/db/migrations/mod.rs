@@ -1,7 +1,320 @@
-mod blog;
+//! Numbered schema migrations. Each one is applied once, in its own
+//! transaction, and recorded in `schema_migrations` with a checksum of its SQL
+//! so edits to already-applied migrations are caught. Migrations are never
+//! changed once released; changes to the schema go in a new one.
+
+mod legacy;
+
+use ring::digest::{digest, SHA256};
+use sqlx::{Connection, SqliteConnection};
+use std::fmt;
 
 use super::DbPool;
+use crate::utils::now_timestamp;
+
+#[derive(Debug)]
+pub struct Migration {
+    pub version: i64,
+    pub name: &'static str,
+    pub sql: &'static str,
+}
+
+impl Migration {
+    /// Hex SHA-256 of the migration's SQL
+    pub fn checksum(&self) -> String {
+        digest(&SHA256, self.sql.as_bytes())
+            .as_ref()
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect()
+    }
+}
+
+/// Every migration, in the order they're applied. Only ever append to this.
+pub const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        name: "blog",
+        sql: include_str!("sql/0001_blog.sql"),
+    },
+    Migration {
+        version: 2,
+        name: "local_auth",
+        sql: include_str!("sql/0002_local_auth.sql"),
+    },
+    Migration {
+        version: 3,
+        name: "audit",
+        sql: include_str!("sql/0003_audit.sql"),
+    },
+    Migration {
+        version: 4,
+        name: "inquiries",
+        sql: include_str!("sql/0004_inquiries.sql"),
+    },
+    Migration {
+        version: 5,
+        name: "rate_limit",
+        sql: include_str!("sql/0005_rate_limit.sql"),
+    },
+    Migration {
+        version: 6,
+        name: "spam",
+        sql: include_str!("sql/0006_spam.sql"),
+    },
+    Migration {
+        version: 7,
+        name: "email_outbox",
+        sql: include_str!("sql/0007_email_outbox.sql"),
+    },
+    Migration {
+        version: 8,
+        name: "webhooks",
+        sql: include_str!("sql/0008_webhooks.sql"),
+    },
+];
+
+#[derive(Debug)]
+pub enum MigrationError {
+    Database(sqlx::Error),
+    /// The database has migrations from a newer build than this one
+    NewerDatabase {
+        version: i64,
+        latest: i64,
+    },
+    /// An applied migration this build doesn't have
+    UnknownVersion(i64),
+    /// An applied migration's SQL was edited afterwards
+    ChecksumMismatch {
+        version: i64,
+        name: String,
+    },
+    /// A migration's SQL failed; nothing of it was applied
+    Failed {
+        version: i64,
+        name: &'static str,
+        error: sqlx::Error,
+    },
+    /// The database has tables but no history, and isn't from the first release
+    UnrecognizedSchema,
+    /// Bringing a first-release database up to the baseline failed; nothing
+    /// of it was applied
+    AdoptionFailed(sqlx::Error),
+}
+
+impl fmt::Display for MigrationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Database(e) => write!(f, "Failed to read migration history: {e}"),
+            Self::NewerDatabase { version, latest } => write!(
+                f,
+                "Database is at migration {version} but this build only knows up to {latest}; \
+                 refusing to run an older binary against it"
+            ),
+            Self::UnknownVersion(version) => {
+                write!(
+                    f,
+                    "Database has migration {version}, which this build doesn't know"
+                )
+            }
+            Self::ChecksumMismatch { version, name } => write!(
+                f,
+                "Migration {version} ({name}) was changed after it was applied"
+            ),
+            Self::Failed {
+                version,
+                name,
+                error,
+            } => write!(f, "Migration {version} ({name}) failed: {error}"),
+            Self::UnrecognizedSchema => f.write_str(
+                "Database has no migration history and isn't from the first release; \
+                 refusing to guess which migrations it has",
+            ),
+            Self::AdoptionFailed(e) => write!(
+                f,
+                "Failed to bring a database from before versioned migrations up to date: {e}"
+            ),
+        }
+    }
+}
+
+impl From<sqlx::Error> for MigrationError {
+    fn from(e: sqlx::Error) -> Self {
+        Self::Database(e)
+    }
+}
+
+/// Bring the database up to date with `MIGRATIONS`, returning the ones applied.
+pub async fn run(pool: &DbPool) -> Result<Vec<&'static Migration>, MigrationError> {
+    migrate(pool, MIGRATIONS, false).await
+}
+
+/// Migrations `run` would apply. They're applied and then rolled back, so
+/// failures show up too, and the database is left as it was.
+pub async fn dry_run(pool: &DbPool) -> Result<Vec<&'static Migration>, MigrationError> {
+    migrate(pool, MIGRATIONS, true).await
+}
+
+/// Apply pending `migrations`, which must be sorted by version. A dry run
+/// does everything inside one transaction and rolls it back at the end.
+pub async fn migrate<'a>(
+    pool: &DbPool,
+    migrations: &'a [Migration],
+    dry_run: bool,
+) -> Result<Vec<&'a Migration>, MigrationError> {
+    let mut conn = pool.acquire().await?;
+
+    if !dry_run {
+        return apply(&mut conn, migrations, dry_run).await;
+    }
+
+    let mut tx = conn.begin().await?;
+    let pending = apply(&mut tx, migrations, dry_run).await;
+    tx.rollback().await?;
+    pending
+}
+
+/// Check the history and apply what's pending. Each migration gets its own
+/// transaction, a savepoint in a dry run.
+async fn apply<'a>(
+    conn: &mut SqliteConnection,
+    migrations: &'a [Migration],
+    dry_run: bool,
+) -> Result<Vec<&'a Migration>, MigrationError> {
+    if !table_exists(conn, "schema_migrations").await? && table_exists(conn, "blog_posts").await? {
+        adopt_legacy(conn, migrations, dry_run).await?;
+    }
+
+    create_history(conn).await?;
+
+    let applied: Vec<(i64, String, String)> =
+        sqlx::query_as("SELECT version, name, checksum FROM schema_migrations ORDER BY version")
+            .fetch_all(&mut *conn)
+            .await?;
+
+    let latest = migrations.last().map_or(0, |m| m.version);
+    for (version, name, checksum) in &applied {
+        let Some(migration) = migrations.iter().find(|m| m.version == *version) else {
+            return Err(if *version > latest {
+                MigrationError::NewerDatabase {
+                    version: *version,
+                    latest,
+                }
+            } else {
+                MigrationError::UnknownVersion(*version)
+            });
+        };
+        if migration.checksum() != *checksum {
+            return Err(MigrationError::ChecksumMismatch {
+                version: *version,
+                name: name.clone(),
+            });
+        }
+    }
+
+    let mut pending = vec![];
+    for migration in migrations {
+        if applied
+            .iter()
+            .any(|(version, _, _)| *version == migration.version)
+        {
+            continue;
+        }
+
+        let mut tx = conn.begin().await?;
+        sqlx::raw_sql(migration.sql)
+            .execute(&mut *tx)
+            .await
+            .map_err(|error| MigrationError::Failed {
+                version: migration.version,
+                name: migration.name,
+                error,
+            })?;
+        record(&mut tx, migration).await?;
+        tx.commit().await?;
+
+        if !dry_run {
+            tracing::info!(
+                "Applied migration {} ({})",
+                migration.version,
+                migration.name
+            );
+        }
+        pending.push(migration);
+    }
+
+    Ok(pending)
+}
+
+/// Databases from the first release have its one table but no history. Bring
+/// them up to the baseline migration and record it as applied, leaving the
+/// later ones pending.
+async fn adopt_legacy(
+    conn: &mut SqliteConnection,
+    migrations: &[Migration],
+    dry_run: bool,
+) -> Result<(), MigrationError> {
+    let baseline = migrations
+        .iter()
+        .find(|m| m.version == legacy::BASELINE_VERSION)
+        .ok_or(MigrationError::UnrecognizedSchema)?;
+    if !legacy::is_legacy(conn).await? {
+        return Err(MigrationError::UnrecognizedSchema);
+    }
+
+    let mut tx = conn.begin().await?;
+    legacy::upgrade(&mut tx, baseline)
+        .await
+        .map_err(MigrationError::AdoptionFailed)?;
+    create_history(&mut tx).await?;
+    record(&mut tx, baseline).await?;
+    tx.commit().await?;
+
+    let action = if dry_run { "Would adopt" } else { "Adopted" };
+    tracing::info!(
+        "{} a database from before versioned migrations as migration {} ({})",
+        action,
+        baseline.version,
+        baseline.name
+    );
+    Ok(())
+}
+
+/// One row per applied migration
+async fn create_history(conn: &mut SqliteConnection) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS schema_migrations (
+            version INTEGER PRIMARY KEY,
+            name TEXT NOT NULL,
+            checksum TEXT NOT NULL,
+            applied_at INTEGER NOT NULL
+        )
+        "#,
+    )
+    .execute(conn)
+    .await
+    .map(|_| ())
+}
+
+async fn record(conn: &mut SqliteConnection, migration: &Migration) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "INSERT INTO schema_migrations (version, name, checksum, applied_at) VALUES (?, ?, ?, ?)",
+    )
+    .bind(migration.version)
+    .bind(migration.name)
+    .bind(migration.checksum())
+    .bind(now_timestamp())
+    .execute(conn)
+    .await
+    .map(|_| ())
+}
 
-pub async fn run(pool: &DbPool) {
-    blog::migrate(pool).await;
+async fn table_exists(conn: &mut SqliteConnection, name: &str) -> Result<bool, sqlx::Error> {
+    sqlx::query_scalar(
+        "SELECT EXISTS(SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = ?)",
+    )
+    .bind(name)
+    .fetch_one(conn)
+    .await
 }
@@ -0,0 +1,109 @@
+-- Blog posts, their full-text indexes, revision history, tags and slug history
+
+CREATE TABLE blog_posts (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    title TEXT NOT NULL,
+    description TEXT NOT NULL,
+    body TEXT NOT NULL,
+    created_at INTEGER NOT NULL,
+    updated_at INTEGER NOT NULL,
+    deleted_at INTEGER,
+    -- Posts start as drafts and only become public once published
+    status TEXT NOT NULL DEFAULT 'draft',
+    published_at INTEGER,
+    slug TEXT,
+    -- Rendered Markdown cache, refreshed whenever a post is written
+    body_html TEXT NOT NULL DEFAULT '',
+    toc TEXT NOT NULL DEFAULT '[]',
+    reading_minutes INTEGER NOT NULL DEFAULT 1,
+    -- Who created, last changed and deleted each post (Firebase `sub`)
+    created_by TEXT,
+    updated_by TEXT,
+    deleted_by TEXT
+);
+
+CREATE INDEX idx_blog_posts_deleted_at ON blog_posts(deleted_at);
+
+-- Public visibility filtering and the scheduled-post publisher
+CREATE INDEX idx_blog_posts_status_published_at ON blog_posts(status, published_at);
+
+CREATE UNIQUE INDEX idx_blog_posts_slug ON blog_posts(slug);
+
+CREATE VIRTUAL TABLE blog_posts_fts USING fts5(
+    title,
+    description,
+    body,
+    content='blog_posts',
+    content_rowid='id',
+    tokenize='porter'
+);
+
+-- Default bm25 weights: title=10.0, description=1.0, body=1.0
+INSERT INTO blog_posts_fts(blog_posts_fts, rank) VALUES('rank', 'bm25(10.0, 1.0, 1.0)');
+
+-- Unstemmed index of the same columns. Search uses the porter-stemmed table,
+-- but suggestions need real words, which stems like "databas" aren't.
+CREATE VIRTUAL TABLE blog_posts_terms USING fts5(
+    title,
+    description,
+    body,
+    content='blog_posts',
+    content_rowid='id',
+    tokenize='unicode61 remove_diacritics 2'
+);
+
+-- Per-term document counts for search suggestions
+CREATE VIRTUAL TABLE blog_posts_vocab USING fts5vocab(blog_posts_terms, row);
+
+CREATE TRIGGER blog_posts_ai AFTER INSERT ON blog_posts BEGIN
+    INSERT INTO blog_posts_fts(rowid, title, description, body) VALUES (new.id, new.title, new.description, new.body);
+    INSERT INTO blog_posts_terms(rowid, title, description, body) VALUES (new.id, new.title, new.description, new.body);
+END;
+
+CREATE TRIGGER blog_posts_ad AFTER DELETE ON blog_posts BEGIN
+    INSERT INTO blog_posts_fts(blog_posts_fts, rowid, title, description, body) VALUES ('delete', old.id, old.title, old.description, old.body);
+    INSERT INTO blog_posts_terms(blog_posts_terms, rowid, title, description, body) VALUES ('delete', old.id, old.title, old.description, old.body);
+END;
+
+CREATE TRIGGER blog_posts_au AFTER UPDATE ON blog_posts BEGIN
+    INSERT INTO blog_posts_fts(blog_posts_fts, rowid, title, description, body) VALUES ('delete', old.id, old.title, old.description, old.body);
+    INSERT INTO blog_posts_fts(rowid, title, description, body) VALUES (new.id, new.title, new.description, new.body);
+    INSERT INTO blog_posts_terms(blog_posts_terms, rowid, title, description, body) VALUES ('delete', old.id, old.title, old.description, old.body);
+    INSERT INTO blog_posts_terms(rowid, title, description, body) VALUES (new.id, new.title, new.description, new.body);
+END;
+
+-- Revision history: one row per create/update, numbered per post
+CREATE TABLE blog_post_revisions (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    post_id INTEGER NOT NULL REFERENCES blog_posts(id),
+    revision INTEGER NOT NULL,
+    title TEXT NOT NULL,
+    description TEXT NOT NULL,
+    body TEXT NOT NULL,
+    author_sub TEXT,
+    created_at INTEGER NOT NULL,
+    UNIQUE (post_id, revision)
+);
+
+-- Names are stored normalized (trimmed, lowercase) by the service layer
+CREATE TABLE tags (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL UNIQUE,
+    created_at INTEGER NOT NULL
+);
+
+CREATE TABLE blog_post_tags (
+    post_id INTEGER NOT NULL REFERENCES blog_posts(id),
+    tag_id INTEGER NOT NULL REFERENCES tags(id),
+    PRIMARY KEY (post_id, tag_id)
+);
+
+-- Tag -> posts lookups (the primary key covers post -> tags)
+CREATE INDEX idx_blog_post_tags_tag_id ON blog_post_tags(tag_id);
+
+-- Slugs a post used before being renamed, so old links can redirect
+CREATE TABLE blog_post_slugs (
+    slug TEXT PRIMARY KEY,
+    post_id INTEGER NOT NULL REFERENCES blog_posts(id),
+    created_at INTEGER NOT NULL
+);
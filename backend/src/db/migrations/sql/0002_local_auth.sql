@@ -0,0 +1,26 @@
+-- Tables for the local identity provider. Unused with Firebase.
+
+CREATE TABLE local_users (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    email TEXT NOT NULL UNIQUE,
+    password_hash TEXT NOT NULL,
+    disabled INTEGER NOT NULL DEFAULT 0,
+    created_at INTEGER NOT NULL,
+    -- Role put in the `roles` claim
+    role TEXT NOT NULL DEFAULT 'admin'
+);
+
+-- Only a hash of each refresh token is stored; tokens are single use
+CREATE TABLE local_refresh_tokens (
+    token_hash TEXT PRIMARY KEY,
+    user_id INTEGER NOT NULL REFERENCES local_users(id),
+    expires_at INTEGER NOT NULL
+);
+
+-- Ed25519 keys the local provider signs ID tokens with, kept so sessions
+-- survive restarts
+CREATE TABLE local_signing_keys (
+    kid TEXT PRIMARY KEY,
+    private_key BLOB NOT NULL,
+    created_at INTEGER NOT NULL
+);
@@ -0,0 +1,18 @@
+-- One row per audited RPC call; `changes` is a JSON object of the fields set
+
+CREATE TABLE audit_events (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    created_at INTEGER NOT NULL,
+    actor_sub TEXT,
+    actor_email TEXT,
+    action TEXT NOT NULL,
+    target_id INTEGER,
+    status_code INTEGER NOT NULL,
+    request_id TEXT,
+    client_ip TEXT,
+    changes TEXT NOT NULL DEFAULT '{}'
+);
+
+CREATE INDEX idx_audit_events_created_at ON audit_events(created_at);
+CREATE INDEX idx_audit_events_actor_sub ON audit_events(actor_sub, created_at);
+CREATE INDEX idx_audit_events_actor_email ON audit_events(actor_email, created_at);
@@ -0,0 +1,21 @@
+-- Contact form submissions; `status` moves from new to handled to archived,
+-- or starts out quarantined when the spam filter flags it
+
+CREATE TABLE inquiries (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL,
+    email TEXT NOT NULL,
+    subject TEXT NOT NULL,
+    message TEXT NOT NULL,
+    status TEXT NOT NULL DEFAULT 'new',
+    created_at INTEGER NOT NULL,
+    handled_at INTEGER,
+    handled_by TEXT,
+    archived_at INTEGER,
+    -- Spam filter verdict, and the admin's, which trains the classifier
+    spam_score REAL NOT NULL DEFAULT 0,
+    spam_reasons TEXT NOT NULL DEFAULT '[]',
+    marked_spam INTEGER
+);
+
+CREATE INDEX idx_inquiries_status_created_at ON inquiries(status, created_at);
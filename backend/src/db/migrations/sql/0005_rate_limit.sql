@@ -0,0 +1,8 @@
+-- Token buckets, saved only when RATE_LIMIT_PERSIST is on
+
+CREATE TABLE rate_limit_buckets (
+    key TEXT PRIMARY KEY,
+    tokens REAL NOT NULL,
+    updated_at REAL NOT NULL,
+    full_at REAL NOT NULL
+);
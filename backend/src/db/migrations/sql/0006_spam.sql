@@ -0,0 +1,14 @@
+-- Word counts of the spam classifier, from submissions admins marked as spam
+-- or not
+
+CREATE TABLE spam_tokens (
+    token TEXT PRIMARY KEY,
+    spam_count INTEGER NOT NULL DEFAULT 0,
+    ham_count INTEGER NOT NULL DEFAULT 0
+);
+
+-- Number of marked submissions per label, 'spam' or 'ham'
+CREATE TABLE spam_documents (
+    label TEXT PRIMARY KEY,
+    count INTEGER NOT NULL DEFAULT 0
+);
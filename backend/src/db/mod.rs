@@ -1,4 +1,4 @@
-mod migrations;
+pub mod migrations;
 
 use sqlx::sqlite::{SqliteConnectOptions, SqlitePool};
 use std::str::FromStr;
@@ -7,11 +7,19 @@ pub type DbPool = SqlitePool;
 
 pub async fn init(database_url: &str) -> DbPool {
     let pool = connect(database_url).await;
-    migrations::run(&pool).await;
+    migrations::run(&pool)
+        .await
+        .unwrap_or_else(|e| panic!("Failed to migrate database: {e}"));
     pool
 }
 
-async fn connect(database_url: &str) -> DbPool {
+/// Check that the database answers a trivial query
+pub async fn ping(pool: &DbPool) -> Result<(), sqlx::Error> {
+    sqlx::query("SELECT 1").execute(pool).await.map(|_| ())
+}
+
+/// Open the database without migrating it
+pub async fn connect(database_url: &str) -> DbPool {
     let options = SqliteConnectOptions::from_str(database_url)
         .expect("Invalid DATABASE_URL")
         .create_if_missing(true);
@@ -9,11 +9,16 @@ pub enum AuthError {
     NetworkError,
     InvalidResponse,
     KeyFetchFailed,
+    /// The local provider's database failed
+    StorageError,
 }
 
 impl AuthError {
+    /// Map a Firebase error message, which may append a description to the
+    /// code (`TOO_MANY_ATTEMPTS_TRY_LATER : Access to this account...`)
     pub fn from_firebase_message(message: &str) -> Self {
-        match message {
+        let code = message.split(" : ").next().unwrap_or(message).trim();
+        match code {
             "EMAIL_NOT_FOUND" | "INVALID_PASSWORD" | "INVALID_LOGIN_CREDENTIALS" => {
                 Self::InvalidCredentials
             }
@@ -0,0 +1,8 @@
+use super::AuthTokens;
+
+/// A signed-in user and their tokens
+pub struct AuthSession {
+    pub uid: String,
+    pub email: String,
+    pub tokens: AuthTokens,
+}
@@ -0,0 +1,7 @@
+/// Tokens issued by an identity provider on login or refresh
+pub struct AuthTokens {
+    pub id_token: String,
+    pub refresh_token: String,
+    /// Lifetime of `id_token` in seconds
+    pub expires_in: u64,
+}
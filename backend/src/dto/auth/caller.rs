@@ -0,0 +1,17 @@
+use super::Claims;
+
+/// Who made an authenticated request, taken from their verified claims
+#[derive(Debug, Clone)]
+pub struct Caller {
+    pub sub: String,
+    pub email: Option<String>,
+}
+
+impl From<&Claims> for Caller {
+    fn from(claims: &Claims) -> Self {
+        Self {
+            sub: claims.sub.clone(),
+            email: claims.email.clone(),
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+use super::Role;
+
+/// Claims from a verified ID token, issued by Firebase or the local provider
+/// Reference: https://firebase.google.com/docs/auth/admin/verify-id-tokens
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(dead_code)]
+pub struct Claims {
+    pub email: Option<String>,
+    #[serde(default)]
+    pub email_verified: bool,
+    /// Custom claim, set on Firebase users with the Admin SDK:
+    /// `setCustomUserClaims(uid, { roles: ["editor"] })`
+    #[serde(default)]
+    pub roles: Vec<String>,
+    pub sub: String,
+    pub aud: String,
+    pub iss: String,
+    pub exp: u64,
+}
+
+impl Claims {
+    /// Whether the caller holds `role`; admins hold every role. Roles only
+    /// count once the email address is verified.
+    pub fn has_role(&self, role: Role) -> bool {
+        self.email_verified
+            && self
+                .roles
+                .iter()
+                .any(|r| r == role.as_str() || r == Role::Admin.as_str())
+    }
+}
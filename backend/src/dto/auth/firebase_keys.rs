@@ -4,4 +4,8 @@ use std::collections::HashMap;
 #[derive(Clone)]
 pub struct FirebaseKeys {
     pub keys: HashMap<String, DecodingKey>,
+    /// When the keys were fetched (unix seconds)
+    pub fetched_at: i64,
+    /// When Google's `Cache-Control: max-age` says to stop trusting them
+    pub expires_at: i64,
 }
@@ -0,0 +1,12 @@
+use serde::Serialize;
+
+/// State of the Firebase public key cache, for monitoring
+#[derive(Debug, Clone, Serialize)]
+pub struct KeyCacheStatus {
+    pub key_count: usize,
+    pub last_refresh_at: Option<i64>,
+    pub expires_at: Option<i64>,
+    /// Failed refreshes since the last successful one
+    pub consecutive_failures: u32,
+    pub last_failure_at: Option<i64>,
+}
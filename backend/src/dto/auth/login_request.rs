@@ -0,0 +1,8 @@
+use serde::Deserialize;
+
+/// Credentials posted to the REST login endpoint
+#[derive(Deserialize)]
+pub struct LoginRequest {
+    pub email: String,
+    pub password: String,
+}
@@ -1,15 +1,29 @@
 pub mod auth_error;
+pub mod auth_session;
+pub mod auth_tokens;
+pub mod caller;
+pub mod claims;
 pub mod firebase_auth_error_response;
 pub mod firebase_auth_request;
 pub mod firebase_auth_response;
-pub mod firebase_claims;
 pub mod firebase_keys;
+pub mod key_cache_status;
+pub mod login_request;
+pub mod login_response;
+pub mod role;
 pub mod token_refresh_response;
 
 pub use auth_error::AuthError;
+pub use auth_session::AuthSession;
+pub use auth_tokens::AuthTokens;
+pub use caller::Caller;
+pub use claims::Claims;
 pub use firebase_auth_error_response::FirebaseAuthErrorResponse;
 pub use firebase_auth_request::FirebaseAuthRequest;
 pub use firebase_auth_response::FirebaseAuthResponse;
-pub use firebase_claims::FirebaseClaims;
 pub use firebase_keys::FirebaseKeys;
+pub use key_cache_status::KeyCacheStatus;
+pub use login_request::LoginRequest;
+pub use login_response::LoginResponse;
+pub use role::Role;
 pub use token_refresh_response::TokenRefreshResponse;
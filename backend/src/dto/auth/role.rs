@@ -0,0 +1,18 @@
+/// What an admin user may do, granted through the `roles` custom claim
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    /// Write and publish posts
+    Editor,
+    /// Everything, including deleting and restoring posts
+    Admin,
+}
+
+impl Role {
+    /// Name used in the `roles` claim
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Editor => "editor",
+            Self::Admin => "admin",
+        }
+    }
+}
@@ -0,0 +1,12 @@
+use serde::Serialize;
+
+use crate::dao::blog::BlogPostSummary;
+
+/// One page of posts returned by `GET /blog`
+#[derive(Serialize)]
+pub struct BlogPostListResponse {
+    pub posts: Vec<BlogPostSummary>,
+    pub total: i32,
+    /// Pass back as `page_token` to fetch the following page
+    pub next_page_token: Option<String>,
+}
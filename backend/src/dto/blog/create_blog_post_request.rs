@@ -0,0 +1,11 @@
+use serde::Deserialize;
+
+/// Body of `POST /blog`
+#[derive(Deserialize)]
+pub struct CreateBlogPostRequest {
+    pub title: String,
+    pub description: String,
+    pub body: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
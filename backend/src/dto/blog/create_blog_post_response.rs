@@ -0,0 +1,7 @@
+use serde::Serialize;
+
+/// Response to `POST /blog`
+#[derive(Serialize)]
+pub struct CreateBlogPostResponse {
+    pub id: i64,
+}
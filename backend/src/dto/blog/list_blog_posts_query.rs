@@ -0,0 +1,42 @@
+use serde::Deserialize;
+
+use crate::dao::blog::{BlogPostSort, HighlightMarkers};
+
+/// Query string of `GET /blog`, mirroring `GetBlogPostsRequest`
+#[derive(Deserialize)]
+pub struct ListBlogPostsQuery {
+    pub query: Option<String>,
+    /// Comma-separated; posts must carry every tag
+    pub tags: Option<String>,
+    #[serde(default)]
+    pub limit: i32,
+    #[serde(default)]
+    pub offset: i32,
+    pub page_token: Option<String>,
+    #[serde(default)]
+    pub sort: BlogPostSort,
+    pub highlight_open: Option<String>,
+    pub highlight_close: Option<String>,
+}
+
+impl ListBlogPostsQuery {
+    pub fn tags(&self) -> Vec<String> {
+        self.tags
+            .as_deref()
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|t| !t.is_empty())
+            .map(str::to_string)
+            .collect()
+    }
+
+    /// Search highlight markers, falling back to `<mark>` tags.
+    pub fn markers(&self) -> HighlightMarkers {
+        let defaults = HighlightMarkers::default();
+        HighlightMarkers {
+            open: self.highlight_open.clone().unwrap_or(defaults.open),
+            close: self.highlight_close.clone().unwrap_or(defaults.close),
+        }
+    }
+}
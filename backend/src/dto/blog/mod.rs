@@ -0,0 +1,13 @@
+pub mod blog_post_list_response;
+pub mod create_blog_post_request;
+pub mod create_blog_post_response;
+pub mod list_blog_posts_query;
+pub mod unpublish_blog_post_query;
+pub mod update_blog_post_request;
+
+pub use blog_post_list_response::BlogPostListResponse;
+pub use create_blog_post_request::CreateBlogPostRequest;
+pub use create_blog_post_response::CreateBlogPostResponse;
+pub use list_blog_posts_query::ListBlogPostsQuery;
+pub use unpublish_blog_post_query::UnpublishBlogPostQuery;
+pub use update_blog_post_request::UpdateBlogPostRequest;
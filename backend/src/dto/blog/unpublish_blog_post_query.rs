@@ -0,0 +1,9 @@
+use serde::Deserialize;
+
+/// Query string of `POST /blog/{id}/unpublish`
+#[derive(Deserialize)]
+pub struct UnpublishBlogPostQuery {
+    /// Archive the post instead of returning it to draft
+    #[serde(default)]
+    pub archive: bool,
+}
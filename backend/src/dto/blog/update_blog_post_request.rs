@@ -0,0 +1,12 @@
+use serde::Deserialize;
+
+/// Body of `PATCH /blog/{id}`; omitted fields are left unchanged
+#[derive(Deserialize)]
+pub struct UpdateBlogPostRequest {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub body: Option<String>,
+    /// Replaces the post's tags when present
+    pub tags: Option<Vec<String>>,
+    pub slug: Option<String>,
+}
@@ -0,0 +1,7 @@
+use serde::Serialize;
+
+/// Body of `GET /inquiries/form-token`
+#[derive(Serialize)]
+pub struct FormTokenResponse {
+    pub form_token: String,
+}
@@ -0,0 +1,10 @@
+use serde::Serialize;
+
+use crate::dao::inquiry::Inquiry;
+
+/// One page of inquiries returned by `GET /inquiries`
+#[derive(Serialize)]
+pub struct InquiryListResponse {
+    pub inquiries: Vec<Inquiry>,
+    pub total: i32,
+}
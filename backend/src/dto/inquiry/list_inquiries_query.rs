@@ -0,0 +1,14 @@
+use serde::Deserialize;
+
+use crate::dao::inquiry::InquiryStatus;
+
+/// Query string of `GET /inquiries`, mirroring `ListInquiriesRequest`
+#[derive(Deserialize)]
+pub struct ListInquiriesQuery {
+    /// Leave out for the inbox: everything not archived
+    pub status: Option<InquiryStatus>,
+    #[serde(default)]
+    pub limit: i32,
+    #[serde(default)]
+    pub offset: i32,
+}
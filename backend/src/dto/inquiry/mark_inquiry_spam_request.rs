@@ -0,0 +1,7 @@
+use serde::Deserialize;
+
+/// Body of `POST /inquiries/{id}/spam`
+#[derive(Deserialize)]
+pub struct MarkInquirySpamRequest {
+    pub spam: bool,
+}
@@ -0,0 +1,11 @@
+pub mod form_token_response;
+pub mod inquiry_list_response;
+pub mod list_inquiries_query;
+pub mod mark_inquiry_spam_request;
+pub mod submit_inquiry_request;
+
+pub use form_token_response::FormTokenResponse;
+pub use inquiry_list_response::InquiryListResponse;
+pub use list_inquiries_query::ListInquiriesQuery;
+pub use mark_inquiry_spam_request::MarkInquirySpamRequest;
+pub use submit_inquiry_request::SubmitInquiryRequest;
@@ -0,0 +1,16 @@
+use serde::Deserialize;
+
+/// Body of `POST /inquiries`
+#[derive(Deserialize)]
+pub struct SubmitInquiryRequest {
+    pub name: String,
+    pub email: String,
+    pub subject: String,
+    pub message: String,
+    /// Honeypot, left empty by people
+    #[serde(default)]
+    pub website: String,
+    /// From `GET /inquiries/form-token`
+    #[serde(default)]
+    pub form_token: String,
+}
@@ -1 +1,3 @@
 pub mod auth;
+pub mod blog;
+pub mod inquiry;
@@ -6,11 +6,55 @@ use proto::auth::{
 use tonic::{Request, Response, Status};
 
 use crate::dto::auth::AuthError;
+use crate::middleware::{
+    client_ip, decode_message, login_email_key, AuditDetails, AuditRules, RateLimitKey,
+    RateLimitRule, RateLimitRules,
+};
 use crate::state::AppState;
 use crate::utils::{
     clear_grpc_auth_cookies, parse_cookie, set_grpc_auth_cookies, REFRESH_TOKEN_COOKIE,
 };
 
+/// AuthService RPCs recorded by `AuditLayer`. Login attempts carry no token,
+/// so the actor is the email address tried; the password is never recorded.
+pub const AUTH_AUDIT: AuditRules = &[("Login", describe_login)];
+
+fn describe_login(request: &[u8], _response: Option<&[u8]>) -> AuditDetails {
+    let req: LoginRequest = decode_message(request);
+    AuditDetails {
+        actor_email: Some(req.email),
+        ..Default::default()
+    }
+}
+
+/// AuthService rate limits, enforced by `RateLimitLayer`. Password guessing is
+/// limited both per client and per targeted account, so spreading attempts
+/// over many addresses doesn't help an attacker.
+pub const AUTH_RATE_LIMITS: RateLimitRules = &[
+    RateLimitRule {
+        method: Some("Login"),
+        name: "login-ip",
+        key: RateLimitKey::ClientIp,
+        limit: |config| config.login_rate_limit_per_ip,
+    },
+    RateLimitRule {
+        method: Some("Login"),
+        name: "login-email",
+        key: RateLimitKey::Message(login_email),
+        limit: |config| config.login_rate_limit_per_email,
+    },
+    RateLimitRule {
+        method: Some("RefreshToken"),
+        name: "refresh-ip",
+        key: RateLimitKey::ClientIp,
+        limit: |config| config.refresh_rate_limit_per_ip,
+    },
+];
+
+fn login_email(request: &[u8]) -> Option<String> {
+    login_email_key(&decode_message::<LoginRequest>(request).email)
+}
+
 pub struct AuthController {
     state: AppState,
 }
@@ -27,33 +71,39 @@ impl AuthServiceTrait for AuthController {
         &self,
         request: Request<LoginRequest>,
     ) -> Result<Response<LoginResponse>, Status> {
+        let client_ip = client_ip(request.extensions());
         let req = request.into_inner();
 
-        let auth_response = self
+        let session = self
             .state
-            .firebase_auth
-            .login(req.email, req.password)
-            .await
-            .map_err(|e| match e {
-                AuthError::InvalidCredentials => Status::unauthenticated("Invalid credentials"),
-                AuthError::UserDisabled => Status::permission_denied("User disabled"),
-                AuthError::TooManyAttempts => {
-                    Status::resource_exhausted("Too many attempts, try later")
-                }
-                AuthError::NetworkError | AuthError::InvalidResponse => {
-                    Status::internal("Authentication service error")
-                }
-                _ => Status::internal("Authentication failed"),
-            })?;
-
-        let expires_in: u64 = auth_response.expires_in.parse().unwrap_or(3600);
+            .identity
+            .login(req.email.clone(), req.password)
+            .await;
+        if let Err(AuthError::InvalidCredentials) = session {
+            self.state
+                .notifier
+                .login_failed(&req.email, client_ip)
+                .await;
+        }
+
+        let session = session.map_err(|e| match e {
+            AuthError::InvalidCredentials => Status::unauthenticated("Invalid credentials"),
+            AuthError::UserDisabled => Status::permission_denied("User disabled"),
+            AuthError::TooManyAttempts => {
+                Status::resource_exhausted("Too many attempts, try later")
+            }
+            AuthError::NetworkError | AuthError::InvalidResponse => {
+                Status::internal("Authentication service error")
+            }
+            _ => Status::internal("Authentication failed"),
+        })?;
 
         let mut response = Response::new(LoginResponse {});
         set_grpc_auth_cookies(
             &mut response,
-            &auth_response.id_token,
-            &auth_response.refresh_token,
-            expires_in,
+            &session.tokens.id_token,
+            &session.tokens.refresh_token,
+            session.tokens.expires_in,
         );
 
         Ok(response)
@@ -84,8 +134,8 @@ impl AuthServiceTrait for AuthController {
 
         let refreshed = self
             .state
-            .firebase_auth
-            .refresh_token(&refresh_token)
+            .identity
+            .refresh(&refresh_token)
             .await
             .map_err(|e| match e {
                 AuthError::InvalidRefreshToken => Status::unauthenticated("Invalid refresh token"),
@@ -93,14 +143,12 @@ impl AuthServiceTrait for AuthController {
                 _ => Status::internal("Token refresh failed"),
             })?;
 
-        let expires_in: u64 = refreshed.expires_in.parse().unwrap_or(3600);
-
         let mut response = Response::new(RefreshTokenResponse {});
         set_grpc_auth_cookies(
             &mut response,
             &refreshed.id_token,
             &refreshed.refresh_token,
-            expires_in,
+            refreshed.expires_in,
         );
 
         Ok(response)
@@ -1,15 +1,191 @@
 use proto::blog::blog_admin_service_server::BlogAdminService as BlogAdminServiceTrait;
 use proto::blog::blog_service_server::BlogService as BlogServiceTrait;
+use proto::blog::diff_line::Op as DiffLineOp;
 use proto::blog::{
-    BlogPost, BlogPostSummary, CreateBlogPostRequest, CreateBlogPostResponse,
-    DeleteBlogPostRequest, DeleteBlogPostResponse, GetBlogPostRequest, GetBlogPostResponse,
-    GetBlogPostsRequest, GetBlogPostsResponse, RestoreBlogPostRequest, RestoreBlogPostResponse,
-    UpdateBlogPostRequest, UpdateBlogPostResponse,
+    AuditEvent, BlogPost, BlogPostRevision, BlogPostRevisionSummary, BlogPostStatus,
+    BlogPostSummary, CreateBlogPostRequest, CreateBlogPostResponse, DeleteBlogPostRequest,
+    DeleteBlogPostResponse, DiffBlogPostRevisionsRequest, DiffBlogPostRevisionsResponse, DiffLine,
+    GetBlogPostBySlugRequest, GetBlogPostBySlugResponse, GetBlogPostRequest, GetBlogPostResponse,
+    GetBlogPostRevisionRequest, GetBlogPostRevisionResponse, GetBlogPostsRequest,
+    GetBlogPostsResponse, ListAuditEventsRequest, ListAuditEventsResponse,
+    ListBlogPostRevisionsRequest, ListBlogPostRevisionsResponse, ListTagsRequest, ListTagsResponse,
+    MergeTagsRequest, MergeTagsResponse, PublishBlogPostRequest, PublishBlogPostResponse,
+    RenameTagRequest, RenameTagResponse, RestoreBlogPostRequest, RestoreBlogPostResponse,
+    RevertBlogPostRequest, RevertBlogPostResponse, ScheduleBlogPostRequest,
+    ScheduleBlogPostResponse, SuggestBlogSearchRequest, SuggestBlogSearchResponse, Tag, TocEntry,
+    UnpublishBlogPostRequest, UnpublishBlogPostResponse, UpdateBlogPostRequest,
+    UpdateBlogPostResponse,
 };
+use serde_json::{json, Map, Value};
 use tonic::{Request, Response, Status};
 
-use crate::dao::blog::BlogPostSort;
+use crate::dao::audit::{self as audit, AuditEventFilter};
+use crate::dao::blog::{
+    self as dao, BlogPostFilter, BlogPostSort, BlogPostUpdate, BlogPostVisibility,
+    HighlightMarkers, PageStart,
+};
+use crate::dto::auth::{Caller, Claims, Role};
+use crate::middleware::{
+    decode_message, AuditDetails, AuditRules, Permissions, RateLimitKey, RateLimitRule,
+    RateLimitRules,
+};
+use crate::services::blog::{
+    self as service, decode_page_token, encode_page_token, normalize_tag, parse_search_query,
+    DiffOp,
+};
 use crate::state::AppState;
+use crate::utils::{now_timestamp, slugify};
+
+fn to_summary(p: dao::BlogPostSummary) -> BlogPostSummary {
+    BlogPostSummary {
+        id: p.id,
+        title: p.title,
+        slug: p.slug,
+        description: p.description,
+        created_at: p.created_at,
+        updated_at: p.updated_at,
+        status: BlogPostStatus::from(p.status).into(),
+        published_at: p.published_at,
+        tags: p.tags,
+        snippet: p.snippet,
+        highlighted_title: p.highlighted_title,
+        highlighted_description: p.highlighted_description,
+    }
+}
+
+/// Turn the reader's search input into a safe FTS5 expression.
+fn parse_query(query: Option<&str>) -> Result<Option<String>, Status> {
+    let Some(query) = query else {
+        return Ok(None);
+    };
+
+    parse_search_query(query).map_err(|e| Status::invalid_argument(e.to_string()))
+}
+
+/// Where the requested page starts: after the page token's cursor if one was
+/// given, otherwise at the requested offset.
+fn page_start(
+    req: &GetBlogPostsRequest,
+    sort: BlogPostSort,
+    searching: bool,
+) -> Result<PageStart, Status> {
+    let Some(token) = req.page_token.as_deref().filter(|t| !t.is_empty()) else {
+        return Ok(PageStart::Offset(req.offset));
+    };
+
+    decode_page_token(token, sort, searching)
+        .map(PageStart::After)
+        .map_err(|e| Status::invalid_argument(e.to_string()))
+}
+
+fn to_posts_response(page: dao::BlogPostPage) -> GetBlogPostsResponse {
+    GetBlogPostsResponse {
+        posts: page.posts.into_iter().map(to_summary).collect(),
+        total: page.total,
+        next_page_token: page.next.as_ref().map(encode_page_token),
+    }
+}
+
+/// Search highlight markers from the request, falling back to `<mark>` tags.
+fn highlight_markers(req: &GetBlogPostsRequest) -> HighlightMarkers {
+    let defaults = HighlightMarkers::default();
+    HighlightMarkers {
+        open: req.highlight_open.clone().unwrap_or(defaults.open),
+        close: req.highlight_close.clone().unwrap_or(defaults.close),
+    }
+}
+
+fn to_revision_summary(r: dao::BlogPostRevisionSummary) -> BlogPostRevisionSummary {
+    BlogPostRevisionSummary {
+        post_id: r.post_id,
+        revision: r.revision,
+        title: r.title,
+        author_sub: r.author_sub,
+        created_at: r.created_at,
+    }
+}
+
+fn to_revision(r: dao::BlogPostRevision) -> BlogPostRevision {
+    BlogPostRevision {
+        post_id: r.post_id,
+        revision: r.revision,
+        title: r.title,
+        description: r.description,
+        body: r.body,
+        author_sub: r.author_sub,
+        created_at: r.created_at,
+    }
+}
+
+fn to_diff_line(l: service::DiffLine) -> DiffLine {
+    let op = match l.op {
+        DiffOp::Equal => DiffLineOp::Equal,
+        DiffOp::Insert => DiffLineOp::Insert,
+        DiffOp::Delete => DiffLineOp::Delete,
+    };
+    DiffLine {
+        op: op.into(),
+        text: l.text,
+        old_line: l.old_line.map(|n| n as i32),
+        new_line: l.new_line.map(|n| n as i32),
+    }
+}
+
+fn to_toc_entry(e: dao::TocEntry) -> TocEntry {
+    TocEntry {
+        level: e.level,
+        anchor: e.anchor,
+        title: e.title,
+    }
+}
+
+fn to_post(p: dao::BlogPost) -> BlogPost {
+    BlogPost {
+        id: p.id,
+        title: p.title,
+        slug: p.slug,
+        description: p.description,
+        body: p.body,
+        body_html: p.body_html,
+        toc: p.toc.0.into_iter().map(to_toc_entry).collect(),
+        reading_minutes: p.reading_minutes,
+        created_at: p.created_at,
+        updated_at: p.updated_at,
+        status: BlogPostStatus::from(p.status).into(),
+        published_at: p.published_at,
+        tags: p.tags,
+        deleted_at: None,
+        created_by: None,
+        updated_by: None,
+        deleted_by: None,
+    }
+}
+
+/// A post with who created, changed and deleted it, for admin views
+fn to_admin_post(p: dao::BlogPost) -> BlogPost {
+    let (deleted_at, created_by, updated_by, deleted_by) = (
+        p.deleted_at,
+        p.created_by.clone(),
+        p.updated_by.clone(),
+        p.deleted_by.clone(),
+    );
+    BlogPost {
+        deleted_at,
+        created_by,
+        updated_by,
+        deleted_by,
+        ..to_post(p)
+    }
+}
+
+/// Public BlogService rate limit, enforced by `RateLimitLayer`: generous
+/// enough for readers, but stops a single client scraping or hammering search.
+pub const BLOG_RATE_LIMITS: RateLimitRules = &[RateLimitRule {
+    method: None,
+    name: "public-ip",
+    key: RateLimitKey::ClientIp,
+    limit: |config| config.public_rate_limit_per_ip,
+}];
 
 /// gRPC controller for the public BlogService (read operations).
 pub struct BlogController {
@@ -30,26 +206,18 @@ impl BlogServiceTrait for BlogController {
     ) -> Result<Response<GetBlogPostsResponse>, Status> {
         let req = request.into_inner();
         let sort: BlogPostSort = req.sort.into();
+        let markers = highlight_markers(&req);
+        let query = parse_query(req.query.as_deref())?;
+        let start = page_start(&req, sort, query.is_some())?;
 
-        let (posts, total) = self
+        let page = self
             .state
             .blog_service
-            .list(req.query.as_deref(), req.limit, req.offset, sort)
+            .list(query.as_deref(), &req.tags, markers, req.limit, start, sort)
             .await
             .map_err(|e| Status::internal(e.to_string()))?;
 
-        let posts = posts
-            .into_iter()
-            .map(|p| BlogPostSummary {
-                id: p.id,
-                title: p.title,
-                description: p.description,
-                created_at: p.created_at,
-                updated_at: p.updated_at,
-            })
-            .collect();
-
-        Ok(Response::new(GetBlogPostsResponse { posts, total }))
+        Ok(Response::new(to_posts_response(page)))
     }
 
     async fn get_blog_post(
@@ -65,21 +233,228 @@ impl BlogServiceTrait for BlogController {
             .await
             .map_err(|e| Status::internal(e.to_string()))?;
 
-        let post = post.map(|p| BlogPost {
-            id: p.id,
-            title: p.title,
-            description: p.description,
-            body: p.body,
-            created_at: p.created_at,
-            updated_at: p.updated_at,
-        });
+        let post = post.map(to_post);
 
         Ok(Response::new(GetBlogPostResponse { post }))
     }
+
+    async fn get_blog_post_by_slug(
+        &self,
+        request: Request<GetBlogPostBySlugRequest>,
+    ) -> Result<Response<GetBlogPostBySlugResponse>, Status> {
+        let req = request.into_inner();
+
+        let post = self
+            .state
+            .blog_service
+            .get_by_slug(&req.slug)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+
+        let Some(post) = post else {
+            return Err(Status::not_found("Blog post not found"));
+        };
+
+        // Old slugs still resolve, but tell the client where the post lives now
+        let redirect_to = (post.slug != req.slug).then(|| post.slug.clone());
+
+        Ok(Response::new(GetBlogPostBySlugResponse {
+            post: Some(to_post(post)),
+            redirect_to,
+        }))
+    }
+
+    async fn suggest_blog_search(
+        &self,
+        request: Request<SuggestBlogSearchRequest>,
+    ) -> Result<Response<SuggestBlogSearchResponse>, Status> {
+        let req = request.into_inner();
+
+        let suggestions = self
+            .state
+            .blog_service
+            .suggest(&req.query, req.limit)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+
+        Ok(Response::new(SuggestBlogSearchResponse {
+            completions: suggestions.completions,
+            did_you_mean: suggestions.did_you_mean,
+        }))
+    }
+
+    async fn list_tags(
+        &self,
+        _request: Request<ListTagsRequest>,
+    ) -> Result<Response<ListTagsResponse>, Status> {
+        let tags = self
+            .state
+            .blog_service
+            .list_tags()
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+
+        let tags = tags
+            .into_iter()
+            .map(|t| Tag {
+                name: t.name,
+                post_count: t.post_count,
+            })
+            .collect();
+
+        Ok(Response::new(ListTagsResponse { tags }))
+    }
+}
+
+/// Role required for each BlogAdminService RPC, enforced by `AuthorizeLayer`.
+/// Editors write and publish posts; deleting, restoring and reshaping the tag
+/// set are left to admins.
+pub const BLOG_ADMIN_PERMISSIONS: Permissions = &[
+    ("GetBlogPosts", Role::Editor),
+    ("GetBlogPost", Role::Editor),
+    ("CreateBlogPost", Role::Editor),
+    ("UpdateBlogPost", Role::Editor),
+    ("PublishBlogPost", Role::Editor),
+    ("UnpublishBlogPost", Role::Editor),
+    ("ScheduleBlogPost", Role::Editor),
+    ("ListBlogPostRevisions", Role::Editor),
+    ("GetBlogPostRevision", Role::Editor),
+    ("DiffBlogPostRevisions", Role::Editor),
+    ("RevertBlogPost", Role::Editor),
+    ("DeleteBlogPost", Role::Admin),
+    ("RestoreBlogPost", Role::Admin),
+    ("RenameTag", Role::Admin),
+    ("MergeTags", Role::Admin),
+    ("ListAuditEvents", Role::Admin),
+];
+
+/// BlogAdminService RPCs recorded by `AuditLayer`: every call that changes a
+/// post or the tag set. Bodies are summarized by length rather than stored.
+pub const BLOG_ADMIN_AUDIT: AuditRules = &[
+    ("CreateBlogPost", describe_create),
+    ("UpdateBlogPost", describe_update),
+    ("DeleteBlogPost", describe_delete),
+    ("RestoreBlogPost", describe_restore),
+    ("PublishBlogPost", describe_publish),
+    ("UnpublishBlogPost", describe_unpublish),
+    ("ScheduleBlogPost", describe_schedule),
+    ("RevertBlogPost", describe_revert),
+    ("RenameTag", describe_rename_tag),
+    ("MergeTags", describe_merge_tags),
+];
+
+fn on_post(id: i64, changes: Value) -> AuditDetails {
+    AuditDetails {
+        target_id: Some(id),
+        changes,
+        ..Default::default()
+    }
+}
+
+fn describe_create(request: &[u8], response: Option<&[u8]>) -> AuditDetails {
+    let req: CreateBlogPostRequest = decode_message(request);
+    AuditDetails {
+        target_id: response.map(|r| decode_message::<CreateBlogPostResponse>(r).id),
+        changes: json!({
+            "title": req.title,
+            "description": req.description,
+            "body_length": req.body.len(),
+            "tags": req.tags,
+        }),
+        ..Default::default()
+    }
+}
+
+fn describe_update(request: &[u8], _response: Option<&[u8]>) -> AuditDetails {
+    let req: UpdateBlogPostRequest = decode_message(request);
+    let mut changes = Map::new();
+    if let Some(title) = req.title {
+        changes.insert("title".to_string(), title.into());
+    }
+    if let Some(description) = req.description {
+        changes.insert("description".to_string(), description.into());
+    }
+    if let Some(body) = req.body {
+        changes.insert("body_length".to_string(), body.len().into());
+    }
+    if let Some(tags) = req.tags {
+        changes.insert("tags".to_string(), tags.tags.into());
+    }
+    if let Some(slug) = req.slug {
+        changes.insert("slug".to_string(), slug.into());
+    }
+    on_post(req.id, changes.into())
+}
+
+fn describe_delete(request: &[u8], _response: Option<&[u8]>) -> AuditDetails {
+    on_post(
+        decode_message::<DeleteBlogPostRequest>(request).id,
+        Value::Null,
+    )
+}
+
+fn describe_restore(request: &[u8], _response: Option<&[u8]>) -> AuditDetails {
+    on_post(
+        decode_message::<RestoreBlogPostRequest>(request).id,
+        Value::Null,
+    )
+}
+
+fn describe_publish(request: &[u8], _response: Option<&[u8]>) -> AuditDetails {
+    on_post(
+        decode_message::<PublishBlogPostRequest>(request).id,
+        Value::Null,
+    )
+}
+
+fn describe_unpublish(request: &[u8], _response: Option<&[u8]>) -> AuditDetails {
+    let req: UnpublishBlogPostRequest = decode_message(request);
+    on_post(req.id, json!({ "archive": req.archive }))
+}
+
+fn describe_schedule(request: &[u8], _response: Option<&[u8]>) -> AuditDetails {
+    let req: ScheduleBlogPostRequest = decode_message(request);
+    on_post(req.id, json!({ "publish_at": req.publish_at }))
+}
+
+fn describe_revert(request: &[u8], _response: Option<&[u8]>) -> AuditDetails {
+    let req: RevertBlogPostRequest = decode_message(request);
+    on_post(req.post_id, json!({ "revision": req.revision }))
+}
+
+fn describe_rename_tag(request: &[u8], _response: Option<&[u8]>) -> AuditDetails {
+    let req: RenameTagRequest = decode_message(request);
+    AuditDetails {
+        changes: json!({ "name": req.name, "new_name": req.new_name }),
+        ..Default::default()
+    }
+}
+
+fn describe_merge_tags(request: &[u8], _response: Option<&[u8]>) -> AuditDetails {
+    let req: MergeTagsRequest = decode_message(request);
+    AuditDetails {
+        changes: json!({ "sources": req.sources, "target": req.target }),
+        ..Default::default()
+    }
+}
+
+fn to_audit_event(e: audit::AuditEvent) -> AuditEvent {
+    AuditEvent {
+        id: e.id,
+        created_at: e.created_at,
+        actor_sub: e.actor_sub,
+        actor_email: e.actor_email,
+        action: e.action,
+        target_id: e.target_id,
+        status_code: e.status_code,
+        request_id: e.request_id,
+        client_ip: e.client_ip,
+        changes: e.changes.0.to_string(),
+    }
 }
 
 /// gRPC controller for the protected BlogAdminService (write operations).
-/// Requires authentication via AuthMiddleware.
+/// Requires authentication and a role from `BLOG_ADMIN_PERMISSIONS`.
 pub struct BlogAdminController {
     state: AppState,
 }
@@ -88,20 +463,81 @@ impl BlogAdminController {
     pub fn new(state: AppState) -> Self {
         Self { state }
     }
+
+    /// The verified caller, as attached to the request by `AuthorizeLayer`
+    fn caller<T>(request: &Request<T>) -> Result<Caller, Status> {
+        request
+            .extensions()
+            .get::<Claims>()
+            .map(Caller::from)
+            .ok_or_else(|| Status::unauthenticated("Missing caller identity"))
+    }
 }
 
 #[tonic::async_trait]
 impl BlogAdminServiceTrait for BlogAdminController {
+    async fn get_blog_posts(
+        &self,
+        request: Request<GetBlogPostsRequest>,
+    ) -> Result<Response<GetBlogPostsResponse>, Status> {
+        let req = request.into_inner();
+        let sort: BlogPostSort = req.sort.into();
+        let markers = highlight_markers(&req);
+        let query = parse_query(req.query.as_deref())?;
+        let start = page_start(&req, sort, query.is_some())?;
+        let filter = BlogPostFilter {
+            query,
+            tags: req.tags,
+            visibility: BlogPostVisibility::All,
+            status: req.status.and_then(dao::BlogPostStatus::from_proto),
+            markers,
+        };
+
+        let page = self
+            .state
+            .blog_service
+            .list_all(filter, req.limit, start, sort)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+
+        Ok(Response::new(to_posts_response(page)))
+    }
+
+    async fn get_blog_post(
+        &self,
+        request: Request<GetBlogPostRequest>,
+    ) -> Result<Response<GetBlogPostResponse>, Status> {
+        let req = request.into_inner();
+
+        let post = self
+            .state
+            .blog_service
+            .get_any(req.id)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+
+        let post = post.map(to_admin_post);
+
+        Ok(Response::new(GetBlogPostResponse { post }))
+    }
+
     async fn create_blog_post(
         &self,
         request: Request<CreateBlogPostRequest>,
     ) -> Result<Response<CreateBlogPostResponse>, Status> {
+        let caller = Self::caller(&request)?;
         let req = request.into_inner();
 
         let id = self
             .state
             .blog_service
-            .create(&req.title, &req.description, &req.body)
+            .create(
+                &req.title,
+                &req.description,
+                &req.body,
+                &req.tags,
+                Some(caller.sub.as_str()),
+            )
             .await
             .map_err(|e| Status::internal(e.to_string()))?;
 
@@ -112,17 +548,40 @@ impl BlogAdminServiceTrait for BlogAdminController {
         &self,
         request: Request<UpdateBlogPostRequest>,
     ) -> Result<Response<UpdateBlogPostResponse>, Status> {
+        let caller = Self::caller(&request)?;
         let req = request.into_inner();
 
+        let slug = req.slug.as_deref().map(slugify);
+        if let Some(slug) = &slug {
+            if slug.is_empty() {
+                return Err(Status::invalid_argument(
+                    "Slug must contain letters or digits",
+                ));
+            }
+
+            let taken = self
+                .state
+                .blog_service
+                .slug_taken(slug, req.id)
+                .await
+                .map_err(|e| Status::internal(e.to_string()))?;
+            if taken {
+                return Err(Status::already_exists("Slug is already in use"));
+            }
+        }
+
+        let changes = BlogPostUpdate {
+            title: req.title.as_deref(),
+            description: req.description.as_deref(),
+            body: req.body.as_deref(),
+            tags: req.tags.as_ref().map(|t| t.tags.as_slice()),
+            slug: slug.as_deref(),
+        };
+
         let updated = self
             .state
             .blog_service
-            .update(
-                req.id,
-                req.title.as_deref(),
-                req.description.as_deref(),
-                req.body.as_deref(),
-            )
+            .update(req.id, changes, Some(caller.sub.as_str()))
             .await
             .map_err(|e| Status::internal(e.to_string()))?;
 
@@ -137,12 +596,13 @@ impl BlogAdminServiceTrait for BlogAdminController {
         &self,
         request: Request<DeleteBlogPostRequest>,
     ) -> Result<Response<DeleteBlogPostResponse>, Status> {
+        let caller = Self::caller(&request)?;
         let req = request.into_inner();
 
         let deleted = self
             .state
             .blog_service
-            .delete(req.id)
+            .delete(req.id, Some(caller.sub.as_str()))
             .await
             .map_err(|e| Status::internal(e.to_string()))?;
 
@@ -157,12 +617,13 @@ impl BlogAdminServiceTrait for BlogAdminController {
         &self,
         request: Request<RestoreBlogPostRequest>,
     ) -> Result<Response<RestoreBlogPostResponse>, Status> {
+        let caller = Self::caller(&request)?;
         let req = request.into_inner();
 
         let restored = self
             .state
             .blog_service
-            .restore(req.id)
+            .restore(req.id, Some(caller.sub.as_str()))
             .await
             .map_err(|e| Status::internal(e.to_string()))?;
 
@@ -172,4 +633,235 @@ impl BlogAdminServiceTrait for BlogAdminController {
             Err(Status::not_found("Blog post not found"))
         }
     }
+
+    async fn publish_blog_post(
+        &self,
+        request: Request<PublishBlogPostRequest>,
+    ) -> Result<Response<PublishBlogPostResponse>, Status> {
+        let caller = Self::caller(&request)?;
+        let req = request.into_inner();
+
+        let published = self
+            .state
+            .blog_service
+            .publish(req.id, Some(caller.sub.as_str()))
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+
+        if published {
+            Ok(Response::new(PublishBlogPostResponse {}))
+        } else {
+            Err(Status::not_found("Blog post not found"))
+        }
+    }
+
+    async fn unpublish_blog_post(
+        &self,
+        request: Request<UnpublishBlogPostRequest>,
+    ) -> Result<Response<UnpublishBlogPostResponse>, Status> {
+        let caller = Self::caller(&request)?;
+        let req = request.into_inner();
+
+        let unpublished = self
+            .state
+            .blog_service
+            .unpublish(req.id, req.archive, Some(caller.sub.as_str()))
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+
+        if unpublished {
+            Ok(Response::new(UnpublishBlogPostResponse {}))
+        } else {
+            Err(Status::not_found("Blog post not found"))
+        }
+    }
+
+    async fn schedule_blog_post(
+        &self,
+        request: Request<ScheduleBlogPostRequest>,
+    ) -> Result<Response<ScheduleBlogPostResponse>, Status> {
+        let caller = Self::caller(&request)?;
+        let req = request.into_inner();
+
+        if req.publish_at <= now_timestamp() {
+            return Err(Status::invalid_argument(
+                "publish_at must be in the future; use PublishBlogPost to publish now",
+            ));
+        }
+
+        let scheduled = self
+            .state
+            .blog_service
+            .schedule(req.id, req.publish_at, Some(caller.sub.as_str()))
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+
+        if scheduled {
+            Ok(Response::new(ScheduleBlogPostResponse {}))
+        } else {
+            Err(Status::not_found("Blog post not found"))
+        }
+    }
+
+    async fn list_blog_post_revisions(
+        &self,
+        request: Request<ListBlogPostRevisionsRequest>,
+    ) -> Result<Response<ListBlogPostRevisionsResponse>, Status> {
+        let req = request.into_inner();
+
+        let (revisions, total) = self
+            .state
+            .blog_service
+            .list_revisions(req.post_id, req.limit, req.offset)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+
+        let revisions = revisions.into_iter().map(to_revision_summary).collect();
+
+        Ok(Response::new(ListBlogPostRevisionsResponse {
+            revisions,
+            total,
+        }))
+    }
+
+    async fn get_blog_post_revision(
+        &self,
+        request: Request<GetBlogPostRevisionRequest>,
+    ) -> Result<Response<GetBlogPostRevisionResponse>, Status> {
+        let req = request.into_inner();
+
+        let revision = self
+            .state
+            .blog_service
+            .get_revision(req.post_id, req.revision)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+
+        let revision = revision.map(to_revision);
+
+        Ok(Response::new(GetBlogPostRevisionResponse { revision }))
+    }
+
+    async fn diff_blog_post_revisions(
+        &self,
+        request: Request<DiffBlogPostRevisionsRequest>,
+    ) -> Result<Response<DiffBlogPostRevisionsResponse>, Status> {
+        let req = request.into_inner();
+
+        let lines = self
+            .state
+            .blog_service
+            .diff_revisions(req.post_id, req.from_revision, req.to_revision)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?
+            .ok_or_else(|| Status::not_found("Blog post revision not found"))?;
+
+        let lines = lines.into_iter().map(to_diff_line).collect();
+
+        Ok(Response::new(DiffBlogPostRevisionsResponse { lines }))
+    }
+
+    async fn revert_blog_post(
+        &self,
+        request: Request<RevertBlogPostRequest>,
+    ) -> Result<Response<RevertBlogPostResponse>, Status> {
+        let caller = Self::caller(&request)?;
+        let req = request.into_inner();
+
+        let revision = self
+            .state
+            .blog_service
+            .revert(req.post_id, req.revision, Some(caller.sub.as_str()))
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?
+            .ok_or_else(|| Status::not_found("Blog post revision not found"))?;
+
+        Ok(Response::new(RevertBlogPostResponse { revision }))
+    }
+
+    async fn rename_tag(
+        &self,
+        request: Request<RenameTagRequest>,
+    ) -> Result<Response<RenameTagResponse>, Status> {
+        let caller = Self::caller(&request)?;
+        let req = request.into_inner();
+
+        let name = normalize_tag(&req.name)
+            .ok_or_else(|| Status::invalid_argument("Tag name must not be empty"))?;
+        let new_name = normalize_tag(&req.new_name)
+            .ok_or_else(|| Status::invalid_argument("New tag name must not be empty"))?;
+
+        if name != new_name
+            && self
+                .state
+                .blog_service
+                .tag_exists(&new_name)
+                .await
+                .map_err(|e| Status::internal(e.to_string()))?
+        {
+            return Err(Status::already_exists(
+                "A tag with that name already exists; use MergeTags to combine them",
+            ));
+        }
+
+        let renamed = self
+            .state
+            .blog_service
+            .rename_tag(&name, &new_name, Some(caller.sub.as_str()))
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+
+        if renamed {
+            Ok(Response::new(RenameTagResponse {}))
+        } else {
+            Err(Status::not_found("Tag not found"))
+        }
+    }
+
+    async fn merge_tags(
+        &self,
+        request: Request<MergeTagsRequest>,
+    ) -> Result<Response<MergeTagsResponse>, Status> {
+        let caller = Self::caller(&request)?;
+        let req = request.into_inner();
+
+        let target = normalize_tag(&req.target)
+            .ok_or_else(|| Status::invalid_argument("Target tag name must not be empty"))?;
+
+        let merged = self
+            .state
+            .blog_service
+            .merge_tags(&req.sources, &target, Some(caller.sub.as_str()))
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+
+        Ok(Response::new(MergeTagsResponse {
+            merged: merged as i32,
+        }))
+    }
+
+    async fn list_audit_events(
+        &self,
+        request: Request<ListAuditEventsRequest>,
+    ) -> Result<Response<ListAuditEventsResponse>, Status> {
+        let req = request.into_inner();
+
+        let filter = AuditEventFilter {
+            actor: req.actor.filter(|a| !a.is_empty()),
+            action: req.action.filter(|a| !a.is_empty()),
+            since: req.since,
+            until: req.until,
+        };
+
+        let (events, total) = self
+            .state
+            .audit_service
+            .list(&filter, req.limit, req.offset)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+
+        let events = events.into_iter().map(to_audit_event).collect();
+
+        Ok(Response::new(ListAuditEventsResponse { events, total }))
+    }
 }
@@ -0,0 +1,287 @@
+use proto::inquiry::inquiry_admin_service_server::InquiryAdminService as InquiryAdminServiceTrait;
+use proto::inquiry::inquiry_service_server::InquiryService as InquiryServiceTrait;
+use proto::inquiry::{
+    ArchiveInquiryRequest, ArchiveInquiryResponse, DeleteInquiryRequest, DeleteInquiryResponse,
+    GetFormTokenRequest, GetFormTokenResponse, GetInquiryRequest, GetInquiryResponse, Inquiry,
+    InquiryStatus, ListInquiriesRequest, ListInquiriesResponse, MarkInquiryHandledRequest,
+    MarkInquiryHandledResponse, MarkInquirySpamRequest, MarkInquirySpamResponse,
+    SubmitInquiryRequest, SubmitInquiryResponse,
+};
+use serde_json::json;
+use tonic::{Request, Response, Status};
+
+use crate::dao::inquiry as dao;
+use crate::dto::auth::{Caller, Claims, Role};
+use crate::middleware::{
+    client_ip, decode_message, AuditDetails, AuditRules, Permissions, RateLimitKey, RateLimitRule,
+    RateLimitRules,
+};
+use crate::services::inquiry::validate_inquiry;
+use crate::services::spam::Submission;
+use crate::state::AppState;
+
+fn to_inquiry(i: dao::Inquiry) -> Inquiry {
+    Inquiry {
+        id: i.id,
+        name: i.name,
+        email: i.email,
+        subject: i.subject,
+        message: i.message,
+        status: InquiryStatus::from(i.status).into(),
+        created_at: i.created_at,
+        handled_at: i.handled_at,
+        handled_by: i.handled_by,
+        archived_at: i.archived_at,
+        spam_score: i.spam_score,
+        spam_reasons: i.spam_reasons.0,
+        marked_spam: i.marked_spam,
+    }
+}
+
+/// Public InquiryService rate limit, enforced by `RateLimitLayer`, so the
+/// contact form can't be used to flood the inbox.
+pub const INQUIRY_RATE_LIMITS: RateLimitRules = &[RateLimitRule {
+    method: Some("SubmitInquiry"),
+    name: "inquiry-ip",
+    key: RateLimitKey::ClientIp,
+    limit: |config| config.inquiry_rate_limit_per_ip,
+}];
+
+/// gRPC controller for the public InquiryService (the contact form).
+pub struct InquiryController {
+    state: AppState,
+}
+
+impl InquiryController {
+    pub fn new(state: AppState) -> Self {
+        Self { state }
+    }
+}
+
+#[tonic::async_trait]
+impl InquiryServiceTrait for InquiryController {
+    async fn get_form_token(
+        &self,
+        _request: Request<GetFormTokenRequest>,
+    ) -> Result<Response<GetFormTokenResponse>, Status> {
+        Ok(Response::new(GetFormTokenResponse {
+            form_token: self.state.spam_filter.form_token(),
+        }))
+    }
+
+    async fn submit_inquiry(
+        &self,
+        request: Request<SubmitInquiryRequest>,
+    ) -> Result<Response<SubmitInquiryResponse>, Status> {
+        let client_ip = client_ip(request.extensions());
+        let req = request.into_inner();
+
+        let inquiry = validate_inquiry(&req.name, &req.email, &req.subject, &req.message)
+            .map_err(|e| Status::invalid_argument(e.to_string()))?;
+
+        let spam = self
+            .state
+            .spam_filter
+            .check(&Submission {
+                client_ip,
+                honeypot: &req.website,
+                form_token: &req.form_token,
+                text: &[
+                    &inquiry.name,
+                    &inquiry.email,
+                    &inquiry.subject,
+                    &inquiry.message,
+                ],
+            })
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+
+        self.state
+            .inquiry_service
+            .submit(&inquiry, &spam)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+
+        Ok(Response::new(SubmitInquiryResponse {}))
+    }
+}
+
+/// Role required for each InquiryAdminService RPC, enforced by
+/// `AuthorizeLayer`. Editors work the inbox; only admins delete for good.
+pub const INQUIRY_ADMIN_PERMISSIONS: Permissions = &[
+    ("ListInquiries", Role::Editor),
+    ("GetInquiry", Role::Editor),
+    ("MarkInquiryHandled", Role::Editor),
+    ("ArchiveInquiry", Role::Editor),
+    ("MarkInquirySpam", Role::Editor),
+    ("DeleteInquiry", Role::Admin),
+];
+
+/// InquiryAdminService RPCs recorded by `AuditLayer`
+pub const INQUIRY_ADMIN_AUDIT: AuditRules = &[
+    ("MarkInquiryHandled", describe_mark_handled),
+    ("ArchiveInquiry", describe_archive),
+    ("MarkInquirySpam", describe_mark_spam),
+    ("DeleteInquiry", describe_delete),
+];
+
+fn on_inquiry(id: i64) -> AuditDetails {
+    AuditDetails {
+        target_id: Some(id),
+        ..Default::default()
+    }
+}
+
+fn describe_mark_handled(request: &[u8], _response: Option<&[u8]>) -> AuditDetails {
+    on_inquiry(decode_message::<MarkInquiryHandledRequest>(request).id)
+}
+
+fn describe_archive(request: &[u8], _response: Option<&[u8]>) -> AuditDetails {
+    on_inquiry(decode_message::<ArchiveInquiryRequest>(request).id)
+}
+
+fn describe_mark_spam(request: &[u8], _response: Option<&[u8]>) -> AuditDetails {
+    let req = decode_message::<MarkInquirySpamRequest>(request);
+    AuditDetails {
+        changes: json!({ "spam": req.spam }),
+        ..on_inquiry(req.id)
+    }
+}
+
+fn describe_delete(request: &[u8], _response: Option<&[u8]>) -> AuditDetails {
+    on_inquiry(decode_message::<DeleteInquiryRequest>(request).id)
+}
+
+/// gRPC controller for the protected InquiryAdminService (the inbox).
+/// Requires authentication and a role from `INQUIRY_ADMIN_PERMISSIONS`.
+pub struct InquiryAdminController {
+    state: AppState,
+}
+
+impl InquiryAdminController {
+    pub fn new(state: AppState) -> Self {
+        Self { state }
+    }
+
+    /// The verified caller, as attached to the request by `AuthorizeLayer`
+    fn caller<T>(request: &Request<T>) -> Result<Caller, Status> {
+        request
+            .extensions()
+            .get::<Claims>()
+            .map(Caller::from)
+            .ok_or_else(|| Status::unauthenticated("Missing caller identity"))
+    }
+}
+
+fn found<T>(found: bool, response: T) -> Result<Response<T>, Status> {
+    if found {
+        Ok(Response::new(response))
+    } else {
+        Err(Status::not_found("Inquiry not found"))
+    }
+}
+
+#[tonic::async_trait]
+impl InquiryAdminServiceTrait for InquiryAdminController {
+    async fn list_inquiries(
+        &self,
+        request: Request<ListInquiriesRequest>,
+    ) -> Result<Response<ListInquiriesResponse>, Status> {
+        let req = request.into_inner();
+        let status = req.status.and_then(dao::InquiryStatus::from_proto);
+
+        let (inquiries, total) = self
+            .state
+            .inquiry_service
+            .list(status, req.limit, req.offset)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+
+        let inquiries = inquiries.into_iter().map(to_inquiry).collect();
+
+        Ok(Response::new(ListInquiriesResponse { inquiries, total }))
+    }
+
+    async fn get_inquiry(
+        &self,
+        request: Request<GetInquiryRequest>,
+    ) -> Result<Response<GetInquiryResponse>, Status> {
+        let req = request.into_inner();
+
+        let inquiry = self
+            .state
+            .inquiry_service
+            .get(req.id)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+
+        let inquiry = inquiry.map(to_inquiry);
+
+        Ok(Response::new(GetInquiryResponse { inquiry }))
+    }
+
+    async fn mark_inquiry_handled(
+        &self,
+        request: Request<MarkInquiryHandledRequest>,
+    ) -> Result<Response<MarkInquiryHandledResponse>, Status> {
+        let caller = Self::caller(&request)?;
+        let req = request.into_inner();
+
+        let handled = self
+            .state
+            .inquiry_service
+            .mark_handled(req.id, Some(caller.sub.as_str()))
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+
+        found(handled, MarkInquiryHandledResponse {})
+    }
+
+    async fn archive_inquiry(
+        &self,
+        request: Request<ArchiveInquiryRequest>,
+    ) -> Result<Response<ArchiveInquiryResponse>, Status> {
+        let req = request.into_inner();
+
+        let archived = self
+            .state
+            .inquiry_service
+            .archive(req.id)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+
+        found(archived, ArchiveInquiryResponse {})
+    }
+
+    async fn mark_inquiry_spam(
+        &self,
+        request: Request<MarkInquirySpamRequest>,
+    ) -> Result<Response<MarkInquirySpamResponse>, Status> {
+        let req = request.into_inner();
+
+        let marked = self
+            .state
+            .inquiry_service
+            .mark_spam(req.id, req.spam)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+
+        found(marked, MarkInquirySpamResponse {})
+    }
+
+    async fn delete_inquiry(
+        &self,
+        request: Request<DeleteInquiryRequest>,
+    ) -> Result<Response<DeleteInquiryResponse>, Status> {
+        let req = request.into_inner();
+
+        let deleted = self
+            .state
+            .inquiry_service
+            .delete(req.id)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+
+        found(deleted, DeleteInquiryResponse {})
+    }
+}
@@ -1,5 +1,15 @@
 mod auth;
 mod blog;
+mod inquiry;
+mod webhook;
 
-pub use auth::AuthController;
-pub use blog::{BlogAdminController, BlogController};
+pub use auth::{AuthController, AUTH_AUDIT, AUTH_RATE_LIMITS};
+pub use blog::{
+    BlogAdminController, BlogController, BLOG_ADMIN_AUDIT, BLOG_ADMIN_PERMISSIONS,
+    BLOG_RATE_LIMITS,
+};
+pub use inquiry::{
+    InquiryAdminController, InquiryController, INQUIRY_ADMIN_AUDIT, INQUIRY_ADMIN_PERMISSIONS,
+    INQUIRY_RATE_LIMITS,
+};
+pub use webhook::{WebhookAdminController, WEBHOOK_ADMIN_AUDIT, WEBHOOK_ADMIN_PERMISSIONS};
@@ -0,0 +1,350 @@
+use proto::webhook::webhook_admin_service_server::WebhookAdminService as WebhookAdminServiceTrait;
+use proto::webhook::{
+    CreateWebhookRequest, CreateWebhookResponse, DeleteWebhookRequest, DeleteWebhookResponse,
+    GetWebhookRequest, GetWebhookResponse, ListWebhookDeliveriesRequest,
+    ListWebhookDeliveriesResponse, ListWebhooksRequest, ListWebhooksResponse,
+    RedeliverWebhookRequest, RedeliverWebhookResponse, UpdateWebhookRequest, UpdateWebhookResponse,
+    Webhook, WebhookDelivery, WebhookDeliveryStatus, WebhookEvent,
+};
+use serde_json::{json, Map};
+use tonic::{Request, Response, Status};
+
+use crate::dao::webhook as dao;
+use crate::dto::auth::{Caller, Claims, Role};
+use crate::middleware::{decode_message, AuditDetails, AuditRules, Permissions};
+use crate::services::webhook::{generate_secret, validate_secret, validate_url};
+use crate::state::AppState;
+
+fn to_webhook(w: dao::Webhook) -> Webhook {
+    Webhook {
+        id: w.id,
+        url: w.url,
+        events: w
+            .events
+            .0
+            .into_iter()
+            .map(|event| WebhookEvent::from(event).into())
+            .collect(),
+        active: w.active,
+        created_at: w.created_at,
+        updated_at: w.updated_at,
+        created_by: w.created_by,
+    }
+}
+
+fn to_delivery(d: dao::WebhookDelivery) -> WebhookDelivery {
+    WebhookDelivery {
+        id: d.id,
+        webhook_id: d.webhook_id,
+        event: WebhookEvent::from(d.event).into(),
+        payload: d.payload,
+        status: WebhookDeliveryStatus::from(d.status).into(),
+        attempts: d.attempts,
+        next_attempt_at: d.next_attempt_at,
+        response_status: d.response_status,
+        last_error: d.last_error,
+        created_at: d.created_at,
+        delivered_at: d.delivered_at,
+        redelivery_of: d.redelivery_of,
+    }
+}
+
+/// Events a request named, without unspecified or unknown ones or repeats
+fn events_from_proto(events: &[i32]) -> Vec<dao::WebhookEvent> {
+    let mut parsed: Vec<dao::WebhookEvent> = vec![];
+    for event in events
+        .iter()
+        .filter_map(|&e| dao::WebhookEvent::from_proto(e))
+    {
+        if !parsed.contains(&event) {
+            parsed.push(event);
+        }
+    }
+    parsed
+}
+
+/// Event names for the audit log
+fn event_names(events: &[i32]) -> Vec<&'static str> {
+    events_from_proto(events)
+        .into_iter()
+        .map(dao::WebhookEvent::as_str)
+        .collect()
+}
+
+/// Role required for each WebhookAdminService RPC, enforced by
+/// `AuthorizeLayer`. Webhooks carry signing secrets and reach other systems,
+/// so they're admin only.
+pub const WEBHOOK_ADMIN_PERMISSIONS: Permissions = &[
+    ("ListWebhooks", Role::Admin),
+    ("GetWebhook", Role::Admin),
+    ("CreateWebhook", Role::Admin),
+    ("UpdateWebhook", Role::Admin),
+    ("DeleteWebhook", Role::Admin),
+    ("ListWebhookDeliveries", Role::Admin),
+    ("RedeliverWebhook", Role::Admin),
+];
+
+/// WebhookAdminService RPCs recorded by `AuditLayer`. Secrets are never
+/// logged, only whether one was set.
+pub const WEBHOOK_ADMIN_AUDIT: AuditRules = &[
+    ("CreateWebhook", describe_create),
+    ("UpdateWebhook", describe_update),
+    ("DeleteWebhook", describe_delete),
+    ("RedeliverWebhook", describe_redeliver),
+];
+
+fn describe_create(request: &[u8], response: Option<&[u8]>) -> AuditDetails {
+    let req: CreateWebhookRequest = decode_message(request);
+    AuditDetails {
+        target_id: response
+            .and_then(|r| decode_message::<CreateWebhookResponse>(r).webhook)
+            .map(|webhook| webhook.id),
+        changes: json!({
+            "url": req.url,
+            "events": event_names(&req.events),
+            "secret_generated": req.secret.is_empty(),
+        }),
+        ..Default::default()
+    }
+}
+
+fn describe_update(request: &[u8], _response: Option<&[u8]>) -> AuditDetails {
+    let req: UpdateWebhookRequest = decode_message(request);
+    let mut changes = Map::new();
+    if let Some(url) = req.url {
+        changes.insert("url".to_string(), url.into());
+    }
+    if req.secret.is_some() {
+        changes.insert("secret".to_string(), "changed".into());
+    }
+    if let Some(events) = req.events {
+        changes.insert("events".to_string(), event_names(&events.events).into());
+    }
+    if let Some(active) = req.active {
+        changes.insert("active".to_string(), active.into());
+    }
+    AuditDetails {
+        target_id: Some(req.id),
+        changes: changes.into(),
+        ..Default::default()
+    }
+}
+
+fn describe_delete(request: &[u8], _response: Option<&[u8]>) -> AuditDetails {
+    AuditDetails {
+        target_id: Some(decode_message::<DeleteWebhookRequest>(request).id),
+        ..Default::default()
+    }
+}
+
+fn describe_redeliver(request: &[u8], response: Option<&[u8]>) -> AuditDetails {
+    let req: RedeliverWebhookRequest = decode_message(request);
+    let mut changes = Map::new();
+    if let Some(response) = response {
+        let redelivery = decode_message::<RedeliverWebhookResponse>(response).delivery_id;
+        changes.insert("redelivery_id".to_string(), redelivery.into());
+    }
+    AuditDetails {
+        target_id: Some(req.delivery_id),
+        changes: changes.into(),
+        ..Default::default()
+    }
+}
+
+/// gRPC controller for the protected WebhookAdminService.
+/// Requires authentication and a role from `WEBHOOK_ADMIN_PERMISSIONS`.
+pub struct WebhookAdminController {
+    state: AppState,
+}
+
+impl WebhookAdminController {
+    pub fn new(state: AppState) -> Self {
+        Self { state }
+    }
+
+    /// The verified caller, as attached to the request by `AuthorizeLayer`
+    fn caller<T>(request: &Request<T>) -> Result<Caller, Status> {
+        request
+            .extensions()
+            .get::<Claims>()
+            .map(Caller::from)
+            .ok_or_else(|| Status::unauthenticated("Missing caller identity"))
+    }
+}
+
+fn found<T>(found: bool, response: T) -> Result<Response<T>, Status> {
+    if found {
+        Ok(Response::new(response))
+    } else {
+        Err(Status::not_found("Webhook not found"))
+    }
+}
+
+#[tonic::async_trait]
+impl WebhookAdminServiceTrait for WebhookAdminController {
+    async fn list_webhooks(
+        &self,
+        _request: Request<ListWebhooksRequest>,
+    ) -> Result<Response<ListWebhooksResponse>, Status> {
+        let webhooks = self
+            .state
+            .webhook_service
+            .list()
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+
+        let webhooks = webhooks.into_iter().map(to_webhook).collect();
+
+        Ok(Response::new(ListWebhooksResponse { webhooks }))
+    }
+
+    async fn get_webhook(
+        &self,
+        request: Request<GetWebhookRequest>,
+    ) -> Result<Response<GetWebhookResponse>, Status> {
+        let req = request.into_inner();
+
+        let webhook = self
+            .state
+            .webhook_service
+            .get(req.id)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+
+        let webhook = webhook.map(to_webhook);
+
+        Ok(Response::new(GetWebhookResponse { webhook }))
+    }
+
+    async fn create_webhook(
+        &self,
+        request: Request<CreateWebhookRequest>,
+    ) -> Result<Response<CreateWebhookResponse>, Status> {
+        let caller = Self::caller(&request)?;
+        let req = request.into_inner();
+
+        let allow_private = self.state.config.webhook_allow_private_urls;
+        let url = validate_url(&req.url, allow_private)
+            .map_err(|e| Status::invalid_argument(e.to_string()))?;
+        let secret = if req.secret.is_empty() {
+            generate_secret()
+        } else {
+            validate_secret(&req.secret)
+                .map_err(|e| Status::invalid_argument(e.to_string()))?
+                .to_string()
+        };
+        let webhook = dao::NewWebhook {
+            url,
+            secret,
+            events: events_from_proto(&req.events),
+        };
+
+        let service = &self.state.webhook_service;
+        let id = service
+            .create(&webhook, Some(caller.sub.as_str()))
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+        let created = service
+            .get(id)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?
+            .ok_or_else(|| Status::internal("Created webhook not found"))?;
+
+        Ok(Response::new(CreateWebhookResponse {
+            webhook: Some(to_webhook(created)),
+            secret: webhook.secret,
+        }))
+    }
+
+    async fn update_webhook(
+        &self,
+        request: Request<UpdateWebhookRequest>,
+    ) -> Result<Response<UpdateWebhookResponse>, Status> {
+        let req = request.into_inner();
+
+        let allow_private = self.state.config.webhook_allow_private_urls;
+        let url = req
+            .url
+            .as_deref()
+            .map(|url| validate_url(url, allow_private))
+            .transpose()
+            .map_err(|e| Status::invalid_argument(e.to_string()))?;
+        let secret = req
+            .secret
+            .as_deref()
+            .map(validate_secret)
+            .transpose()
+            .map_err(|e| Status::invalid_argument(e.to_string()))?;
+        let events = req.events.map(|events| events_from_proto(&events.events));
+
+        let changes = dao::WebhookUpdate {
+            url: url.as_deref(),
+            secret,
+            events: events.as_deref(),
+            active: req.active,
+        };
+        let updated = self
+            .state
+            .webhook_service
+            .update(req.id, changes)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+
+        found(updated, UpdateWebhookResponse {})
+    }
+
+    async fn delete_webhook(
+        &self,
+        request: Request<DeleteWebhookRequest>,
+    ) -> Result<Response<DeleteWebhookResponse>, Status> {
+        let req = request.into_inner();
+
+        let deleted = self
+            .state
+            .webhook_service
+            .delete(req.id)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+
+        found(deleted, DeleteWebhookResponse {})
+    }
+
+    async fn list_webhook_deliveries(
+        &self,
+        request: Request<ListWebhookDeliveriesRequest>,
+    ) -> Result<Response<ListWebhookDeliveriesResponse>, Status> {
+        let req = request.into_inner();
+        let status = req.status.and_then(dao::DeliveryStatus::from_proto);
+
+        let (deliveries, total) = self
+            .state
+            .webhook_service
+            .list_deliveries(req.webhook_id, status, req.limit, req.offset)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+
+        let deliveries = deliveries.into_iter().map(to_delivery).collect();
+
+        Ok(Response::new(ListWebhookDeliveriesResponse {
+            deliveries,
+            total,
+        }))
+    }
+
+    async fn redeliver_webhook(
+        &self,
+        request: Request<RedeliverWebhookRequest>,
+    ) -> Result<Response<RedeliverWebhookResponse>, Status> {
+        let req = request.into_inner();
+
+        let delivery_id = self
+            .state
+            .webhook_service
+            .redeliver(req.delivery_id)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?
+            .ok_or_else(|| Status::not_found("Webhook delivery not found"))?;
+
+        Ok(Response::new(RedeliverWebhookResponse { delivery_id }))
+    }
+}
@@ -0,0 +1,12 @@
+pub mod api;
+pub mod config;
+pub mod dao;
+pub mod db;
+pub mod dto;
+pub mod grpc;
+pub mod middleware;
+pub mod repositories;
+pub mod services;
+pub mod state;
+pub mod utils;
+pub mod web;
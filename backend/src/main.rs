@@ -1,28 +1,32 @@
-mod config;
-mod dao;
-mod db;
-mod dto;
-mod grpc;
-mod middleware;
-mod repositories;
-mod services;
-mod state;
-mod utils;
-
-use http::header::HeaderName;
 use proto::auth::auth_service_server::AuthServiceServer;
 use proto::blog::blog_admin_service_server::BlogAdminServiceServer;
 use proto::blog::blog_service_server::BlogServiceServer;
+use proto::inquiry::inquiry_admin_service_server::InquiryAdminServiceServer;
+use proto::inquiry::inquiry_service_server::InquiryServiceServer;
+use proto::webhook::webhook_admin_service_server::WebhookAdminServiceServer;
 use tonic::service::Routes;
 use tonic::transport::Server;
 use tonic_web::GrpcWebLayer;
-use tower_http::cors::{AllowOrigin, CorsLayer};
+use tower::Layer;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
 
-use crate::config::Config;
-use crate::grpc::{AuthController, BlogAdminController, BlogController};
-use crate::middleware::auth_interceptor;
-use crate::state::AppState;
+use backend::config::Config;
+use backend::grpc::{
+    AuthController, BlogAdminController, BlogController, InquiryAdminController,
+    InquiryController, WebhookAdminController, AUTH_AUDIT, AUTH_RATE_LIMITS, BLOG_ADMIN_AUDIT,
+    BLOG_ADMIN_PERMISSIONS, BLOG_RATE_LIMITS, INQUIRY_ADMIN_AUDIT, INQUIRY_ADMIN_PERMISSIONS,
+    INQUIRY_RATE_LIMITS, WEBHOOK_ADMIN_AUDIT, WEBHOOK_ADMIN_PERMISSIONS,
+};
+use backend::middleware::{
+    cors_layer, resolve_client_ip, AuditLayer, AuthorizeLayer, RateLimitLayer,
+};
+use backend::services::blog::spawn_scheduler;
+use backend::services::email::spawn_email_outbox;
+use backend::services::health::spawn_health_monitor;
+use backend::services::rate_limit::spawn_rate_limit_maintenance;
+use backend::services::webhook::spawn_webhook_delivery;
+use backend::state::AppState;
+use backend::{api, db, services, web};
 
 #[tokio::main]
 async fn main() {
@@ -37,47 +41,130 @@ async fn main() {
     let config = Config::from_env();
     let addr = config.socket_addr();
     let cors_origin = config.cors_origin.clone();
+    let publish_interval = std::time::Duration::from_secs(config.publish_interval_secs);
+    let health_interval = std::time::Duration::from_secs(config.health_check_interval_secs);
+    let outbox_interval = std::time::Duration::from_secs(config.email_outbox_interval_secs);
+    let webhook_interval = std::time::Duration::from_secs(config.webhook_delivery_interval_secs);
 
-    let pool = db::init(&config.database_path).await;
-    let state = AppState::new(config, pool);
-
-    let cors = CorsLayer::new()
-        .allow_origin(AllowOrigin::exact(cors_origin.parse().unwrap()))
-        .allow_headers([
-            HeaderName::from_static("content-type"),
-            HeaderName::from_static("connect-protocol-version"),
-            HeaderName::from_static("connect-timeout-ms"),
-            HeaderName::from_static("grpc-timeout"),
-            HeaderName::from_static("x-grpc-web"),
-            HeaderName::from_static("x-user-agent"),
-        ])
-        .expose_headers([
-            HeaderName::from_static("grpc-status"),
-            HeaderName::from_static("grpc-message"),
-            HeaderName::from_static("grpc-status-details-bin"),
-        ])
-        .allow_methods([http::Method::POST])
-        .max_age(std::time::Duration::from_secs(7200))
-        .allow_credentials(true);
-
-    // Pre-fetch Firebase public keys at startup
-    if let Err(e) = state.firebase_auth.prefetch_keys().await {
-        tracing::warn!("Failed to prefetch Firebase keys: {:?}", e);
+    // `--migrate-dry-run` reports pending migrations without applying them
+    if std::env::args().any(|arg| arg == "--migrate-dry-run") {
+        let pool = db::connect(&config.database_path).await;
+        match db::migrations::dry_run(&pool).await {
+            Ok(pending) if pending.is_empty() => tracing::info!("Database is up to date"),
+            Ok(pending) => {
+                for migration in pending {
+                    tracing::info!(
+                        "Would apply migration {} ({})",
+                        migration.version,
+                        migration.name
+                    );
+                }
+            }
+            Err(e) => {
+                tracing::error!("{}", e);
+                std::process::exit(1);
+            }
+        }
+        return;
     }
 
-    let routes = Routes::new(AuthServiceServer::new(AuthController::new(state.clone())))
-        .add_service(BlogServiceServer::new(BlogController::new(state.clone())))
-        .add_service(BlogAdminServiceServer::with_interceptor(
+    let pool = db::init(&config.database_path).await;
+    let identity = services::identity::connect(&config, pool.clone()).await;
+    let state = AppState::new(config, pool.clone(), identity);
+
+    let cors = cors_layer(&cors_origin);
+
+    // Publish scheduled blog posts in the background
+    spawn_scheduler(
+        state.blog_service.clone(),
+        state.notifier.clone(),
+        publish_interval,
+    );
+
+    // Send queued notification emails, retrying failures with backoff
+    spawn_email_outbox(state.notifier.clone(), outbox_interval);
+
+    // Deliver queued webhook events, retrying failures with backoff
+    spawn_webhook_delivery(state.webhook_service.clone(), webhook_interval);
+
+    // Drop refilled rate limit buckets, and save the rest if persistence is on
+    spawn_rate_limit_maintenance(state.rate_limiter.clone());
+
+    // Report per-service health from the database and identity provider
+    let (health_reporter, health_service) = tonic_health::server::health_reporter();
+    spawn_health_monitor(
+        health_reporter,
+        pool,
+        state.identity.clone(),
+        health_interval,
+    );
+
+    // Let tools like grpcurl discover the services
+    let reflection_service = tonic_reflection::server::Builder::configure()
+        .register_encoded_file_descriptor_set(proto::auth::FILE_DESCRIPTOR_SET)
+        .register_encoded_file_descriptor_set(proto::blog::FILE_DESCRIPTOR_SET)
+        .register_encoded_file_descriptor_set(proto::inquiry::FILE_DESCRIPTOR_SET)
+        .register_encoded_file_descriptor_set(proto::webhook::FILE_DESCRIPTOR_SET)
+        .register_encoded_file_descriptor_set(tonic_health::pb::FILE_DESCRIPTOR_SET)
+        .build_v1()
+        .expect("Failed to build gRPC reflection service");
+
+    // Rate limits come first, so floods never reach Firebase or the audit log.
+    // Admin calls are authorized before they're audited, so refused calls
+    // don't reach the audit log either
+    let auth_service = RateLimitLayer::new(state.clone(), AUTH_RATE_LIMITS).layer(
+        AuditLayer::new(state.clone(), AUTH_AUDIT)
+            .layer(AuthServiceServer::new(AuthController::new(state.clone()))),
+    );
+    let blog_service = RateLimitLayer::new(state.clone(), BLOG_RATE_LIMITS)
+        .layer(BlogServiceServer::new(BlogController::new(state.clone())));
+    let blog_admin_service = AuthorizeLayer::new(state.clone(), BLOG_ADMIN_PERMISSIONS).layer(
+        AuditLayer::new(state.clone(), BLOG_ADMIN_AUDIT).layer(BlogAdminServiceServer::new(
             BlogAdminController::new(state.clone()),
-            auth_interceptor(state.clone()),
-        ));
+        )),
+    );
+    let inquiry_service = RateLimitLayer::new(state.clone(), INQUIRY_RATE_LIMITS)
+        .layer(InquiryServiceServer::new(InquiryController::new(state.clone())));
+    let inquiry_admin_service = AuthorizeLayer::new(state.clone(), INQUIRY_ADMIN_PERMISSIONS)
+        .layer(
+            AuditLayer::new(state.clone(), INQUIRY_ADMIN_AUDIT).layer(
+                InquiryAdminServiceServer::new(InquiryAdminController::new(state.clone())),
+            ),
+        );
+    let webhook_admin_service = AuthorizeLayer::new(state.clone(), WEBHOOK_ADMIN_PERMISSIONS)
+        .layer(
+            AuditLayer::new(state.clone(), WEBHOOK_ADMIN_AUDIT).layer(
+                WebhookAdminServiceServer::new(WebhookAdminController::new(state.clone())),
+            ),
+        );
+
+    // gRPC-Web only applies to the gRPC services; it rejects plain HTTP/1.1
+    // requests, so the web routes and REST API are merged in without it
+    let grpc = Routes::new(auth_service)
+        .add_service(blog_service)
+        .add_service(blog_admin_service)
+        .add_service(inquiry_service)
+        .add_service(inquiry_admin_service)
+        .add_service(webhook_admin_service)
+        .add_service(health_service)
+        .add_service(reflection_service)
+        .into_axum_router()
+        .layer(GrpcWebLayer::new());
+    // Client addresses are worked out once, ahead of rate limits and auditing
+    let routes = Routes::from(
+        grpc.merge(web::router(state.clone()))
+            .merge(api::router(state.clone()))
+            .layer(axum::middleware::from_fn_with_state(
+                state.clone(),
+                resolve_client_ip,
+            )),
+    );
 
-    tracing::info!("gRPC server listening on {}", addr);
+    tracing::info!("gRPC and REST server listening on {}", addr);
 
     Server::builder()
         .accept_http1(true)
         .layer(cors)
-        .layer(GrpcWebLayer::new())
         .add_routes(routes)
         .serve(addr)
         .await
@@ -0,0 +1,312 @@
+use std::convert::Infallible;
+use std::future::{self, Future};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use axum::extract::{FromRequestParts, MatchedPath, RawPathParams, Request as HttpRequest, State};
+use axum::http::{Method, StatusCode, Uri};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use http::HeaderMap;
+use http_body_util::{BodyExt, Full};
+use serde::de::DeserializeOwned;
+use tonic::body::Body;
+use tonic::server::NamedService;
+use tonic::Code;
+use tower::{Layer, Service};
+
+use super::request::{client_ip, grpc_message, rpc_method, MAX_BODY_BYTES};
+use crate::dao::audit::NewAuditEvent;
+use crate::dto::auth::Claims;
+use crate::services::AuditService;
+use crate::state::AppState;
+
+/// Header clients can send to correlate audit events with their own logs
+const X_REQUEST_ID: &str = "x-request-id";
+
+/// What an audited call did, as far as its messages tell
+#[derive(Debug, Default)]
+pub struct AuditDetails {
+    /// Who the call claims to be, for calls made before signing in (login)
+    pub actor_email: Option<String>,
+    pub target_id: Option<i64>,
+    /// Summary of the fields the request set
+    pub changes: serde_json::Value,
+}
+
+/// Describes a call from its encoded request message and, if it succeeded, its
+/// encoded response message.
+pub type Describe = fn(request: &[u8], response: Option<&[u8]>) -> AuditDetails;
+
+/// RPCs a service records audit events for, keyed by method name
+pub type AuditRules = &'static [(&'static str, Describe)];
+
+/// Decode a message for a [`Describe`] function; malformed input decodes to
+/// the default message, since the audit log shouldn't fail the call.
+pub fn decode_message<T: prost::Message + Default>(bytes: &[u8]) -> T {
+    T::decode(bytes).unwrap_or_default()
+}
+
+/// A REST call as its [`DescribeRest`] function sees it
+pub struct RestCall<'a> {
+    /// The `{id}` path parameter, if the route has one
+    pub id: Option<i64>,
+    pub uri: &'a Uri,
+    /// JSON request body
+    pub request: &'a [u8],
+    /// JSON response body, if the call succeeded
+    pub response: Option<&'a [u8]>,
+}
+
+/// Describes a REST call, like [`Describe`] does an RPC.
+pub type DescribeRest = fn(call: &RestCall) -> AuditDetails;
+
+/// A REST route recorded by [`audit`]. Its action is the name of the RPC that
+/// does the same thing, so events read alike whichever API made the call.
+pub struct RestAuditRule {
+    pub method: Method,
+    /// Route as registered, e.g. `/blog/{id}`
+    pub path: &'static str,
+    pub action: &'static str,
+    pub describe: DescribeRest,
+}
+
+/// REST routes of a router that are recorded in the audit log
+pub type RestAuditRules = &'static [RestAuditRule];
+
+/// Decode a JSON body for a [`DescribeRest`] function, or nothing if it's
+/// malformed.
+pub fn decode_json<T: DeserializeOwned>(bytes: &[u8]) -> Option<T> {
+    serde_json::from_slice(bytes).ok()
+}
+
+/// Layer recording an `audit_events` row for every call to the RPCs listed in
+/// its rules, whatever the outcome.
+///
+/// The caller comes from the `Claims` that `AuthorizeLayer` attached, so the
+/// layer goes inside it; calls refused by authorization never reach the
+/// service and aren't recorded. Only unary RPCs may be audited, as request and
+/// response bodies are buffered to decode the messages.
+#[derive(Clone)]
+pub struct AuditLayer {
+    state: AppState,
+    rules: AuditRules,
+}
+
+impl AuditLayer {
+    pub fn new(state: AppState, rules: AuditRules) -> Self {
+        Self { state, rules }
+    }
+}
+
+impl<S> Layer<S> for AuditLayer {
+    type Service = Audit<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        Audit {
+            inner,
+            state: self.state.clone(),
+            rules: self.rules,
+        }
+    }
+}
+
+/// Service produced by [`AuditLayer`]
+#[derive(Clone)]
+pub struct Audit<S> {
+    inner: S,
+    state: AppState,
+    rules: AuditRules,
+}
+
+impl<S: NamedService> NamedService for Audit<S> {
+    const NAME: &'static str = S::NAME;
+}
+
+impl<S> Service<http::Request<Body>> for Audit<S>
+where
+    S: Service<http::Request<Body>, Response = http::Response<Body>, Error = Infallible>
+        + Clone
+        + Send
+        + 'static,
+    S::Future: Send,
+{
+    type Response = http::Response<Body>;
+    type Error = Infallible;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Infallible>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Infallible>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: http::Request<Body>) -> Self::Future {
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+
+        let method = rpc_method(req.uri().path());
+        let Some(&(action, describe)) = self.rules.iter().find(|(name, _)| *name == method) else {
+            return Box::pin(inner.call(req));
+        };
+        let audit_service = self.state.audit_service.clone();
+
+        Box::pin(async move {
+            let (parts, body) = req.into_parts();
+            let request_body = match body.collect().await {
+                Ok(collected) => collected.to_bytes(),
+                Err(status) => return Ok(status.into_http()),
+            };
+
+            let mut event = new_event(action, &parts);
+
+            let req = http::Request::from_parts(parts, Body::new(Full::new(request_body.clone())));
+            let response = inner.call(req).await?;
+
+            // Errors come back trailers-only, with the status in the headers;
+            // successful calls carry it in the trailers after the message
+            let (parts, body) = response.into_parts();
+            let (response_body, trailers) = match body.collect().await {
+                Ok(collected) => {
+                    let trailers = collected.trailers().cloned();
+                    (collected.to_bytes(), trailers)
+                }
+                Err(status) => {
+                    event.status_code = status.code() as i32;
+                    record(&audit_service, &event).await;
+                    return Ok(status.into_http());
+                }
+            };
+            event.status_code = grpc_status(&parts.headers)
+                .or_else(|| trailers.as_ref().and_then(grpc_status))
+                .unwrap_or(Code::Ok as i32);
+
+            let succeeded = event.status_code == Code::Ok as i32;
+            let details = describe(
+                grpc_message(&request_body),
+                succeeded.then(|| grpc_message(&response_body)),
+            );
+            describe_event(&mut event, details);
+            record(&audit_service, &event).await;
+
+            let body = Full::new(response_body)
+                .with_trailers(future::ready(trailers.map(Ok::<_, Infallible>)));
+            Ok(http::Response::from_parts(parts, Body::new(body)))
+        })
+    }
+}
+
+/// Record an `audit_events` row for calls to the REST routes listed in
+/// `rules`, the way [`AuditLayer`] does for RPCs. The HTTP status is stored as
+/// the gRPC code it corresponds to.
+///
+/// Goes inside `require_auth` and `require_role`, so the caller's `Claims` are
+/// known and refused calls aren't recorded.
+pub async fn audit(
+    State((state, rules)): State<(AppState, RestAuditRules)>,
+    req: HttpRequest,
+    next: Next,
+) -> Response {
+    let path = req
+        .extensions()
+        .get::<MatchedPath>()
+        .map(MatchedPath::as_str);
+    let Some(rule) = rules
+        .iter()
+        .find(|rule| rule.method == req.method() && Some(rule.path) == path)
+    else {
+        return next.run(req).await;
+    };
+
+    let (mut parts, body) = req.into_parts();
+    let request_body = match axum::body::to_bytes(body, MAX_BODY_BYTES).await {
+        Ok(body) => body,
+        Err(_) => return StatusCode::PAYLOAD_TOO_LARGE.into_response(),
+    };
+    let id = RawPathParams::from_request_parts(&mut parts, &())
+        .await
+        .ok()
+        .and_then(|params| {
+            params
+                .iter()
+                .find(|(name, _)| *name == "id")
+                .and_then(|(_, value)| value.parse().ok())
+        });
+    let uri = parts.uri.clone();
+    let mut event = new_event(rule.action, &parts);
+
+    let req = HttpRequest::from_parts(parts, axum::body::Body::from(request_body.clone()));
+    let (parts, body) = next.run(req).await.into_parts();
+    let response_body = body
+        .collect()
+        .await
+        .map(|collected| collected.to_bytes())
+        .unwrap_or_default();
+
+    event.status_code = grpc_code(parts.status) as i32;
+    let details = (rule.describe)(&RestCall {
+        id,
+        uri: &uri,
+        request: &request_body,
+        response: parts.status.is_success().then_some(&response_body[..]),
+    });
+    describe_event(&mut event, details);
+    record(&state.audit_service, &event).await;
+
+    Response::from_parts(parts, axum::body::Body::from(response_body))
+}
+
+/// An event for a call that succeeded, until its outcome is known
+fn new_event(action: &str, parts: &http::request::Parts) -> NewAuditEvent {
+    let claims = parts.extensions.get::<Claims>();
+    NewAuditEvent {
+        actor_sub: claims.map(|claims| claims.sub.clone()),
+        actor_email: claims.and_then(|claims| claims.email.clone()),
+        action: action.to_string(),
+        target_id: None,
+        status_code: Code::Ok as i32,
+        request_id: parts
+            .headers
+            .get(X_REQUEST_ID)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string),
+        client_ip: client_ip(&parts.extensions).map(|ip| ip.to_string()),
+        changes: serde_json::json!({}),
+    }
+}
+
+fn describe_event(event: &mut NewAuditEvent, details: AuditDetails) {
+    event.actor_email = event.actor_email.take().or(details.actor_email);
+    event.target_id = details.target_id;
+    event.changes = match details.changes {
+        serde_json::Value::Null => serde_json::json!({}),
+        changes => changes,
+    };
+}
+
+/// The gRPC code a REST status corresponds to
+fn grpc_code(status: StatusCode) -> Code {
+    match status {
+        status if status.is_success() => Code::Ok,
+        StatusCode::BAD_REQUEST => Code::InvalidArgument,
+        StatusCode::UNAUTHORIZED => Code::Unauthenticated,
+        StatusCode::FORBIDDEN => Code::PermissionDenied,
+        StatusCode::NOT_FOUND => Code::NotFound,
+        StatusCode::CONFLICT => Code::AlreadyExists,
+        StatusCode::TOO_MANY_REQUESTS => Code::ResourceExhausted,
+        StatusCode::INTERNAL_SERVER_ERROR => Code::Internal,
+        StatusCode::SERVICE_UNAVAILABLE => Code::Unavailable,
+        _ => Code::Unknown,
+    }
+}
+
+async fn record(audit_service: &AuditService, event: &NewAuditEvent) {
+    if let Err(e) = audit_service.record(event).await {
+        tracing::error!("Failed to record audit event {:?}: {}", event, e);
+    }
+}
+
+fn grpc_status(headers: &HeaderMap) -> Option<i32> {
+    headers
+        .get("grpc-status")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse().ok())
+}
@@ -1,32 +1,197 @@
-use tonic::{Request, Status};
+use std::convert::Infallible;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
 
+use axum::extract::{Request as HttpRequest, State};
+use axum::http::header::{AUTHORIZATION, COOKIE};
+use axum::http::{HeaderMap, StatusCode};
+use axum::middleware::Next;
+use axum::response::Response;
+use tonic::server::NamedService;
+use tonic::Status;
+use tower::{Layer, Service};
+
+use crate::dto::auth::{Claims, Role};
 use crate::state::AppState;
 use crate::utils::{parse_cookie, ID_TOKEN_COOKIE};
 
-/// Sync auth interceptor for protected gRPC services.
+/// Role each RPC of a service requires, keyed by method name
+pub type Permissions = &'static [(&'static str, Role)];
+
+/// Auth layer for protected gRPC services.
+///
+/// Verifies the caller's ID token, then checks the role the permission table
+/// requires for the called RPC. RPCs missing from the table are refused. On
+/// success the verified `Claims` are inserted into the request extensions so
+/// controllers can tell who made the call.
 ///
-/// Uses cached Firebase public keys for JWT verification. If keys are missing
-/// or the key ID isn't found (due to key rotation), verification fails and the
-/// frontend's refresh flow will fetch fresh keys. Not the most precise or
-/// performant approach, but keeps things simple for a single-user admin panel.
-pub fn auth_interceptor(
+/// Verification goes through the configured identity provider. With Firebase it
+/// uses cached public keys: if keys are missing or the key ID isn't found (due
+/// to key rotation), verification fails and the background key refresher is
+/// woken to refetch them, so the frontend's retry after its token refresh
+/// succeeds.
+#[derive(Clone)]
+pub struct AuthorizeLayer {
+    state: AppState,
+    permissions: Permissions,
+}
+
+impl AuthorizeLayer {
+    pub fn new(state: AppState, permissions: Permissions) -> Self {
+        Self { state, permissions }
+    }
+}
+
+impl<S> Layer<S> for AuthorizeLayer {
+    type Service = Authorize<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        Authorize {
+            inner,
+            state: self.state.clone(),
+            permissions: self.permissions,
+        }
+    }
+}
+
+/// Service produced by [`AuthorizeLayer`]
+#[derive(Clone)]
+pub struct Authorize<S> {
+    inner: S,
     state: AppState,
-) -> impl Fn(Request<()>) -> Result<Request<()>, Status> + Clone {
-    move |req: Request<()>| {
-        let cookie_header = req
-            .metadata()
-            .get("cookie")
-            .and_then(|v| v.to_str().ok())
-            .unwrap_or("");
-
-        let id_token = parse_cookie(cookie_header, ID_TOKEN_COOKIE)
-            .ok_or_else(|| Status::unauthenticated("Missing authentication token"))?;
-
-        state
-            .firebase_auth
-            .verify_token_sync(&id_token)
-            .map_err(|_| Status::unauthenticated("Invalid or expired token"))?;
-
-        Ok(req)
+    permissions: Permissions,
+}
+
+impl<S: NamedService> NamedService for Authorize<S> {
+    const NAME: &'static str = S::NAME;
+}
+
+impl<S, ReqBody, ResBody> Service<http::Request<ReqBody>> for Authorize<S>
+where
+    S: Service<http::Request<ReqBody>, Response = http::Response<ResBody>, Error = Infallible>
+        + Clone
+        + Send
+        + 'static,
+    S::Future: Send,
+    ReqBody: Send + 'static,
+    ResBody: Default,
+{
+    type Response = S::Response;
+    type Error = Infallible;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Infallible>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Infallible>> {
+        self.inner.poll_ready(cx)
     }
+
+    fn call(&mut self, mut req: http::Request<ReqBody>) -> Self::Future {
+        // Take the service that was polled ready and leave a fresh clone behind
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+        let state = self.state.clone();
+        let required = rpc_role(self.permissions, req.uri().path());
+
+        Box::pin(async move {
+            match authorize(&state, req.headers(), required).await {
+                Ok(claims) => {
+                    req.extensions_mut().insert(claims);
+                    inner.call(req).await
+                }
+                Err(status) => Ok(status.into_http()),
+            }
+        })
+    }
+}
+
+/// Role the permission table requires for `/package.Service/Method`
+fn rpc_role(permissions: Permissions, path: &str) -> Option<Role> {
+    let method = path.rsplit('/').next()?;
+    permissions
+        .iter()
+        .find(|(name, _)| *name == method)
+        .map(|(_, role)| *role)
+}
+
+async fn authorize(
+    state: &AppState,
+    headers: &HeaderMap,
+    required: Option<Role>,
+) -> Result<Claims, Status> {
+    let id_token = request_token(headers)
+        .ok_or_else(|| Status::unauthenticated("Missing authentication token"))?;
+
+    let claims = state
+        .identity
+        .verify(&id_token)
+        .await
+        .map_err(|_| Status::unauthenticated("Invalid or expired token"))?;
+
+    let Some(role) = required else {
+        return Err(Status::permission_denied("Method not permitted"));
+    };
+    if !claims.has_role(role) {
+        return Err(Status::permission_denied(format!(
+            "Requires the {} role and a verified email address",
+            role.as_str()
+        )));
+    }
+
+    Ok(claims)
+}
+
+/// Auth middleware for protected REST routes, the axum counterpart of
+/// [`AuthorizeLayer`]. Roles are checked per route by [`require_role`].
+///
+/// Accepts the `id_token` cookie set by login, or an `Authorization: Bearer`
+/// header so scripts can pass an ID token directly. Verified claims are
+/// inserted into the request extensions.
+pub async fn require_auth(
+    State(state): State<AppState>,
+    mut req: HttpRequest,
+    next: Next,
+) -> Result<Response, StatusCode> {
+    let id_token = request_token(req.headers()).ok_or(StatusCode::UNAUTHORIZED)?;
+
+    let claims = state
+        .identity
+        .verify(&id_token)
+        .await
+        .map_err(|_| StatusCode::UNAUTHORIZED)?;
+
+    req.extensions_mut().insert(claims);
+
+    Ok(next.run(req).await)
+}
+
+/// Role check for REST routes behind [`require_auth`], with the role as
+/// middleware state: `from_fn_with_state(Role::Admin, require_role)`.
+pub async fn require_role(
+    State(role): State<Role>,
+    req: HttpRequest,
+    next: Next,
+) -> Result<Response, StatusCode> {
+    let claims = req
+        .extensions()
+        .get::<Claims>()
+        .ok_or(StatusCode::UNAUTHORIZED)?;
+    if !claims.has_role(role) {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    Ok(next.run(req).await)
+}
+
+/// ID token from an `Authorization: Bearer` header or the `id_token` cookie
+fn request_token(headers: &HeaderMap) -> Option<String> {
+    let bearer = headers
+        .get(AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "));
+    if let Some(token) = bearer {
+        return Some(token.trim().to_string());
+    }
+
+    let cookie_header = headers.get(COOKIE).and_then(|v| v.to_str().ok())?;
+    parse_cookie(cookie_header, ID_TOKEN_COOKIE)
 }
@@ -0,0 +1,30 @@
+use std::time::Duration;
+
+use http::header::HeaderName;
+use http::Method;
+use tower_http::cors::{AllowOrigin, CorsLayer};
+
+/// Server-wide CORS for the frontend at `origin`: gRPC-Web and REST calls,
+/// with credentials so the auth cookies are sent along.
+pub fn cors_layer(origin: &str) -> CorsLayer {
+    CorsLayer::new()
+        .allow_origin(AllowOrigin::exact(origin.parse().unwrap()))
+        .allow_headers([
+            HeaderName::from_static("authorization"),
+            HeaderName::from_static("content-type"),
+            HeaderName::from_static("connect-protocol-version"),
+            HeaderName::from_static("connect-timeout-ms"),
+            HeaderName::from_static("grpc-timeout"),
+            HeaderName::from_static("x-grpc-web"),
+            HeaderName::from_static("x-user-agent"),
+        ])
+        .expose_headers([
+            HeaderName::from_static("grpc-status"),
+            HeaderName::from_static("grpc-message"),
+            HeaderName::from_static("grpc-status-details-bin"),
+            HeaderName::from_static("retry-after"),
+        ])
+        .allow_methods([Method::GET, Method::POST, Method::PATCH, Method::DELETE])
+        .max_age(Duration::from_secs(7200))
+        .allow_credentials(true)
+}
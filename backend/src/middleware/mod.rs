@@ -1,3 +1,17 @@
+pub mod audit;
 pub mod auth;
+mod cors;
+pub mod rate_limit;
+mod request;
 
-pub use auth::auth_interceptor;
+pub use audit::{
+    audit, decode_json, decode_message, AuditDetails, AuditLayer, AuditRules, RestAuditRule,
+    RestAuditRules, RestCall,
+};
+pub use auth::{require_auth, require_role, AuthorizeLayer, Permissions};
+pub use cors::cors_layer;
+pub use rate_limit::{
+    login_email_key, rate_limit, RateLimitKey, RateLimitLayer, RateLimitRule, RateLimitRules,
+};
+pub(crate) use request::client_ip;
+pub use request::resolve_client_ip;
@@ -0,0 +1,221 @@
+use std::convert::Infallible;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use axum::extract::{Request as HttpRequest, State};
+use axum::http::header::RETRY_AFTER;
+use axum::http::StatusCode;
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use http_body_util::{BodyExt, Full};
+use tonic::body::Body;
+use tonic::metadata::MetadataValue;
+use tonic::server::NamedService;
+use tonic::Status;
+use tower::{Layer, Service};
+
+use super::request::{client_ip, grpc_message, rpc_method, MAX_BODY_BYTES};
+use crate::config::{Config, RateLimit};
+use crate::state::AppState;
+
+/// What a rate limit counts requests by
+#[derive(Clone, Copy)]
+pub enum RateLimitKey {
+    /// The peer address of the connection
+    ClientIp,
+    /// A value read from the encoded request message, or the JSON body of a
+    /// REST request, such as the email of a login attempt. Requests it returns
+    /// `None` for aren't limited.
+    Message(fn(&[u8]) -> Option<String>),
+}
+
+/// A token bucket applied to calls of a service
+pub struct RateLimitRule {
+    /// RPC the rule applies to, or `None` for every RPC of the service
+    pub method: Option<&'static str>,
+    /// Bucket namespace; rules with the same name share their buckets
+    pub name: &'static str,
+    pub key: RateLimitKey,
+    pub limit: fn(&Config) -> RateLimit,
+}
+
+/// Rate limits of a service, checked in order
+pub type RateLimitRules = &'static [RateLimitRule];
+
+/// Layer refusing calls with `resource_exhausted` once a client has used up a
+/// rate limit, with a `retry-after` metadata entry (whole seconds) saying when
+/// to try again.
+///
+/// Limits are counted per key by the shared in-memory `RateLimiter`. Calls
+/// refused here never reach the inner service, so the layer goes outermost.
+#[derive(Clone)]
+pub struct RateLimitLayer {
+    state: AppState,
+    rules: RateLimitRules,
+}
+
+impl RateLimitLayer {
+    pub fn new(state: AppState, rules: RateLimitRules) -> Self {
+        Self { state, rules }
+    }
+}
+
+impl<S> Layer<S> for RateLimitLayer {
+    type Service = RateLimited<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RateLimited {
+            inner,
+            state: self.state.clone(),
+            rules: self.rules,
+        }
+    }
+}
+
+/// Service produced by [`RateLimitLayer`]
+#[derive(Clone)]
+pub struct RateLimited<S> {
+    inner: S,
+    state: AppState,
+    rules: RateLimitRules,
+}
+
+impl<S: NamedService> NamedService for RateLimited<S> {
+    const NAME: &'static str = S::NAME;
+}
+
+impl<S> Service<http::Request<Body>> for RateLimited<S>
+where
+    S: Service<http::Request<Body>, Response = http::Response<Body>, Error = Infallible>
+        + Clone
+        + Send
+        + 'static,
+    S::Future: Send,
+{
+    type Response = http::Response<Body>;
+    type Error = Infallible;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Infallible>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Infallible>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: http::Request<Body>) -> Self::Future {
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+
+        let method = rpc_method(req.uri().path());
+        let rules: Vec<&'static RateLimitRule> = self
+            .rules
+            .iter()
+            .filter(|rule| rule.method.is_none_or(|m| m == method))
+            .collect();
+        if rules.is_empty() {
+            return Box::pin(inner.call(req));
+        }
+        let state = self.state.clone();
+
+        Box::pin(async move {
+            // Only buffer the message when a rule needs to read it
+            let reads_message = rules
+                .iter()
+                .any(|rule| matches!(rule.key, RateLimitKey::Message(_)));
+            let (req, message) = if reads_message {
+                let (parts, body) = req.into_parts();
+                let body = match body.collect().await {
+                    Ok(collected) => collected.to_bytes(),
+                    Err(status) => return Ok(status.into_http()),
+                };
+                let req = http::Request::from_parts(parts, Body::new(Full::new(body.clone())));
+                (req, body)
+            } else {
+                (req, Default::default())
+            };
+
+            let ip = client_ip(req.extensions());
+            for rule in rules {
+                let value = match rule.key {
+                    RateLimitKey::ClientIp => ip.map(|ip| ip.to_string()),
+                    RateLimitKey::Message(read) => read(grpc_message(&message)),
+                };
+                if let Some(value) = value
+                    && let Err(retry_after) = check(&state, rule, &value)
+                {
+                    return Ok(exhausted(retry_after).into_http());
+                }
+            }
+
+            inner.call(req).await
+        })
+    }
+}
+
+/// Rate limit REST routes, sharing buckets with gRPC rules of the same name.
+/// Responds `429 Too Many Requests` with a `Retry-After` header.
+pub async fn rate_limit(
+    State((state, rule)): State<(AppState, &'static RateLimitRule)>,
+    req: HttpRequest,
+    next: Next,
+) -> Response {
+    let (req, value) = match rule.key {
+        RateLimitKey::ClientIp => {
+            let ip = client_ip(req.extensions());
+            (req, ip.map(|ip| ip.to_string()))
+        }
+        RateLimitKey::Message(read) => {
+            let (parts, body) = req.into_parts();
+            let body = match axum::body::to_bytes(body, MAX_BODY_BYTES).await {
+                Ok(body) => body,
+                Err(_) => return StatusCode::PAYLOAD_TOO_LARGE.into_response(),
+            };
+            let value = read(&body);
+            (
+                HttpRequest::from_parts(parts, axum::body::Body::from(body)),
+                value,
+            )
+        }
+    };
+
+    if let Some(value) = value
+        && let Err(retry_after) = check(&state, rule, &value)
+    {
+        let mut response = (StatusCode::TOO_MANY_REQUESTS, "Too many requests").into_response();
+        response
+            .headers_mut()
+            .insert(RETRY_AFTER, retry_after_secs(retry_after).into());
+        return response;
+    }
+
+    next.run(req).await
+}
+
+/// Rate limit key for the email a login attempt targets, normalized so case
+/// variations of an address share a bucket, over gRPC and REST alike
+pub fn login_email_key(email: &str) -> Option<String> {
+    let email = email.trim().to_lowercase();
+    (!email.is_empty()).then_some(email)
+}
+
+fn check(state: &AppState, rule: &RateLimitRule, value: &str) -> Result<(), Duration> {
+    let key = format!("{}:{}", rule.name, value);
+    state
+        .rate_limiter
+        .check(&key, (rule.limit)(&state.config))
+        .inspect_err(|_| tracing::warn!("Rate limit exceeded for {}", key))
+}
+
+fn exhausted(retry_after: Duration) -> Status {
+    let mut status = Status::resource_exhausted("Too many requests, try again later");
+    status.metadata_mut().insert(
+        RETRY_AFTER.as_str(),
+        MetadataValue::from(retry_after_secs(retry_after)),
+    );
+    status
+}
+
+/// Whole seconds to wait, rounded up so a retry isn't refused again
+fn retry_after_secs(retry_after: Duration) -> u64 {
+    retry_after.as_secs_f64().ceil().max(1.0) as u64
+}
@@ -0,0 +1,99 @@
+use std::net::IpAddr;
+
+use axum::extract::{Request as HttpRequest, State};
+use axum::middleware::Next;
+use axum::response::Response;
+use http::HeaderMap;
+use ipnet::IpNet;
+use tonic::transport::server::TcpConnectInfo;
+
+use crate::state::AppState;
+
+/// Header reverse proxies append the address they received a request from to
+const X_FORWARDED_FOR: &str = "x-forwarded-for";
+
+/// Most of a REST request body middleware buffers, the same as axum's default
+/// limit for extractors
+pub(crate) const MAX_BODY_BYTES: usize = 2 * 1024 * 1024;
+
+/// Method name of a gRPC call from its path, e.g. `Login` for
+/// `/auth.AuthService/Login`
+pub(crate) fn rpc_method(path: &str) -> &str {
+    path.rsplit('/').next().unwrap_or(path)
+}
+
+/// Client address worked out by [`resolve_client_ip`]
+#[derive(Debug, Clone, Copy)]
+struct ClientIp(IpAddr);
+
+/// Address of the client: the one [`resolve_client_ip`] worked out, or else
+/// the peer, as attached to every request by the tonic server
+pub(crate) fn client_ip(extensions: &http::Extensions) -> Option<IpAddr> {
+    extensions
+        .get::<ClientIp>()
+        .map(|ip| ip.0)
+        .or_else(|| peer_ip(extensions))
+}
+
+fn peer_ip(extensions: &http::Extensions) -> Option<IpAddr> {
+    extensions
+        .get::<TcpConnectInfo>()
+        .and_then(TcpConnectInfo::remote_addr)
+        .map(|addr| addr.ip().to_canonical())
+}
+
+/// Work out the client's address for everything behind this middleware, which
+/// goes outermost, around every service.
+///
+/// A peer in `trusted_proxies` is taken to be forwarding for the last address
+/// in `X-Forwarded-For`; further trusted proxies are skipped from the right.
+/// The header is ignored from anyone else, so clients can't pick the address
+/// their rate limits are counted by.
+pub async fn resolve_client_ip(
+    State(state): State<AppState>,
+    mut req: HttpRequest,
+    next: Next,
+) -> Response {
+    if let Some(peer) = peer_ip(req.extensions()) {
+        let ip = forwarded_client(peer, req.headers(), &state.config.trusted_proxies);
+        req.extensions_mut().insert(ClientIp(ip));
+    }
+
+    next.run(req).await
+}
+
+fn forwarded_client(peer: IpAddr, headers: &HeaderMap, trusted_proxies: &[IpNet]) -> IpAddr {
+    let trusted = |ip: &IpAddr| trusted_proxies.iter().any(|net| net.contains(ip));
+    if !trusted(&peer) {
+        return peer;
+    }
+
+    let hops: Vec<&str> = headers
+        .get_all(X_FORWARDED_FOR)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .collect();
+    let mut client = peer;
+    for hop in hops.into_iter().rev() {
+        match hop.trim().parse::<IpAddr>() {
+            Ok(ip) => client = ip.to_canonical(),
+            // Whatever came before a malformed hop can't be trusted
+            Err(_) => break,
+        }
+        if !trusted(&client) {
+            break;
+        }
+    }
+    client
+}
+
+/// The message inside a single uncompressed gRPC frame, or nothing if the body
+/// isn't one
+pub(crate) fn grpc_message(body: &[u8]) -> &[u8] {
+    let Some((&[0, a, b, c, d], message)) = body.split_first_chunk::<5>() else {
+        return &[];
+    };
+    let len = u32::from_be_bytes([a, b, c, d]) as usize;
+    message.get(..len).unwrap_or(&[])
+}
@@ -0,0 +1,86 @@
+use sqlx::types::Json;
+use sqlx::{QueryBuilder, Sqlite};
+
+use crate::dao::audit::{AuditEvent, AuditEventFilter, NewAuditEvent};
+use crate::db::DbPool;
+use crate::utils::now_timestamp;
+
+pub struct AuditRepository {
+    pool: DbPool,
+}
+
+impl AuditRepository {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+
+    pub async fn insert(&self, event: &NewAuditEvent) -> Result<i64, sqlx::Error> {
+        let result = sqlx::query(
+            r#"
+            INSERT INTO audit_events
+                (created_at, actor_sub, actor_email, action, target_id, status_code,
+                 request_id, client_ip, changes)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(now_timestamp())
+        .bind(&event.actor_sub)
+        .bind(&event.actor_email)
+        .bind(&event.action)
+        .bind(event.target_id)
+        .bind(event.status_code)
+        .bind(&event.request_id)
+        .bind(&event.client_ip)
+        .bind(Json(&event.changes))
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.last_insert_rowid())
+    }
+
+    /// Events matching `filter`, newest first, with the total number of matches.
+    pub async fn list(
+        &self,
+        filter: &AuditEventFilter,
+        limit: i32,
+        offset: i32,
+    ) -> Result<(Vec<AuditEvent>, i32), sqlx::Error> {
+        let mut query = QueryBuilder::<Sqlite>::new("SELECT * FROM audit_events WHERE 1 = 1");
+        push_filter(&mut query, filter);
+        query
+            .push(" ORDER BY created_at DESC, id DESC LIMIT ")
+            .push_bind(limit)
+            .push(" OFFSET ")
+            .push_bind(offset);
+        let events = query
+            .build_query_as::<AuditEvent>()
+            .fetch_all(&self.pool)
+            .await?;
+
+        let mut count =
+            QueryBuilder::<Sqlite>::new("SELECT COUNT(*) FROM audit_events WHERE 1 = 1");
+        push_filter(&mut count, filter);
+        let total: i32 = count.build_query_scalar().fetch_one(&self.pool).await?;
+
+        Ok((events, total))
+    }
+}
+
+fn push_filter<'a>(qb: &mut QueryBuilder<'a, Sqlite>, filter: &'a AuditEventFilter) {
+    if let Some(actor) = &filter.actor {
+        qb.push(" AND (actor_sub = ")
+            .push_bind(actor)
+            .push(" OR actor_email = ")
+            .push_bind(actor)
+            .push(")");
+    }
+    if let Some(action) = &filter.action {
+        qb.push(" AND action = ").push_bind(action);
+    }
+    if let Some(since) = filter.since {
+        qb.push(" AND created_at >= ").push_bind(since);
+    }
+    if let Some(until) = filter.until {
+        qb.push(" AND created_at <= ").push_bind(until);
+    }
+}
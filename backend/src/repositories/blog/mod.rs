@@ -1,7 +1,33 @@
-use std::time::{SystemTime, UNIX_EPOCH};
+mod published;
+mod revisions;
+mod search;
+mod slugs;
+mod tags;
 
-use crate::dao::blog::{BlogPost, BlogPostSort, BlogPostSummary};
+use quick_xml::escape::escape;
+use sqlx::types::Json;
+use sqlx::{QueryBuilder, Sqlite};
+
+use crate::dao::blog::{
+    BlogPost, BlogPostCursor, BlogPostFilter, BlogPostPage, BlogPostSort, BlogPostStatus,
+    BlogPostSummary, BlogPostUpdate, BlogPostVisibility, CursorKey, HighlightMarkers, PageStart,
+};
 use crate::db::DbPool;
+use crate::utils::{now_timestamp, render_markdown, slugify};
+
+use revisions::insert_revision;
+use slugs::change_slug;
+use tags::set_post_tags;
+
+pub(crate) use slugs::unique_slug;
+
+/// Approximate number of tokens in a search result snippet (FTS5 allows up to 64)
+const SNIPPET_TOKENS: i64 = 24;
+
+/// Stand-ins FTS5 wraps matched terms in. Excerpts are HTML-escaped before
+/// they're swapped for the caller's markers, so post text can't add markup.
+const MATCH_OPEN: &str = "\u{E000}";
+const MATCH_CLOSE: &str = "\u{E001}";
 
 pub struct BlogRepository {
     pool: DbPool,
@@ -12,160 +38,284 @@ impl BlogRepository {
         Self { pool }
     }
 
+    /// One page of posts matching `filter`. Pages past the first start either at
+    /// an offset or, for stable paging, right after a previously returned cursor.
     pub async fn list(
         &self,
-        query: Option<&str>,
+        filter: &BlogPostFilter,
         limit: i32,
-        offset: i32,
+        start: PageStart,
         sort: BlogPostSort,
-    ) -> Result<(Vec<BlogPostSummary>, i32), sqlx::Error> {
-        let limit = if limit <= 0 { 10 } else { limit };
-
-        let (posts, total) = if let Some(q) = query.filter(|s| !s.is_empty()) {
-            let order_by = match sort {
-                BlogPostSort::Relevance => "rank",
-                BlogPostSort::CreatedAsc => "bp.created_at ASC",
-                BlogPostSort::CreatedDesc => "bp.created_at DESC",
-                BlogPostSort::UpdatedAsc => "bp.updated_at ASC",
-                BlogPostSort::UpdatedDesc => "bp.updated_at DESC",
-            };
+    ) -> Result<BlogPostPage, sqlx::Error> {
+        let now = now_timestamp();
+        let search = filter.query.as_deref().filter(|s| !s.is_empty());
+        let (column, descending) = sort_order(sort, search.is_some());
+        let direction = if descending { "DESC" } else { "ASC" };
 
-            let sql = format!(
-                r#"
-                SELECT bp.id, bp.title, bp.description, bp.created_at, bp.updated_at
-                FROM blog_posts_fts fts
-                INNER JOIN blog_posts bp ON bp.id = fts.rowid
-                WHERE blog_posts_fts MATCH ?
-                  AND bp.deleted_at IS NULL
-                ORDER BY {order_by}
-                LIMIT ? OFFSET ?
-                "#
+        let mut select = QueryBuilder::<Sqlite>::new(
+            r#"
+            SELECT bp.id, bp.title, bp.slug, bp.description, bp.created_at, bp.updated_at,
+                   bp.status, bp.published_at
+            "#,
+        );
+        if search.is_some() {
+            push_search_excerpts(&mut select);
+            select.push(", rank");
+        } else {
+            select.push(
+                ", NULL AS snippet, NULL AS highlighted_title, NULL AS highlighted_description, \
+                 NULL AS rank",
             );
+        }
+        push_list_conditions(&mut select, filter, search, now);
 
-            let posts = sqlx::query_as::<_, BlogPostSummary>(&sql)
-                .bind(q)
-                .bind(limit)
-                .bind(offset)
-                .fetch_all(&self.pool)
-                .await?;
+        if let PageStart::After(cursor) = start {
+            select.push(format!(
+                " AND ({}, bp.id) {} (",
+                column.sql(),
+                if descending { "<" } else { ">" }
+            ));
+            match cursor.key {
+                CursorKey::Timestamp(value) => select.push_bind(value),
+                CursorKey::Rank(value) => select.push_bind(value),
+            };
+            select.push(", ").push_bind(cursor.id).push(")");
+        }
 
-            let total: i32 = sqlx::query_scalar(
-                r#"
-                SELECT COUNT(*)
-                FROM blog_posts_fts fts
-                INNER JOIN blog_posts bp ON bp.id = fts.rowid
-                WHERE blog_posts_fts MATCH ?
-                  AND bp.deleted_at IS NULL
-                "#,
-            )
-            .bind(q)
-            .fetch_one(&self.pool)
+        // One extra row tells us whether another page follows
+        select
+            .push(format!(
+                " ORDER BY {} {direction}, bp.id {direction} LIMIT ",
+                column.sql()
+            ))
+            .push_bind(limit + 1);
+        if let PageStart::Offset(offset) = start {
+            select.push(" OFFSET ").push_bind(offset);
+        }
+
+        let mut posts = select
+            .build_query_as::<BlogPostSummary>()
+            .fetch_all(&self.pool)
             .await?;
 
-            (posts, total)
+        let next = if posts.len() > limit as usize {
+            posts.truncate(limit as usize);
+            posts.last().map(|last| BlogPostCursor {
+                sort,
+                key: column.key(last),
+                id: last.id,
+            })
         } else {
-            // Without a search query, relevance doesn't apply - default to created_at DESC
-            let order_by = match sort {
-                BlogPostSort::Relevance | BlogPostSort::CreatedDesc => "created_at DESC",
-                BlogPostSort::CreatedAsc => "created_at ASC",
-                BlogPostSort::UpdatedAsc => "updated_at ASC",
-                BlogPostSort::UpdatedDesc => "updated_at DESC",
-            };
+            None
+        };
 
-            let sql = format!(
-                r#"
-                SELECT id, title, description, created_at, updated_at
-                FROM blog_posts
-                WHERE deleted_at IS NULL
-                ORDER BY {order_by}
-                LIMIT ? OFFSET ?
-                "#
-            );
+        let mut count = QueryBuilder::<Sqlite>::new("SELECT COUNT(*)");
+        push_list_conditions(&mut count, filter, search, now);
 
-            let posts = sqlx::query_as::<_, BlogPostSummary>(&sql)
-                .bind(limit)
-                .bind(offset)
-                .fetch_all(&self.pool)
-                .await?;
+        let total: i32 = count.build_query_scalar().fetch_one(&self.pool).await?;
 
-            let total: i32 =
-                sqlx::query_scalar("SELECT COUNT(*) FROM blog_posts WHERE deleted_at IS NULL")
-                    .fetch_one(&self.pool)
-                    .await?;
+        let ids: Vec<i64> = posts.iter().map(|p| p.id).collect();
+        let mut tags = self.tags_for_posts(&ids).await?;
+        for post in &mut posts {
+            post.tags = tags.remove(&post.id).unwrap_or_default();
+            for excerpt in [
+                &mut post.snippet,
+                &mut post.highlighted_title,
+                &mut post.highlighted_description,
+            ]
+            .into_iter()
+            .flatten()
+            {
+                *excerpt = mark_matches(excerpt, &filter.markers);
+            }
+        }
 
-            (posts, total)
-        };
-
-        Ok((posts, total))
+        Ok(BlogPostPage { posts, total, next })
     }
 
-    pub async fn get(&self, id: i64) -> Result<Option<BlogPost>, sqlx::Error> {
-        sqlx::query_as::<_, BlogPost>(
+    pub async fn get(
+        &self,
+        id: i64,
+        visibility: BlogPostVisibility,
+    ) -> Result<Option<BlogPost>, sqlx::Error> {
+        let mut query = QueryBuilder::<Sqlite>::new(
             r#"
-            SELECT id, title, description, body, created_at, updated_at, deleted_at
-            FROM blog_posts
-            WHERE id = ? AND deleted_at IS NULL
+            SELECT bp.id, bp.title, bp.slug, bp.description, bp.body, bp.body_html, bp.toc,
+                   bp.reading_minutes, bp.created_at, bp.updated_at, bp.status,
+                   bp.published_at, bp.deleted_at, bp.created_by, bp.updated_by,
+                   bp.deleted_by
+            FROM blog_posts bp
+            WHERE bp.id =
             "#,
-        )
-        .bind(id)
-        .fetch_optional(&self.pool)
-        .await
+        );
+        query.push_bind(id);
+        if visibility != BlogPostVisibility::WithDeleted {
+            query.push(" AND bp.deleted_at IS NULL");
+        }
+        push_visibility(&mut query, visibility, now_timestamp());
+
+        let post = query
+            .build_query_as::<BlogPost>()
+            .fetch_optional(&self.pool)
+            .await?;
+
+        let Some(mut post) = post else {
+            return Ok(None);
+        };
+        post.tags = self
+            .tags_for_posts(&[post.id])
+            .await?
+            .remove(&post.id)
+            .unwrap_or_default();
+
+        Ok(Some(post))
     }
 
+    /// Insert a new draft post along with its tags and first revision. The slug is
+    /// derived from the title and made unique, and the body is rendered up front.
     pub async fn create(
         &self,
         title: &str,
         description: &str,
         body: &str,
+        tags: &[String],
+        author_sub: Option<&str>,
     ) -> Result<i64, sqlx::Error> {
         let now = now_timestamp();
+        let mut tx = self.pool.begin().await?;
+
+        let slug = unique_slug(&mut tx, &slugify(title), None).await?;
+        let rendered = render_markdown(body);
 
         let result = sqlx::query(
             r#"
-            INSERT INTO blog_posts (title, description, body, created_at, updated_at)
-            VALUES (?, ?, ?, ?, ?)
+            INSERT INTO blog_posts
+                (title, slug, description, body, body_html, toc, reading_minutes,
+                 created_at, updated_at, status, created_by, updated_by)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
             "#,
         )
         .bind(title)
+        .bind(&slug)
         .bind(description)
         .bind(body)
+        .bind(&rendered.html)
+        .bind(Json(&rendered.toc))
+        .bind(rendered.reading_minutes)
         .bind(now)
         .bind(now)
-        .execute(&self.pool)
+        .bind(BlogPostStatus::Draft)
+        .bind(author_sub)
+        .bind(author_sub)
+        .execute(&mut *tx)
         .await?;
 
-        Ok(result.last_insert_rowid())
+        let id = result.last_insert_rowid();
+        set_post_tags(&mut tx, id, tags, now).await?;
+        insert_revision(&mut tx, id, title, description, body, author_sub, now).await?;
+        tx.commit().await?;
+
+        Ok(id)
     }
 
+    /// Update a post, re-render its body and record the resulting content as a new
+    /// revision. Returns the new revision number, or `None` if the post doesn't exist.
     pub async fn update(
         &self,
         id: i64,
-        title: Option<&str>,
-        description: Option<&str>,
-        body: Option<&str>,
-    ) -> Result<bool, sqlx::Error> {
+        changes: BlogPostUpdate<'_>,
+        author_sub: Option<&str>,
+    ) -> Result<Option<i32>, sqlx::Error> {
         let now = now_timestamp();
+        let mut tx = self.pool.begin().await?;
 
-        let current = self.get(id).await?;
-        let Some(current) = current else {
-            return Ok(false);
+        let current: Option<(String, String, String, String)> = sqlx::query_as(
+            r#"
+            SELECT title, description, body, slug
+            FROM blog_posts
+            WHERE id = ? AND deleted_at IS NULL
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(&mut *tx)
+        .await?;
+        let Some((current_title, current_description, current_body, current_slug)) = current else {
+            return Ok(None);
         };
 
-        let title = title.unwrap_or(&current.title);
-        let description = description.unwrap_or(&current.description);
-        let body = body.unwrap_or(&current.body);
+        let title = changes.title.unwrap_or(&current_title);
+        let description = changes.description.unwrap_or(&current_description);
+        let body = changes.body.unwrap_or(&current_body);
+        let rendered = render_markdown(body);
 
-        let result = sqlx::query(
+        sqlx::query(
             r#"
             UPDATE blog_posts
-            SET title = ?, description = ?, body = ?, updated_at = ?
+            SET title = ?, description = ?, body = ?, body_html = ?, toc = ?,
+                reading_minutes = ?, updated_at = ?, updated_by = ?
             WHERE id = ? AND deleted_at IS NULL
             "#,
         )
         .bind(title)
         .bind(description)
         .bind(body)
+        .bind(&rendered.html)
+        .bind(Json(&rendered.toc))
+        .bind(rendered.reading_minutes)
+        .bind(now)
+        .bind(author_sub)
+        .bind(id)
+        .execute(&mut *tx)
+        .await?;
+
+        if let Some(tags) = changes.tags {
+            set_post_tags(&mut tx, id, tags, now).await?;
+        }
+
+        if let Some(slug) = changes.slug {
+            change_slug(&mut tx, id, &current_slug, slug, now).await?;
+        }
+
+        let revision =
+            insert_revision(&mut tx, id, title, description, body, author_sub, now).await?;
+        tx.commit().await?;
+
+        Ok(Some(revision))
+    }
+
+    pub async fn delete(&self, id: i64, actor_sub: Option<&str>) -> Result<bool, sqlx::Error> {
+        let now = now_timestamp();
+
+        let result = sqlx::query(
+            r#"
+            UPDATE blog_posts
+            SET deleted_at = ?, updated_at = ?, deleted_by = ?, updated_by = ?
+            WHERE id = ? AND deleted_at IS NULL
+            "#,
+        )
+        .bind(now)
+        .bind(now)
+        .bind(actor_sub)
+        .bind(actor_sub)
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn restore(&self, id: i64, actor_sub: Option<&str>) -> Result<bool, sqlx::Error> {
+        let now = now_timestamp();
+
+        let result = sqlx::query(
+            r#"
+            UPDATE blog_posts
+            SET deleted_at = NULL, deleted_by = NULL, updated_at = ?, updated_by = ?
+            WHERE id = ? AND deleted_at IS NOT NULL
+            "#,
+        )
         .bind(now)
+        .bind(actor_sub)
         .bind(id)
         .execute(&self.pool)
         .await?;
@@ -173,18 +323,26 @@ impl BlogRepository {
         Ok(result.rows_affected() > 0)
     }
 
-    pub async fn delete(&self, id: i64) -> Result<bool, sqlx::Error> {
+    /// Make a post public immediately. Already-published posts keep their original
+    /// `published_at` so republishing is idempotent.
+    pub async fn publish(&self, id: i64, actor_sub: Option<&str>) -> Result<bool, sqlx::Error> {
         let now = now_timestamp();
 
         let result = sqlx::query(
             r#"
             UPDATE blog_posts
-            SET deleted_at = ?, updated_at = ?
+            SET published_at = CASE WHEN status = ? THEN published_at ELSE ? END,
+                status = ?,
+                updated_at = ?,
+                updated_by = ?
             WHERE id = ? AND deleted_at IS NULL
             "#,
         )
+        .bind(BlogPostStatus::Published)
         .bind(now)
+        .bind(BlogPostStatus::Published)
         .bind(now)
+        .bind(actor_sub)
         .bind(id)
         .execute(&self.pool)
         .await?;
@@ -192,28 +350,202 @@ impl BlogRepository {
         Ok(result.rows_affected() > 0)
     }
 
-    pub async fn restore(&self, id: i64) -> Result<bool, sqlx::Error> {
+    /// Take a post out of public view, either back to draft or into the archive.
+    /// Archived posts keep their `published_at` for reference.
+    pub async fn unpublish(
+        &self,
+        id: i64,
+        status: BlogPostStatus,
+        actor_sub: Option<&str>,
+    ) -> Result<bool, sqlx::Error> {
         let now = now_timestamp();
 
         let result = sqlx::query(
             r#"
             UPDATE blog_posts
-            SET deleted_at = NULL, updated_at = ?
-            WHERE id = ? AND deleted_at IS NOT NULL
+            SET published_at = CASE WHEN ? = ? THEN published_at ELSE NULL END,
+                status = ?,
+                updated_at = ?,
+                updated_by = ?
+            WHERE id = ? AND deleted_at IS NULL
             "#,
         )
+        .bind(status)
+        .bind(BlogPostStatus::Archived)
+        .bind(status)
         .bind(now)
+        .bind(actor_sub)
         .bind(id)
         .execute(&self.pool)
         .await?;
 
         Ok(result.rows_affected() > 0)
     }
+
+    pub async fn schedule(
+        &self,
+        id: i64,
+        publish_at: i64,
+        actor_sub: Option<&str>,
+    ) -> Result<bool, sqlx::Error> {
+        let now = now_timestamp();
+
+        let result = sqlx::query(
+            r#"
+            UPDATE blog_posts
+            SET status = ?, published_at = ?, updated_at = ?, updated_by = ?
+            WHERE id = ? AND deleted_at IS NULL
+            "#,
+        )
+        .bind(BlogPostStatus::Scheduled)
+        .bind(publish_at)
+        .bind(now)
+        .bind(actor_sub)
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Flip scheduled posts whose `published_at` has passed to published.
+    /// `updated_by` stays whoever scheduled the post.
+    /// Returns the ids of the posts that went live.
+    pub async fn publish_due(&self) -> Result<Vec<i64>, sqlx::Error> {
+        let now = now_timestamp();
+
+        sqlx::query_scalar(
+            r#"
+            UPDATE blog_posts
+            SET status = ?, updated_at = ?
+            WHERE status = ? AND published_at <= ? AND deleted_at IS NULL
+            RETURNING id
+            "#,
+        )
+        .bind(BlogPostStatus::Published)
+        .bind(now)
+        .bind(BlogPostStatus::Scheduled)
+        .bind(now)
+        .fetch_all(&self.pool)
+        .await
+    }
+}
+
+/// Column a listing is ordered by; `bp.id` breaks ties so keyset paging is stable.
+#[derive(Debug, Clone, Copy)]
+enum SortColumn {
+    Rank,
+    CreatedAt,
+    UpdatedAt,
+}
+
+impl SortColumn {
+    fn sql(self) -> &'static str {
+        match self {
+            Self::Rank => "rank",
+            Self::CreatedAt => "bp.created_at",
+            Self::UpdatedAt => "bp.updated_at",
+        }
+    }
+
+    fn key(self, post: &BlogPostSummary) -> CursorKey {
+        match self {
+            Self::Rank => CursorKey::Rank(post.rank.unwrap_or_default()),
+            Self::CreatedAt => CursorKey::Timestamp(post.created_at),
+            Self::UpdatedAt => CursorKey::Timestamp(post.updated_at),
+        }
+    }
+}
+
+/// The column to order by and whether it's descending. FTS5 ranks are better the
+/// lower they are, so relevance sorts ascending.
+fn sort_order(sort: BlogPostSort, searching: bool) -> (SortColumn, bool) {
+    match sort {
+        BlogPostSort::Relevance if searching => (SortColumn::Rank, false),
+        // Without a search query, relevance doesn't apply - default to created_at DESC
+        BlogPostSort::Relevance | BlogPostSort::CreatedDesc => (SortColumn::CreatedAt, true),
+        BlogPostSort::CreatedAsc => (SortColumn::CreatedAt, false),
+        BlogPostSort::UpdatedAsc => (SortColumn::UpdatedAt, false),
+        BlogPostSort::UpdatedDesc => (SortColumn::UpdatedAt, true),
+    }
 }
 
-fn now_timestamp() -> i64 {
-    SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap()
-        .as_secs() as i64
+/// `FROM`/`WHERE` clauses shared by the listing and its total count.
+fn push_list_conditions<'a>(
+    qb: &mut QueryBuilder<'a, Sqlite>,
+    filter: &'a BlogPostFilter,
+    search: Option<&'a str>,
+    now: i64,
+) {
+    match search {
+        Some(q) => {
+            qb.push(
+                r#"
+                FROM blog_posts_fts fts
+                INNER JOIN blog_posts bp ON bp.id = fts.rowid
+                WHERE blog_posts_fts MATCH "#,
+            )
+            .push_bind(q)
+            .push(" AND bp.deleted_at IS NULL");
+        }
+        None => {
+            qb.push(" FROM blog_posts bp WHERE bp.deleted_at IS NULL");
+        }
+    }
+
+    push_visibility(qb, filter.visibility, now);
+
+    if let Some(status) = filter.status {
+        qb.push(" AND bp.status = ").push_bind(status);
+    }
+
+    if !filter.tags.is_empty() {
+        qb.push(
+            r#"
+            AND bp.id IN (
+                SELECT bpt.post_id
+                FROM blog_post_tags bpt
+                INNER JOIN tags t ON t.id = bpt.tag_id
+                WHERE t.name IN ("#,
+        );
+        let mut names = qb.separated(", ");
+        for tag in &filter.tags {
+            names.push_bind(tag);
+        }
+        qb.push(") GROUP BY bpt.post_id HAVING COUNT(DISTINCT t.id) = ")
+            .push_bind(filter.tags.len() as i64)
+            .push(")");
+    }
+}
+
+/// Select FTS5 excerpts for a search, matches wrapped in `MATCH_OPEN` and
+/// `MATCH_CLOSE`. Column indexes follow `blog_posts_fts`: title = 0,
+/// description = 1, body = 2.
+fn push_search_excerpts(qb: &mut QueryBuilder<'_, Sqlite>) {
+    qb.push(format!(
+        ", snippet(blog_posts_fts, 2, '{MATCH_OPEN}', '{MATCH_CLOSE}', '…', {SNIPPET_TOKENS}) \
+         AS snippet, \
+         highlight(blog_posts_fts, 0, '{MATCH_OPEN}', '{MATCH_CLOSE}') AS highlighted_title, \
+         highlight(blog_posts_fts, 1, '{MATCH_OPEN}', '{MATCH_CLOSE}') AS highlighted_description"
+    ));
+}
+
+/// HTML-escape an FTS5 excerpt and put the caller's markers around its matches
+fn mark_matches(excerpt: &str, markers: &HighlightMarkers) -> String {
+    escape(excerpt)
+        .replace(MATCH_OPEN, &markers.open)
+        .replace(MATCH_CLOSE, &markers.close)
+}
+
+/// Restrict `bp` rows to what the given visibility level may see.
+fn push_visibility(qb: &mut QueryBuilder<'_, Sqlite>, visibility: BlogPostVisibility, now: i64) {
+    match visibility {
+        BlogPostVisibility::Public => {
+            qb.push(" AND bp.status = ")
+                .push_bind(BlogPostStatus::Published)
+                .push(" AND bp.published_at <= ")
+                .push_bind(now);
+        }
+        BlogPostVisibility::All | BlogPostVisibility::WithDeleted => {}
+    }
 }
@@ -0,0 +1,78 @@
+use sqlx::{QueryBuilder, Sqlite};
+
+use super::{push_visibility, BlogRepository};
+use crate::dao::blog::{BlogPost, BlogPostVisibility};
+use crate::utils::now_timestamp;
+
+impl BlogRepository {
+    /// The most recently published public posts, optionally only those tagged
+    /// `tag`, with their full content.
+    pub async fn list_published(
+        &self,
+        tag: Option<&str>,
+        limit: i32,
+    ) -> Result<Vec<BlogPost>, sqlx::Error> {
+        let mut query = QueryBuilder::<Sqlite>::new(
+            r#"
+            SELECT bp.id, bp.title, bp.slug, bp.description, bp.body, bp.body_html, bp.toc,
+                   bp.reading_minutes, bp.created_at, bp.updated_at, bp.status,
+                   bp.published_at, bp.deleted_at, bp.created_by, bp.updated_by,
+                   bp.deleted_by
+            FROM blog_posts bp
+            WHERE bp.deleted_at IS NULL
+            "#,
+        );
+        push_visibility(&mut query, BlogPostVisibility::Public, now_timestamp());
+        if let Some(tag) = tag {
+            query
+                .push(
+                    r#"
+                    AND bp.id IN (
+                        SELECT bpt.post_id
+                        FROM blog_post_tags bpt
+                        INNER JOIN tags t ON t.id = bpt.tag_id
+                        WHERE t.name = "#,
+                )
+                .push_bind(tag)
+                .push(")");
+        }
+        query
+            .push(" ORDER BY bp.published_at DESC, bp.id DESC LIMIT ")
+            .push_bind(limit);
+
+        let mut posts = query
+            .build_query_as::<BlogPost>()
+            .fetch_all(&self.pool)
+            .await?;
+
+        let ids: Vec<i64> = posts.iter().map(|p| p.id).collect();
+        let mut tags = self.tags_for_posts(&ids).await?;
+        for post in &mut posts {
+            post.tags = tags.remove(&post.id).unwrap_or_default();
+        }
+
+        Ok(posts)
+    }
+
+    /// Slug and `updated_at` of every public post, newest first.
+    pub async fn list_published_slugs(&self) -> Result<Vec<(String, i64)>, sqlx::Error> {
+        let mut query = QueryBuilder::<Sqlite>::new(
+            "SELECT bp.slug, bp.updated_at FROM blog_posts bp WHERE bp.deleted_at IS NULL",
+        );
+        push_visibility(&mut query, BlogPostVisibility::Public, now_timestamp());
+        query.push(" ORDER BY bp.published_at DESC, bp.id DESC");
+
+        query.build_query_as().fetch_all(&self.pool).await
+    }
+
+    /// Latest `updated_at` of a public post. Publishing a post and editing it
+    /// while it's public both bump it; drafts and scheduled posts don't count.
+    pub async fn last_modified(&self) -> Result<Option<i64>, sqlx::Error> {
+        let mut query = QueryBuilder::<Sqlite>::new(
+            "SELECT MAX(bp.updated_at) FROM blog_posts bp WHERE bp.deleted_at IS NULL",
+        );
+        push_visibility(&mut query, BlogPostVisibility::Public, now_timestamp());
+
+        query.build_query_scalar().fetch_one(&self.pool).await
+    }
+}
@@ -0,0 +1,87 @@
+use sqlx::SqliteConnection;
+
+use super::BlogRepository;
+use crate::dao::blog::{BlogPostRevision, BlogPostRevisionSummary};
+
+impl BlogRepository {
+    /// List a post's revisions, newest first.
+    pub async fn list_revisions(
+        &self,
+        post_id: i64,
+        limit: i32,
+        offset: i32,
+    ) -> Result<(Vec<BlogPostRevisionSummary>, i32), sqlx::Error> {
+        let revisions = sqlx::query_as::<_, BlogPostRevisionSummary>(
+            r#"
+            SELECT post_id, revision, title, author_sub, created_at
+            FROM blog_post_revisions
+            WHERE post_id = ?
+            ORDER BY revision DESC
+            LIMIT ? OFFSET ?
+            "#,
+        )
+        .bind(post_id)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let total: i32 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM blog_post_revisions WHERE post_id = ?")
+                .bind(post_id)
+                .fetch_one(&self.pool)
+                .await?;
+
+        Ok((revisions, total))
+    }
+
+    pub async fn get_revision(
+        &self,
+        post_id: i64,
+        revision: i32,
+    ) -> Result<Option<BlogPostRevision>, sqlx::Error> {
+        sqlx::query_as::<_, BlogPostRevision>(
+            r#"
+            SELECT post_id, revision, title, description, body, author_sub, created_at
+            FROM blog_post_revisions
+            WHERE post_id = ? AND revision = ?
+            "#,
+        )
+        .bind(post_id)
+        .bind(revision)
+        .fetch_optional(&self.pool)
+        .await
+    }
+}
+
+/// Record the given content as the next revision of a post. Must run inside the
+/// same transaction as the write it snapshots. Returns the new revision number.
+pub(super) async fn insert_revision(
+    conn: &mut SqliteConnection,
+    post_id: i64,
+    title: &str,
+    description: &str,
+    body: &str,
+    author_sub: Option<&str>,
+    created_at: i64,
+) -> Result<i32, sqlx::Error> {
+    sqlx::query_scalar(
+        r#"
+        INSERT INTO blog_post_revisions
+            (post_id, revision, title, description, body, author_sub, created_at)
+        SELECT ?, COALESCE(MAX(revision), 0) + 1, ?, ?, ?, ?, ?
+        FROM blog_post_revisions
+        WHERE post_id = ?
+        RETURNING revision
+        "#,
+    )
+    .bind(post_id)
+    .bind(title)
+    .bind(description)
+    .bind(body)
+    .bind(author_sub)
+    .bind(created_at)
+    .bind(post_id)
+    .fetch_one(conn)
+    .await
+}
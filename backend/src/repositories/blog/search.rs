@@ -0,0 +1,97 @@
+use std::collections::HashSet;
+
+use sqlx::types::Json;
+
+use super::BlogRepository;
+use crate::dao::blog::BlogPostStatus;
+use crate::utils::now_timestamp;
+
+/// Vocabulary rows considered per requested completion before visibility filtering
+const CANDIDATES_PER_COMPLETION: i32 = 5;
+
+/// Restricts `v.term` to words that occur in at least one publicly visible post,
+/// so drafts don't leak through suggestions. Binds: status, now.
+const TERM_IS_PUBLIC: &str = r#"
+    EXISTS (
+        SELECT 1
+        FROM blog_posts_terms
+        INNER JOIN blog_posts bp ON bp.id = blog_posts_terms.rowid
+        WHERE blog_posts_terms MATCH '"' || v.term || '"'
+          AND bp.deleted_at IS NULL
+          AND bp.status = ?
+          AND bp.published_at <= ?
+    )
+"#;
+
+impl BlogRepository {
+    /// Indexed words starting with `prefix` that appear in public posts, most
+    /// widely used first.
+    pub async fn complete_terms(
+        &self,
+        prefix: &str,
+        limit: i32,
+    ) -> Result<Vec<String>, sqlx::Error> {
+        let upper = format!("{prefix}{}", char::MAX);
+
+        sqlx::query_scalar(&format!(
+            r#"
+            SELECT v.term
+            FROM (
+                SELECT term, doc
+                FROM blog_posts_vocab
+                WHERE term >= ? AND term < ?
+                ORDER BY doc DESC, term ASC
+                LIMIT ?
+            ) v
+            WHERE {TERM_IS_PUBLIC}
+            ORDER BY v.doc DESC, v.term ASC
+            LIMIT ?
+            "#
+        ))
+        .bind(prefix)
+        .bind(upper)
+        .bind(limit * CANDIDATES_PER_COMPLETION)
+        .bind(BlogPostStatus::Published)
+        .bind(now_timestamp())
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    /// Indexed words whose length is within `min_len..=max_len` characters, with
+    /// the number of posts each appears in. Includes words from unpublished posts;
+    /// check candidates with [`BlogRepository::public_terms`].
+    pub async fn terms_by_length(
+        &self,
+        min_len: usize,
+        max_len: usize,
+    ) -> Result<Vec<(String, i64)>, sqlx::Error> {
+        sqlx::query_as("SELECT term, doc FROM blog_posts_vocab WHERE length(term) BETWEEN ? AND ?")
+            .bind(min_len as i64)
+            .bind(max_len as i64)
+            .fetch_all(&self.pool)
+            .await
+    }
+
+    /// Which of `terms` occur in any publicly visible post.
+    pub async fn public_terms<'a>(
+        &self,
+        terms: impl IntoIterator<Item = &'a String>,
+    ) -> Result<HashSet<String>, sqlx::Error> {
+        let terms: Vec<&String> = terms.into_iter().collect();
+        if terms.is_empty() {
+            return Ok(HashSet::new());
+        }
+
+        let public: Vec<String> = sqlx::query_scalar(&format!(
+            "SELECT v.term FROM (SELECT value AS term FROM json_each(?)) v WHERE {TERM_IS_PUBLIC}"
+        ))
+        .bind(Json(terms))
+        .bind(BlogPostStatus::Published)
+        .bind(now_timestamp())
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(public.into_iter().collect())
+    }
+}
@@ -0,0 +1,106 @@
+use sqlx::SqliteConnection;
+
+use super::BlogRepository;
+
+impl BlogRepository {
+    /// Whether `slug` is the current slug of any post other than `exclude_id`.
+    pub async fn slug_taken(&self, slug: &str, exclude_id: i64) -> Result<bool, sqlx::Error> {
+        sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM blog_posts WHERE slug = ? AND id != ?)")
+            .bind(slug)
+            .bind(exclude_id)
+            .fetch_one(&self.pool)
+            .await
+    }
+
+    /// The post a slug belongs to, checking current slugs first and then the
+    /// slugs posts used before being renamed.
+    pub async fn resolve_slug(&self, slug: &str) -> Result<Option<i64>, sqlx::Error> {
+        sqlx::query_scalar(
+            r#"
+            SELECT id FROM blog_posts WHERE slug = ?
+            UNION ALL
+            SELECT post_id FROM blog_post_slugs WHERE slug = ?
+            LIMIT 1
+            "#,
+        )
+        .bind(slug)
+        .bind(slug)
+        .fetch_optional(&self.pool)
+        .await
+    }
+}
+
+/// First free variant of `base` (`base`, `base-2`, `base-3`, ...). A slug is free
+/// when no other post uses it now or used it before, so generated slugs never
+/// hijack an old link.
+pub(crate) async fn unique_slug(
+    conn: &mut SqliteConnection,
+    base: &str,
+    post_id: Option<i64>,
+) -> Result<String, sqlx::Error> {
+    let base = if base.is_empty() { "post" } else { base };
+    let post_id = post_id.unwrap_or(0);
+
+    let mut suffix = 1;
+    loop {
+        let candidate = if suffix == 1 {
+            base.to_string()
+        } else {
+            format!("{base}-{suffix}")
+        };
+
+        let taken: bool = sqlx::query_scalar(
+            r#"
+            SELECT EXISTS(SELECT 1 FROM blog_posts WHERE slug = ? AND id != ?)
+                OR EXISTS(SELECT 1 FROM blog_post_slugs WHERE slug = ? AND post_id != ?)
+            "#,
+        )
+        .bind(&candidate)
+        .bind(post_id)
+        .bind(&candidate)
+        .bind(post_id)
+        .fetch_one(&mut *conn)
+        .await?;
+
+        if !taken {
+            return Ok(candidate);
+        }
+        suffix += 1;
+    }
+}
+
+/// Point a post at a new slug, remembering the old one so it keeps resolving.
+/// An explicit rename takes the slug over from any post that used it in the past.
+pub(super) async fn change_slug(
+    conn: &mut SqliteConnection,
+    post_id: i64,
+    old_slug: &str,
+    new_slug: &str,
+    now: i64,
+) -> Result<(), sqlx::Error> {
+    if old_slug == new_slug {
+        return Ok(());
+    }
+
+    sqlx::query("DELETE FROM blog_post_slugs WHERE slug = ?")
+        .bind(new_slug)
+        .execute(&mut *conn)
+        .await?;
+
+    sqlx::query(
+        "INSERT OR REPLACE INTO blog_post_slugs (slug, post_id, created_at) VALUES (?, ?, ?)",
+    )
+    .bind(old_slug)
+    .bind(post_id)
+    .bind(now)
+    .execute(&mut *conn)
+    .await?;
+
+    sqlx::query("UPDATE blog_posts SET slug = ? WHERE id = ?")
+        .bind(new_slug)
+        .bind(post_id)
+        .execute(&mut *conn)
+        .await?;
+
+    Ok(())
+}
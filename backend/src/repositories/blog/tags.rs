@@ -0,0 +1,239 @@
+use std::collections::HashMap;
+
+use sqlx::{QueryBuilder, Sqlite, SqliteConnection};
+
+use super::BlogRepository;
+use crate::dao::blog::{BlogPostStatus, TagCount};
+use crate::utils::now_timestamp;
+
+impl BlogRepository {
+    /// Tag names for each of the given posts, sorted alphabetically.
+    pub(super) async fn tags_for_posts(
+        &self,
+        post_ids: &[i64],
+    ) -> Result<HashMap<i64, Vec<String>>, sqlx::Error> {
+        let mut tags: HashMap<i64, Vec<String>> = HashMap::new();
+        if post_ids.is_empty() {
+            return Ok(tags);
+        }
+
+        let mut query = QueryBuilder::<Sqlite>::new(
+            r#"
+            SELECT bpt.post_id, t.name
+            FROM blog_post_tags bpt
+            INNER JOIN tags t ON t.id = bpt.tag_id
+            WHERE bpt.post_id IN ("#,
+        );
+        let mut ids = query.separated(", ");
+        for id in post_ids {
+            ids.push_bind(id);
+        }
+        query.push(") ORDER BY t.name");
+
+        let rows: Vec<(i64, String)> = query.build_query_as().fetch_all(&self.pool).await?;
+        for (post_id, name) in rows {
+            tags.entry(post_id).or_default().push(name);
+        }
+
+        Ok(tags)
+    }
+
+    /// Every tag used by at least one publicly visible post, with its post count.
+    pub async fn list_tags(&self) -> Result<Vec<TagCount>, sqlx::Error> {
+        sqlx::query_as::<_, TagCount>(
+            r#"
+            SELECT t.name, COUNT(bp.id) AS post_count
+            FROM tags t
+            INNER JOIN blog_post_tags bpt ON bpt.tag_id = t.id
+            INNER JOIN blog_posts bp ON bp.id = bpt.post_id
+            WHERE bp.deleted_at IS NULL
+              AND bp.status = ?
+              AND bp.published_at <= ?
+            GROUP BY t.id
+            ORDER BY post_count DESC, t.name ASC
+            "#,
+        )
+        .bind(BlogPostStatus::Published)
+        .bind(now_timestamp())
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    pub async fn tag_exists(&self, name: &str) -> Result<bool, sqlx::Error> {
+        sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM tags WHERE name = ?)")
+            .bind(name)
+            .fetch_one(&self.pool)
+            .await
+    }
+
+    /// Rename a tag, marking the posts carrying it as updated by `actor_sub`.
+    pub async fn rename_tag(
+        &self,
+        name: &str,
+        new_name: &str,
+        actor_sub: Option<&str>,
+    ) -> Result<bool, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        let tag_id: Option<i64> = sqlx::query_scalar("SELECT id FROM tags WHERE name = ?")
+            .bind(name)
+            .fetch_optional(&mut *tx)
+            .await?;
+        let Some(tag_id) = tag_id else {
+            return Ok(false);
+        };
+
+        sqlx::query("UPDATE tags SET name = ? WHERE id = ?")
+            .bind(new_name)
+            .bind(tag_id)
+            .execute(&mut *tx)
+            .await?;
+        touch_tagged(&mut tx, tag_id, now_timestamp(), actor_sub).await?;
+
+        tx.commit().await?;
+
+        Ok(true)
+    }
+
+    /// Ids of posts, deleted ones included, tagged with any of `names`.
+    pub async fn post_ids_tagged(&self, names: &[String]) -> Result<Vec<i64>, sqlx::Error> {
+        if names.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let mut query = QueryBuilder::<Sqlite>::new(
+            r#"
+            SELECT DISTINCT bpt.post_id
+            FROM blog_post_tags bpt
+            INNER JOIN tags t ON t.id = bpt.tag_id
+            WHERE t.name IN ("#,
+        );
+        let mut binds = query.separated(", ");
+        for name in names {
+            binds.push_bind(name);
+        }
+        query.push(") ORDER BY bpt.post_id");
+
+        query.build_query_scalar().fetch_all(&self.pool).await
+    }
+
+    /// Move every post tagged with one of `sources` onto `target` (created if
+    /// needed), marking them as updated by `actor_sub`, and drop the source
+    /// tags. Returns how many source tags existed; when none did, nothing
+    /// changes and `target` isn't created.
+    pub async fn merge_tags(
+        &self,
+        sources: &[String],
+        target: &str,
+        actor_sub: Option<&str>,
+    ) -> Result<u64, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        let mut source_ids = Vec::new();
+        for source in sources.iter().filter(|s| s.as_str() != target) {
+            let source_id: Option<i64> = sqlx::query_scalar("SELECT id FROM tags WHERE name = ?")
+                .bind(source)
+                .fetch_optional(&mut *tx)
+                .await?;
+            source_ids.extend(source_id);
+        }
+        source_ids.sort_unstable();
+        source_ids.dedup();
+        if source_ids.is_empty() {
+            return Ok(0);
+        }
+
+        let now = now_timestamp();
+        let target_id = upsert_tag(&mut tx, target, now).await?;
+        for &source_id in &source_ids {
+            touch_tagged(&mut tx, source_id, now, actor_sub).await?;
+
+            sqlx::query(
+                r#"
+                INSERT OR IGNORE INTO blog_post_tags (post_id, tag_id)
+                SELECT post_id, ? FROM blog_post_tags WHERE tag_id = ?
+                "#,
+            )
+            .bind(target_id)
+            .bind(source_id)
+            .execute(&mut *tx)
+            .await?;
+
+            sqlx::query("DELETE FROM blog_post_tags WHERE tag_id = ?")
+                .bind(source_id)
+                .execute(&mut *tx)
+                .await?;
+
+            sqlx::query("DELETE FROM tags WHERE id = ?")
+                .bind(source_id)
+                .execute(&mut *tx)
+                .await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(source_ids.len() as u64)
+    }
+}
+
+/// Replace a post's tags, creating any tags that don't exist yet.
+pub(super) async fn set_post_tags(
+    conn: &mut SqliteConnection,
+    post_id: i64,
+    tags: &[String],
+    now: i64,
+) -> Result<(), sqlx::Error> {
+    sqlx::query("DELETE FROM blog_post_tags WHERE post_id = ?")
+        .bind(post_id)
+        .execute(&mut *conn)
+        .await?;
+
+    for tag in tags {
+        let tag_id = upsert_tag(conn, tag, now).await?;
+
+        sqlx::query("INSERT OR IGNORE INTO blog_post_tags (post_id, tag_id) VALUES (?, ?)")
+            .bind(post_id)
+            .bind(tag_id)
+            .execute(&mut *conn)
+            .await?;
+    }
+
+    Ok(())
+}
+
+/// Bump `updated_at` on every post carrying the tag, so caches of what readers
+/// see, like feed `Last-Modified`, notice the change.
+async fn touch_tagged(
+    conn: &mut SqliteConnection,
+    tag_id: i64,
+    now: i64,
+    actor_sub: Option<&str>,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        UPDATE blog_posts
+        SET updated_at = ?, updated_by = ?
+        WHERE id IN (SELECT post_id FROM blog_post_tags WHERE tag_id = ?)
+        "#,
+    )
+    .bind(now)
+    .bind(actor_sub)
+    .bind(tag_id)
+    .execute(&mut *conn)
+    .await?;
+
+    Ok(())
+}
+
+async fn upsert_tag(conn: &mut SqliteConnection, name: &str, now: i64) -> Result<i64, sqlx::Error> {
+    sqlx::query("INSERT OR IGNORE INTO tags (name, created_at) VALUES (?, ?)")
+        .bind(name)
+        .bind(now)
+        .execute(&mut *conn)
+        .await?;
+
+    sqlx::query_scalar("SELECT id FROM tags WHERE name = ?")
+        .bind(name)
+        .fetch_one(&mut *conn)
+        .await
+}
@@ -0,0 +1,105 @@
+use crate::dao::email::{NewEmail, OutboxEmail, OutboxStatus};
+use crate::db::DbPool;
+use crate::utils::now_timestamp;
+
+/// Notification emails waiting to be sent, and a record of those that were
+pub struct EmailOutboxRepository {
+    pool: DbPool,
+}
+
+impl EmailOutboxRepository {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+
+    /// Queue an email to be sent right away. Returns its id.
+    pub async fn enqueue(&self, email: &NewEmail) -> Result<i64, sqlx::Error> {
+        let now = now_timestamp();
+        let result = sqlx::query(
+            r#"
+            INSERT INTO email_outbox
+                (event, recipient, subject, text_body, html_body, status, next_attempt_at, created_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(&email.event)
+        .bind(&email.recipient)
+        .bind(&email.subject)
+        .bind(&email.text_body)
+        .bind(&email.html_body)
+        .bind(OutboxStatus::Pending)
+        .bind(now)
+        .bind(now)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.last_insert_rowid())
+    }
+
+    /// Pending emails whose next attempt is due by `now`, oldest first
+    pub async fn due(&self, now: i64, limit: i32) -> Result<Vec<OutboxEmail>, sqlx::Error> {
+        sqlx::query_as(
+            r#"
+            SELECT * FROM email_outbox
+            WHERE status = ? AND next_attempt_at <= ?
+            ORDER BY next_attempt_at, id
+            LIMIT ?
+            "#,
+        )
+        .bind(OutboxStatus::Pending)
+        .bind(now)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    pub async fn get(&self, id: i64) -> Result<Option<OutboxEmail>, sqlx::Error> {
+        sqlx::query_as("SELECT * FROM email_outbox WHERE id = ?")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+    }
+
+    pub async fn mark_sent(&self, id: i64) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            "UPDATE email_outbox SET status = ?, sent_at = ?, last_error = NULL WHERE id = ?",
+        )
+        .bind(OutboxStatus::Sent)
+        .bind(now_timestamp())
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Record a failed attempt. The email is retried at `next_attempt_at`, or
+    /// given up on if that's `None`.
+    pub async fn mark_attempt_failed(
+        &self,
+        id: i64,
+        error: &str,
+        next_attempt_at: Option<i64>,
+    ) -> Result<(), sqlx::Error> {
+        let status = match next_attempt_at {
+            Some(_) => OutboxStatus::Pending,
+            None => OutboxStatus::Failed,
+        };
+        sqlx::query(
+            r#"
+            UPDATE email_outbox
+            SET status = ?,
+                attempts = attempts + 1,
+                last_error = ?,
+                next_attempt_at = COALESCE(?, next_attempt_at)
+            WHERE id = ?
+            "#,
+        )
+        .bind(status)
+        .bind(error)
+        .bind(next_attempt_at)
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,171 @@
+use sqlx::types::Json;
+use sqlx::{QueryBuilder, Sqlite};
+
+use crate::dao::inquiry::{Inquiry, InquiryStatus, NewInquiry};
+use crate::db::DbPool;
+use crate::utils::now_timestamp;
+
+pub struct InquiryRepository {
+    pool: DbPool,
+}
+
+impl InquiryRepository {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+
+    /// Store a submission as new, or as quarantined if the spam filter flagged it.
+    pub async fn create(
+        &self,
+        inquiry: &NewInquiry,
+        status: InquiryStatus,
+        spam_score: f64,
+        spam_reasons: &[String],
+    ) -> Result<i64, sqlx::Error> {
+        let result = sqlx::query(
+            r#"
+            INSERT INTO inquiries
+                (name, email, subject, message, status, created_at, spam_score, spam_reasons)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(&inquiry.name)
+        .bind(&inquiry.email)
+        .bind(&inquiry.subject)
+        .bind(&inquiry.message)
+        .bind(status)
+        .bind(now_timestamp())
+        .bind(spam_score)
+        .bind(Json(spam_reasons))
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.last_insert_rowid())
+    }
+
+    /// Inquiries with `status`, or every one neither archived nor quarantined
+    /// if `None`, newest first, with the total number of matches.
+    pub async fn list(
+        &self,
+        status: Option<InquiryStatus>,
+        limit: i32,
+        offset: i32,
+    ) -> Result<(Vec<Inquiry>, i32), sqlx::Error> {
+        let mut query = QueryBuilder::<Sqlite>::new("SELECT * FROM inquiries");
+        push_status(&mut query, status);
+        query
+            .push(" ORDER BY created_at DESC, id DESC LIMIT ")
+            .push_bind(limit)
+            .push(" OFFSET ")
+            .push_bind(offset);
+        let inquiries = query
+            .build_query_as::<Inquiry>()
+            .fetch_all(&self.pool)
+            .await?;
+
+        let mut count = QueryBuilder::<Sqlite>::new("SELECT COUNT(*) FROM inquiries");
+        push_status(&mut count, status);
+        let total: i32 = count.build_query_scalar().fetch_one(&self.pool).await?;
+
+        Ok((inquiries, total))
+    }
+
+    pub async fn get(&self, id: i64) -> Result<Option<Inquiry>, sqlx::Error> {
+        sqlx::query_as("SELECT * FROM inquiries WHERE id = ?")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+    }
+
+    /// Record that an inquiry was dealt with. New inquiries become handled;
+    /// archived ones stay archived. Marking twice keeps the first record.
+    pub async fn mark_handled(
+        &self,
+        id: i64,
+        actor_sub: Option<&str>,
+    ) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query(
+            r#"
+            UPDATE inquiries
+            SET status = CASE WHEN status = ? THEN ? ELSE status END,
+                handled_at = COALESCE(handled_at, ?),
+                handled_by = COALESCE(handled_by, ?)
+            WHERE id = ?
+            "#,
+        )
+        .bind(InquiryStatus::New)
+        .bind(InquiryStatus::Handled)
+        .bind(now_timestamp())
+        .bind(actor_sub)
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn archive(&self, id: i64) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query(
+            r#"
+            UPDATE inquiries
+            SET status = ?, archived_at = COALESCE(archived_at, ?)
+            WHERE id = ?
+            "#,
+        )
+        .bind(InquiryStatus::Archived)
+        .bind(now_timestamp())
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Record an admin's spam verdict. Spam is quarantined; a quarantined
+    /// inquiry that isn't goes back to the inbox as new.
+    pub async fn mark_spam(&self, id: i64, spam: bool) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query(
+            r#"
+            UPDATE inquiries
+            SET marked_spam = ?,
+                status = CASE
+                    WHEN ? THEN ?
+                    WHEN status = ? THEN ?
+                    ELSE status
+                END
+            WHERE id = ?
+            "#,
+        )
+        .bind(spam)
+        .bind(spam)
+        .bind(InquiryStatus::Quarantined)
+        .bind(InquiryStatus::Quarantined)
+        .bind(InquiryStatus::New)
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn delete(&self, id: i64) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query("DELETE FROM inquiries WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+fn push_status(qb: &mut QueryBuilder<'_, Sqlite>, status: Option<InquiryStatus>) {
+    match status {
+        Some(status) => qb.push(" WHERE status = ").push_bind(status),
+        None => qb
+            .push(" WHERE status NOT IN (")
+            .push_bind(InquiryStatus::Archived)
+            .push(", ")
+            .push_bind(InquiryStatus::Quarantined)
+            .push(")"),
+    };
+}
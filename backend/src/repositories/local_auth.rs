@@ -0,0 +1,119 @@
+use crate::dao::auth::LocalUser;
+use crate::db::DbPool;
+use crate::utils::now_timestamp;
+
+/// Storage for the local identity provider: accounts, refresh tokens and the
+/// token signing key.
+pub struct LocalAuthRepository {
+    pool: DbPool,
+}
+
+impl LocalAuthRepository {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+
+    pub async fn find_user(&self, id: i64) -> Result<Option<LocalUser>, sqlx::Error> {
+        sqlx::query_as("SELECT * FROM local_users WHERE id = ?")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+    }
+
+    pub async fn find_user_by_email(&self, email: &str) -> Result<Option<LocalUser>, sqlx::Error> {
+        sqlx::query_as("SELECT * FROM local_users WHERE email = ?")
+            .bind(email)
+            .fetch_optional(&self.pool)
+            .await
+    }
+
+    pub async fn count_users(&self) -> Result<i64, sqlx::Error> {
+        sqlx::query_scalar("SELECT COUNT(*) FROM local_users")
+            .fetch_one(&self.pool)
+            .await
+    }
+
+    pub async fn create_user(&self, email: &str, password_hash: &str) -> Result<i64, sqlx::Error> {
+        let result = sqlx::query(
+            "INSERT INTO local_users (email, password_hash, created_at) VALUES (?, ?, ?)",
+        )
+        .bind(email)
+        .bind(password_hash)
+        .bind(now_timestamp())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.last_insert_rowid())
+    }
+
+    pub async fn set_password_hash(&self, id: i64, password_hash: &str) -> Result<(), sqlx::Error> {
+        sqlx::query("UPDATE local_users SET password_hash = ? WHERE id = ?")
+            .bind(password_hash)
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Store a refresh token hash, dropping expired ones along the way.
+    pub async fn insert_refresh_token(
+        &self,
+        token_hash: &str,
+        user_id: i64,
+        expires_at: i64,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query("DELETE FROM local_refresh_tokens WHERE expires_at <= ?")
+            .bind(now_timestamp())
+            .execute(&self.pool)
+            .await?;
+
+        sqlx::query(
+            "INSERT INTO local_refresh_tokens (token_hash, user_id, expires_at) VALUES (?, ?, ?)",
+        )
+        .bind(token_hash)
+        .bind(user_id)
+        .bind(expires_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Consume a refresh token, returning its user if it was valid and unexpired.
+    pub async fn take_refresh_token(&self, token_hash: &str) -> Result<Option<i64>, sqlx::Error> {
+        sqlx::query_scalar(
+            "DELETE FROM local_refresh_tokens WHERE token_hash = ? AND expires_at > ? RETURNING user_id",
+        )
+        .bind(token_hash)
+        .bind(now_timestamp())
+        .fetch_optional(&self.pool)
+        .await
+    }
+
+    /// The signing key as `(kid, PKCS#8 document)`, if one was created.
+    pub async fn signing_key(&self) -> Result<Option<(String, Vec<u8>)>, sqlx::Error> {
+        sqlx::query_as(
+            "SELECT kid, private_key FROM local_signing_keys ORDER BY created_at DESC LIMIT 1",
+        )
+        .fetch_optional(&self.pool)
+        .await
+    }
+
+    pub async fn insert_signing_key(
+        &self,
+        kid: &str,
+        private_key: &[u8],
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            "INSERT INTO local_signing_keys (kid, private_key, created_at) VALUES (?, ?, ?)",
+        )
+        .bind(kid)
+        .bind(private_key)
+        .bind(now_timestamp())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
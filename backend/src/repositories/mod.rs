@@ -1,3 +1,17 @@
+pub mod audit;
 pub mod blog;
+pub mod email;
+pub mod inquiry;
+pub mod local_auth;
+pub mod rate_limit;
+pub mod spam;
+pub mod webhook;
 
+pub use audit::AuditRepository;
 pub use blog::BlogRepository;
+pub use email::EmailOutboxRepository;
+pub use inquiry::InquiryRepository;
+pub use local_auth::LocalAuthRepository;
+pub use rate_limit::RateLimitRepository;
+pub use spam::SpamRepository;
+pub use webhook::WebhookRepository;
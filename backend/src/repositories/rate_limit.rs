@@ -0,0 +1,41 @@
+use crate::dao::rate_limit::RateLimitBucket;
+use crate::db::DbPool;
+
+pub struct RateLimitRepository {
+    pool: DbPool,
+}
+
+impl RateLimitRepository {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+
+    pub async fn load(&self) -> Result<Vec<RateLimitBucket>, sqlx::Error> {
+        sqlx::query_as("SELECT key, tokens, updated_at, full_at FROM rate_limit_buckets")
+            .fetch_all(&self.pool)
+            .await
+    }
+
+    /// Replace every saved bucket with `buckets`.
+    pub async fn replace(&self, buckets: &[RateLimitBucket]) -> Result<(), sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query("DELETE FROM rate_limit_buckets")
+            .execute(&mut *tx)
+            .await?;
+
+        for bucket in buckets {
+            sqlx::query(
+                "INSERT INTO rate_limit_buckets (key, tokens, updated_at, full_at) VALUES (?, ?, ?, ?)",
+            )
+            .bind(&bucket.key)
+            .bind(bucket.tokens)
+            .bind(bucket.updated_at)
+            .bind(bucket.full_at)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await
+    }
+}
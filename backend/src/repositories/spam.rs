@@ -0,0 +1,93 @@
+use sqlx::{QueryBuilder, Sqlite};
+
+use crate::dao::spam::{SpamLabel, SpamToken};
+use crate::db::DbPool;
+
+/// Word and document counts of the spam classifier
+pub struct SpamRepository {
+    pool: DbPool,
+}
+
+impl SpamRepository {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+
+    /// Counts of the given words; words never seen in training are left out.
+    pub async fn tokens(&self, tokens: &[String]) -> Result<Vec<SpamToken>, sqlx::Error> {
+        if tokens.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let mut query = QueryBuilder::<Sqlite>::new("SELECT * FROM spam_tokens WHERE token IN (");
+        let mut separated = query.separated(", ");
+        for token in tokens {
+            separated.push_bind(token);
+        }
+        query.push(")");
+
+        query
+            .build_query_as::<SpamToken>()
+            .fetch_all(&self.pool)
+            .await
+    }
+
+    /// Number of documents trained as spam and as ham
+    pub async fn documents(&self) -> Result<(i64, i64), sqlx::Error> {
+        let counts: Vec<(SpamLabel, i64)> =
+            sqlx::query_as("SELECT label, count FROM spam_documents")
+                .fetch_all(&self.pool)
+                .await?;
+
+        let count = |label| {
+            counts
+                .iter()
+                .find(|(l, _)| *l == label)
+                .map_or(0, |(_, count)| *count)
+        };
+        Ok((count(SpamLabel::Spam), count(SpamLabel::Ham)))
+    }
+
+    /// Add a document with `tokens` to the counts of `label`, or take one away
+    /// with a `delta` of -1 when an admin changes their mind.
+    pub async fn train(
+        &self,
+        tokens: &[String],
+        label: SpamLabel,
+        delta: i64,
+    ) -> Result<(), sqlx::Error> {
+        let column = match label {
+            SpamLabel::Spam => "spam_count",
+            SpamLabel::Ham => "ham_count",
+        };
+        let mut tx = self.pool.begin().await?;
+
+        for token in tokens {
+            sqlx::query(&format!(
+                r#"
+                INSERT INTO spam_tokens (token, {column}) VALUES (?, MAX(?, 0))
+                ON CONFLICT(token) DO UPDATE SET {column} = MAX({column} + ?, 0)
+                "#
+            ))
+            .bind(token)
+            .bind(delta)
+            .bind(delta)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        sqlx::query(
+            r#"
+            INSERT INTO spam_documents (label, count) VALUES (?, MAX(?, 0))
+            ON CONFLICT(label) DO UPDATE SET count = MAX(count + ?, 0)
+            "#,
+        )
+        .bind(label)
+        .bind(delta)
+        .bind(delta)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await
+    }
+}
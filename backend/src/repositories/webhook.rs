@@ -0,0 +1,253 @@
+use sqlx::types::Json;
+use sqlx::{QueryBuilder, Sqlite};
+
+use crate::dao::webhook::{
+    DeliveryStatus, NewWebhook, Webhook, WebhookDelivery, WebhookEvent, WebhookUpdate,
+};
+use crate::db::DbPool;
+use crate::utils::now_timestamp;
+
+/// Webhook subscriptions and the queue of deliveries to them, which doubles
+/// as their delivery log
+pub struct WebhookRepository {
+    pool: DbPool,
+}
+
+impl WebhookRepository {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+
+    pub async fn create(
+        &self,
+        webhook: &NewWebhook,
+        created_by: Option<&str>,
+    ) -> Result<i64, sqlx::Error> {
+        let now = now_timestamp();
+        let result = sqlx::query(
+            r#"
+            INSERT INTO webhooks (url, secret, events, active, created_at, updated_at, created_by)
+            VALUES (?, ?, ?, 1, ?, ?, ?)
+            "#,
+        )
+        .bind(&webhook.url)
+        .bind(&webhook.secret)
+        .bind(Json(&webhook.events))
+        .bind(now)
+        .bind(now)
+        .bind(created_by)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.last_insert_rowid())
+    }
+
+    /// Every webhook, oldest first
+    pub async fn list(&self) -> Result<Vec<Webhook>, sqlx::Error> {
+        sqlx::query_as("SELECT * FROM webhooks ORDER BY id")
+            .fetch_all(&self.pool)
+            .await
+    }
+
+    pub async fn list_active(&self) -> Result<Vec<Webhook>, sqlx::Error> {
+        sqlx::query_as("SELECT * FROM webhooks WHERE active = 1 ORDER BY id")
+            .fetch_all(&self.pool)
+            .await
+    }
+
+    pub async fn get(&self, id: i64) -> Result<Option<Webhook>, sqlx::Error> {
+        sqlx::query_as("SELECT * FROM webhooks WHERE id = ?")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+    }
+
+    pub async fn update(&self, id: i64, changes: WebhookUpdate<'_>) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query(
+            r#"
+            UPDATE webhooks
+            SET url = COALESCE(?, url),
+                secret = COALESCE(?, secret),
+                events = COALESCE(?, events),
+                active = COALESCE(?, active),
+                updated_at = ?
+            WHERE id = ?
+            "#,
+        )
+        .bind(changes.url)
+        .bind(changes.secret)
+        .bind(changes.events.map(Json))
+        .bind(changes.active)
+        .bind(now_timestamp())
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Delete a webhook along with its delivery log
+    pub async fn delete(&self, id: i64) -> Result<bool, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query("DELETE FROM webhook_deliveries WHERE webhook_id = ?")
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+        let result = sqlx::query("DELETE FROM webhooks WHERE id = ?")
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Queue `payload` to be sent to a webhook right away. Returns the
+    /// delivery's id.
+    pub async fn enqueue(
+        &self,
+        webhook_id: i64,
+        event: WebhookEvent,
+        payload: &str,
+        redelivery_of: Option<i64>,
+    ) -> Result<i64, sqlx::Error> {
+        let now = now_timestamp();
+        let result = sqlx::query(
+            r#"
+            INSERT INTO webhook_deliveries
+                (webhook_id, event, payload, status, next_attempt_at, created_at, redelivery_of)
+            VALUES (?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(webhook_id)
+        .bind(event)
+        .bind(payload)
+        .bind(DeliveryStatus::Pending)
+        .bind(now)
+        .bind(now)
+        .bind(redelivery_of)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.last_insert_rowid())
+    }
+
+    /// Pending deliveries whose next attempt is due by `now`, oldest first
+    pub async fn due(&self, now: i64, limit: i32) -> Result<Vec<WebhookDelivery>, sqlx::Error> {
+        sqlx::query_as(
+            r#"
+            SELECT * FROM webhook_deliveries
+            WHERE status = ? AND next_attempt_at <= ?
+            ORDER BY next_attempt_at, id
+            LIMIT ?
+            "#,
+        )
+        .bind(DeliveryStatus::Pending)
+        .bind(now)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    pub async fn get_delivery(&self, id: i64) -> Result<Option<WebhookDelivery>, sqlx::Error> {
+        sqlx::query_as("SELECT * FROM webhook_deliveries WHERE id = ?")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+    }
+
+    /// Deliveries to a webhook, optionally only those with `status`, newest
+    /// first, with the total number of matches.
+    pub async fn list_deliveries(
+        &self,
+        webhook_id: i64,
+        status: Option<DeliveryStatus>,
+        limit: i32,
+        offset: i32,
+    ) -> Result<(Vec<WebhookDelivery>, i32), sqlx::Error> {
+        let mut query = QueryBuilder::<Sqlite>::new("SELECT * FROM webhook_deliveries");
+        push_delivery_conditions(&mut query, webhook_id, status);
+        query
+            .push(" ORDER BY created_at DESC, id DESC LIMIT ")
+            .push_bind(limit)
+            .push(" OFFSET ")
+            .push_bind(offset);
+        let deliveries = query
+            .build_query_as::<WebhookDelivery>()
+            .fetch_all(&self.pool)
+            .await?;
+
+        let mut count = QueryBuilder::<Sqlite>::new("SELECT COUNT(*) FROM webhook_deliveries");
+        push_delivery_conditions(&mut count, webhook_id, status);
+        let total: i32 = count.build_query_scalar().fetch_one(&self.pool).await?;
+
+        Ok((deliveries, total))
+    }
+
+    pub async fn mark_delivered(&self, id: i64, response_status: i32) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            UPDATE webhook_deliveries
+            SET status = ?,
+                attempts = attempts + 1,
+                response_status = ?,
+                last_error = NULL,
+                delivered_at = ?
+            WHERE id = ?
+            "#,
+        )
+        .bind(DeliveryStatus::Delivered)
+        .bind(response_status)
+        .bind(now_timestamp())
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Record a failed attempt. The delivery is retried at `next_attempt_at`,
+    /// or given up on if that's `None`.
+    pub async fn mark_attempt_failed(
+        &self,
+        id: i64,
+        response_status: Option<i32>,
+        error: &str,
+        next_attempt_at: Option<i64>,
+    ) -> Result<(), sqlx::Error> {
+        let status = match next_attempt_at {
+            Some(_) => DeliveryStatus::Pending,
+            None => DeliveryStatus::Failed,
+        };
+        sqlx::query(
+            r#"
+            UPDATE webhook_deliveries
+            SET status = ?,
+                attempts = attempts + 1,
+                response_status = ?,
+                last_error = ?,
+                next_attempt_at = COALESCE(?, next_attempt_at)
+            WHERE id = ?
+            "#,
+        )
+        .bind(status)
+        .bind(response_status)
+        .bind(error)
+        .bind(next_attempt_at)
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+}
+
+fn push_delivery_conditions(
+    qb: &mut QueryBuilder<'_, Sqlite>,
+    webhook_id: i64,
+    status: Option<DeliveryStatus>,
+) {
+    qb.push(" WHERE webhook_id = ").push_bind(webhook_id);
+    if let Some(status) = status {
+        qb.push(" AND status = ").push_bind(status);
+    }
+}
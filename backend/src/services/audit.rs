@@ -0,0 +1,36 @@
+use crate::dao::audit::{AuditEvent, AuditEventFilter, NewAuditEvent};
+use crate::repositories::AuditRepository;
+
+const DEFAULT_PAGE_SIZE: i32 = 50;
+const MAX_PAGE_SIZE: i32 = 200;
+
+/// Records admin calls and login attempts, over gRPC or REST, and lists them
+/// for admins.
+pub struct AuditService {
+    repo: AuditRepository,
+}
+
+impl AuditService {
+    pub fn new(repo: AuditRepository) -> Self {
+        Self { repo }
+    }
+
+    pub async fn record(&self, event: &NewAuditEvent) -> Result<i64, sqlx::Error> {
+        self.repo.insert(event).await
+    }
+
+    /// Events matching `filter`, newest first, with the total number of matches.
+    pub async fn list(
+        &self,
+        filter: &AuditEventFilter,
+        limit: i32,
+        offset: i32,
+    ) -> Result<(Vec<AuditEvent>, i32), sqlx::Error> {
+        let limit = if limit <= 0 {
+            DEFAULT_PAGE_SIZE
+        } else {
+            limit.min(MAX_PAGE_SIZE)
+        };
+        self.repo.list(filter, limit, offset.max(0)).await
+    }
+}
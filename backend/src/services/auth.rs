@@ -1,50 +1,75 @@
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
 use jsonwebtoken::{decode, decode_header, errors::ErrorKind, DecodingKey, Validation};
+use reqwest::header::{HeaderMap, CACHE_CONTROL};
 use std::collections::HashMap;
-use std::sync::Arc;
-use tokio::sync::RwLock;
+use std::sync::{Arc, Mutex};
+use tokio::sync::{Notify, RwLock};
 
+use crate::config::Config;
 use crate::dto::auth::{
-    AuthError, FirebaseAuthErrorResponse, FirebaseAuthRequest, FirebaseAuthResponse,
-    FirebaseClaims, FirebaseKeys, TokenRefreshResponse,
+    AuthError, AuthSession, AuthTokens, Claims, FirebaseAuthErrorResponse, FirebaseAuthRequest,
+    FirebaseAuthResponse, FirebaseKeys, KeyCacheStatus, TokenRefreshResponse,
 };
-
-/// Google's public key endpoint for Firebase token verification
-/// Reference: https://firebase.google.com/docs/auth/admin/verify-id-tokens
-const FIREBASE_PUBLIC_KEYS_URL: &str =
-    "https://www.googleapis.com/robot/v1/metadata/x509/securetoken@system.gserviceaccount.com";
+use crate::services::identity::IdentityProvider;
+use crate::utils::now_timestamp;
 
 /// Firebase secure token issuer base URL
 /// Reference: https://firebase.google.com/docs/auth/admin/verify-id-tokens
 const FIREBASE_ISSUER_BASE: &str = "https://securetoken.google.com";
 
-/// Firebase REST API for password sign-in (v1 is required - signInWithPassword not available in v2)
+/// Password sign-in path under the Identity Toolkit base URL (v1 is required -
+/// signInWithPassword not available in v2)
 /// Reference: https://firebase.google.com/docs/reference/rest/auth
-const FIREBASE_AUTH_URL: &str =
-    "https://identitytoolkit.googleapis.com/v1/accounts:signInWithPassword";
+const SIGN_IN_PATH: &str = "/v1/accounts:signInWithPassword";
 
-/// Firebase REST API for token refresh
+/// Token refresh path under the Secure Token base URL
 /// Reference: https://firebase.google.com/docs/reference/rest/auth#section-refresh-token
-const FIREBASE_TOKEN_URL: &str = "https://securetoken.googleapis.com/v1/token";
+const TOKEN_PATH: &str = "/v1/token";
+
+/// Key cache lifetime used when the key endpoint sends no `max-age`
+const DEFAULT_KEYS_MAX_AGE_SECS: i64 = 60 * 60;
+
+/// Failed key refreshes since the last success
+#[derive(Default)]
+struct KeyRefreshFailures {
+    count: u32,
+    last_at: Option<i64>,
+}
 
 pub struct FirebaseAuthService {
     project_id: String,
     api_key: String,
+    auth_url: String,
+    token_url: String,
+    public_keys_url: String,
+    /// Accept the Auth Emulator's unsigned tokens
+    allow_unsigned_tokens: bool,
     keys: Arc<RwLock<Option<FirebaseKeys>>>,
+    failures: Mutex<KeyRefreshFailures>,
+    /// Signalled when a token names a key we don't have, i.e. Google rotated keys
+    refresh_wanted: Notify,
     http_client: reqwest::Client,
 }
 
 impl FirebaseAuthService {
-    pub fn new(project_id: String, api_key: String) -> Self {
+    pub fn new(config: &Config) -> Self {
         Self {
-            project_id,
-            api_key,
+            project_id: config.firebase_project_id.clone(),
+            api_key: config.firebase_api_key.clone(),
+            auth_url: format!("{}{}", config.firebase_auth_url, SIGN_IN_PATH),
+            token_url: format!("{}{}", config.firebase_token_url, TOKEN_PATH),
+            public_keys_url: config.firebase_public_keys_url.clone(),
+            allow_unsigned_tokens: config.firebase_allow_unsigned_tokens,
             keys: Arc::new(RwLock::new(None)),
+            failures: Mutex::new(KeyRefreshFailures::default()),
+            refresh_wanted: Notify::new(),
             http_client: reqwest::Client::new(),
         }
     }
 
     /// Authenticate user with email/password via Firebase REST API
-    pub async fn login(
+    async fn sign_in(
         &self,
         email: String,
         password: String,
@@ -55,7 +80,7 @@ impl FirebaseAuthService {
             return_secure_token: true,
         };
 
-        let url = format!("{}?key={}", FIREBASE_AUTH_URL, self.api_key);
+        let url = format!("{}?key={}", self.auth_url, self.api_key);
 
         let response = self
             .http_client
@@ -82,15 +107,21 @@ impl FirebaseAuthService {
         }
     }
 
-    /// Synchronous token verification using cached keys.
+    /// Token verification using cached keys.
     /// Returns error if keys aren't cached (triggers token refresh flow).
-    pub fn verify_token_sync(&self, token: &str) -> Result<FirebaseClaims, AuthError> {
+    /// Missing keys or an unknown key ID also ask the key refresher to refetch.
+    pub async fn verify_token(&self, token: &str) -> Result<Claims, AuthError> {
+        if self.allow_unsigned_tokens && is_unsigned(token) {
+            return self.verify_unsigned_token(token);
+        }
+
         let keys = self
             .keys
-            .try_read()
-            .ok()
-            .and_then(|guard| guard.clone())
-            .ok_or(AuthError::InvalidToken)?;
+            .read()
+            .await
+            .clone()
+            .ok_or(AuthError::InvalidToken)
+            .inspect_err(|_| self.refresh_wanted.notify_one())?;
 
         self.verify_token_with_keys(token, &keys)
     }
@@ -99,17 +130,20 @@ impl FirebaseAuthService {
         &self,
         token: &str,
         keys: &FirebaseKeys,
-    ) -> Result<FirebaseClaims, AuthError> {
+    ) -> Result<Claims, AuthError> {
         let header = decode_header(token).map_err(|_| AuthError::InvalidToken)?;
         let kid = header.kid.ok_or(AuthError::InvalidToken)?;
 
-        let key = keys.keys.get(&kid).ok_or(AuthError::InvalidToken)?;
+        let Some(key) = keys.keys.get(&kid) else {
+            self.refresh_wanted.notify_one();
+            return Err(AuthError::InvalidToken);
+        };
 
         let mut validation = Validation::new(jsonwebtoken::Algorithm::RS256);
         validation.set_audience(&[&self.project_id]);
         validation.set_issuer(&[format!("{}/{}", FIREBASE_ISSUER_BASE, self.project_id)]);
 
-        let token_data = decode::<FirebaseClaims>(token, key, &validation).map_err(|e| {
+        let token_data = decode::<Claims>(token, key, &validation).map_err(|e| {
             match e.kind() {
                 ErrorKind::ExpiredSignature => AuthError::ExpiredToken,
                 _ => AuthError::InvalidToken,
@@ -119,9 +153,31 @@ impl FirebaseAuthService {
         Ok(token_data.claims)
     }
 
+    /// Check the claims of an unsigned emulator token, which is all there is to
+    /// check without a signature
+    fn verify_unsigned_token(&self, token: &str) -> Result<Claims, AuthError> {
+        let payload = token.split('.').nth(1).ok_or(AuthError::InvalidToken)?;
+        let payload = URL_SAFE_NO_PAD
+            .decode(payload)
+            .map_err(|_| AuthError::InvalidToken)?;
+        let claims: Claims =
+            serde_json::from_slice(&payload).map_err(|_| AuthError::InvalidToken)?;
+
+        if claims.aud != self.project_id
+            || claims.iss != format!("{}/{}", FIREBASE_ISSUER_BASE, self.project_id)
+        {
+            return Err(AuthError::InvalidToken);
+        }
+        if claims.exp < now_timestamp() as u64 {
+            return Err(AuthError::ExpiredToken);
+        }
+
+        Ok(claims)
+    }
+
     /// Refresh an expired ID token using a refresh token
-    pub async fn refresh_token(&self, refresh_token: &str) -> Result<TokenRefreshResponse, AuthError> {
-        let url = format!("{}?key={}", FIREBASE_TOKEN_URL, self.api_key);
+    async fn refresh_token(&self, refresh_token: &str) -> Result<TokenRefreshResponse, AuthError> {
+        let url = format!("{}?key={}", self.token_url, self.api_key);
 
         let params = [
             ("grant_type", "refresh_token"),
@@ -148,19 +204,50 @@ impl FirebaseAuthService {
 
     /// Pre-fetch and cache Firebase public keys. Call at startup.
     pub async fn prefetch_keys(&self) -> Result<(), AuthError> {
-        self.get_keys().await.map(|_| ())
+        self.refresh_keys().await
+    }
+
+    /// When the cached keys expire, if any are cached
+    pub async fn keys_expire_at(&self) -> Option<i64> {
+        self.keys.read().await.as_ref().map(|keys| keys.expires_at)
+    }
+
+    /// Wait until a request fails on missing keys or an unknown key ID
+    pub async fn refresh_requested(&self) {
+        self.refresh_wanted.notified().await
     }
 
-    async fn get_keys(&self) -> Result<FirebaseKeys, AuthError> {
-        let keys = self.fetch_keys().await?;
-        *self.keys.write().await = Some(keys.clone());
-        Ok(keys)
+    /// Fetch and cache the current Firebase public keys
+    pub async fn refresh_keys(&self) -> Result<(), AuthError> {
+        match self.fetch_keys().await {
+            Ok(keys) => {
+                *self.keys.write().await = Some(keys);
+                *self.failures.lock().unwrap() = KeyRefreshFailures::default();
+                Ok(())
+            }
+            Err(e) => {
+                let mut failures = self.failures.lock().unwrap();
+                failures.count += 1;
+                failures.last_at = Some(now_timestamp());
+                Err(e)
+            }
+        }
     }
 
     async fn fetch_keys(&self) -> Result<FirebaseKeys, AuthError> {
-        let response: HashMap<String, String> = reqwest::get(FIREBASE_PUBLIC_KEYS_URL)
+        let response = self
+            .http_client
+            .get(&self.public_keys_url)
+            .send()
             .await
             .map_err(|_| AuthError::KeyFetchFailed)?
+            .error_for_status()
+            .map_err(|_| AuthError::KeyFetchFailed)?;
+
+        let fetched_at = now_timestamp();
+        let max_age = cache_max_age(response.headers()).unwrap_or(DEFAULT_KEYS_MAX_AGE_SECS);
+
+        let response: HashMap<String, String> = response
             .json()
             .await
             .map_err(|_| AuthError::KeyFetchFailed)?;
@@ -172,6 +259,95 @@ impl FirebaseAuthService {
             }
         }
 
-        Ok(FirebaseKeys { keys })
+        if keys.is_empty() {
+            return Err(AuthError::KeyFetchFailed);
+        }
+
+        Ok(FirebaseKeys {
+            keys,
+            fetched_at,
+            expires_at: fetched_at + max_age,
+        })
     }
 }
+
+#[tonic::async_trait]
+impl IdentityProvider for FirebaseAuthService {
+    async fn login(&self, email: String, password: String) -> Result<AuthSession, AuthError> {
+        let response = self.sign_in(email, password).await?;
+
+        Ok(AuthSession {
+            uid: response.local_id,
+            email: response.email,
+            tokens: AuthTokens {
+                id_token: response.id_token,
+                refresh_token: response.refresh_token,
+                expires_in: response.expires_in.parse().unwrap_or(3600),
+            },
+        })
+    }
+
+    async fn refresh(&self, refresh_token: &str) -> Result<AuthTokens, AuthError> {
+        let response = self.refresh_token(refresh_token).await?;
+
+        Ok(AuthTokens {
+            id_token: response.id_token,
+            refresh_token: response.refresh_token,
+            expires_in: response.expires_in.parse().unwrap_or(3600),
+        })
+    }
+
+    async fn verify(&self, id_token: &str) -> Result<Claims, AuthError> {
+        self.verify_token(id_token).await
+    }
+
+    /// Ready once public keys are cached, or always when accepting the
+    /// emulator's unsigned tokens
+    async fn is_ready(&self) -> bool {
+        self.allow_unsigned_tokens
+            || self
+                .keys
+                .read()
+                .await
+                .as_ref()
+                .is_some_and(|keys| !keys.keys.is_empty())
+    }
+
+    async fn key_cache_status(&self) -> Option<KeyCacheStatus> {
+        let keys = self.keys.read().await;
+        let failures = self.failures.lock().unwrap();
+        Some(KeyCacheStatus {
+            key_count: keys.as_ref().map_or(0, |keys| keys.keys.len()),
+            last_refresh_at: keys.as_ref().map(|keys| keys.fetched_at),
+            expires_at: keys.as_ref().map(|keys| keys.expires_at),
+            consecutive_failures: failures.count,
+            last_failure_at: failures.last_at,
+        })
+    }
+}
+
+/// `max-age` from a `Cache-Control` header, in seconds
+fn cache_max_age(headers: &HeaderMap) -> Option<i64> {
+    headers
+        .get(CACHE_CONTROL)?
+        .to_str()
+        .ok()?
+        .split(',')
+        .find_map(|directive| directive.trim().strip_prefix("max-age="))
+        .and_then(|secs| secs.parse().ok())
+}
+
+/// Whether a token's header says `alg: none`, as the Auth Emulator's do
+fn is_unsigned(token: &str) -> bool {
+    #[derive(serde::Deserialize)]
+    struct Header {
+        alg: String,
+    }
+
+    token
+        .split('.')
+        .next()
+        .and_then(|header| URL_SAFE_NO_PAD.decode(header).ok())
+        .and_then(|header| serde_json::from_slice::<Header>(&header).ok())
+        .is_some_and(|header| header.alg.eq_ignore_ascii_case("none"))
+}
@@ -1,27 +1,177 @@
-use crate::dao::blog::{BlogPost, BlogPostSort, BlogPostSummary};
+mod diff;
+mod page_token;
+mod scheduler;
+mod search_query;
+mod suggest;
+mod tags;
+
+use std::sync::atomic::{AtomicI64, AtomicU64, Ordering};
+use std::sync::Arc;
+
+use crate::dao::blog::{
+    BlogPost, BlogPostFilter, BlogPostPage, BlogPostRevision, BlogPostRevisionSummary,
+    BlogPostSort, BlogPostStatus, BlogPostUpdate, BlogPostVisibility, HighlightMarkers, PageStart,
+    TagCount,
+};
+use crate::dao::webhook::WebhookEvent;
 use crate::repositories::BlogRepository;
+use crate::services::WebhookService;
+use crate::utils::now_timestamp;
+
+pub use diff::{diff_lines, DiffLine, DiffOp};
+pub use page_token::{decode_page_token, encode_page_token, PageTokenError};
+pub use scheduler::spawn_scheduler;
+pub use search_query::{parse_search_query, SearchQueryError};
+pub use suggest::SearchSuggestions;
+pub use tags::normalize_tag;
+
+const DEFAULT_PAGE_SIZE: i32 = 10;
+const MAX_PAGE_SIZE: i32 = 100;
+const DEFAULT_REVISION_PAGE_SIZE: i32 = 20;
+
+/// Page size to query for a requested `limit`, which callers may leave unset
+/// (zero) or set too high.
+fn page_size(limit: i32, default: i32) -> i32 {
+    if limit <= 0 {
+        default
+    } else {
+        limit.min(MAX_PAGE_SIZE)
+    }
+}
+
+/// `start` with negative offsets treated as the first page
+fn page_start(start: PageStart) -> PageStart {
+    match start {
+        PageStart::Offset(offset) => PageStart::Offset(offset.max(0)),
+        after => after,
+    }
+}
 
 pub struct BlogService {
     repo: BlogRepository,
+    /// Bumped after every write, so derived documents know when to rebuild
+    changes: AtomicU64,
+    /// Last time a post may have left public view. Such posts no longer count
+    /// towards the public `updated_at`, so the moment is kept here; it starts
+    /// at startup, as earlier ones aren't known.
+    withdrawn_at: AtomicI64,
+    webhooks: Arc<WebhookService>,
 }
 
 impl BlogService {
-    pub fn new(repo: BlogRepository) -> Self {
-        Self { repo }
+    pub fn new(repo: BlogRepository, webhooks: Arc<WebhookService>) -> Self {
+        Self {
+            repo,
+            changes: AtomicU64::new(0),
+            withdrawn_at: AtomicI64::new(now_timestamp()),
+            webhooks,
+        }
     }
 
+    /// Counter that increases whenever a post or tag is written. Caches of data
+    /// derived from posts compare it to decide whether they're stale.
+    pub fn change_count(&self) -> u64 {
+        self.changes.load(Ordering::Acquire)
+    }
+
+    /// Record a write if `changed`, passing the flag through.
+    fn changed(&self, changed: bool) -> bool {
+        if changed {
+            self.changes.fetch_add(1, Ordering::AcqRel);
+        }
+        changed
+    }
+
+    /// Record that a post may have just left public view, if `changed`.
+    fn withdrawn(&self, changed: bool) -> bool {
+        if changed {
+            self.withdrawn_at
+                .fetch_max(now_timestamp(), Ordering::AcqRel);
+        }
+        self.changed(changed)
+    }
+
+    /// Tell webhooks subscribed to `event` about the post as it is now.
+    async fn notify(&self, event: WebhookEvent, id: i64, actor_sub: Option<&str>) {
+        match self.repo.get(id, BlogPostVisibility::WithDeleted).await {
+            Ok(Some(post)) => self.webhooks.dispatch(event, &post, actor_sub).await,
+            Ok(None) => {}
+            Err(e) => tracing::warn!("Failed to load blog post {} for webhooks: {:?}", id, e),
+        }
+    }
+
+    async fn notify_all(&self, event: WebhookEvent, ids: &[i64], actor_sub: Option<&str>) {
+        for &id in ids {
+            self.notify(event, id, actor_sub).await;
+        }
+    }
+
+    /// List posts visible to public readers, optionally narrowed to posts
+    /// carrying every one of `tags`. `query` is an FTS5 expression as produced by
+    /// [`parse_search_query`]; results carry excerpts with matches wrapped in `markers`.
     pub async fn list(
         &self,
         query: Option<&str>,
+        tags: &[String],
+        markers: HighlightMarkers,
         limit: i32,
-        offset: i32,
+        start: PageStart,
         sort: BlogPostSort,
-    ) -> Result<(Vec<BlogPostSummary>, i32), sqlx::Error> {
-        self.repo.list(query, limit, offset, sort).await
+    ) -> Result<BlogPostPage, sqlx::Error> {
+        let filter = BlogPostFilter {
+            query: query.map(str::to_string),
+            tags: tags::normalize_tags(tags),
+            visibility: BlogPostVisibility::Public,
+            status: None,
+            markers,
+        };
+        let limit = page_size(limit, DEFAULT_PAGE_SIZE);
+        self.repo
+            .list(&filter, limit, page_start(start), sort)
+            .await
     }
 
+    /// List posts for admins, who may see beyond public posts: `filter` sets the
+    /// visibility and can narrow the listing to one status.
+    pub async fn list_all(
+        &self,
+        filter: BlogPostFilter,
+        limit: i32,
+        start: PageStart,
+        sort: BlogPostSort,
+    ) -> Result<BlogPostPage, sqlx::Error> {
+        let filter = BlogPostFilter {
+            tags: tags::normalize_tags(&filter.tags),
+            ..filter
+        };
+        let limit = page_size(limit, DEFAULT_PAGE_SIZE);
+        self.repo
+            .list(&filter, limit, page_start(start), sort)
+            .await
+    }
+
+    /// Get a post if it's visible to public readers.
     pub async fn get(&self, id: i64) -> Result<Option<BlogPost>, sqlx::Error> {
-        self.repo.get(id).await
+        self.repo.get(id, BlogPostVisibility::Public).await
+    }
+
+    /// Get a publicly visible post by its current or a previous slug. Callers can
+    /// compare the post's slug with the requested one to detect a stale link.
+    pub async fn get_by_slug(&self, slug: &str) -> Result<Option<BlogPost>, sqlx::Error> {
+        match self.repo.resolve_slug(slug).await? {
+            Some(id) => self.get(id).await,
+            None => Ok(None),
+        }
+    }
+
+    /// Whether `slug` is the current slug of a post other than `exclude_id`.
+    pub async fn slug_taken(&self, slug: &str, exclude_id: i64) -> Result<bool, sqlx::Error> {
+        self.repo.slug_taken(slug, exclude_id).await
+    }
+
+    /// Get a post regardless of its publication status, even if soft-deleted.
+    pub async fn get_any(&self, id: i64) -> Result<Option<BlogPost>, sqlx::Error> {
+        self.repo.get(id, BlogPostVisibility::WithDeleted).await
     }
 
     pub async fn create(
@@ -29,25 +179,237 @@ impl BlogService {
         title: &str,
         description: &str,
         body: &str,
+        tags: &[String],
+        author_sub: Option<&str>,
     ) -> Result<i64, sqlx::Error> {
-        self.repo.create(title, description, body).await
+        let tags = tags::normalize_tags(tags);
+        let id = self
+            .repo
+            .create(title, description, body, &tags, author_sub)
+            .await?;
+        self.changed(true);
+        self.notify(WebhookEvent::PostCreated, id, author_sub).await;
+        Ok(id)
     }
 
     pub async fn update(
         &self,
         id: i64,
-        title: Option<&str>,
-        description: Option<&str>,
-        body: Option<&str>,
+        changes: BlogPostUpdate<'_>,
+        author_sub: Option<&str>,
     ) -> Result<bool, sqlx::Error> {
-        self.repo.update(id, title, description, body).await
+        let tags = changes.tags.map(tags::normalize_tags);
+        let changes = BlogPostUpdate {
+            tags: tags.as_deref(),
+            ..changes
+        };
+        let revision = self.repo.update(id, changes, author_sub).await?;
+        if self.changed(revision.is_some()) {
+            self.notify(WebhookEvent::PostUpdated, id, author_sub).await;
+        }
+        Ok(revision.is_some())
     }
 
-    pub async fn delete(&self, id: i64) -> Result<bool, sqlx::Error> {
-        self.repo.delete(id).await
+    pub async fn delete(&self, id: i64, actor_sub: Option<&str>) -> Result<bool, sqlx::Error> {
+        let changed = self.repo.delete(id, actor_sub).await?;
+        if self.withdrawn(changed) {
+            self.notify(WebhookEvent::PostDeleted, id, actor_sub).await;
+        }
+        Ok(changed)
     }
 
-    pub async fn restore(&self, id: i64) -> Result<bool, sqlx::Error> {
-        self.repo.restore(id).await
+    pub async fn restore(&self, id: i64, actor_sub: Option<&str>) -> Result<bool, sqlx::Error> {
+        let changed = self.repo.restore(id, actor_sub).await?;
+        if self.changed(changed) {
+            self.notify(WebhookEvent::PostRestored, id, actor_sub).await;
+        }
+        Ok(changed)
+    }
+
+    pub async fn publish(&self, id: i64, actor_sub: Option<&str>) -> Result<bool, sqlx::Error> {
+        let changed = self.repo.publish(id, actor_sub).await?;
+        if self.changed(changed) {
+            self.notify(WebhookEvent::PostPublished, id, actor_sub)
+                .await;
+        }
+        Ok(changed)
+    }
+
+    pub async fn unpublish(
+        &self,
+        id: i64,
+        archive: bool,
+        actor_sub: Option<&str>,
+    ) -> Result<bool, sqlx::Error> {
+        let status = if archive {
+            BlogPostStatus::Archived
+        } else {
+            BlogPostStatus::Draft
+        };
+        let changed = self.repo.unpublish(id, status, actor_sub).await?;
+        if self.withdrawn(changed) {
+            self.notify(WebhookEvent::PostUpdated, id, actor_sub).await;
+        }
+        Ok(changed)
+    }
+
+    pub async fn schedule(
+        &self,
+        id: i64,
+        publish_at: i64,
+        actor_sub: Option<&str>,
+    ) -> Result<bool, sqlx::Error> {
+        let changed = self.repo.schedule(id, publish_at, actor_sub).await?;
+        if self.withdrawn(changed) {
+            self.notify(WebhookEvent::PostUpdated, id, actor_sub).await;
+        }
+        Ok(changed)
+    }
+
+    /// Publish scheduled posts whose time has come. Returns the ids that went live.
+    pub async fn publish_due(&self) -> Result<Vec<i64>, sqlx::Error> {
+        let published = self.repo.publish_due().await?;
+        self.changed(!published.is_empty());
+        for &id in &published {
+            self.notify(WebhookEvent::PostPublished, id, None).await;
+        }
+        Ok(published)
+    }
+
+    pub async fn list_revisions(
+        &self,
+        post_id: i64,
+        limit: i32,
+        offset: i32,
+    ) -> Result<(Vec<BlogPostRevisionSummary>, i32), sqlx::Error> {
+        let limit = page_size(limit, DEFAULT_REVISION_PAGE_SIZE);
+        self.repo
+            .list_revisions(post_id, limit, offset.max(0))
+            .await
+    }
+
+    pub async fn get_revision(
+        &self,
+        post_id: i64,
+        revision: i32,
+    ) -> Result<Option<BlogPostRevision>, sqlx::Error> {
+        self.repo.get_revision(post_id, revision).await
+    }
+
+    /// Line-level diff of the body between two revisions of a post.
+    /// Returns `None` if either revision doesn't exist.
+    pub async fn diff_revisions(
+        &self,
+        post_id: i64,
+        from_revision: i32,
+        to_revision: i32,
+    ) -> Result<Option<Vec<DiffLine>>, sqlx::Error> {
+        let from = self.repo.get_revision(post_id, from_revision).await?;
+        let to = self.repo.get_revision(post_id, to_revision).await?;
+
+        Ok(from
+            .zip(to)
+            .map(|(from, to)| diff::diff_lines(&from.body, &to.body)))
+    }
+
+    /// Restore a post's content to an earlier revision. The rollback is itself
+    /// recorded as a new revision, whose number is returned. Returns `None` if the
+    /// revision or the post doesn't exist.
+    pub async fn revert(
+        &self,
+        post_id: i64,
+        revision: i32,
+        author_sub: Option<&str>,
+    ) -> Result<Option<i32>, sqlx::Error> {
+        let Some(target) = self.repo.get_revision(post_id, revision).await? else {
+            return Ok(None);
+        };
+
+        let changes = BlogPostUpdate {
+            title: Some(&target.title),
+            description: Some(&target.description),
+            body: Some(&target.body),
+            ..Default::default()
+        };
+        let revision = self.repo.update(post_id, changes, author_sub).await?;
+        if self.changed(revision.is_some()) {
+            self.notify(WebhookEvent::PostUpdated, post_id, author_sub)
+                .await;
+        }
+        Ok(revision)
+    }
+
+    /// Newest public posts for syndication feeds, optionally for a single tag.
+    pub async fn list_published(
+        &self,
+        tag: Option<&str>,
+        limit: i32,
+    ) -> Result<Vec<BlogPost>, sqlx::Error> {
+        self.repo.list_published(tag, limit).await
+    }
+
+    /// Slug and `updated_at` of every public post, newest first.
+    pub async fn list_published_slugs(&self) -> Result<Vec<(String, i64)>, sqlx::Error> {
+        self.repo.list_published_slugs().await
+    }
+
+    /// When what readers see last changed, for HTTP caching of generated
+    /// documents: the latest edit of a public post or the last time one was
+    /// taken out of view, whichever is later.
+    pub async fn last_modified(&self) -> Result<i64, sqlx::Error> {
+        let withdrawn_at = self.withdrawn_at.load(Ordering::Acquire);
+        let updated_at = self.repo.last_modified().await?;
+        Ok(updated_at.map_or(withdrawn_at, |t| t.max(withdrawn_at)))
+    }
+
+    /// Completions and "did you mean" corrections for a partially typed search,
+    /// drawn only from words in publicly visible posts.
+    pub async fn suggest(&self, query: &str, limit: i32) -> Result<SearchSuggestions, sqlx::Error> {
+        suggest::suggest(&self.repo, query, limit).await
+    }
+
+    /// Tags used by publicly visible posts, with their post counts.
+    pub async fn list_tags(&self) -> Result<Vec<TagCount>, sqlx::Error> {
+        self.repo.list_tags().await
+    }
+
+    pub async fn tag_exists(&self, name: &str) -> Result<bool, sqlx::Error> {
+        self.repo.tag_exists(name).await
+    }
+
+    /// Rename a tag. Both names must already be normalized. Posts carrying it
+    /// are reported to webhooks as updated.
+    pub async fn rename_tag(
+        &self,
+        name: &str,
+        new_name: &str,
+        actor_sub: Option<&str>,
+    ) -> Result<bool, sqlx::Error> {
+        let post_ids = self.repo.post_ids_tagged(&[name.to_string()]).await?;
+        let changed = self.repo.rename_tag(name, new_name, actor_sub).await?;
+        if self.changed(changed) {
+            self.notify_all(WebhookEvent::PostUpdated, &post_ids, actor_sub)
+                .await;
+        }
+        Ok(changed)
+    }
+
+    /// Fold `sources` into `target`. Returns how many source tags existed.
+    /// Posts that carried a source tag are reported to webhooks as updated.
+    pub async fn merge_tags(
+        &self,
+        sources: &[String],
+        target: &str,
+        actor_sub: Option<&str>,
+    ) -> Result<u64, sqlx::Error> {
+        let mut sources = tags::normalize_tags(sources);
+        sources.retain(|source| source != target);
+        let post_ids = self.repo.post_ids_tagged(&sources).await?;
+        let merged = self.repo.merge_tags(&sources, target, actor_sub).await?;
+        if self.changed(merged > 0) {
+            self.notify_all(WebhookEvent::PostUpdated, &post_ids, actor_sub)
+                .await;
+        }
+        Ok(merged)
     }
 }
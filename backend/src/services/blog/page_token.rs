@@ -0,0 +1,97 @@
+use std::fmt;
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+
+use crate::dao::blog::{BlogPostCursor, BlogPostSort, CursorKey};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PageTokenError {
+    Malformed,
+    SortMismatch,
+}
+
+impl fmt::Display for PageTokenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Malformed => "Invalid page token",
+            Self::SortMismatch => {
+                "Page token was issued for a different sort or search; start from the first page"
+            }
+        })
+    }
+}
+
+fn sort_code(sort: BlogPostSort) -> &'static str {
+    match sort {
+        BlogPostSort::Relevance => "r",
+        BlogPostSort::CreatedAsc => "ca",
+        BlogPostSort::CreatedDesc => "cd",
+        BlogPostSort::UpdatedAsc => "ua",
+        BlogPostSort::UpdatedDesc => "ud",
+    }
+}
+
+fn sort_from_code(code: &str) -> Option<BlogPostSort> {
+    Some(match code {
+        "r" => BlogPostSort::Relevance,
+        "ca" => BlogPostSort::CreatedAsc,
+        "cd" => BlogPostSort::CreatedDesc,
+        "ua" => BlogPostSort::UpdatedAsc,
+        "ud" => BlogPostSort::UpdatedDesc,
+        _ => return None,
+    })
+}
+
+/// Encode a cursor as an opaque, URL-safe page token. Ranks are stored as their
+/// exact bit pattern so the next page compares against the very same value.
+pub fn encode_page_token(cursor: &BlogPostCursor) -> String {
+    let key = match cursor.key {
+        CursorKey::Timestamp(value) => format!("t{value}"),
+        CursorKey::Rank(value) => format!("r{:x}", value.to_bits()),
+    };
+    let raw = format!("{}:{key}:{}", sort_code(cursor.sort), cursor.id);
+    URL_SAFE_NO_PAD.encode(raw)
+}
+
+/// Decode a page token for a listing with the given sort. Tokens only continue
+/// the listing they came from, so the sort and search mode must match.
+pub fn decode_page_token(
+    token: &str,
+    sort: BlogPostSort,
+    searching: bool,
+) -> Result<BlogPostCursor, PageTokenError> {
+    let raw = URL_SAFE_NO_PAD
+        .decode(token)
+        .ok()
+        .and_then(|bytes| String::from_utf8(bytes).ok())
+        .ok_or(PageTokenError::Malformed)?;
+
+    let mut parts = raw.splitn(3, ':');
+    let (Some(code), Some(key), Some(id)) = (parts.next(), parts.next(), parts.next()) else {
+        return Err(PageTokenError::Malformed);
+    };
+
+    let token_sort = sort_from_code(code).ok_or(PageTokenError::Malformed)?;
+    let id = id.parse().map_err(|_| PageTokenError::Malformed)?;
+    let key = if let Some(value) = key.strip_prefix('t') {
+        CursorKey::Timestamp(value.parse().map_err(|_| PageTokenError::Malformed)?)
+    } else if let Some(bits) = key.strip_prefix('r') {
+        let bits = u64::from_str_radix(bits, 16).map_err(|_| PageTokenError::Malformed)?;
+        CursorKey::Rank(f64::from_bits(bits))
+    } else {
+        return Err(PageTokenError::Malformed);
+    };
+
+    // Relevance is keyed by rank only while searching
+    let ranked = sort == BlogPostSort::Relevance && searching;
+    if token_sort != sort || matches!(key, CursorKey::Rank(_)) != ranked {
+        return Err(PageTokenError::SortMismatch);
+    }
+
+    Ok(BlogPostCursor {
+        sort: token_sort,
+        key,
+        id,
+    })
+}
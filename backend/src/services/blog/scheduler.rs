@@ -0,0 +1,38 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::BlogService;
+use crate::services::Notifier;
+
+/// Spawn the background task that publishes scheduled posts once their
+/// `published_at` has passed, and notifies the owner of each. Runs every
+/// `interval` for the life of the process.
+pub fn spawn_scheduler(
+    blog_service: Arc<BlogService>,
+    notifier: Arc<Notifier>,
+    interval: Duration,
+) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+
+            match blog_service.publish_due().await {
+                Ok(ids) if !ids.is_empty() => {
+                    tracing::info!("Published scheduled blog posts: {:?}", ids);
+                    for id in ids {
+                        match blog_service.get(id).await {
+                            Ok(Some(post)) => notifier.post_published(&post).await,
+                            Ok(None) => {}
+                            Err(e) => tracing::warn!("Failed to load blog post {}: {:?}", id, e),
+                        }
+                    }
+                }
+                Ok(_) => {}
+                Err(e) => tracing::warn!("Failed to publish scheduled blog posts: {:?}", e),
+            }
+        }
+    });
+}
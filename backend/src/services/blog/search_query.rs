@@ -0,0 +1,162 @@
+use std::fmt;
+
+/// Columns of `blog_posts_fts` a term can be scoped to with `field:term`.
+const SEARCH_FIELDS: [&str; 3] = ["title", "description", "body"];
+
+/// Upper bound on terms in one query, to keep FTS5 expressions cheap.
+const MAX_TERMS: usize = 32;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SearchQueryError {
+    UnclosedQuote,
+    MisplacedWildcard(String),
+    OnlyExclusions,
+    TooManyTerms,
+}
+
+impl fmt::Display for SearchQueryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnclosedQuote => write!(
+                f,
+                "Search query has an unclosed quote; close the phrase with a matching \""
+            ),
+            Self::MisplacedWildcard(term) => write!(
+                f,
+                "Invalid wildcard in '{term}'; '*' is only allowed at the end of a word"
+            ),
+            Self::OnlyExclusions => write!(
+                f,
+                "Search query needs at least one term that isn't excluded with '-'"
+            ),
+            Self::TooManyTerms => write!(f, "Search query has too many terms"),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Term {
+    text: String,
+    field: Option<&'static str>,
+    prefix: bool,
+    exclude: bool,
+}
+
+impl Term {
+    /// Render as an FTS5 string, which turns operators and punctuation in user
+    /// input into plain text instead of syntax.
+    fn to_fts(&self) -> String {
+        let mut out = String::new();
+        if let Some(field) = self.field {
+            out.push_str(field);
+            out.push_str(" : ");
+        }
+        out.push('"');
+        out.push_str(&self.text.replace('"', "\"\""));
+        out.push('"');
+        if self.prefix {
+            out.push('*');
+        }
+        out
+    }
+}
+
+/// Parse a reader's search box input into an FTS5 match expression.
+///
+/// Supported syntax, with every term required to match:
+/// - `word` and `"quoted phrase"`
+/// - `-word` or `-"phrase"` to exclude posts containing it
+/// - `word*` for prefix matches
+/// - `title:word`, `description:word`, `body:word` to search a single field;
+///   the scope also applies across a space (`title: word`). Any other `name:`
+///   is searched as plain text, so URLs and the like still work.
+///
+/// Returns `Ok(None)` when the input has nothing searchable in it.
+pub fn parse_search_query(input: &str) -> Result<Option<String>, SearchQueryError> {
+    let terms = tokenize(input)?;
+    if terms.len() > MAX_TERMS {
+        return Err(SearchQueryError::TooManyTerms);
+    }
+
+    let (excluded, included): (Vec<_>, Vec<_>) = terms.into_iter().partition(|t| t.exclude);
+    if included.is_empty() {
+        return if excluded.is_empty() {
+            Ok(None)
+        } else {
+            Err(SearchQueryError::OnlyExclusions)
+        };
+    }
+
+    let included: Vec<String> = included.iter().map(Term::to_fts).collect();
+    let mut expr = format!("({})", included.join(" AND "));
+    for term in &excluded {
+        expr.push_str(" NOT ");
+        expr.push_str(&term.to_fts());
+    }
+
+    Ok(Some(expr))
+}
+
+fn tokenize(input: &str) -> Result<Vec<Term>, SearchQueryError> {
+    let mut terms = Vec::new();
+    let mut rest = input.trim_start();
+
+    while !rest.is_empty() {
+        let mut exclude = false;
+        if let Some(after) = rest.strip_prefix('-') {
+            exclude = true;
+            rest = after;
+        }
+
+        let mut field = None;
+        if let Some((name, after)) = split_field(rest) {
+            field = Some(name);
+            rest = after.trim_start();
+        }
+
+        let (text, prefix, after) = if let Some(quoted) = rest.strip_prefix('"') {
+            let end = quoted.find('"').ok_or(SearchQueryError::UnclosedQuote)?;
+            let after = &quoted[end + 1..];
+            match after.strip_prefix('*') {
+                Some(after) => (&quoted[..end], true, after),
+                None => (&quoted[..end], false, after),
+            }
+        } else {
+            let end = rest
+                .find(|c: char| c.is_whitespace() || c == '"')
+                .unwrap_or(rest.len());
+            let word = &rest[..end];
+            let (word, prefix) = match word.strip_suffix('*') {
+                Some(stem) => (stem, true),
+                None => (word, false),
+            };
+            if word.contains('*') || (prefix && word.is_empty()) {
+                return Err(SearchQueryError::MisplacedWildcard(rest[..end].to_string()));
+            }
+            (word, prefix, &rest[end..])
+        };
+
+        // Pure punctuation produces no tokens, so it can't narrow anything down
+        if text.chars().any(char::is_alphanumeric) {
+            terms.push(Term {
+                text: text.to_string(),
+                field,
+                prefix,
+                exclude,
+            });
+        }
+
+        rest = after.trim_start();
+    }
+
+    Ok(terms)
+}
+
+/// Split `field:rest` when the input starts with one of `SEARCH_FIELDS`.
+fn split_field(input: &str) -> Option<(&'static str, &str)> {
+    let (name, rest) = input.split_once(':')?;
+    let field = SEARCH_FIELDS
+        .into_iter()
+        .find(|f| f.eq_ignore_ascii_case(name))?;
+    Some((field, rest))
+}
@@ -0,0 +1,171 @@
+use std::collections::HashSet;
+
+use crate::repositories::BlogRepository;
+
+/// Completions returned when the request doesn't set a limit
+const DEFAULT_LIMIT: i32 = 5;
+const MAX_LIMIT: i32 = 20;
+
+/// Shortest partial word worth completing
+const MIN_PREFIX_LEN: usize = 2;
+
+/// Longer input gets no suggestions; it's no longer being typed into a search box
+const MAX_QUERY_CHARS: usize = 200;
+/// Words checked for typos, counting back from the end of the query
+const MAX_CHECKED_WORDS: usize = 5;
+/// Closest vocabulary words considered as corrections of each word
+const MAX_CANDIDATES: usize = 20;
+
+/// Completions and corrections for a partially typed search.
+#[derive(Debug, Clone, Default)]
+pub struct SearchSuggestions {
+    /// The query with its last word completed, best match first
+    pub completions: Vec<String>,
+    /// The query with misspelled words replaced, if any were found
+    pub did_you_mean: Option<String>,
+}
+
+/// Split input the way the `unicode61` tokenizer does: lowercase runs of letters
+/// and digits.
+fn words(query: &str) -> Vec<String> {
+    query
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Largest edit distance still treated as a typo of a word this long.
+fn max_distance(len: usize) -> usize {
+    match len {
+        0..=2 => 0,
+        3..=5 => 1,
+        _ => 2,
+    }
+}
+
+/// Complete the last word of `query` from the search vocabulary and look for
+/// likely typos among its words.
+pub(super) async fn suggest(
+    repo: &BlogRepository,
+    query: &str,
+    limit: i32,
+) -> Result<SearchSuggestions, sqlx::Error> {
+    if query.chars().count() > MAX_QUERY_CHARS {
+        return Ok(SearchSuggestions::default());
+    }
+    let limit = if limit <= 0 {
+        DEFAULT_LIMIT
+    } else {
+        limit.min(MAX_LIMIT)
+    };
+    let mut words = words(query);
+    // A trailing space means the last word is finished
+    let partial = if query.ends_with(|c: char| c.is_alphanumeric()) {
+        words.pop()
+    } else {
+        None
+    };
+
+    let mut suggestions = SearchSuggestions::default();
+
+    if let Some(partial) = &partial
+        && partial.chars().count() >= MIN_PREFIX_LEN
+    {
+        let head = words.join(" ");
+        for term in repo.complete_terms(partial, limit).await? {
+            suggestions.completions.push(if head.is_empty() {
+                term
+            } else {
+                format!("{head} {term}")
+            });
+        }
+    }
+
+    // Only correct the partial word when nothing starts with it
+    let mut checked = words.clone();
+    if let Some(partial) = partial.filter(|_| suggestions.completions.is_empty()) {
+        checked.push(partial);
+    }
+
+    // Typos are looked for near the end, where the reader is typing
+    let first_checked = checked.len().saturating_sub(MAX_CHECKED_WORDS);
+    let corrections = correct(repo, &checked[first_checked..]).await?;
+    if corrections.iter().any(Option::is_some) {
+        for (word, correction) in checked[first_checked..].iter_mut().zip(corrections) {
+            if let Some(correction) = correction {
+                *word = correction;
+            }
+        }
+        suggestions.did_you_mean = Some(checked.join(" "));
+    }
+
+    Ok(suggestions)
+}
+
+/// For each word, the closest public word within typo distance, or `None` if
+/// the word is already known or nothing is close enough. Reads the vocabulary
+/// once and checks which candidates are public in a single query.
+async fn correct(
+    repo: &BlogRepository,
+    words: &[String],
+) -> Result<Vec<Option<String>>, sqlx::Error> {
+    let lens: Vec<(usize, usize)> = words
+        .iter()
+        .map(|word| {
+            let len = word.chars().count();
+            (len, max_distance(len))
+        })
+        .filter(|&(_, max)| max > 0)
+        .collect();
+    let (Some(min_len), Some(max_len)) = (
+        lens.iter().map(|&(len, max)| len - max).min(),
+        lens.iter().map(|&(len, max)| len + max).max(),
+    ) else {
+        return Ok(vec![None; words.len()]);
+    };
+    let vocabulary = repo.terms_by_length(min_len, max_len).await?;
+
+    // Closest first, then the most widely used
+    let candidates: Vec<Vec<String>> = words
+        .iter()
+        .map(|word| {
+            let max = max_distance(word.chars().count());
+            if max == 0 {
+                return vec![];
+            }
+            let mut close: Vec<(usize, i64, &String)> = vocabulary
+                .iter()
+                .filter_map(|(term, doc)| {
+                    let distance = strsim::damerau_levenshtein(word, term);
+                    (distance <= max).then_some((distance, *doc, term))
+                })
+                .collect();
+            close.sort_by(|a, b| a.0.cmp(&b.0).then(b.1.cmp(&a.1)));
+            close
+                .into_iter()
+                .take(MAX_CANDIDATES)
+                .map(|(_, _, term)| term.clone())
+                .collect()
+        })
+        .collect();
+
+    let lookup: HashSet<&String> = words
+        .iter()
+        .zip(&candidates)
+        .filter(|(_, close)| !close.is_empty())
+        .flat_map(|(word, close)| std::iter::once(word).chain(close))
+        .collect();
+    let public = repo.public_terms(lookup).await?;
+
+    Ok(words
+        .iter()
+        .zip(candidates)
+        .map(|(word, close)| {
+            if public.contains(word) {
+                return None;
+            }
+            close.into_iter().find(|term| public.contains(term))
+        })
+        .collect())
+}
@@ -0,0 +1,22 @@
+/// Canonical form of a tag name: trimmed, lowercase, inner whitespace collapsed
+/// to single dashes. Returns `None` for names that are empty once trimmed.
+pub fn normalize_tag(name: &str) -> Option<String> {
+    let normalized = name
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join("-")
+        .to_lowercase();
+
+    (!normalized.is_empty()).then_some(normalized)
+}
+
+/// Normalize a list of tag names, dropping empties and duplicates while keeping order.
+pub fn normalize_tags(names: &[String]) -> Vec<String> {
+    let mut tags: Vec<String> = Vec::with_capacity(names.len());
+    for tag in names.iter().filter_map(|name| normalize_tag(name)) {
+        if !tags.contains(&tag) {
+            tags.push(tag);
+        }
+    }
+    tags
+}
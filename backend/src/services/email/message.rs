@@ -0,0 +1,91 @@
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+
+use crate::utils::rfc2822;
+
+/// Longest line of a base64 body, per RFC 2045
+const BASE64_LINE_LEN: usize = 76;
+/// Bytes of header text per RFC 2047 encoded word, which keeps each line of
+/// the header, name included, within the 78 characters RFC 5322 asks for
+const ENCODED_WORD_BYTES: usize = 39;
+
+/// A `multipart/alternative` message with plain-text and HTML versions of the
+/// same content, ready to hand to SMTP.
+pub fn render_message(
+    from: &str,
+    to: &str,
+    subject: &str,
+    text: &str,
+    html: &str,
+    sent_at: i64,
+) -> String {
+    let id = uuid::Uuid::new_v4().simple().to_string();
+    let domain = from
+        .rsplit_once('@')
+        .map_or("localhost", |(_, domain)| domain);
+    let boundary = format!("=_{id}");
+
+    let mut message = String::new();
+    for (name, value) in [
+        ("From", header_text(from)),
+        ("To", header_text(to)),
+        ("Subject", encode_header(subject)),
+        ("Date", rfc2822(sent_at)),
+        ("Message-ID", format!("<{id}@{domain}>")),
+        ("MIME-Version", "1.0".to_string()),
+        (
+            "Content-Type",
+            format!("multipart/alternative; boundary=\"{boundary}\""),
+        ),
+    ] {
+        message.push_str(&format!("{name}: {value}\r\n"));
+    }
+
+    for (content_type, body) in [("text/plain", text), ("text/html", html)] {
+        message.push_str(&format!(
+            "\r\n--{boundary}\r\nContent-Type: {content_type}; charset=utf-8\r\nContent-Transfer-Encoding: base64\r\n\r\n"
+        ));
+        let encoded = STANDARD.encode(body);
+        for line in encoded.as_bytes().chunks(BASE64_LINE_LEN) {
+            // Base64 is ASCII, so the chunks are valid UTF-8
+            message.push_str(std::str::from_utf8(line).unwrap_or_default());
+            message.push_str("\r\n");
+        }
+    }
+    message.push_str(&format!("--{boundary}--\r\n"));
+
+    message
+}
+
+/// Header value with line breaks and other control characters removed, so
+/// submitted text can't add headers of its own
+fn header_text(value: &str) -> String {
+    value
+        .chars()
+        .map(|c| if c.is_control() { ' ' } else { c })
+        .collect()
+}
+
+/// Header value as is if it's plain ASCII, otherwise as RFC 2047 encoded words
+fn encode_header(value: &str) -> String {
+    let value = header_text(value);
+    if value.is_ascii() {
+        return value;
+    }
+
+    let mut words = vec![];
+    let mut start = 0;
+    for (i, c) in value.char_indices() {
+        if i + c.len_utf8() - start > ENCODED_WORD_BYTES {
+            words.push(&value[start..i]);
+            start = i;
+        }
+    }
+    words.push(&value[start..]);
+
+    words
+        .iter()
+        .map(|word| format!("=?UTF-8?B?{}?=", STANDARD.encode(word)))
+        .collect::<Vec<_>>()
+        .join("\r\n ")
+}
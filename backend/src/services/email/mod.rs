@@ -0,0 +1,189 @@
+mod message;
+mod smtp;
+pub mod templates;
+
+use std::collections::VecDeque;
+use std::net::IpAddr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::config::{Config, RateLimit};
+use crate::dao::blog::BlogPost;
+use crate::dao::email::NewEmail;
+use crate::dao::inquiry::NewInquiry;
+use crate::repositories::EmailOutboxRepository;
+use crate::utils::now_timestamp;
+
+pub use message::render_message;
+pub use smtp::{SmtpClient, SmtpError};
+pub use templates::EmailContent;
+
+/// Attempts at sending an email before it's marked failed
+const MAX_ATTEMPTS: i32 = 8;
+/// Wait before the first retry, doubled after every failed attempt
+const RETRY_BASE_SECS: i64 = 60;
+const RETRY_MAX_SECS: i64 = 6 * 60 * 60;
+/// Emails sent per outbox run
+const BATCH_SIZE: i32 = 20;
+
+/// Failed logins within the notification period
+#[derive(Default)]
+struct FailedLogins {
+    at: VecDeque<i64>,
+    notified_at: Option<i64>,
+}
+
+/// Emails the site owner about activity they'd otherwise have to poll the
+/// admin page for.
+///
+/// Emails are queued in the outbox table and sent by
+/// [`spawn_email_outbox`], so a slow or unreachable SMTP server never holds
+/// up a request. Notifications are best effort: failing to queue one is
+/// logged, not returned.
+pub struct Notifier {
+    outbox: EmailOutboxRepository,
+    smtp: Option<SmtpClient>,
+    from: String,
+    to: Option<String>,
+    site_url: String,
+    failed_logins_limit: RateLimit,
+    failed_logins: Mutex<FailedLogins>,
+}
+
+impl Notifier {
+    pub fn new(outbox: EmailOutboxRepository, config: &Config) -> Self {
+        Self {
+            outbox,
+            smtp: SmtpClient::from_config(config),
+            from: config.email_from.clone(),
+            to: config.email_to.clone(),
+            site_url: config.site_url.clone(),
+            failed_logins_limit: config.notify_failed_logins,
+            failed_logins: Mutex::new(FailedLogins::default()),
+        }
+    }
+
+    /// A contact form submission reached the inbox
+    pub async fn new_inquiry(&self, inquiry: &NewInquiry) {
+        let manage_url = format!("{}/manage", self.site_url);
+        self.enqueue("new_inquiry", templates::new_inquiry(inquiry, &manage_url))
+            .await;
+    }
+
+    /// A login failed for `email`. Notifies once failures across all accounts
+    /// reach the `NOTIFY_FAILED_LOGINS` count within its period, and then not
+    /// again for another period.
+    pub async fn login_failed(&self, email: &str, client_ip: Option<IpAddr>) {
+        let now = now_timestamp();
+        let period = self.failed_logins_limit.period_secs as i64;
+        let count = {
+            let mut failed = self.failed_logins.lock().unwrap();
+            failed.at.push_back(now);
+            while failed.at.front().is_some_and(|&at| at <= now - period) {
+                failed.at.pop_front();
+            }
+
+            let quiet = failed.notified_at.is_none_or(|at| at <= now - period);
+            if failed.at.len() < self.failed_logins_limit.capacity as usize || !quiet {
+                return;
+            }
+            failed.notified_at = Some(now);
+            failed.at.len()
+        };
+
+        let content = templates::failed_logins(count, period as u64, email, client_ip);
+        self.enqueue("failed_logins", content).await;
+    }
+
+    /// A scheduled post went live
+    pub async fn post_published(&self, post: &BlogPost) {
+        let url = format!("{}/blog/{}", self.site_url, post.slug);
+        self.enqueue(
+            "post_published",
+            templates::post_published(&post.title, &url),
+        )
+        .await;
+    }
+
+    async fn enqueue(&self, event: &str, content: EmailContent) {
+        let (Some(to), Some(_)) = (&self.to, &self.smtp) else {
+            return;
+        };
+
+        let email = NewEmail {
+            event: event.to_string(),
+            recipient: to.clone(),
+            subject: content.subject,
+            text_body: content.text,
+            html_body: content.html,
+        };
+        if let Err(e) = self.outbox.enqueue(&email).await {
+            tracing::warn!("Failed to queue {} email: {:?}", event, e);
+        }
+    }
+
+    /// Send the emails that are due. Failed attempts are retried with
+    /// exponential backoff, up to `MAX_ATTEMPTS`. Returns how many were sent.
+    pub async fn deliver_due(&self) -> Result<usize, sqlx::Error> {
+        let Some(smtp) = &self.smtp else {
+            return Ok(0);
+        };
+
+        let now = now_timestamp();
+        let mut sent = 0;
+        for email in self.outbox.due(now, BATCH_SIZE).await? {
+            let message = render_message(
+                &self.from,
+                &email.recipient,
+                &email.subject,
+                &email.text_body,
+                &email.html_body,
+                now,
+            );
+
+            match smtp.send(&self.from, &email.recipient, &message).await {
+                Ok(()) => {
+                    self.outbox.mark_sent(email.id).await?;
+                    sent += 1;
+                }
+                Err(e) => {
+                    let attempts = email.attempts + 1;
+                    let retry_at = (attempts < MAX_ATTEMPTS).then(|| {
+                        let backoff = RETRY_BASE_SECS << (attempts - 1).min(16);
+                        now + backoff.min(RETRY_MAX_SECS)
+                    });
+                    tracing::warn!(
+                        "Failed to send {} email {} (attempt {}): {}",
+                        email.event,
+                        email.id,
+                        attempts,
+                        e
+                    );
+                    self.outbox
+                        .mark_attempt_failed(email.id, &e.to_string(), retry_at)
+                        .await?;
+                }
+            }
+        }
+        Ok(sent)
+    }
+}
+
+/// Spawn the background task that sends queued emails every `interval`.
+/// Runs for the life of the process.
+pub fn spawn_email_outbox(notifier: Arc<Notifier>, interval: Duration) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+
+            match notifier.deliver_due().await {
+                Ok(0) => {}
+                Ok(count) => tracing::info!("Sent {} notification emails", count),
+                Err(e) => tracing::warn!("Failed to send notification emails: {:?}", e),
+            }
+        }
+    });
+}
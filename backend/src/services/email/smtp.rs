@@ -0,0 +1,203 @@
+use std::fmt;
+use std::time::Duration;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufStream};
+use tokio::net::TcpStream;
+use tokio_native_tls::{native_tls, TlsConnector};
+
+use crate::config::{Config, SmtpTls};
+
+/// Longest a whole SMTP conversation may take
+const SMTP_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug)]
+pub enum SmtpError {
+    Io(std::io::Error),
+    Tls(native_tls::Error),
+    /// The server answered with an unexpected reply code
+    Rejected {
+        command: &'static str,
+        reply: String,
+    },
+    Timeout,
+    /// Credentials are configured but the connection wouldn't be encrypted
+    InsecureAuth,
+}
+
+impl fmt::Display for SmtpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "SMTP connection failed: {e}"),
+            Self::Tls(e) => write!(f, "SMTP TLS failed: {e}"),
+            Self::Rejected { command, reply } => write!(f, "SMTP {command} rejected: {reply}"),
+            Self::Timeout => f.write_str("SMTP server timed out"),
+            Self::InsecureAuth => {
+                f.write_str("SMTP credentials are only sent over TLS; set SMTP_TLS")
+            }
+        }
+    }
+}
+
+impl From<std::io::Error> for SmtpError {
+    fn from(e: std::io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl From<native_tls::Error> for SmtpError {
+    fn from(e: native_tls::Error) -> Self {
+        Self::Tls(e)
+    }
+}
+
+trait Stream: AsyncRead + AsyncWrite + Unpin + Send {}
+
+impl<T: AsyncRead + AsyncWrite + Unpin + Send> Stream for T {}
+
+/// Minimal SMTP client: one message per connection, `AUTH PLAIN` if
+/// credentials are configured. Credentials are never sent without TLS.
+pub struct SmtpClient {
+    host: String,
+    port: u16,
+    tls: SmtpTls,
+    credentials: Option<(String, String)>,
+}
+
+impl SmtpClient {
+    /// A client for the configured server, or `None` if there isn't one
+    pub fn from_config(config: &Config) -> Option<Self> {
+        let host = config.smtp_host.clone()?;
+        let credentials = config
+            .smtp_username
+            .clone()
+            .map(|username| (username, config.smtp_password.clone().unwrap_or_default()));
+        if credentials.is_some() && config.smtp_tls == SmtpTls::None {
+            tracing::warn!(
+                "SMTP_USERNAME is set but SMTP_TLS=none; emails won't be sent, since \
+                 credentials are never sent in the clear"
+            );
+        }
+        Some(Self {
+            host,
+            port: config.smtp_port,
+            tls: config.smtp_tls,
+            credentials,
+        })
+    }
+
+    /// Send `message`, a complete RFC 5322 message with CRLF line endings.
+    pub async fn send(&self, from: &str, to: &str, message: &str) -> Result<(), SmtpError> {
+        tokio::time::timeout(SMTP_TIMEOUT, self.converse(from, to, message))
+            .await
+            .map_err(|_| SmtpError::Timeout)?
+    }
+
+    async fn converse(&self, from: &str, to: &str, message: &str) -> Result<(), SmtpError> {
+        if self.credentials.is_some() && self.tls == SmtpTls::None {
+            return Err(SmtpError::InsecureAuth);
+        }
+
+        let tcp = TcpStream::connect((self.host.as_str(), self.port)).await?;
+        let stream: Box<dyn Stream> = match self.tls {
+            SmtpTls::Tls => Box::new(self.handshake(tcp).await?),
+            SmtpTls::None | SmtpTls::StartTls => Box::new(tcp),
+        };
+        let mut conn = Connection {
+            stream: BufStream::new(stream),
+        };
+
+        conn.reply("greeting", 220).await?;
+        conn.command("EHLO", "EHLO localhost", 250).await?;
+
+        if self.tls == SmtpTls::StartTls {
+            conn.command("STARTTLS", "STARTTLS", 220).await?;
+            let tls = self.handshake(conn.stream.into_inner()).await?;
+            conn = Connection {
+                stream: BufStream::new(Box::new(tls)),
+            };
+            conn.command("EHLO", "EHLO localhost", 250).await?;
+        }
+
+        if let Some((username, password)) = &self.credentials {
+            let token = STANDARD.encode(format!("\0{username}\0{password}"));
+            conn.command("AUTH", &format!("AUTH PLAIN {token}"), 235)
+                .await?;
+        }
+
+        conn.command("MAIL FROM", &format!("MAIL FROM:<{from}>"), 250)
+            .await?;
+        conn.command("RCPT TO", &format!("RCPT TO:<{to}>"), 250)
+            .await?;
+        conn.command("DATA", "DATA", 354).await?;
+
+        // Lines starting with a dot get another, so none ends the message early
+        for line in message.split("\r\n") {
+            if line.starts_with('.') {
+                conn.stream.write_all(b".").await?;
+            }
+            conn.stream.write_all(line.as_bytes()).await?;
+            conn.stream.write_all(b"\r\n").await?;
+        }
+        conn.command("message", ".", 250).await?;
+
+        // The message is accepted; a failed goodbye doesn't matter
+        let _ = conn.command("QUIT", "QUIT", 221).await;
+        Ok(())
+    }
+
+    async fn handshake<S>(&self, stream: S) -> Result<tokio_native_tls::TlsStream<S>, SmtpError>
+    where
+        S: AsyncRead + AsyncWrite + Unpin,
+    {
+        let connector = TlsConnector::from(native_tls::TlsConnector::new()?);
+        Ok(connector.connect(&self.host, stream).await?)
+    }
+}
+
+struct Connection {
+    stream: BufStream<Box<dyn Stream>>,
+}
+
+impl Connection {
+    async fn command(
+        &mut self,
+        name: &'static str,
+        line: &str,
+        expected: u16,
+    ) -> Result<(), SmtpError> {
+        self.stream.write_all(line.as_bytes()).await?;
+        self.stream.write_all(b"\r\n").await?;
+        self.stream.flush().await?;
+        self.reply(name, expected).await
+    }
+
+    /// Read a reply, which may span several `250-...` lines, and check its code
+    async fn reply(&mut self, command: &'static str, expected: u16) -> Result<(), SmtpError> {
+        let mut reply = String::new();
+        loop {
+            let mut line = String::new();
+            if self.stream.read_line(&mut line).await? == 0 {
+                return Err(SmtpError::Rejected {
+                    command,
+                    reply: format!("{reply}connection closed"),
+                });
+            }
+            reply.push_str(line.trim_end());
+            reply.push(' ');
+            if line.as_bytes().get(3) != Some(&b'-') {
+                break;
+            }
+        }
+
+        let code = reply.get(..3).and_then(|code| code.parse::<u16>().ok());
+        if code != Some(expected) {
+            return Err(SmtpError::Rejected {
+                command,
+                reply: reply.trim_end().to_string(),
+            });
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,94 @@
+use std::net::IpAddr;
+
+use quick_xml::escape::escape;
+
+use crate::dao::inquiry::NewInquiry;
+
+/// Subject and bodies of a notification email
+#[derive(Debug, Clone, PartialEq)]
+pub struct EmailContent {
+    pub subject: String,
+    pub text: String,
+    pub html: String,
+}
+
+fn html_page(heading: &str, body: &str) -> String {
+    format!(
+        r#"<!DOCTYPE html><html><body style="font-family: sans-serif; line-height: 1.5"><h2>{}</h2>{body}</body></html>"#,
+        escape(heading)
+    )
+}
+
+/// Paragraphs of user text, keeping its line breaks
+fn html_text(text: &str) -> String {
+    escape(text).replace('\n', "<br>")
+}
+
+pub fn new_inquiry(inquiry: &NewInquiry, manage_url: &str) -> EmailContent {
+    let from = format!("{} <{}>", inquiry.name, inquiry.email);
+
+    let text = format!(
+        "From: {from}\nSubject: {}\n\n{}\n\nReply to {} or open the inbox: {manage_url}\n",
+        inquiry.subject, inquiry.message, inquiry.email,
+    );
+    let html = html_page(
+        "New inquiry",
+        &format!(
+            r#"<p><b>From:</b> {}<br><b>Subject:</b> {}</p><p>{}</p><p><a href="mailto:{}">Reply</a> or <a href="{}">open the inbox</a>.</p>"#,
+            escape(&from),
+            escape(&inquiry.subject),
+            html_text(&inquiry.message),
+            escape(&inquiry.email),
+            escape(manage_url),
+        ),
+    );
+
+    EmailContent {
+        subject: format!("New inquiry: {}", inquiry.subject),
+        text,
+        html,
+    }
+}
+
+pub fn failed_logins(
+    count: usize,
+    period_secs: u64,
+    last_email: &str,
+    last_ip: Option<IpAddr>,
+) -> EmailContent {
+    let minutes = period_secs.div_ceil(60);
+    let last_ip = last_ip.map_or_else(|| "an unknown address".to_string(), |ip| ip.to_string());
+
+    let summary = format!("{count} login attempts failed in the last {minutes} minutes.");
+    let last = format!("The latest was for {last_email} from {last_ip}.");
+    let advice = "If that wasn't you, someone may be guessing passwords.";
+
+    EmailContent {
+        subject: format!("{count} failed login attempts"),
+        text: format!("{summary}\n{last}\n\n{advice}\n"),
+        html: html_page(
+            "Failed login attempts",
+            &format!(
+                "<p>{}<br>{}</p><p>{}</p>",
+                escape(&summary),
+                escape(&last),
+                escape(advice)
+            ),
+        ),
+    }
+}
+
+pub fn post_published(title: &str, url: &str) -> EmailContent {
+    EmailContent {
+        subject: format!("Published: {title}"),
+        text: format!("Your scheduled post \"{title}\" is now live:\n{url}\n"),
+        html: html_page(
+            "Scheduled post published",
+            &format!(
+                r#"<p>Your scheduled post <a href="{}">{}</a> is now live.</p>"#,
+                escape(url),
+                escape(title)
+            ),
+        ),
+    }
+}
@@ -0,0 +1,85 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use proto::auth::auth_service_server::SERVICE_NAME as AUTH_SERVICE;
+use proto::blog::blog_admin_service_server::SERVICE_NAME as BLOG_ADMIN_SERVICE;
+use proto::blog::blog_service_server::SERVICE_NAME as BLOG_SERVICE;
+use proto::inquiry::inquiry_admin_service_server::SERVICE_NAME as INQUIRY_ADMIN_SERVICE;
+use proto::inquiry::inquiry_service_server::SERVICE_NAME as INQUIRY_SERVICE;
+use proto::webhook::webhook_admin_service_server::SERVICE_NAME as WEBHOOK_ADMIN_SERVICE;
+use tonic_health::server::HealthReporter;
+use tonic_health::ServingStatus;
+
+use crate::db::{self, DbPool};
+use crate::services::identity::IdentityProvider;
+
+/// Results of one round of dependency probes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Dependencies {
+    /// The SQLite pool answers queries
+    database: bool,
+    /// The identity provider can verify ID tokens
+    identity: bool,
+}
+
+impl Dependencies {
+    /// Health of each service, and of the server as a whole under `""`.
+    fn statuses(self) -> [(&'static str, bool); 7] {
+        let admin = self.database && self.identity;
+        [
+            ("", admin),
+            (AUTH_SERVICE, self.identity),
+            (BLOG_SERVICE, self.database),
+            (BLOG_ADMIN_SERVICE, admin),
+            (INQUIRY_SERVICE, self.database),
+            (INQUIRY_ADMIN_SERVICE, admin),
+            (WEBHOOK_ADMIN_SERVICE, admin),
+        ]
+    }
+}
+
+/// Spawn the background task that keeps the `grpc.health.v1.Health` statuses up
+/// to date, probing the database and identity provider every `interval`.
+pub fn spawn_health_monitor(
+    reporter: HealthReporter,
+    pool: DbPool,
+    identity: Arc<dyn IdentityProvider>,
+    interval: Duration,
+) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        let mut last = None;
+
+        loop {
+            ticker.tick().await;
+
+            let database = match db::ping(&pool).await {
+                Ok(()) => true,
+                Err(e) => {
+                    tracing::warn!("Database health check failed: {:?}", e);
+                    false
+                }
+            };
+            let current = Dependencies {
+                database,
+                identity: identity.is_ready().await,
+            };
+
+            // Watchers are notified on every update, so only report changes
+            if last == Some(current) {
+                continue;
+            }
+            last = Some(current);
+
+            for (service, serving) in current.statuses() {
+                let status = if serving {
+                    ServingStatus::Serving
+                } else {
+                    ServingStatus::NotServing
+                };
+                reporter.set_service_status(service, status).await;
+            }
+        }
+    });
+}
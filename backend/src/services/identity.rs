@@ -0,0 +1,59 @@
+use std::sync::Arc;
+
+use crate::config::{Config, IdentityProviderKind};
+use crate::db::DbPool;
+use crate::dto::auth::{AuthError, AuthSession, AuthTokens, Claims, KeyCacheStatus};
+use crate::repositories::LocalAuthRepository;
+use crate::services::key_refresher::spawn_key_refresher;
+use crate::services::{FirebaseAuthService, LocalAuthService};
+
+/// Signs admins in and verifies the ID tokens they present.
+#[tonic::async_trait]
+pub trait IdentityProvider: Send + Sync {
+    /// Authenticate with email and password
+    async fn login(&self, email: String, password: String) -> Result<AuthSession, AuthError>;
+
+    /// Exchange a refresh token for new tokens
+    async fn refresh(&self, refresh_token: &str) -> Result<AuthTokens, AuthError>;
+
+    /// Verify an ID token
+    async fn verify(&self, id_token: &str) -> Result<Claims, AuthError>;
+
+    /// Whether ID tokens can currently be verified
+    async fn is_ready(&self) -> bool;
+
+    /// State of the verification key cache, for providers that fetch keys
+    async fn key_cache_status(&self) -> Option<KeyCacheStatus> {
+        None
+    }
+}
+
+/// Build the provider selected by `IDENTITY_PROVIDER` and start whatever it
+/// needs in the background.
+pub async fn connect(config: &Config, pool: DbPool) -> Arc<dyn IdentityProvider> {
+    match config.identity_provider {
+        IdentityProviderKind::Firebase => {
+            let firebase = Arc::new(FirebaseAuthService::new(config));
+            if config.firebase_allow_unsigned_tokens {
+                tracing::warn!(
+                    "Accepting unsigned Firebase ID tokens; only use this with the Auth Emulator"
+                );
+            }
+
+            // Pre-fetch Firebase public keys at startup, then keep them fresh as
+            // Google rotates them
+            if let Err(e) = firebase.prefetch_keys().await {
+                tracing::warn!("Failed to prefetch Firebase keys: {:?}", e);
+            }
+            spawn_key_refresher(firebase.clone());
+
+            firebase
+        }
+        IdentityProviderKind::Local => {
+            let local = LocalAuthService::init(LocalAuthRepository::new(pool), config)
+                .await
+                .expect("Failed to initialize local identity provider");
+            Arc::new(local)
+        }
+    }
+}
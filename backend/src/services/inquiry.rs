@@ -0,0 +1,175 @@
+use std::fmt;
+use std::sync::Arc;
+
+use crate::dao::inquiry::{Inquiry, InquiryStatus, NewInquiry};
+use crate::repositories::InquiryRepository;
+use crate::services::spam::{SpamFilter, SpamVerdict};
+use crate::services::Notifier;
+
+const DEFAULT_PAGE_SIZE: i32 = 50;
+const MAX_PAGE_SIZE: i32 = 200;
+
+const MAX_NAME_CHARS: usize = 100;
+/// Longest address RFC 5321 allows
+const MAX_EMAIL_CHARS: usize = 254;
+const MAX_SUBJECT_CHARS: usize = 200;
+const MAX_MESSAGE_CHARS: usize = 5000;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum InquiryError {
+    Missing(&'static str),
+    TooLong(&'static str, usize),
+    InvalidEmail,
+}
+
+impl fmt::Display for InquiryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing(field) => write!(f, "Please fill in the {field}"),
+            Self::TooLong(field, max) => {
+                write!(f, "The {field} is too long; keep it under {max} characters")
+            }
+            Self::InvalidEmail => write!(f, "Please enter a valid email address"),
+        }
+    }
+}
+
+/// Check a contact form submission, trimming surrounding whitespace.
+pub fn validate_inquiry(
+    name: &str,
+    email: &str,
+    subject: &str,
+    message: &str,
+) -> Result<NewInquiry, InquiryError> {
+    let inquiry = NewInquiry {
+        name: required("name", name, MAX_NAME_CHARS)?,
+        email: required("email address", email, MAX_EMAIL_CHARS)?,
+        subject: required("subject", subject, MAX_SUBJECT_CHARS)?,
+        message: required("message", message, MAX_MESSAGE_CHARS)?,
+    };
+
+    // Only catch obvious typos; whether it's deliverable shows when replying
+    let valid_email = inquiry
+        .email
+        .split_once('@')
+        .is_some_and(|(local, domain)| {
+            !local.is_empty() && domain.contains('.') && !domain.starts_with('.')
+        })
+        && !inquiry.email.contains(char::is_whitespace);
+    if !valid_email {
+        return Err(InquiryError::InvalidEmail);
+    }
+
+    Ok(inquiry)
+}
+
+fn required(field: &'static str, value: &str, max_chars: usize) -> Result<String, InquiryError> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Err(InquiryError::Missing(field));
+    }
+    if value.chars().count() > max_chars {
+        return Err(InquiryError::TooLong(field, max_chars));
+    }
+    Ok(value.to_string())
+}
+
+/// Stores contact form submissions and manages the admin inbox.
+pub struct InquiryService {
+    repo: InquiryRepository,
+    spam_filter: Arc<SpamFilter>,
+    notifier: Arc<Notifier>,
+}
+
+impl InquiryService {
+    pub fn new(
+        repo: InquiryRepository,
+        spam_filter: Arc<SpamFilter>,
+        notifier: Arc<Notifier>,
+    ) -> Self {
+        Self {
+            repo,
+            spam_filter,
+            notifier,
+        }
+    }
+
+    /// Store a submission checked by [`validate_inquiry`], quarantined if
+    /// `spam` says so, and notify the owner if it isn't. Returns its id.
+    pub async fn submit(
+        &self,
+        inquiry: &NewInquiry,
+        spam: &SpamVerdict,
+    ) -> Result<i64, sqlx::Error> {
+        let status = if spam.quarantine {
+            InquiryStatus::Quarantined
+        } else {
+            InquiryStatus::New
+        };
+        let id = self
+            .repo
+            .create(inquiry, status, spam.score, &spam.reasons)
+            .await?;
+
+        if !spam.quarantine {
+            self.notifier.new_inquiry(inquiry).await;
+        }
+        Ok(id)
+    }
+
+    /// Inquiries with `status`, or the inbox (everything neither archived nor
+    /// quarantined) if `None`, newest first, with the total number of matches.
+    pub async fn list(
+        &self,
+        status: Option<InquiryStatus>,
+        limit: i32,
+        offset: i32,
+    ) -> Result<(Vec<Inquiry>, i32), sqlx::Error> {
+        let limit = if limit <= 0 {
+            DEFAULT_PAGE_SIZE
+        } else {
+            limit.min(MAX_PAGE_SIZE)
+        };
+        self.repo.list(status, limit, offset.max(0)).await
+    }
+
+    pub async fn get(&self, id: i64) -> Result<Option<Inquiry>, sqlx::Error> {
+        self.repo.get(id).await
+    }
+
+    pub async fn mark_handled(
+        &self,
+        id: i64,
+        actor_sub: Option<&str>,
+    ) -> Result<bool, sqlx::Error> {
+        self.repo.mark_handled(id, actor_sub).await
+    }
+
+    pub async fn archive(&self, id: i64) -> Result<bool, sqlx::Error> {
+        self.repo.archive(id).await
+    }
+
+    /// Quarantine an inquiry as spam, or release it to the inbox, and train the
+    /// spam classifier with the verdict.
+    pub async fn mark_spam(&self, id: i64, spam: bool) -> Result<bool, sqlx::Error> {
+        let Some(inquiry) = self.repo.get(id).await? else {
+            return Ok(false);
+        };
+
+        let text = [
+            inquiry.name.as_str(),
+            &inquiry.email,
+            &inquiry.subject,
+            &inquiry.message,
+        ];
+        self.spam_filter
+            .train(&text, spam, inquiry.marked_spam)
+            .await?;
+
+        self.repo.mark_spam(id, spam).await
+    }
+
+    pub async fn delete(&self, id: i64) -> Result<bool, sqlx::Error> {
+        self.repo.delete(id).await
+    }
+}
@@ -0,0 +1,80 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+use crate::services::FirebaseAuthService;
+use crate::utils::now_timestamp;
+
+/// Refresh this long before the cached keys expire
+const REFRESH_MARGIN_SECS: i64 = 5 * 60;
+
+/// First retry delay after a failed refresh, doubled on each further failure
+const INITIAL_BACKOFF: Duration = Duration::from_secs(5);
+const MAX_BACKOFF: Duration = Duration::from_secs(5 * 60);
+
+/// Minimum gap between refreshes triggered by unknown key IDs, so a stream of
+/// forged tokens can't turn into a stream of requests to Google
+const FORCED_REFRESH_COOLDOWN: Duration = Duration::from_secs(60);
+
+/// Spawn the background task that keeps the Firebase public key cache fresh.
+///
+/// Keys are refetched shortly before the `max-age` Google sent with them runs
+/// out, retried with exponential backoff on failure, and refetched early when a
+/// token arrives signed with a key ID that isn't cached (Google rotated keys).
+pub fn spawn_key_refresher(firebase_auth: Arc<FirebaseAuthService>) {
+    tokio::spawn(async move {
+        let mut backoff = INITIAL_BACKOFF;
+        let mut retry_in = None;
+        let mut last_attempt: Option<Instant> = None;
+
+        loop {
+            let wait = match retry_in {
+                Some(delay) => delay,
+                None => refresh_delay(firebase_auth.keys_expire_at().await, now_timestamp()),
+            };
+
+            tokio::select! {
+                _ = tokio::time::sleep(wait) => {}
+                _ = firebase_auth.refresh_requested() => {
+                    if last_attempt.is_some_and(|at| at.elapsed() < FORCED_REFRESH_COOLDOWN) {
+                        continue;
+                    }
+                    tracing::info!("Unknown Firebase key ID seen, refreshing public keys");
+                }
+            }
+
+            last_attempt = Some(Instant::now());
+            match firebase_auth.refresh_keys().await {
+                Ok(()) => {
+                    tracing::debug!("Refreshed Firebase public keys");
+                    backoff = INITIAL_BACKOFF;
+                    retry_in = None;
+                }
+                Err(e) => {
+                    tracing::warn!(
+                        "Failed to refresh Firebase public keys, retrying in {:?}: {:?}",
+                        backoff,
+                        e
+                    );
+                    retry_in = Some(backoff);
+                    backoff = (backoff * 2).min(MAX_BACKOFF);
+                }
+            }
+        }
+    });
+}
+
+/// How long to wait before refreshing keys that expire at `expires_at`, or
+/// right away if none are cached. Keys sent with a `max-age` shorter than the
+/// refresh margin are still kept for `INITIAL_BACKOFF`, so they can't turn the
+/// refresher into a busy loop.
+pub fn refresh_delay(expires_at: Option<i64>, now: i64) -> Duration {
+    match expires_at {
+        Some(expires_at) => {
+            let secs = expires_at - REFRESH_MARGIN_SECS - now;
+            Duration::from_secs(secs.max(0) as u64).max(INITIAL_BACKOFF)
+        }
+        None => Duration::ZERO,
+    }
+}
@@ -0,0 +1,259 @@
+use argon2::password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
+use argon2::Argon2;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use jsonwebtoken::{
+    decode, decode_header, encode, errors::ErrorKind, Algorithm, DecodingKey, EncodingKey, Header,
+    Validation,
+};
+use ring::digest::{digest, SHA256};
+use ring::rand::{SecureRandom, SystemRandom};
+use ring::signature::{Ed25519KeyPair, KeyPair};
+
+use crate::config::Config;
+use crate::dao::auth::LocalUser;
+use crate::dto::auth::{AuthError, AuthSession, AuthTokens, Claims};
+use crate::repositories::LocalAuthRepository;
+use crate::services::identity::IdentityProvider;
+use crate::utils::{now_timestamp, REFRESH_TOKEN_MAX_AGE};
+
+/// Lifetime of a local ID token, matching Firebase's
+const ID_TOKEN_TTL_SECS: i64 = 60 * 60;
+
+/// `aud` claim of local ID tokens; the issuer is the backend's public URL
+const LOCAL_AUDIENCE: &str = "portfolio-admin";
+
+/// Argon2 hash (default parameters) that no password matches. Logins for
+/// unknown emails are checked against it, so they take as long as a wrong
+/// password and don't reveal which accounts exist.
+const DUMMY_PASSWORD_HASH: &str =
+    "$argon2id$v=19$m=19456,t=2,p=1$bm8tc3VjaC1hY2NvdW50IQ$vjQmm6/Us05farmSqFQxZYQlvpfBpjAI5S9QGBlIda8";
+
+/// Identity provider that keeps argon2-hashed admin credentials in SQLite and
+/// signs its own EdDSA ID tokens, so the admin flow works without network access.
+pub struct LocalAuthService {
+    repo: LocalAuthRepository,
+    issuer: String,
+    kid: String,
+    encoding_key: EncodingKey,
+    decoding_key: DecodingKey,
+    rng: SystemRandom,
+}
+
+impl LocalAuthService {
+    /// Load the signing key, creating it on first start, and create or update
+    /// the admin account from `LOCAL_ADMIN_EMAIL`/`LOCAL_ADMIN_PASSWORD`.
+    pub async fn init(repo: LocalAuthRepository, config: &Config) -> Result<Self, sqlx::Error> {
+        let rng = SystemRandom::new();
+
+        let (kid, pkcs8) = match repo.signing_key().await? {
+            Some(key) => key,
+            None => {
+                let pkcs8 = Ed25519KeyPair::generate_pkcs8(&rng)
+                    .expect("Failed to generate local signing key");
+                let kid = random_token(&rng, 8);
+                repo.insert_signing_key(&kid, pkcs8.as_ref()).await?;
+                (kid, pkcs8.as_ref().to_vec())
+            }
+        };
+        let key_pair = Ed25519KeyPair::from_pkcs8(&pkcs8).expect("Invalid local signing key");
+
+        let service = Self {
+            repo,
+            issuer: config.public_url.clone(),
+            kid,
+            encoding_key: EncodingKey::from_ed_der(&pkcs8),
+            decoding_key: DecodingKey::from_ed_der(key_pair.public_key().as_ref()),
+            rng,
+        };
+
+        match (&config.local_admin_email, &config.local_admin_password) {
+            (Some(email), Some(password)) => service.ensure_admin(email, password).await?,
+            _ if service.repo.count_users().await? == 0 => tracing::warn!(
+                "Local identity provider has no accounts; set LOCAL_ADMIN_EMAIL and LOCAL_ADMIN_PASSWORD"
+            ),
+            _ => {}
+        }
+
+        Ok(service)
+    }
+
+    /// Create the account, or reset its password if it no longer matches.
+    async fn ensure_admin(&self, email: &str, password: &str) -> Result<(), sqlx::Error> {
+        let email = normalize_email(email);
+
+        match self.repo.find_user_by_email(&email).await? {
+            Some(user) => {
+                if !verify_password(password.to_string(), user.password_hash).await {
+                    let hash = hash_password(password.to_string()).await;
+                    self.repo.set_password_hash(user.id, &hash).await?;
+                    tracing::info!("Updated password of local admin {}", email);
+                }
+            }
+            None => {
+                let hash = hash_password(password.to_string()).await;
+                self.repo.create_user(&email, &hash).await?;
+                tracing::info!("Created local admin {}", email);
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn issue_tokens(&self, user: &LocalUser) -> Result<AuthTokens, AuthError> {
+        let now = now_timestamp();
+
+        let claims = Claims {
+            email: Some(user.email.clone()),
+            // Accounts are created from trusted config, so the address counts
+            // as verified
+            email_verified: true,
+            roles: vec![user.role.clone()],
+            sub: user.id.to_string(),
+            aud: LOCAL_AUDIENCE.to_string(),
+            iss: self.issuer.clone(),
+            exp: (now + ID_TOKEN_TTL_SECS) as u64,
+        };
+        let mut header = Header::new(Algorithm::EdDSA);
+        header.kid = Some(self.kid.clone());
+        let id_token =
+            encode(&header, &claims, &self.encoding_key).map_err(|_| AuthError::InvalidResponse)?;
+
+        let refresh_token = random_token(&self.rng, 32);
+        self.repo
+            .insert_refresh_token(
+                &token_hash(&refresh_token),
+                user.id,
+                now + REFRESH_TOKEN_MAX_AGE as i64,
+            )
+            .await
+            .map_err(|_| AuthError::StorageError)?;
+
+        Ok(AuthTokens {
+            id_token,
+            refresh_token,
+            expires_in: ID_TOKEN_TTL_SECS as u64,
+        })
+    }
+}
+
+#[tonic::async_trait]
+impl IdentityProvider for LocalAuthService {
+    async fn login(&self, email: String, password: String) -> Result<AuthSession, AuthError> {
+        let user = self
+            .repo
+            .find_user_by_email(&normalize_email(&email))
+            .await
+            .map_err(|_| AuthError::StorageError)?;
+
+        let Some(user) = user else {
+            verify_password(password, DUMMY_PASSWORD_HASH.to_string()).await;
+            return Err(AuthError::InvalidCredentials);
+        };
+        if !verify_password(password, user.password_hash.clone()).await {
+            return Err(AuthError::InvalidCredentials);
+        }
+        if user.disabled {
+            return Err(AuthError::UserDisabled);
+        }
+
+        let tokens = self.issue_tokens(&user).await?;
+
+        Ok(AuthSession {
+            uid: user.id.to_string(),
+            email: user.email,
+            tokens,
+        })
+    }
+
+    /// Refresh tokens are single use: each refresh hands out a new one.
+    async fn refresh(&self, refresh_token: &str) -> Result<AuthTokens, AuthError> {
+        let user_id = self
+            .repo
+            .take_refresh_token(&token_hash(refresh_token))
+            .await
+            .map_err(|_| AuthError::StorageError)?
+            .ok_or(AuthError::InvalidRefreshToken)?;
+
+        let user = self
+            .repo
+            .find_user(user_id)
+            .await
+            .map_err(|_| AuthError::StorageError)?
+            .ok_or(AuthError::InvalidRefreshToken)?;
+        if user.disabled {
+            return Err(AuthError::UserDisabled);
+        }
+
+        self.issue_tokens(&user).await
+    }
+
+    async fn verify(&self, id_token: &str) -> Result<Claims, AuthError> {
+        let header = decode_header(id_token).map_err(|_| AuthError::InvalidToken)?;
+        if header.kid.as_deref() != Some(self.kid.as_str()) {
+            return Err(AuthError::InvalidToken);
+        }
+
+        let mut validation = Validation::new(Algorithm::EdDSA);
+        validation.set_audience(&[LOCAL_AUDIENCE]);
+        validation.set_issuer(&[&self.issuer]);
+
+        decode::<Claims>(id_token, &self.decoding_key, &validation)
+            .map(|data| data.claims)
+            .map_err(|e| match e.kind() {
+                ErrorKind::ExpiredSignature => AuthError::ExpiredToken,
+                _ => AuthError::InvalidToken,
+            })
+    }
+
+    /// The signing key is loaded at startup, so tokens can always be verified
+    async fn is_ready(&self) -> bool {
+        true
+    }
+}
+
+fn normalize_email(email: &str) -> String {
+    email.trim().to_lowercase()
+}
+
+/// `len` random bytes, base64url encoded
+fn random_token(rng: &SystemRandom, len: usize) -> String {
+    let mut bytes = vec![0u8; len];
+    rng.fill(&mut bytes)
+        .expect("Failed to generate random bytes");
+    URL_SAFE_NO_PAD.encode(bytes)
+}
+
+/// Refresh tokens are stored hashed, so a leaked database can't be replayed
+fn token_hash(token: &str) -> String {
+    URL_SAFE_NO_PAD.encode(digest(&SHA256, token.as_bytes()))
+}
+
+/// Argon2 is deliberately slow, so hashing runs off the async runtime
+async fn hash_password(password: String) -> String {
+    tokio::task::spawn_blocking(move || {
+        let mut salt = [0u8; 16];
+        SystemRandom::new()
+            .fill(&mut salt)
+            .expect("Failed to generate salt");
+        let salt = SaltString::encode_b64(&salt).expect("Invalid salt length");
+        Argon2::default()
+            .hash_password(password.as_bytes(), &salt)
+            .expect("Failed to hash password")
+            .to_string()
+    })
+    .await
+    .expect("Password hashing task panicked")
+}
+
+async fn verify_password(password: String, hash: String) -> bool {
+    tokio::task::spawn_blocking(move || {
+        PasswordHash::new(&hash).is_ok_and(|hash| {
+            Argon2::default()
+                .verify_password(password.as_bytes(), &hash)
+                .is_ok()
+        })
+    })
+    .await
+    .unwrap_or(false)
+}
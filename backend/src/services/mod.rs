@@ -1,5 +1,24 @@
+pub mod audit;
 pub mod auth;
 pub mod blog;
+pub mod email;
+pub mod health;
+pub mod identity;
+pub mod inquiry;
+pub mod key_refresher;
+pub mod local_auth;
+pub mod rate_limit;
+pub mod sitemap;
+pub mod spam;
+pub mod webhook;
 
+pub use audit::AuditService;
 pub use auth::FirebaseAuthService;
 pub use blog::BlogService;
+pub use email::Notifier;
+pub use inquiry::InquiryService;
+pub use local_auth::LocalAuthService;
+pub use rate_limit::RateLimiter;
+pub use sitemap::SitemapService;
+pub use spam::SpamFilter;
+pub use webhook::WebhookService;
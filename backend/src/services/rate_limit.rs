@@ -0,0 +1,145 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::config::RateLimit;
+use crate::dao::rate_limit::RateLimitBucket;
+use crate::repositories::RateLimitRepository;
+
+/// How often refilled buckets are dropped and, if enabled, buckets are saved
+const MAINTENANCE_INTERVAL: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, Copy)]
+struct Bucket {
+    tokens: f64,
+    updated_at: f64,
+    full_at: f64,
+}
+
+/// In-memory token buckets keyed by limit name and client, e.g.
+/// `login-email:admin@example.com`.
+///
+/// A bucket only exists while it's below capacity; once it has refilled it's
+/// indistinguishable from a new one and is dropped by [`RateLimiter::prune`].
+pub struct RateLimiter {
+    buckets: Mutex<HashMap<String, Bucket>>,
+    /// Where buckets are saved, if persistence is enabled
+    repo: Option<RateLimitRepository>,
+}
+
+impl RateLimiter {
+    pub fn new(repo: Option<RateLimitRepository>) -> Self {
+        Self {
+            buckets: Mutex::new(HashMap::new()),
+            repo,
+        }
+    }
+
+    /// Take a token from the bucket for `key`. Returns how long until a token
+    /// is available if the bucket is empty.
+    pub fn check(&self, key: &str, limit: RateLimit) -> Result<(), Duration> {
+        let now = now_secs();
+        let capacity = limit.capacity as f64;
+        let rate = limit.refill_rate();
+
+        let mut buckets = self.buckets.lock().unwrap();
+        let bucket = buckets.entry(key.to_string()).or_insert(Bucket {
+            tokens: capacity,
+            updated_at: now,
+            full_at: now,
+        });
+
+        let elapsed = (now - bucket.updated_at).max(0.0);
+        let tokens = (bucket.tokens + elapsed * rate).min(capacity);
+        if tokens < 1.0 {
+            bucket.tokens = tokens;
+            bucket.updated_at = now;
+            return Err(Duration::from_secs_f64((1.0 - tokens) / rate));
+        }
+
+        bucket.tokens = tokens - 1.0;
+        bucket.updated_at = now;
+        bucket.full_at = now + (capacity - bucket.tokens) / rate;
+        Ok(())
+    }
+
+    /// Forget buckets that have refilled. Returns how many were dropped.
+    pub fn prune(&self) -> usize {
+        let now = now_secs();
+        let mut buckets = self.buckets.lock().unwrap();
+        let before = buckets.len();
+        buckets.retain(|_, bucket| bucket.full_at > now);
+        before - buckets.len()
+    }
+
+    /// Load saved buckets, if persistence is enabled. Buckets already in memory
+    /// win over saved ones.
+    pub async fn restore(&self) -> Result<usize, sqlx::Error> {
+        let Some(repo) = &self.repo else {
+            return Ok(0);
+        };
+        let saved = repo.load().await?;
+
+        let mut buckets = self.buckets.lock().unwrap();
+        let count = saved.len();
+        for saved in saved {
+            buckets.entry(saved.key).or_insert(Bucket {
+                tokens: saved.tokens,
+                updated_at: saved.updated_at,
+                full_at: saved.full_at,
+            });
+        }
+        Ok(count)
+    }
+
+    /// Save the current buckets, if persistence is enabled.
+    pub async fn persist(&self) -> Result<(), sqlx::Error> {
+        let Some(repo) = &self.repo else {
+            return Ok(());
+        };
+        let buckets: Vec<RateLimitBucket> = self
+            .buckets
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(key, bucket)| RateLimitBucket {
+                key: key.clone(),
+                tokens: bucket.tokens,
+                updated_at: bucket.updated_at,
+                full_at: bucket.full_at,
+            })
+            .collect();
+        repo.replace(&buckets).await
+    }
+}
+
+/// Spawn the background task that restores saved buckets, then periodically
+/// drops refilled ones and saves the rest. Runs for the life of the process.
+pub fn spawn_rate_limit_maintenance(limiter: Arc<RateLimiter>) {
+    tokio::spawn(async move {
+        match limiter.restore().await {
+            Ok(0) => {}
+            Ok(count) => tracing::info!("Restored {} rate limit buckets", count),
+            Err(e) => tracing::warn!("Failed to restore rate limit buckets: {:?}", e),
+        }
+
+        let mut ticker = tokio::time::interval(MAINTENANCE_INTERVAL);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+
+            limiter.prune();
+            if let Err(e) = limiter.persist().await {
+                tracing::warn!("Failed to save rate limit buckets: {:?}", e);
+            }
+        }
+    });
+}
+
+fn now_secs() -> f64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs_f64()
+}
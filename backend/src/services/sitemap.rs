@@ -0,0 +1,145 @@
+use std::fmt::Write;
+use std::sync::{Arc, RwLock};
+
+use quick_xml::escape::escape;
+
+use crate::config::Config;
+use crate::services::BlogService;
+use crate::utils::rfc3339;
+
+/// Most URLs a single sitemap file may list, per the sitemaps.org protocol
+const MAX_URLS_PER_SITEMAP: usize = 50_000;
+
+/// Portfolio pages listed ahead of the blog posts, relative to the site URL
+const STATIC_ROUTES: [&str; 4] = ["/", "/projects", "/blog", "/more"];
+
+/// Backend paths crawlers may fetch; the rest of this host is the API. The
+/// frontend serves its own robots.txt for its routes, such as `/manage`.
+const ALLOWED_PATHS: [&str; 6] = [
+    "/sitemap.xml",
+    "/sitemaps/",
+    "/feed.xml",
+    "/atom.xml",
+    "/feed.json",
+    "/tags/",
+];
+
+const SITEMAP_NS: &str = "http://www.sitemaps.org/schemas/sitemap/0.9";
+
+/// Generated sitemap and robots.txt documents.
+#[derive(Debug)]
+pub struct SiteIndex {
+    /// `/sitemap.xml`: the URL set itself, or an index of `pages` once split
+    pub sitemap: String,
+    /// Child sitemaps served as `/sitemaps/{n}.xml` (1-based); empty unless split
+    pub pages: Vec<String>,
+    pub robots: String,
+    /// Latest post change reflected in the documents
+    pub last_modified: i64,
+}
+
+/// Builds the sitemap and robots.txt from the blog, caching them until the next
+/// post write.
+pub struct SitemapService {
+    blog: Arc<BlogService>,
+    site_url: String,
+    public_url: String,
+    cache: RwLock<Option<(u64, Arc<SiteIndex>)>>,
+}
+
+impl SitemapService {
+    pub fn new(blog: Arc<BlogService>, config: &Config) -> Self {
+        Self {
+            blog,
+            site_url: config.site_url.clone(),
+            public_url: config.public_url.clone(),
+            cache: RwLock::new(None),
+        }
+    }
+
+    /// Current documents, regenerated if any post changed since they were built.
+    pub async fn index(&self) -> Result<Arc<SiteIndex>, sqlx::Error> {
+        let version = self.blog.change_count();
+        if let Some((built, index)) = self.cache.read().unwrap().as_ref()
+            && *built == version
+        {
+            return Ok(index.clone());
+        }
+
+        let index = Arc::new(self.build().await?);
+        *self.cache.write().unwrap() = Some((version, index.clone()));
+        Ok(index)
+    }
+
+    async fn build(&self) -> Result<SiteIndex, sqlx::Error> {
+        let posts = self.blog.list_published_slugs().await?;
+        let last_modified = self.blog.last_modified().await?;
+
+        let mut urls: Vec<(String, Option<i64>)> = STATIC_ROUTES
+            .iter()
+            .map(|route| (format!("{}{route}", self.site_url), None))
+            .collect();
+        urls.extend(posts.into_iter().map(|(slug, updated_at)| {
+            (format!("{}/blog/{slug}", self.site_url), Some(updated_at))
+        }));
+
+        let (sitemap, pages) = if urls.len() <= MAX_URLS_PER_SITEMAP {
+            (render_urlset(&urls), Vec::new())
+        } else {
+            let pages: Vec<String> = urls
+                .chunks(MAX_URLS_PER_SITEMAP)
+                .map(render_urlset)
+                .collect();
+            (self.render_index(pages.len(), last_modified), pages)
+        };
+
+        Ok(SiteIndex {
+            sitemap,
+            pages,
+            robots: self.render_robots(),
+            last_modified,
+        })
+    }
+
+    fn render_index(&self, count: usize, last_modified: i64) -> String {
+        let mut xml = String::from(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+        let _ = write!(xml, r#"<sitemapindex xmlns="{SITEMAP_NS}">"#);
+        for n in 1..=count {
+            let _ = write!(
+                xml,
+                "<sitemap><loc>{}/sitemaps/{n}.xml</loc><lastmod>{}</lastmod></sitemap>",
+                escape(&self.public_url),
+                rfc3339(last_modified),
+            );
+        }
+        xml.push_str("</sitemapindex>");
+        xml
+    }
+
+    fn render_robots(&self) -> String {
+        let mut robots = String::from("User-agent: *\n");
+        for path in ALLOWED_PATHS {
+            let _ = writeln!(robots, "Allow: {path}");
+        }
+        let _ = write!(
+            robots,
+            "Disallow: /\n\nSitemap: {}/sitemap.xml\n",
+            self.public_url
+        );
+        robots
+    }
+}
+
+fn render_urlset(urls: &[(String, Option<i64>)]) -> String {
+    let mut xml = String::from(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    let _ = write!(xml, r#"<urlset xmlns="{SITEMAP_NS}">"#);
+    for (loc, lastmod) in urls {
+        let _ = write!(xml, "<url><loc>{}</loc>", escape(loc));
+        if let Some(lastmod) = lastmod {
+            let _ = write!(xml, "<lastmod>{}</lastmod>", rfc3339(*lastmod));
+        }
+        xml.push_str("</url>");
+    }
+    xml.push_str("</urlset>");
+    xml
+}
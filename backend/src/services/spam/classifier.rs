@@ -0,0 +1,59 @@
+use std::collections::HashSet;
+
+use crate::dao::spam::SpamToken;
+
+/// Marked submissions of each kind needed before the classifier has an opinion
+pub const MIN_DOCUMENTS: i64 = 5;
+
+/// Words kept per submission
+const MAX_TOKENS: usize = 200;
+/// Only the words furthest from neutral decide, so long messages padded with
+/// innocent text don't drown out the telling ones
+const DECISIVE_TOKENS: usize = 15;
+
+/// Distinct lowercase words of 3 to 24 letters or digits, which is what the
+/// classifier counts, in order of first appearance. Past `MAX_TOKENS` the rest
+/// of the text is ignored, so padding only pushes out words that follow it.
+pub fn tokenize(text: &str) -> Vec<String> {
+    let mut seen = HashSet::new();
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| (3..=24).contains(&word.chars().count()))
+        .map(str::to_lowercase)
+        .filter(|word| seen.insert(word.clone()))
+        .take(MAX_TOKENS)
+        .collect()
+}
+
+/// Naive Bayes probability that a submission with the given known words is
+/// spam, or `None` until enough submissions of each kind have been marked.
+///
+/// Each word's probability is smoothed towards 0.5 by how rarely it was seen,
+/// after Gary Robinson, and the decisive ones combined in log space.
+pub fn spam_probability(tokens: &[SpamToken], spam_docs: i64, ham_docs: i64) -> Option<f64> {
+    if spam_docs < MIN_DOCUMENTS || ham_docs < MIN_DOCUMENTS {
+        return None;
+    }
+
+    let mut probabilities: Vec<f64> = tokens
+        .iter()
+        .filter(|t| t.spam_count + t.ham_count > 0)
+        .map(|t| {
+            let spam = t.spam_count as f64 / spam_docs as f64;
+            let ham = t.ham_count as f64 / ham_docs as f64;
+            let p = spam / (spam + ham);
+            let seen = (t.spam_count + t.ham_count) as f64;
+            ((0.5 + seen * p) / (1.0 + seen)).clamp(0.01, 0.99)
+        })
+        .collect();
+    if probabilities.is_empty() {
+        return None;
+    }
+
+    probabilities.sort_by(|a, b| (b - 0.5).abs().total_cmp(&(a - 0.5).abs()));
+    probabilities.truncate(DECISIVE_TOKENS);
+
+    let (ln_spam, ln_ham) = probabilities
+        .iter()
+        .fold((0.0, 0.0), |(s, h), p| (s + p.ln(), h + (1.0 - p).ln()));
+    Some(1.0 / (1.0 + (ln_ham - ln_spam).exp()))
+}
@@ -0,0 +1,73 @@
+use std::fmt;
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use ring::hmac;
+use ring::rand::SystemRandom;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FormTokenError {
+    Missing,
+    Invalid,
+    Expired,
+}
+
+impl fmt::Display for FormTokenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Missing => "no form token",
+            Self::Invalid => "invalid form token",
+            Self::Expired => "expired form token",
+        })
+    }
+}
+
+/// Signs the time a form was loaded, so a submission can prove how long ago
+/// that was. Tokens aren't tied to a client or single-use; per-IP limits deal
+/// with a bot reusing one.
+pub struct FormTokens {
+    key: hmac::Key,
+    max_age_secs: i64,
+}
+
+impl FormTokens {
+    /// Sign with `secret`, or with a random key if `None`, in which case tokens
+    /// don't survive a restart.
+    pub fn new(secret: Option<&str>, max_age_secs: u64) -> Self {
+        let key = match secret {
+            Some(secret) => hmac::Key::new(hmac::HMAC_SHA256, secret.as_bytes()),
+            None => hmac::Key::generate(hmac::HMAC_SHA256, &SystemRandom::new())
+                .expect("Failed to generate form token key"),
+        };
+        Self {
+            key,
+            max_age_secs: max_age_secs as i64,
+        }
+    }
+
+    /// A token recording `now` as the time the form was loaded
+    pub fn issue(&self, now: i64) -> String {
+        let tag = hmac::sign(&self.key, now.to_string().as_bytes());
+        format!("{now}.{}", URL_SAFE_NO_PAD.encode(tag.as_ref()))
+    }
+
+    /// Seconds since `token` was issued
+    pub fn age(&self, token: &str, now: i64) -> Result<i64, FormTokenError> {
+        if token.is_empty() {
+            return Err(FormTokenError::Missing);
+        }
+
+        let (issued_at, tag) = token.split_once('.').ok_or(FormTokenError::Invalid)?;
+        let tag = URL_SAFE_NO_PAD
+            .decode(tag)
+            .map_err(|_| FormTokenError::Invalid)?;
+        hmac::verify(&self.key, issued_at.as_bytes(), &tag).map_err(|_| FormTokenError::Invalid)?;
+
+        let issued_at: i64 = issued_at.parse().map_err(|_| FormTokenError::Invalid)?;
+        let age = now - issued_at;
+        if age > self.max_age_secs {
+            return Err(FormTokenError::Expired);
+        }
+        Ok(age.max(0))
+    }
+}
@@ -0,0 +1,186 @@
+mod classifier;
+mod form_token;
+
+use std::net::IpAddr;
+use std::sync::Arc;
+
+use crate::config::{Config, RateLimit};
+use crate::dao::spam::SpamLabel;
+use crate::repositories::SpamRepository;
+use crate::services::RateLimiter;
+use crate::utils::now_timestamp;
+
+pub use classifier::{spam_probability, tokenize, MIN_DOCUMENTS};
+pub use form_token::{FormTokenError, FormTokens};
+
+/// Score from which a check alone is enough to quarantine at the default
+/// threshold
+const DECISIVE: f64 = 1.0;
+/// Score of a check that only quarantines together with another
+const SUSPICIOUS: f64 = 0.5;
+/// Classifier spam probabilities from which a submission is suspicious, and
+/// from which that's decisive
+const CLASSIFIER_SUSPICIOUS: f64 = 0.7;
+const CLASSIFIER_DECISIVE: f64 = 0.9;
+
+/// An anonymous write, such as a contact form submission, about to be checked
+pub struct Submission<'a> {
+    pub client_ip: Option<IpAddr>,
+    /// Hidden form field that only bots fill in
+    pub honeypot: &'a str,
+    /// From [`SpamFilter::form_token`] when the form was loaded
+    pub form_token: &'a str,
+    /// Everything the submitter wrote
+    pub text: &'a [&'a str],
+}
+
+/// Outcome of [`SpamFilter::check`]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SpamVerdict {
+    pub score: f64,
+    /// One entry per check that scored, e.g. "honeypot field filled in"
+    pub reasons: Vec<String>,
+    /// Whether the score reached `SPAM_THRESHOLD`. Quarantined submissions are
+    /// stored, but kept out of view until an admin releases them.
+    pub quarantine: bool,
+}
+
+impl SpamVerdict {
+    fn add(&mut self, score: f64, reason: String) {
+        self.score += score;
+        self.reasons.push(reason);
+    }
+}
+
+/// Scores public submissions for spam with a few cheap heuristics and, if
+/// enabled, a naive Bayes classifier trained from admin verdicts.
+pub struct SpamFilter {
+    repo: SpamRepository,
+    rate_limiter: Arc<RateLimiter>,
+    form_tokens: FormTokens,
+    threshold: f64,
+    min_submit_secs: i64,
+    submissions_per_ip: RateLimit,
+    max_links: usize,
+    /// Lowercase
+    blocklist: Vec<String>,
+    classifier: bool,
+}
+
+impl SpamFilter {
+    pub fn new(repo: SpamRepository, rate_limiter: Arc<RateLimiter>, config: &Config) -> Self {
+        Self {
+            repo,
+            rate_limiter,
+            form_tokens: FormTokens::new(
+                config.spam_form_secret.as_deref(),
+                config.spam_form_token_max_age_secs,
+            ),
+            threshold: config.spam_threshold,
+            min_submit_secs: config.spam_min_submit_secs as i64,
+            submissions_per_ip: config.spam_submissions_per_ip,
+            max_links: config.spam_max_links,
+            blocklist: config
+                .spam_blocklist
+                .iter()
+                .map(|term| term.to_lowercase())
+                .collect(),
+            classifier: config.spam_classifier,
+        }
+    }
+
+    /// A token for a form being loaded now, to be sent back with the submission
+    pub fn form_token(&self) -> String {
+        self.form_tokens.issue(now_timestamp())
+    }
+
+    pub async fn check(&self, submission: &Submission<'_>) -> Result<SpamVerdict, sqlx::Error> {
+        let mut verdict = SpamVerdict::default();
+        let text = submission.text.join("\n").to_lowercase();
+
+        if !submission.honeypot.trim().is_empty() {
+            verdict.add(DECISIVE, "honeypot field filled in".to_string());
+        }
+
+        match self.form_tokens.age(submission.form_token, now_timestamp()) {
+            Ok(age) if age < self.min_submit_secs => {
+                verdict.add(DECISIVE, format!("submitted {age}s after loading the form"));
+            }
+            Ok(_) => {}
+            Err(FormTokenError::Expired) => {
+                verdict.add(SUSPICIOUS, FormTokenError::Expired.to_string());
+            }
+            Err(e) => verdict.add(DECISIVE, e.to_string()),
+        }
+
+        // Counted separately from the hard limit in `RateLimitLayer`, which
+        // refuses outright, and shared by every kind of public submission
+        if let Some(ip) = submission.client_ip
+            && self
+                .rate_limiter
+                .check(&format!("spam-ip:{ip}"), self.submissions_per_ip)
+                .is_err()
+        {
+            verdict.add(SUSPICIOUS, "many submissions from this address".to_string());
+        }
+
+        let links = text
+            .split_whitespace()
+            .filter(|word| word.contains("://") || word.starts_with("www."))
+            .count();
+        if links > self.max_links {
+            verdict.add(SUSPICIOUS, format!("{links} links"));
+        }
+
+        for term in &self.blocklist {
+            if text.contains(term.as_str()) {
+                verdict.add(DECISIVE, format!("contains blocked term \"{term}\""));
+            }
+        }
+
+        if self.classifier
+            && let Some(p) = self.spam_probability(&text).await?
+            && p >= CLASSIFIER_SUSPICIOUS
+        {
+            let score = if p >= CLASSIFIER_DECISIVE {
+                DECISIVE
+            } else {
+                SUSPICIOUS
+            };
+            verdict.add(score, format!("classifier: {:.0}% spam", p * 100.0));
+        }
+
+        verdict.quarantine = verdict.score >= self.threshold;
+        Ok(verdict)
+    }
+
+    async fn spam_probability(&self, text: &str) -> Result<Option<f64>, sqlx::Error> {
+        let (spam_docs, ham_docs) = self.repo.documents().await?;
+        if spam_docs < MIN_DOCUMENTS || ham_docs < MIN_DOCUMENTS {
+            return Ok(None);
+        }
+        let tokens = self.repo.tokens(&tokenize(text)).await?;
+        Ok(spam_probability(&tokens, spam_docs, ham_docs))
+    }
+
+    /// Train the classifier with an admin's verdict on a submission, replacing
+    /// their `previous` verdict on it, if any.
+    pub async fn train(
+        &self,
+        text: &[&str],
+        spam: bool,
+        previous: Option<bool>,
+    ) -> Result<(), sqlx::Error> {
+        if previous == Some(spam) {
+            return Ok(());
+        }
+
+        let tokens = tokenize(&text.join("\n"));
+        if let Some(previous) = previous {
+            self.repo
+                .train(&tokens, SpamLabel::new(previous), -1)
+                .await?;
+        }
+        self.repo.train(&tokens, SpamLabel::new(spam), 1).await
+    }
+}
@@ -0,0 +1,74 @@
+use std::error::Error;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+
+/// Whether `ip` is on the public internet. Loopback, private, link-local and
+/// other special-purpose addresses aren't, so webhooks can't be pointed at
+/// services only reachable from inside the network.
+pub fn is_public(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_public_v4(ip),
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(ip) => is_public_v4(ip),
+            None => is_public_v6(ip),
+        },
+    }
+}
+
+fn is_public_v4(ip: Ipv4Addr) -> bool {
+    let [a, b, ..] = ip.octets();
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_broadcast()
+        // "This network" and carrier-grade NAT
+        || a == 0
+        || (a == 100 && (64..128).contains(&b)))
+}
+
+fn is_public_v6(ip: Ipv6Addr) -> bool {
+    let segments = ip.segments();
+    let octets = ip.octets();
+    match segments {
+        // NAT64 and 6to4 reach the IPv4 address they embed
+        [0x64, 0xff9b, 0, 0, 0, 0, ..] => is_public_v4(Ipv4Addr::new(
+            octets[12], octets[13], octets[14], octets[15],
+        )),
+        [0x2002, ..] => is_public_v4(Ipv4Addr::new(octets[2], octets[3], octets[4], octets[5])),
+        // Local-use NAT64
+        [0x64, 0xff9b, 1, ..] => false,
+        _ => {
+            !(ip.is_unspecified()
+                || ip.is_loopback()
+                || ip.is_unique_local()
+                || ip.is_unicast_link_local()
+                || ip.is_multicast()
+                // Deprecated site-local, fec0::/10
+                || segments[0] & 0xffc0 == 0xfec0)
+        }
+    }
+}
+
+/// Resolves host names like the system resolver, leaving out addresses that
+/// aren't public. Names resolving only to such addresses fail to connect, so a
+/// public name later pointed at an internal address is caught too.
+pub struct PublicResolver;
+
+impl Resolve for PublicResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        Box::pin(async move {
+            let addrs: Vec<SocketAddr> = tokio::net::lookup_host((name.as_str(), 0))
+                .await?
+                .filter(|addr| is_public(addr.ip()))
+                .collect();
+            if addrs.is_empty() {
+                let error: Box<dyn Error + Send + Sync> =
+                    format!("{} has no public address", name.as_str()).into();
+                return Err(error);
+            }
+            Ok(Box::new(addrs.into_iter()) as Addrs)
+        })
+    }
+}
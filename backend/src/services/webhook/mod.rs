@@ -0,0 +1,340 @@
+mod address;
+mod signature;
+
+use std::fmt;
+use std::net::IpAddr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use ring::rand::{SecureRandom, SystemRandom};
+use serde_json::json;
+
+use crate::config::Config;
+use crate::dao::blog::BlogPost;
+use crate::dao::webhook::{
+    DeliveryStatus, NewWebhook, Webhook, WebhookDelivery, WebhookEvent, WebhookUpdate,
+};
+use crate::repositories::WebhookRepository;
+use crate::utils::now_timestamp;
+use address::{is_public, PublicResolver};
+
+pub use signature::sign;
+
+pub const EVENT_HEADER: &str = "x-webhook-event";
+pub const DELIVERY_HEADER: &str = "x-webhook-delivery";
+pub const TIMESTAMP_HEADER: &str = "x-webhook-timestamp";
+pub const SIGNATURE_HEADER: &str = "x-webhook-signature";
+
+/// Attempts at a delivery before it's marked failed
+const MAX_ATTEMPTS: i32 = 10;
+/// Wait before the first retry, doubled after every failed attempt
+const RETRY_BASE_SECS: i64 = 30;
+const RETRY_MAX_SECS: i64 = 60 * 60;
+/// Deliveries sent per queue run
+const BATCH_SIZE: i32 = 20;
+
+const DEFAULT_PAGE_SIZE: i32 = 50;
+const MAX_PAGE_SIZE: i32 = 200;
+
+const MAX_URL_CHARS: usize = 2000;
+/// Random bytes in a generated secret
+const SECRET_BYTES: usize = 32;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum WebhookError {
+    InvalidUrl,
+    UrlTooLong(usize),
+    /// The host is a loopback, private or link-local address
+    PrivateAddress,
+    EmptySecret,
+}
+
+impl fmt::Display for WebhookError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidUrl => f.write_str("Webhook URL must be an absolute http or https URL"),
+            Self::UrlTooLong(max) => write!(f, "Webhook URL must be under {max} characters"),
+            Self::PrivateAddress => f.write_str("Webhook URL must point at a public address"),
+            Self::EmptySecret => f.write_str("Webhook secret can't be empty"),
+        }
+    }
+}
+
+/// Check a webhook URL, trimming surrounding whitespace. Unless
+/// `allow_private`, hosts given as an address must be public ones; host names
+/// are checked when they're resolved for each delivery.
+pub fn validate_url(url: &str, allow_private: bool) -> Result<String, WebhookError> {
+    let url = url.trim();
+    if url.chars().count() > MAX_URL_CHARS {
+        return Err(WebhookError::UrlTooLong(MAX_URL_CHARS));
+    }
+
+    let parsed = reqwest::Url::parse(url).map_err(|_| WebhookError::InvalidUrl)?;
+    let Some(host) = parsed.host_str() else {
+        return Err(WebhookError::InvalidUrl);
+    };
+    if !matches!(parsed.scheme(), "http" | "https") {
+        return Err(WebhookError::InvalidUrl);
+    }
+
+    // IPv6 hosts keep their brackets
+    let ip = host.trim_matches(['[', ']']).parse::<IpAddr>().ok();
+    if !allow_private && ip.is_some_and(|ip| !is_public(ip)) {
+        return Err(WebhookError::PrivateAddress);
+    }
+    Ok(url.to_string())
+}
+
+/// Check a secret an admin chose. Surrounding whitespace is kept, since
+/// receivers sign with the secret exactly as given.
+pub fn validate_secret(secret: &str) -> Result<&str, WebhookError> {
+    if secret.trim().is_empty() {
+        return Err(WebhookError::EmptySecret);
+    }
+    Ok(secret)
+}
+
+/// A random secret for a webhook created without one
+pub fn generate_secret() -> String {
+    let mut bytes = [0u8; SECRET_BYTES];
+    SystemRandom::new()
+        .fill(&mut bytes)
+        .expect("Failed to generate random bytes");
+    URL_SAFE_NO_PAD.encode(bytes)
+}
+
+/// Manages webhook subscriptions and delivers blog content events to them.
+///
+/// Events are queued in the delivery table and sent by
+/// [`spawn_webhook_delivery`], so a slow or unreachable endpoint never holds
+/// up an edit. Queueing is best effort: failing to queue an event is logged,
+/// not returned, since the edit itself already happened.
+pub struct WebhookService {
+    repo: WebhookRepository,
+    http_client: reqwest::Client,
+    allow_private: bool,
+}
+
+impl WebhookService {
+    /// Redirects aren't followed, since they could lead anywhere. Unless
+    /// `WEBHOOK_ALLOW_PRIVATE_URLS` is set, deliveries only go to public
+    /// addresses, whatever a host name resolves to at the time.
+    pub fn new(repo: WebhookRepository, config: &Config) -> Self {
+        let allow_private = config.webhook_allow_private_urls;
+        let mut builder = reqwest::Client::builder()
+            .timeout(Duration::from_secs(config.webhook_timeout_secs))
+            .redirect(reqwest::redirect::Policy::none());
+        if !allow_private {
+            builder = builder.dns_resolver(Arc::new(PublicResolver));
+        }
+        let http_client = builder
+            .build()
+            .expect("Failed to build webhook HTTP client");
+        Self {
+            repo,
+            http_client,
+            allow_private,
+        }
+    }
+
+    pub async fn list(&self) -> Result<Vec<Webhook>, sqlx::Error> {
+        self.repo.list().await
+    }
+
+    pub async fn get(&self, id: i64) -> Result<Option<Webhook>, sqlx::Error> {
+        self.repo.get(id).await
+    }
+
+    /// Store a subscription checked by [`validate_url`]. Returns its id.
+    pub async fn create(
+        &self,
+        webhook: &NewWebhook,
+        created_by: Option<&str>,
+    ) -> Result<i64, sqlx::Error> {
+        self.repo.create(webhook, created_by).await
+    }
+
+    pub async fn update(&self, id: i64, changes: WebhookUpdate<'_>) -> Result<bool, sqlx::Error> {
+        self.repo.update(id, changes).await
+    }
+
+    pub async fn delete(&self, id: i64) -> Result<bool, sqlx::Error> {
+        self.repo.delete(id).await
+    }
+
+    /// Deliveries to a webhook, newest first, with the total number of matches.
+    pub async fn list_deliveries(
+        &self,
+        webhook_id: i64,
+        status: Option<DeliveryStatus>,
+        limit: i32,
+        offset: i32,
+    ) -> Result<(Vec<WebhookDelivery>, i32), sqlx::Error> {
+        let limit = if limit <= 0 {
+            DEFAULT_PAGE_SIZE
+        } else {
+            limit.min(MAX_PAGE_SIZE)
+        };
+        self.repo
+            .list_deliveries(webhook_id, status, limit, offset.max(0))
+            .await
+    }
+
+    /// Queue a past delivery's payload to be sent again, signed with the
+    /// webhook's current secret. Returns the new delivery's id, or `None` if
+    /// the delivery doesn't exist.
+    pub async fn redeliver(&self, delivery_id: i64) -> Result<Option<i64>, sqlx::Error> {
+        let Some(delivery) = self.repo.get_delivery(delivery_id).await? else {
+            return Ok(None);
+        };
+
+        let id = self
+            .repo
+            .enqueue(
+                delivery.webhook_id,
+                delivery.event,
+                &delivery.payload,
+                Some(delivery.id),
+            )
+            .await?;
+        Ok(Some(id))
+    }
+
+    /// Queue `event` about `post` for every active webhook subscribed to it.
+    /// `actor_sub` is whoever made the change, or `None` for the scheduler.
+    pub async fn dispatch(&self, event: WebhookEvent, post: &BlogPost, actor_sub: Option<&str>) {
+        if let Err(e) = self.try_dispatch(event, post, actor_sub).await {
+            tracing::warn!("Failed to queue {} webhooks: {:?}", event.as_str(), e);
+        }
+    }
+
+    async fn try_dispatch(
+        &self,
+        event: WebhookEvent,
+        post: &BlogPost,
+        actor_sub: Option<&str>,
+    ) -> Result<(), sqlx::Error> {
+        let webhooks = self.repo.list_active().await?;
+        if !webhooks.iter().any(|webhook| webhook.subscribes_to(event)) {
+            return Ok(());
+        }
+
+        let payload = json!({
+            "event": event,
+            "occurred_at": now_timestamp(),
+            "actor": actor_sub,
+            "post": post,
+        })
+        .to_string();
+
+        for webhook in webhooks
+            .iter()
+            .filter(|webhook| webhook.subscribes_to(event))
+        {
+            self.repo.enqueue(webhook.id, event, &payload, None).await?;
+        }
+        Ok(())
+    }
+
+    /// Send the deliveries that are due. Failed attempts are retried with
+    /// exponential backoff, up to `MAX_ATTEMPTS`. Returns how many went through.
+    pub async fn deliver_due(&self) -> Result<usize, sqlx::Error> {
+        let now = now_timestamp();
+        let mut delivered = 0;
+        for delivery in self.repo.due(now, BATCH_SIZE).await? {
+            let webhook = self.repo.get(delivery.webhook_id).await?;
+            let result = match &webhook {
+                Some(webhook) if webhook.active => self.send(webhook, &delivery, now).await,
+                // Disabled, or deleted since the batch was fetched
+                _ => {
+                    self.repo
+                        .mark_attempt_failed(delivery.id, None, "Webhook is disabled", None)
+                        .await?;
+                    continue;
+                }
+            };
+
+            match result {
+                Ok(status) => {
+                    self.repo.mark_delivered(delivery.id, status).await?;
+                    delivered += 1;
+                }
+                Err((status, error)) => {
+                    let attempts = delivery.attempts + 1;
+                    let retry_at = (attempts < MAX_ATTEMPTS).then(|| {
+                        let backoff = RETRY_BASE_SECS << (attempts - 1).min(16);
+                        now + backoff.min(RETRY_MAX_SECS)
+                    });
+                    tracing::warn!(
+                        "Failed to deliver {} webhook {} to {} (attempt {}): {}",
+                        delivery.event.as_str(),
+                        delivery.id,
+                        delivery.webhook_id,
+                        attempts,
+                        error
+                    );
+                    self.repo
+                        .mark_attempt_failed(delivery.id, status, &error, retry_at)
+                        .await?;
+                }
+            }
+        }
+        Ok(delivered)
+    }
+
+    /// POST a delivery's payload. Returns the response status if it's a
+    /// success, or the status (if any) and an error message if not.
+    async fn send(
+        &self,
+        webhook: &Webhook,
+        delivery: &WebhookDelivery,
+        now: i64,
+    ) -> Result<i32, (Option<i32>, String)> {
+        // Subscriptions made while private addresses were allowed
+        validate_url(&webhook.url, self.allow_private).map_err(|e| (None, e.to_string()))?;
+
+        let response = self
+            .http_client
+            .post(&webhook.url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header(EVENT_HEADER, delivery.event.as_str())
+            .header(DELIVERY_HEADER, delivery.id.to_string())
+            .header(TIMESTAMP_HEADER, now.to_string())
+            .header(
+                SIGNATURE_HEADER,
+                sign(&webhook.secret, now, &delivery.payload),
+            )
+            .body(delivery.payload.clone())
+            .send()
+            .await
+            .map_err(|e| (None, format!("Request failed: {e}")))?;
+
+        let status = response.status();
+        if status.is_success() {
+            Ok(status.as_u16().into())
+        } else {
+            Err((Some(status.as_u16().into()), format!("HTTP {status}")))
+        }
+    }
+}
+
+/// Spawn the background task that sends queued webhook deliveries every
+/// `interval`. Runs for the life of the process.
+pub fn spawn_webhook_delivery(webhooks: Arc<WebhookService>, interval: Duration) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+
+            match webhooks.deliver_due().await {
+                Ok(0) => {}
+                Ok(count) => tracing::info!("Delivered {} webhooks", count),
+                Err(e) => tracing::warn!("Failed to deliver webhooks: {:?}", e),
+            }
+        }
+    });
+}
@@ -0,0 +1,11 @@
+use ring::hmac;
+
+/// Value of the `X-Webhook-Signature` header: `sha256=` and the hex
+/// HMAC-SHA256 of `"{timestamp}.{body}"` keyed with the webhook's secret.
+/// Signing the timestamp along with the body lets receivers reject replays.
+pub fn sign(secret: &str, timestamp: i64, body: &str) -> String {
+    let key = hmac::Key::new(hmac::HMAC_SHA256, secret.as_bytes());
+    let tag = hmac::sign(&key, format!("{timestamp}.{body}").as_bytes());
+    let hex: String = tag.as_ref().iter().map(|b| format!("{b:02x}")).collect();
+    format!("sha256={hex}")
+}
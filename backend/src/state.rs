@@ -2,25 +2,69 @@ use std::sync::Arc;
 
 use crate::config::Config;
 use crate::db::DbPool;
-use crate::repositories::BlogRepository;
-use crate::services::{BlogService, FirebaseAuthService};
+use crate::repositories::{
+    AuditRepository, BlogRepository, EmailOutboxRepository, InquiryRepository, RateLimitRepository,
+    SpamRepository, WebhookRepository,
+};
+use crate::services::identity::IdentityProvider;
+use crate::services::{
+    AuditService, BlogService, InquiryService, Notifier, RateLimiter, SitemapService, SpamFilter,
+    WebhookService,
+};
 
 #[derive(Clone)]
 pub struct AppState {
-    pub firebase_auth: Arc<FirebaseAuthService>,
+    pub config: Arc<Config>,
+    pub identity: Arc<dyn IdentityProvider>,
     pub blog_service: Arc<BlogService>,
+    pub sitemap_service: Arc<SitemapService>,
+    pub inquiry_service: Arc<InquiryService>,
+    pub audit_service: Arc<AuditService>,
+    pub rate_limiter: Arc<RateLimiter>,
+    pub spam_filter: Arc<SpamFilter>,
+    pub notifier: Arc<Notifier>,
+    pub webhook_service: Arc<WebhookService>,
 }
 
 impl AppState {
-    pub fn new(config: Config, pool: DbPool) -> Self {
-        let firebase_auth = Arc::new(FirebaseAuthService::new(
-            config.firebase_project_id,
-            config.firebase_api_key,
+    pub fn new(config: Config, pool: DbPool, identity: Arc<dyn IdentityProvider>) -> Self {
+        let webhook_service = Arc::new(WebhookService::new(
+            WebhookRepository::new(pool.clone()),
+            &config,
+        ));
+        let blog_service = Arc::new(BlogService::new(
+            BlogRepository::new(pool.clone()),
+            webhook_service.clone(),
+        ));
+        let sitemap_service = Arc::new(SitemapService::new(blog_service.clone(), &config));
+        let rate_limit_repo = config
+            .rate_limit_persist
+            .then(|| RateLimitRepository::new(pool.clone()));
+        let rate_limiter = Arc::new(RateLimiter::new(rate_limit_repo));
+        let spam_filter = Arc::new(SpamFilter::new(
+            SpamRepository::new(pool.clone()),
+            rate_limiter.clone(),
+            &config,
+        ));
+        let notifier = Arc::new(Notifier::new(
+            EmailOutboxRepository::new(pool.clone()),
+            &config,
         ));
-        let blog_service = Arc::new(BlogService::new(BlogRepository::new(pool)));
         Self {
-            firebase_auth,
+            config: Arc::new(config),
+            identity,
             blog_service,
+            sitemap_service,
+            inquiry_service: Arc::new(InquiryService::new(
+                InquiryRepository::new(pool.clone()),
+                spam_filter.clone(),
+                notifier.clone(),
+            )),
+            audit_service: Arc::new(AuditService::new(AuditRepository::new(pool))),
+            rate_limiter,
+            spam_filter,
+            notifier,
+            webhook_service,
         }
     }
 }
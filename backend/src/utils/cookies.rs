@@ -1,3 +1,5 @@
+use http::header::SET_COOKIE;
+
 pub const ID_TOKEN_COOKIE: &str = "id_token";
 pub const REFRESH_TOKEN_COOKIE: &str = "refresh_token";
 /// Refresh tokens don't expire, but we set a reasonable max age (30 days)
@@ -8,9 +10,7 @@ pub fn parse_cookie(cookie_header: &str, name: &str) -> Option<String> {
     cookie_header
         .split(';')
         .filter_map(|part| {
-            let mut parts = part.trim().splitn(2, '=');
-            let key = parts.next()?;
-            let value = parts.next()?;
+            let (key, value) = part.trim().split_once('=')?;
             if key == name {
                 Some(value.to_string())
             } else {
@@ -77,3 +77,25 @@ pub fn clear_grpc_auth_cookies<T>(response: &mut tonic::Response<T>) {
         .metadata_mut()
         .append("set-cookie", refresh_cookie.parse().unwrap());
 }
+
+/// Set auth cookies on an HTTP response
+pub fn set_auth_cookies(
+    response: &mut axum::response::Response,
+    id_token: &str,
+    refresh_token: &str,
+    id_token_expires_in: u64,
+) {
+    let (id_cookie, refresh_cookie) =
+        build_auth_cookies(id_token, refresh_token, id_token_expires_in);
+    let headers = response.headers_mut();
+    headers.append(SET_COOKIE, id_cookie.parse().unwrap());
+    headers.append(SET_COOKIE, refresh_cookie.parse().unwrap());
+}
+
+/// Clear auth cookies on an HTTP response
+pub fn clear_auth_cookies(response: &mut axum::response::Response) {
+    let (id_cookie, refresh_cookie) = build_clear_auth_cookies();
+    let headers = response.headers_mut();
+    headers.append(SET_COOKIE, id_cookie.parse().unwrap());
+    headers.append(SET_COOKIE, refresh_cookie.parse().unwrap());
+}
@@ -0,0 +1,124 @@
+use std::collections::HashSet;
+use std::sync::LazyLock;
+
+use ammonia::Builder;
+use pulldown_cmark::{html, CowStr, Event, Options, Parser, Tag, TagEnd};
+
+use super::slugify;
+use crate::dao::blog::TocEntry;
+
+/// Average adult silent reading speed
+const WORDS_PER_MINUTE: usize = 200;
+
+/// Prefix for every id in rendered HTML, so post content can't clobber page
+/// element ids or named globals
+const ID_PREFIX: &str = "user-content-";
+
+static SANITIZER: LazyLock<Builder<'static>> = LazyLock::new(sanitizer);
+
+/// A Markdown body rendered for display.
+#[derive(Debug, Clone)]
+pub struct RenderedMarkdown {
+    pub html: String,
+    pub toc: Vec<TocEntry>,
+    pub reading_minutes: i32,
+}
+
+/// Render CommonMark with GFM extensions to sanitized HTML. Every heading gets a
+/// unique anchor id, collected in order into the table of contents.
+pub fn render_markdown(source: &str) -> RenderedMarkdown {
+    let mut options = Options::empty();
+    options.insert(Options::ENABLE_TABLES);
+    options.insert(Options::ENABLE_FOOTNOTES);
+    options.insert(Options::ENABLE_STRIKETHROUGH);
+    options.insert(Options::ENABLE_TASKLISTS);
+    options.insert(Options::ENABLE_GFM);
+
+    let mut events: Vec<Event> = Parser::new_ext(source, options).collect();
+
+    let mut toc = Vec::new();
+    let mut anchors = HashSet::new();
+    let mut words = 0;
+    let mut heading: Option<(usize, String)> = None;
+
+    for i in 0..events.len() {
+        match &events[i] {
+            Event::Start(Tag::Heading { .. }) => heading = Some((i, String::new())),
+            Event::Text(text) | Event::Code(text) => {
+                words += text.split_whitespace().count();
+                if let Some((_, title)) = &mut heading {
+                    title.push_str(text);
+                }
+            }
+            Event::End(TagEnd::Heading(level)) => {
+                let level = *level as i32;
+                if let Some((start, title)) = heading.take() {
+                    let anchor = unique_anchor(&mut anchors, &title);
+                    if let Event::Start(Tag::Heading { id, .. }) = &mut events[start] {
+                        *id = Some(CowStr::from(anchor.clone()));
+                    }
+                    toc.push(TocEntry {
+                        level,
+                        anchor,
+                        title,
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut unsafe_html = String::with_capacity(source.len() * 3 / 2);
+    html::push_html(&mut unsafe_html, events.into_iter());
+
+    RenderedMarkdown {
+        html: SANITIZER.clean(&unsafe_html).to_string(),
+        toc,
+        reading_minutes: words.div_ceil(WORDS_PER_MINUTE).max(1) as i32,
+    }
+}
+
+fn unique_anchor(taken: &mut HashSet<String>, title: &str) -> String {
+    let base = match slugify(title) {
+        slug if slug.is_empty() => "section".to_string(),
+        slug => slug,
+    };
+
+    let mut anchor = format!("{ID_PREFIX}{base}");
+    let mut suffix = 2;
+    while !taken.insert(anchor.clone()) {
+        anchor = format!("{ID_PREFIX}{base}-{suffix}");
+        suffix += 1;
+    }
+    anchor
+}
+
+/// Ammonia's defaults plus the attributes the Markdown renderer relies on:
+/// heading anchors, code languages, footnote ids and task list checkboxes.
+/// Ids are prefixed, with in-page links rewritten to match, and every input
+/// is forced to a disabled checkbox.
+fn sanitizer() -> Builder<'static> {
+    let mut builder = Builder::default();
+    builder
+        .id_prefix(Some(ID_PREFIX))
+        .attribute_filter(|element, attribute, value| match (element, attribute) {
+            ("a", "href") => match value.strip_prefix('#') {
+                Some(fragment) if !fragment.starts_with(ID_PREFIX) => {
+                    Some(format!("#{ID_PREFIX}{fragment}").into())
+                }
+                _ => Some(value.into()),
+            },
+            _ => Some(value.into()),
+        })
+        .add_tags(["input"])
+        .add_tag_attributes("input", ["checked"])
+        .set_tag_attribute_value("input", "type", "checkbox")
+        .set_tag_attribute_value("input", "disabled", "")
+        .add_tag_attributes("code", ["class"])
+        .add_tag_attributes("div", ["class", "id"])
+        .add_tag_attributes("sup", ["class"]);
+    for heading in ["h1", "h2", "h3", "h4", "h5", "h6"] {
+        builder.add_tag_attributes(heading, ["id"]);
+    }
+    builder
+}
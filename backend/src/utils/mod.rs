@@ -1,6 +1,12 @@
 pub mod cookies;
+pub mod markdown;
+pub mod slug;
+pub mod time;
 
 pub use cookies::{
-    clear_grpc_auth_cookies, parse_cookie, set_grpc_auth_cookies, ID_TOKEN_COOKIE,
-    REFRESH_TOKEN_COOKIE,
+    clear_auth_cookies, clear_grpc_auth_cookies, parse_cookie, set_auth_cookies,
+    set_grpc_auth_cookies, ID_TOKEN_COOKIE, REFRESH_TOKEN_COOKIE, REFRESH_TOKEN_MAX_AGE,
 };
+pub use markdown::render_markdown;
+pub use slug::slugify;
+pub use time::{http_date, now_timestamp, parse_http_date, rfc2822, rfc3339};
@@ -0,0 +1,22 @@
+/// Longest slug generated from a title, before any uniqueness suffix
+const MAX_SLUG_LEN: usize = 80;
+
+/// Turn arbitrary text into a URL slug: lowercase ASCII letters and digits
+/// separated by single dashes. Returns an empty string if nothing usable remains.
+pub fn slugify(text: &str) -> String {
+    let mut slug = String::with_capacity(text.len().min(MAX_SLUG_LEN));
+
+    for ch in text.chars() {
+        if ch.is_ascii_alphanumeric() {
+            slug.push(ch.to_ascii_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+
+    if slug.len() > MAX_SLUG_LEN {
+        slug.truncate(MAX_SLUG_LEN);
+    }
+
+    slug.trim_end_matches('-').to_string()
+}
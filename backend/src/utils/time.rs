@@ -0,0 +1,39 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use chrono::{DateTime, Utc};
+
+/// Current Unix timestamp in seconds
+pub fn now_timestamp() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64
+}
+
+fn datetime(timestamp: i64) -> DateTime<Utc> {
+    DateTime::from_timestamp(timestamp, 0).unwrap_or_default()
+}
+
+/// RFC 3339 date-time, as used by Atom and JSON Feed
+pub fn rfc3339(timestamp: i64) -> String {
+    datetime(timestamp).to_rfc3339()
+}
+
+/// RFC 2822 date-time, as used by RSS
+pub fn rfc2822(timestamp: i64) -> String {
+    datetime(timestamp).to_rfc2822()
+}
+
+/// HTTP date for headers such as `Last-Modified`
+pub fn http_date(timestamp: i64) -> String {
+    datetime(timestamp)
+        .format("%a, %d %b %Y %H:%M:%S GMT")
+        .to_string()
+}
+
+/// Parse an HTTP date header value into a Unix timestamp.
+pub fn parse_http_date(value: &str) -> Option<i64> {
+    DateTime::parse_from_rfc2822(value)
+        .ok()
+        .map(|date| date.timestamp())
+}
@@ -0,0 +1,69 @@
+use axum::body::Body;
+use axum::http::{header, HeaderMap, HeaderValue, StatusCode};
+use axum::response::{IntoResponse, Response};
+use ring::digest::{digest, SHA256};
+
+use crate::utils::{http_date, parse_http_date};
+
+/// How long clients and proxies may reuse a generated document without asking
+const MAX_AGE_SECS: u32 = 300;
+
+/// Respond with a generated document, or `304 Not Modified` if the client's copy
+/// is current. `If-None-Match` is checked against a SHA-256 prefix of the body,
+/// stable across restarts and builds, and wins over `If-Modified-Since`, which
+/// is compared with `last_modified`.
+pub fn cached_response(
+    headers: &HeaderMap,
+    content_type: &'static str,
+    body: String,
+    last_modified: i64,
+) -> Response {
+    let hash = digest(&SHA256, body.as_bytes());
+    let hex: String = hash.as_ref()[..16]
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect();
+    let etag = format!("\"{hex}\"");
+
+    let not_modified = match headers.get(header::IF_NONE_MATCH) {
+        Some(value) => value
+            .to_str()
+            .map(|tags| etag_matches(tags, &etag))
+            .unwrap_or(false),
+        None => headers
+            .get(header::IF_MODIFIED_SINCE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(parse_http_date)
+            .is_some_and(|since| last_modified <= since),
+    };
+
+    let mut response = if not_modified {
+        StatusCode::NOT_MODIFIED.into_response()
+    } else {
+        let mut response = Response::new(Body::from(body));
+        response
+            .headers_mut()
+            .insert(header::CONTENT_TYPE, HeaderValue::from_static(content_type));
+        response
+    };
+
+    let headers = response.headers_mut();
+    if let Ok(value) = HeaderValue::from_str(&etag) {
+        headers.insert(header::ETAG, value);
+    }
+    if let Ok(value) = HeaderValue::from_str(&http_date(last_modified)) {
+        headers.insert(header::LAST_MODIFIED, value);
+    }
+    if let Ok(value) = HeaderValue::from_str(&format!("public, max-age={MAX_AGE_SECS}")) {
+        headers.insert(header::CACHE_CONTROL, value);
+    }
+
+    response
+}
+
+/// Weak comparison of an `If-None-Match` list against our entity tag.
+fn etag_matches(header: &str, etag: &str) -> bool {
+    header.split(',').map(str::trim).any(|candidate| {
+        candidate == "*" || candidate.strip_prefix("W/").unwrap_or(candidate) == etag
+    })
+}
@@ -0,0 +1,45 @@
+use std::fmt::Write;
+
+use quick_xml::escape::escape;
+
+use super::Feed;
+use crate::utils::rfc3339;
+
+/// Render an Atom 1.0 feed with full post content.
+pub(super) fn render(feed: &Feed) -> String {
+    let mut xml = String::from(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    xml.push_str(r#"<feed xmlns="http://www.w3.org/2005/Atom">"#);
+
+    let _ = write!(
+        xml,
+        r#"<id>{}</id><title>{}</title><subtitle>{}</subtitle><updated>{}</updated><link rel="self" href="{}"/><link rel="alternate" type="text/html" href="{}"/><author><name>{}</name></author>"#,
+        escape(&feed.self_url),
+        escape(&feed.title),
+        escape(&feed.description),
+        rfc3339(feed.updated),
+        escape(&feed.self_url),
+        escape(&feed.home_url),
+        escape(&feed.title),
+    );
+
+    for post in &feed.posts {
+        let _ = write!(
+            xml,
+            r#"<entry><id>{}</id><title>{}</title><link rel="alternate" type="text/html" href="{}"/><published>{}</published><updated>{}</updated><summary>{}</summary><content type="html">{}</content>"#,
+            escape(feed.post_id(post)),
+            escape(&post.title),
+            escape(feed.post_url(post)),
+            rfc3339(post.published_at.unwrap_or(post.created_at)),
+            rfc3339(post.updated_at),
+            escape(&post.description),
+            escape(&post.body_html),
+        );
+        for tag in &post.tags {
+            let _ = write!(xml, r#"<category term="{}"/>"#, escape(tag));
+        }
+        xml.push_str("</entry>");
+    }
+
+    xml.push_str("</feed>");
+    xml
+}
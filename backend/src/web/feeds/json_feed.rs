@@ -0,0 +1,58 @@
+use serde::Serialize;
+
+use super::Feed;
+use crate::utils::rfc3339;
+
+const VERSION: &str = "https://jsonfeed.org/version/1.1";
+
+#[derive(Serialize)]
+struct JsonFeed<'a> {
+    version: &'static str,
+    title: &'a str,
+    description: &'a str,
+    home_page_url: &'a str,
+    feed_url: &'a str,
+    items: Vec<JsonFeedItem<'a>>,
+}
+
+#[derive(Serialize)]
+struct JsonFeedItem<'a> {
+    id: String,
+    url: String,
+    title: &'a str,
+    summary: &'a str,
+    content_html: &'a str,
+    date_published: String,
+    date_modified: String,
+    #[serde(skip_serializing_if = "<[String]>::is_empty")]
+    tags: &'a [String],
+}
+
+/// Render a JSON Feed 1.1 document with full post content.
+pub(super) fn render(feed: &Feed) -> String {
+    let items = feed
+        .posts
+        .iter()
+        .map(|post| JsonFeedItem {
+            id: feed.post_id(post),
+            url: feed.post_url(post),
+            title: &post.title,
+            summary: &post.description,
+            content_html: &post.body_html,
+            date_published: rfc3339(post.published_at.unwrap_or(post.created_at)),
+            date_modified: rfc3339(post.updated_at),
+            tags: &post.tags,
+        })
+        .collect();
+
+    let json = JsonFeed {
+        version: VERSION,
+        title: &feed.title,
+        description: &feed.description,
+        home_page_url: &feed.home_url,
+        feed_url: &feed.self_url,
+        items,
+    };
+
+    serde_json::to_string(&json).unwrap_or_default()
+}
@@ -0,0 +1,204 @@
+mod atom;
+mod json_feed;
+mod rss;
+
+use axum::extract::{Path, State};
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::Response;
+use axum::routing::get;
+use axum::Router;
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+
+use super::conditional::cached_response;
+use crate::dao::blog::BlogPost;
+use crate::services::blog::normalize_tag;
+use crate::state::AppState;
+
+/// Number of most recent posts included in a feed
+const FEED_LIMIT: i32 = 50;
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/feed.xml", get(rss_feed))
+        .route("/atom.xml", get(atom_feed))
+        .route("/feed.json", get(json_feed))
+        .route("/tags/{tag}/feed.xml", get(tag_rss_feed))
+        .route("/tags/{tag}/atom.xml", get(tag_atom_feed))
+        .route("/tags/{tag}/feed.json", get(tag_json_feed))
+}
+
+#[derive(Debug, Clone, Copy)]
+enum FeedFormat {
+    Rss,
+    Atom,
+    Json,
+}
+
+impl FeedFormat {
+    fn file_name(self) -> &'static str {
+        match self {
+            Self::Rss => "feed.xml",
+            Self::Atom => "atom.xml",
+            Self::Json => "feed.json",
+        }
+    }
+
+    fn content_type(self) -> &'static str {
+        match self {
+            Self::Rss => "application/rss+xml; charset=utf-8",
+            Self::Atom => "application/atom+xml; charset=utf-8",
+            Self::Json => "application/feed+json; charset=utf-8",
+        }
+    }
+}
+
+/// Everything a feed document is rendered from.
+struct Feed {
+    title: String,
+    description: String,
+    /// Page the feed mirrors on the site
+    home_url: String,
+    /// Where the feed itself is served
+    self_url: String,
+    site_url: String,
+    /// Newest `updated_at` among the posts
+    updated: i64,
+    posts: Vec<BlogPost>,
+}
+
+impl Feed {
+    fn post_url(&self, post: &BlogPost) -> String {
+        format!("{}/blog/{}", self.site_url, post.slug)
+    }
+
+    /// Stable identifier for a post; unlike its URL it survives slug changes.
+    fn post_id(&self, post: &BlogPost) -> String {
+        format!("{}/blog?id={}", self.site_url, post.id)
+    }
+}
+
+async fn rss_feed(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<Response, StatusCode> {
+    serve_feed(&state, &headers, FeedFormat::Rss, None).await
+}
+
+async fn atom_feed(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<Response, StatusCode> {
+    serve_feed(&state, &headers, FeedFormat::Atom, None).await
+}
+
+async fn json_feed(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<Response, StatusCode> {
+    serve_feed(&state, &headers, FeedFormat::Json, None).await
+}
+
+async fn tag_rss_feed(
+    State(state): State<AppState>,
+    Path(tag): Path<String>,
+    headers: HeaderMap,
+) -> Result<Response, StatusCode> {
+    serve_feed(&state, &headers, FeedFormat::Rss, Some(&tag)).await
+}
+
+async fn tag_atom_feed(
+    State(state): State<AppState>,
+    Path(tag): Path<String>,
+    headers: HeaderMap,
+) -> Result<Response, StatusCode> {
+    serve_feed(&state, &headers, FeedFormat::Atom, Some(&tag)).await
+}
+
+async fn tag_json_feed(
+    State(state): State<AppState>,
+    Path(tag): Path<String>,
+    headers: HeaderMap,
+) -> Result<Response, StatusCode> {
+    serve_feed(&state, &headers, FeedFormat::Json, Some(&tag)).await
+}
+
+async fn serve_feed(
+    state: &AppState,
+    headers: &HeaderMap,
+    format: FeedFormat,
+    tag: Option<&str>,
+) -> Result<Response, StatusCode> {
+    let tag = match tag {
+        Some(raw) => {
+            let tag = normalize_tag(raw).ok_or(StatusCode::NOT_FOUND)?;
+            let exists = state
+                .blog_service
+                .tag_exists(&tag)
+                .await
+                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+            if !exists {
+                return Err(StatusCode::NOT_FOUND);
+            }
+            Some(tag)
+        }
+        None => None,
+    };
+
+    let posts = state
+        .blog_service
+        .list_published(tag.as_deref(), FEED_LIMIT)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let last_modified = state
+        .blog_service
+        .last_modified()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let config = &state.config;
+    let updated = posts
+        .iter()
+        .map(|p| p.updated_at)
+        .max()
+        .unwrap_or(last_modified);
+    let feed = match &tag {
+        Some(tag) => {
+            let encoded = utf8_percent_encode(tag, NON_ALPHANUMERIC);
+            Feed {
+                title: format!("{} - {tag}", config.site_title),
+                description: format!("Posts tagged {tag} on {}", config.site_title),
+                home_url: format!("{}/blog?tag={encoded}", config.site_url),
+                self_url: format!(
+                    "{}/tags/{encoded}/{}",
+                    config.public_url,
+                    format.file_name()
+                ),
+                site_url: config.site_url.clone(),
+                updated,
+                posts,
+            }
+        }
+        None => Feed {
+            title: config.site_title.clone(),
+            description: format!("Latest posts on {}", config.site_title),
+            home_url: format!("{}/blog", config.site_url),
+            self_url: format!("{}/{}", config.public_url, format.file_name()),
+            site_url: config.site_url.clone(),
+            updated,
+            posts,
+        },
+    };
+
+    let body = match format {
+        FeedFormat::Rss => rss::render(&feed),
+        FeedFormat::Atom => atom::render(&feed),
+        FeedFormat::Json => json_feed::render(&feed),
+    };
+
+    Ok(cached_response(
+        headers,
+        format.content_type(),
+        body,
+        last_modified,
+    ))
+}
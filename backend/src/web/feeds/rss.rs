@@ -0,0 +1,44 @@
+use std::fmt::Write;
+
+use quick_xml::escape::escape;
+
+use super::Feed;
+use crate::utils::rfc2822;
+
+/// Render an RSS 2.0 feed with full post content in `content:encoded`.
+pub(super) fn render(feed: &Feed) -> String {
+    let mut xml = String::from(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    xml.push_str(
+        r#"<rss version="2.0" xmlns:atom="http://www.w3.org/2005/Atom" xmlns:content="http://purl.org/rss/1.0/modules/content/"><channel>"#,
+    );
+
+    let _ = write!(
+        xml,
+        r#"<title>{}</title><link>{}</link><description>{}</description><atom:link href="{}" rel="self" type="application/rss+xml"/><lastBuildDate>{}</lastBuildDate>"#,
+        escape(&feed.title),
+        escape(&feed.home_url),
+        escape(&feed.description),
+        escape(&feed.self_url),
+        rfc2822(feed.updated),
+    );
+
+    for post in &feed.posts {
+        let _ = write!(
+            xml,
+            r#"<item><title>{}</title><link>{}</link><guid isPermaLink="false">{}</guid><pubDate>{}</pubDate><description>{}</description><content:encoded>{}</content:encoded>"#,
+            escape(&post.title),
+            escape(feed.post_url(post)),
+            escape(feed.post_id(post)),
+            rfc2822(post.published_at.unwrap_or(post.created_at)),
+            escape(&post.description),
+            escape(&post.body_html),
+        );
+        for tag in &post.tags {
+            let _ = write!(xml, "<category>{}</category>", escape(tag));
+        }
+        xml.push_str("</item>");
+    }
+
+    xml.push_str("</channel></rss>");
+    xml
+}
@@ -0,0 +1,16 @@
+mod conditional;
+mod feeds;
+mod sitemap;
+
+use axum::Router;
+
+use crate::state::AppState;
+
+/// Plain HTTP endpoints served next to the gRPC services, for clients that don't
+/// speak gRPC-Web such as feed readers and crawlers.
+pub fn router(state: AppState) -> Router {
+    Router::new()
+        .merge(feeds::router())
+        .merge(sitemap::router())
+        .with_state(state)
+}
@@ -0,0 +1,82 @@
+use axum::extract::{Path, State};
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::Response;
+use axum::routing::get;
+use axum::Router;
+
+use super::conditional::cached_response;
+use crate::state::AppState;
+
+const XML: &str = "application/xml; charset=utf-8";
+const TEXT: &str = "text/plain; charset=utf-8";
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/sitemap.xml", get(sitemap))
+        .route("/sitemaps/{file}", get(sitemap_page))
+        .route("/robots.txt", get(robots))
+}
+
+async fn sitemap(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<Response, StatusCode> {
+    let index = state
+        .sitemap_service
+        .index()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(cached_response(
+        &headers,
+        XML,
+        index.sitemap.clone(),
+        index.last_modified,
+    ))
+}
+
+/// A numbered child sitemap, e.g. `/sitemaps/2.xml`, once the sitemap is split.
+async fn sitemap_page(
+    State(state): State<AppState>,
+    Path(file): Path<String>,
+    headers: HeaderMap,
+) -> Result<Response, StatusCode> {
+    let n: usize = file
+        .strip_suffix(".xml")
+        .and_then(|n| n.parse().ok())
+        .ok_or(StatusCode::NOT_FOUND)?;
+
+    let index = state
+        .sitemap_service
+        .index()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let page = n
+        .checked_sub(1)
+        .and_then(|i| index.pages.get(i))
+        .ok_or(StatusCode::NOT_FOUND)?;
+
+    Ok(cached_response(
+        &headers,
+        XML,
+        page.clone(),
+        index.last_modified,
+    ))
+}
+
+/// robots.txt for this host, the backend: crawlers may fetch the sitemap and
+/// feeds but not the API. The frontend host serves its own.
+async fn robots(State(state): State<AppState>, headers: HeaderMap) -> Result<Response, StatusCode> {
+    let index = state
+        .sitemap_service
+        .index()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(cached_response(
+        &headers,
+        TEXT,
+        index.robots.clone(),
+        index.last_modified,
+    ))
+}
@@ -0,0 +1,424 @@
+//! `AuditLayer` recording admin RPCs and login attempts, called over the
+//! gRPC wire format the way `main` stacks the layers.
+
+mod common;
+
+use axum::body::Body;
+use axum::http::header::{AUTHORIZATION, CONTENT_TYPE};
+use axum::http::StatusCode;
+use prost::Message;
+use proto::auth::auth_service_server::AuthServiceServer;
+use proto::auth::LoginRequest;
+use proto::blog::blog_admin_service_server::{BlogAdminService, BlogAdminServiceServer};
+use proto::blog::{
+    AuditEvent, CreateBlogPostRequest, DeleteBlogPostRequest, GetBlogPostRequest,
+    ListAuditEventsRequest, UpdateBlogPostRequest,
+};
+use serde_json::{json, Value};
+use tonic::Code;
+use tower::{Layer, ServiceExt};
+
+use backend::api;
+
+use backend::grpc::{
+    AuthController, BlogAdminController, AUTH_AUDIT, BLOG_ADMIN_AUDIT, BLOG_ADMIN_PERMISSIONS,
+};
+use backend::middleware::{AuditLayer, AuthorizeLayer};
+use backend::state::AppState;
+use common::{as_admin, claims, MockFirebase, KEY_1};
+
+const EMAIL: &str = "admin@example.com";
+const PASSWORD: &str = "correct horse";
+
+async fn setup() -> (MockFirebase, AppState) {
+    let firebase = MockFirebase::start().await;
+    firebase.add_account(EMAIL, PASSWORD);
+    let state = common::app_state(firebase.config()).await;
+    (firebase, state)
+}
+
+/// Call the admin service through authorization and auditing, as `main` does
+async fn admin_call(state: &AppState, token: &str, rpc: &str, message: impl Message) -> Code {
+    let service = AuthorizeLayer::new(state.clone(), BLOG_ADMIN_PERMISSIONS).layer(
+        AuditLayer::new(state.clone(), BLOG_ADMIN_AUDIT).layer(BlogAdminServiceServer::new(
+            BlogAdminController::new(state.clone()),
+        )),
+    );
+    let request = common::grpc_request::<BlogAdminServiceServer<BlogAdminController>>(
+        rpc,
+        message,
+        Some(token),
+    );
+    common::grpc_status(service.oneshot(request).await.unwrap()).await
+}
+
+async fn login_call(state: &AppState, email: &str, password: &str) -> Code {
+    let service = AuditLayer::new(state.clone(), AUTH_AUDIT)
+        .layer(AuthServiceServer::new(AuthController::new(state.clone())));
+    let message = LoginRequest {
+        email: email.to_string(),
+        password: password.to_string(),
+    };
+    let request = common::grpc_request::<AuthServiceServer<AuthController>>("Login", message, None);
+    common::grpc_status(service.oneshot(request).await.unwrap()).await
+}
+
+/// Call the REST API, as `token` if given, with a JSON body if given
+async fn rest_call(
+    state: &AppState,
+    token: Option<&str>,
+    method: &str,
+    uri: &str,
+    body: Option<Value>,
+) -> StatusCode {
+    let mut request = http::Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("x-request-id", format!("req-{method}-{uri}"));
+    if let Some(token) = token {
+        request = request.header(AUTHORIZATION, format!("Bearer {token}"));
+    }
+    let body = match body {
+        Some(body) => {
+            request = request.header(CONTENT_TYPE, "application/json");
+            Body::from(body.to_string())
+        }
+        None => Body::empty(),
+    };
+    api::router(state.clone())
+        .oneshot(request.body(body).unwrap())
+        .await
+        .unwrap()
+        .status()
+}
+
+async fn list(state: &AppState, request: ListAuditEventsRequest) -> Vec<AuditEvent> {
+    BlogAdminController::new(state.clone())
+        .list_audit_events(as_admin(request))
+        .await
+        .unwrap()
+        .into_inner()
+        .events
+}
+
+fn changes(event: &AuditEvent) -> Value {
+    serde_json::from_str(&event.changes).unwrap()
+}
+
+#[tokio::test]
+async fn admin_writes_are_recorded_with_outcome() {
+    let (firebase, state) = setup().await;
+    let token = firebase.id_token(&KEY_1, "uid-alice");
+
+    let create = CreateBlogPostRequest {
+        title: "Hello".to_string(),
+        description: "First post".to_string(),
+        body: "Body".to_string(),
+        tags: vec!["Rust".to_string()],
+    };
+    assert_eq!(
+        admin_call(&state, &token, "CreateBlogPost", create).await,
+        Code::Ok
+    );
+    let update = UpdateBlogPostRequest {
+        id: 1,
+        title: Some("Hello again".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(
+        admin_call(&state, &token, "UpdateBlogPost", update).await,
+        Code::Ok
+    );
+    let delete = DeleteBlogPostRequest { id: 42 };
+    assert_eq!(
+        admin_call(&state, &token, "DeleteBlogPost", delete).await,
+        Code::NotFound
+    );
+
+    let events = list(&state, ListAuditEventsRequest::default()).await;
+    let actions: Vec<&str> = events.iter().map(|e| e.action.as_str()).collect();
+    assert_eq!(
+        actions,
+        ["DeleteBlogPost", "UpdateBlogPost", "CreateBlogPost"]
+    );
+
+    let [deleted, updated, created] = &events[..] else {
+        unreachable!()
+    };
+    assert_eq!(created.actor_sub.as_deref(), Some("uid-alice"));
+    assert_eq!(
+        created.actor_email.as_deref(),
+        Some("uid-alice@example.com")
+    );
+    assert_eq!(created.target_id, Some(1));
+    assert_eq!(created.status_code, Code::Ok as i32);
+    assert_eq!(created.request_id.as_deref(), Some("req-CreateBlogPost"));
+    assert_eq!(
+        changes(created),
+        json!({ "title": "Hello", "description": "First post", "body_length": 4, "tags": ["Rust"] })
+    );
+
+    assert_eq!(updated.target_id, Some(1));
+    assert_eq!(changes(updated), json!({ "title": "Hello again" }));
+
+    assert_eq!(deleted.target_id, Some(42));
+    assert_eq!(deleted.status_code, Code::NotFound as i32);
+}
+
+#[tokio::test]
+async fn reads_and_refused_calls_are_not_recorded() {
+    let (firebase, state) = setup().await;
+    let token = firebase.id_token(&KEY_1, "uid-alice");
+
+    let get = GetBlogPostRequest { id: 1 };
+    assert_eq!(
+        admin_call(&state, &token, "GetBlogPost", get).await,
+        Code::Ok
+    );
+    let delete = DeleteBlogPostRequest { id: 1 };
+    assert_eq!(
+        admin_call(&state, "not-a-jwt", "DeleteBlogPost", delete).await,
+        Code::Unauthenticated
+    );
+
+    assert!(list(&state, ListAuditEventsRequest::default())
+        .await
+        .is_empty());
+}
+
+#[tokio::test]
+async fn login_attempts_are_recorded_without_the_password() {
+    let (_firebase, state) = setup().await;
+
+    assert_eq!(login_call(&state, EMAIL, PASSWORD).await, Code::Ok);
+    assert_eq!(
+        login_call(&state, EMAIL, "wrong").await,
+        Code::Unauthenticated
+    );
+
+    let events = list(&state, ListAuditEventsRequest::default()).await;
+    assert_eq!(events.len(), 2);
+    for event in &events {
+        assert_eq!(event.action, "Login");
+        assert_eq!(event.actor_sub, None);
+        assert_eq!(event.actor_email.as_deref(), Some(EMAIL));
+        assert!(!event.changes.contains(PASSWORD));
+        assert!(!event.changes.contains("wrong"));
+    }
+    let codes: Vec<i32> = events.iter().map(|e| e.status_code).collect();
+    assert_eq!(codes, [Code::Unauthenticated as i32, Code::Ok as i32]);
+}
+
+#[tokio::test]
+async fn events_filter_by_actor_action_and_time() {
+    let (firebase, state) = setup().await;
+    let alice = firebase.id_token(&KEY_1, "uid-alice");
+    let bob = firebase.id_token(&KEY_1, "uid-bob");
+
+    admin_call(
+        &state,
+        &alice,
+        "DeleteBlogPost",
+        DeleteBlogPostRequest { id: 1 },
+    )
+    .await;
+    admin_call(
+        &state,
+        &bob,
+        "DeleteBlogPost",
+        DeleteBlogPostRequest { id: 2 },
+    )
+    .await;
+    login_call(&state, EMAIL, PASSWORD).await;
+
+    let by_sub = list(
+        &state,
+        ListAuditEventsRequest {
+            actor: Some("uid-bob".to_string()),
+            ..Default::default()
+        },
+    )
+    .await;
+    assert_eq!(by_sub.len(), 1);
+    assert_eq!(by_sub[0].target_id, Some(2));
+
+    let by_email = list(
+        &state,
+        ListAuditEventsRequest {
+            actor: Some(EMAIL.to_string()),
+            ..Default::default()
+        },
+    )
+    .await;
+    assert_eq!(by_email.len(), 1);
+    assert_eq!(by_email[0].action, "Login");
+
+    let deletes = list(
+        &state,
+        ListAuditEventsRequest {
+            action: Some("DeleteBlogPost".to_string()),
+            ..Default::default()
+        },
+    )
+    .await;
+    assert_eq!(deletes.len(), 2);
+
+    let now = jsonwebtoken::get_current_timestamp() as i64;
+    let future = list(
+        &state,
+        ListAuditEventsRequest {
+            since: Some(now + 60),
+            ..Default::default()
+        },
+    )
+    .await;
+    assert!(future.is_empty());
+    let past = list(
+        &state,
+        ListAuditEventsRequest {
+            until: Some(now - 60),
+            ..Default::default()
+        },
+    )
+    .await;
+    assert!(past.is_empty());
+}
+
+#[tokio::test]
+async fn rest_writes_are_recorded_like_rpcs() {
+    let (firebase, state) = setup().await;
+    let token = firebase.id_token(&KEY_1, "uid-alice");
+    let token = Some(token.as_str());
+
+    let create = json!({
+        "title": "Hello",
+        "description": "First post",
+        "body": "Body",
+        "tags": ["Rust"],
+    });
+    assert_eq!(
+        rest_call(&state, token, "POST", "/blog", Some(create)).await,
+        StatusCode::CREATED
+    );
+    let update = json!({ "title": "Hello again" });
+    assert_eq!(
+        rest_call(&state, token, "PATCH", "/blog/1", Some(update)).await,
+        StatusCode::NO_CONTENT
+    );
+    assert_eq!(
+        rest_call(
+            &state,
+            token,
+            "POST",
+            "/blog/1/unpublish?archive=true",
+            None
+        )
+        .await,
+        StatusCode::NO_CONTENT
+    );
+    assert_eq!(
+        rest_call(&state, token, "DELETE", "/blog/42", None).await,
+        StatusCode::NOT_FOUND
+    );
+    let spam = json!({ "spam": true });
+    assert_eq!(
+        rest_call(&state, token, "POST", "/inquiries/7/spam", Some(spam)).await,
+        StatusCode::NOT_FOUND
+    );
+
+    let events = list(&state, ListAuditEventsRequest::default()).await;
+    let actions: Vec<&str> = events.iter().map(|e| e.action.as_str()).collect();
+    assert_eq!(
+        actions,
+        [
+            "MarkInquirySpam",
+            "DeleteBlogPost",
+            "UnpublishBlogPost",
+            "UpdateBlogPost",
+            "CreateBlogPost"
+        ]
+    );
+
+    let [spam, deleted, unpublished, updated, created] = &events[..] else {
+        unreachable!()
+    };
+    assert_eq!(created.actor_sub.as_deref(), Some("uid-alice"));
+    assert_eq!(
+        created.actor_email.as_deref(),
+        Some("uid-alice@example.com")
+    );
+    assert_eq!(created.target_id, Some(1));
+    assert_eq!(created.status_code, Code::Ok as i32);
+    assert_eq!(created.request_id.as_deref(), Some("req-POST-/blog"));
+    assert_eq!(
+        changes(created),
+        json!({ "title": "Hello", "description": "First post", "body_length": 4, "tags": ["Rust"] })
+    );
+
+    assert_eq!(updated.target_id, Some(1));
+    assert_eq!(changes(updated), json!({ "title": "Hello again" }));
+
+    assert_eq!(unpublished.target_id, Some(1));
+    assert_eq!(changes(unpublished), json!({ "archive": true }));
+
+    assert_eq!(deleted.target_id, Some(42));
+    assert_eq!(deleted.status_code, Code::NotFound as i32);
+
+    assert_eq!(spam.target_id, Some(7));
+    assert_eq!(spam.status_code, Code::NotFound as i32);
+    assert_eq!(changes(spam), json!({ "spam": true }));
+}
+
+#[tokio::test]
+async fn rest_reads_and_refused_calls_are_not_recorded() {
+    let (firebase, state) = setup().await;
+    let token = firebase.id_token(&KEY_1, "uid-alice");
+
+    assert_eq!(
+        rest_call(&state, Some(&token), "GET", "/inquiries", None).await,
+        StatusCode::OK
+    );
+    assert_eq!(
+        rest_call(&state, None, "DELETE", "/blog/1", None).await,
+        StatusCode::UNAUTHORIZED
+    );
+
+    let mut viewer = claims("uid-viewer");
+    viewer.roles = vec![];
+    let viewer = firebase.id_token_with(&KEY_1, &viewer);
+    assert_eq!(
+        rest_call(&state, Some(&viewer), "DELETE", "/blog/1", None).await,
+        StatusCode::FORBIDDEN
+    );
+
+    assert!(list(&state, ListAuditEventsRequest::default())
+        .await
+        .is_empty());
+}
+
+#[tokio::test]
+async fn rest_logins_are_recorded_without_the_password() {
+    let (_firebase, state) = setup().await;
+
+    let login = |password: &str| json!({ "email": EMAIL, "password": password });
+    assert_eq!(
+        rest_call(&state, None, "POST", "/auth/login", Some(login(PASSWORD))).await,
+        StatusCode::OK
+    );
+    assert_eq!(
+        rest_call(&state, None, "POST", "/auth/login", Some(login("wrong"))).await,
+        StatusCode::UNAUTHORIZED
+    );
+
+    let events = list(&state, ListAuditEventsRequest::default()).await;
+    assert_eq!(events.len(), 2);
+    for event in &events {
+        assert_eq!(event.action, "Login");
+        assert_eq!(event.actor_sub, None);
+        assert_eq!(event.actor_email.as_deref(), Some(EMAIL));
+        assert!(!event.changes.contains(PASSWORD));
+        assert!(!event.changes.contains("wrong"));
+    }
+    let codes: Vec<i32> = events.iter().map(|e| e.status_code).collect();
+    assert_eq!(codes, [Code::Unauthenticated as i32, Code::Ok as i32]);
+}
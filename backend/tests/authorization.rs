@@ -0,0 +1,130 @@
+//! Role checks of `AuthorizeLayer` against the BlogAdminService permission
+//! table, with Firebase-signed tokens carrying custom claims.
+
+mod common;
+
+use tonic::Code;
+
+use backend::dto::auth::Claims;
+use backend::grpc::BLOG_ADMIN_PERMISSIONS;
+use backend::state::AppState;
+use common::{claims, MockFirebase, KEY_1};
+
+async fn setup() -> (MockFirebase, AppState) {
+    let firebase = MockFirebase::start().await;
+    let state = common::app_state(firebase.config()).await;
+    (firebase, state)
+}
+
+fn with_roles(uid: &str, roles: &[&str]) -> Claims {
+    Claims {
+        roles: roles.iter().map(|role| role.to_string()).collect(),
+        ..claims(uid)
+    }
+}
+
+async fn call(
+    state: &AppState,
+    firebase: &MockFirebase,
+    rpc: &str,
+    claims: &Claims,
+) -> Result<String, Code> {
+    let token = firebase.id_token_with(&KEY_1, claims);
+    common::authorize(state, BLOG_ADMIN_PERMISSIONS, rpc, &token).await
+}
+
+#[tokio::test]
+async fn editors_write_but_cannot_delete() {
+    let (firebase, state) = setup().await;
+    let editor = with_roles("uid-editor", &["editor"]);
+
+    for rpc in [
+        "GetBlogPosts",
+        "CreateBlogPost",
+        "UpdateBlogPost",
+        "PublishBlogPost",
+    ] {
+        assert_eq!(
+            call(&state, &firebase, rpc, &editor).await,
+            Ok("uid-editor".to_string()),
+            "{rpc}"
+        );
+    }
+    for rpc in ["DeleteBlogPost", "RestoreBlogPost", "MergeTags"] {
+        assert_eq!(
+            call(&state, &firebase, rpc, &editor).await,
+            Err(Code::PermissionDenied),
+            "{rpc}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn admins_may_call_every_rpc() {
+    let (firebase, state) = setup().await;
+    let admin = with_roles("uid-admin", &["admin"]);
+
+    for (rpc, _) in BLOG_ADMIN_PERMISSIONS {
+        assert_eq!(
+            call(&state, &firebase, rpc, &admin).await,
+            Ok("uid-admin".to_string()),
+            "{rpc}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn users_without_roles_are_refused() {
+    let (firebase, state) = setup().await;
+
+    let nobody = with_roles("uid-nobody", &[]);
+    assert_eq!(
+        call(&state, &firebase, "GetBlogPosts", &nobody).await,
+        Err(Code::PermissionDenied)
+    );
+
+    let unknown_role = with_roles("uid-viewer", &["viewer"]);
+    assert_eq!(
+        call(&state, &firebase, "GetBlogPosts", &unknown_role).await,
+        Err(Code::PermissionDenied)
+    );
+}
+
+#[tokio::test]
+async fn unverified_email_is_refused() {
+    let (firebase, state) = setup().await;
+    let unverified = Claims {
+        email_verified: false,
+        ..with_roles("uid-unverified", &["admin"])
+    };
+
+    assert_eq!(
+        call(&state, &firebase, "GetBlogPosts", &unverified).await,
+        Err(Code::PermissionDenied)
+    );
+}
+
+#[tokio::test]
+async fn rpcs_missing_from_the_table_are_refused() {
+    let (firebase, state) = setup().await;
+    let admin = with_roles("uid-admin", &["admin"]);
+
+    assert_eq!(
+        call(&state, &firebase, "PurgeEverything", &admin).await,
+        Err(Code::PermissionDenied)
+    );
+}
+
+#[tokio::test]
+async fn missing_or_invalid_tokens_are_unauthenticated() {
+    let (_firebase, state) = setup().await;
+
+    assert_eq!(
+        common::authorize(&state, BLOG_ADMIN_PERMISSIONS, "GetBlogPosts", "").await,
+        Err(Code::Unauthenticated)
+    );
+    assert_eq!(
+        common::authorize(&state, BLOG_ADMIN_PERMISSIONS, "GetBlogPosts", "not-a-jwt").await,
+        Err(Code::Unauthenticated)
+    );
+}
@@ -0,0 +1,134 @@
+//! `BlogAdminController` records who created, changed and deleted each post.
+
+mod common;
+
+use proto::blog::blog_admin_service_server::BlogAdminService;
+use proto::blog::blog_service_server::BlogService;
+use proto::blog::{
+    BlogPost, CreateBlogPostRequest, DeleteBlogPostRequest, GetBlogPostRequest,
+    PublishBlogPostRequest, RestoreBlogPostRequest, UpdateBlogPostRequest,
+};
+use tonic::{Code, Request};
+
+use backend::grpc::{BlogAdminController, BlogController};
+use backend::state::AppState;
+use common::{as_user, setup};
+
+async fn admin_view(state: &AppState, id: i64) -> BlogPost {
+    BlogAdminController::new(state.clone())
+        .get_blog_post(as_user("uid-reader", GetBlogPostRequest { id }))
+        .await
+        .unwrap()
+        .into_inner()
+        .post
+        .expect("post exists")
+}
+
+async fn create(state: &AppState, uid: &str) -> i64 {
+    BlogAdminController::new(state.clone())
+        .create_blog_post(as_user(
+            uid,
+            CreateBlogPostRequest {
+                title: "Hello".to_string(),
+                description: "First post".to_string(),
+                body: "Body".to_string(),
+                tags: vec![],
+            },
+        ))
+        .await
+        .unwrap()
+        .into_inner()
+        .id
+}
+
+#[tokio::test]
+async fn admin_view_shows_who_changed_a_post() {
+    let state = setup().await;
+    let controller = BlogAdminController::new(state.clone());
+
+    let id = create(&state, "uid-alice").await;
+    let post = admin_view(&state, id).await;
+    assert_eq!(post.created_by.as_deref(), Some("uid-alice"));
+    assert_eq!(post.updated_by.as_deref(), Some("uid-alice"));
+    assert_eq!(post.deleted_by, None);
+
+    controller
+        .update_blog_post(as_user(
+            "uid-bob",
+            UpdateBlogPostRequest {
+                id,
+                title: Some("Hello again".to_string()),
+                ..Default::default()
+            },
+        ))
+        .await
+        .unwrap();
+    let post = admin_view(&state, id).await;
+    assert_eq!(post.created_by.as_deref(), Some("uid-alice"));
+    assert_eq!(post.updated_by.as_deref(), Some("uid-bob"));
+
+    controller
+        .publish_blog_post(as_user("uid-carol", PublishBlogPostRequest { id }))
+        .await
+        .unwrap();
+    assert_eq!(
+        admin_view(&state, id).await.updated_by.as_deref(),
+        Some("uid-carol")
+    );
+}
+
+#[tokio::test]
+async fn deleted_posts_show_who_deleted_them_until_restored() {
+    let state = setup().await;
+    let controller = BlogAdminController::new(state.clone());
+    let id = create(&state, "uid-alice").await;
+
+    controller
+        .delete_blog_post(as_user("uid-admin", DeleteBlogPostRequest { id }))
+        .await
+        .unwrap();
+    let post = admin_view(&state, id).await;
+    assert!(post.deleted_at.is_some());
+    assert_eq!(post.deleted_by.as_deref(), Some("uid-admin"));
+    assert_eq!(post.updated_by.as_deref(), Some("uid-admin"));
+
+    controller
+        .restore_blog_post(as_user("uid-root", RestoreBlogPostRequest { id }))
+        .await
+        .unwrap();
+    let post = admin_view(&state, id).await;
+    assert_eq!(post.deleted_at, None);
+    assert_eq!(post.deleted_by, None);
+    assert_eq!(post.updated_by.as_deref(), Some("uid-root"));
+}
+
+#[tokio::test]
+async fn public_view_hides_who_changed_a_post() {
+    let state = setup().await;
+    let id = create(&state, "uid-alice").await;
+    BlogAdminController::new(state.clone())
+        .publish_blog_post(as_user("uid-alice", PublishBlogPostRequest { id }))
+        .await
+        .unwrap();
+
+    let post = BlogController::new(state.clone())
+        .get_blog_post(Request::new(GetBlogPostRequest { id }))
+        .await
+        .unwrap()
+        .into_inner()
+        .post
+        .expect("published post is public");
+    assert_eq!(post.created_by, None);
+    assert_eq!(post.updated_by, None);
+}
+
+#[tokio::test]
+async fn writes_without_a_caller_are_refused() {
+    let state = setup().await;
+
+    let status = BlogAdminController::new(state)
+        .delete_blog_post(Request::new(DeleteBlogPostRequest { id: 1 }))
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), Code::Unauthenticated);
+}
@@ -0,0 +1,461 @@
+//! In-process stand-in for the Firebase endpoints the backend talks to:
+//! password sign-in, token refresh and the public key (X.509 certificate) list.
+//! Plus setup shared by the integration tests: app state on a fresh database
+//! and requests as they reach the controllers.
+
+// Each test binary uses its own subset of the helpers
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+use axum::extract::{Query, State};
+use axum::http::header::CACHE_CONTROL;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{Form, Json, Router};
+use http_body_util::BodyExt;
+use jsonwebtoken::{Algorithm, EncodingKey, Header};
+use prost::Message;
+use serde::Deserialize;
+use serde_json::json;
+use tonic::body::Body;
+use tonic::server::NamedService;
+use tonic::Code;
+use tower::{service_fn, Layer, ServiceExt};
+
+use backend::config::{Config, IdentityProviderKind, SmtpTls};
+use backend::db;
+use backend::dto::auth::Claims;
+use backend::middleware::{AuthorizeLayer, Permissions};
+use backend::services::identity;
+use backend::state::AppState;
+
+pub const PROJECT_ID: &str = "demo-portfolio";
+pub const API_KEY: &str = "test-api-key";
+
+/// A signing key pair from `tests/fixtures`
+pub struct TestKey {
+    pub kid: &'static str,
+    private_pem: &'static [u8],
+    cert_pem: &'static str,
+}
+
+pub const KEY_1: TestKey = TestKey {
+    kid: "key-1",
+    private_pem: include_bytes!("../fixtures/key1.pem"),
+    cert_pem: include_str!("../fixtures/key1.crt"),
+};
+
+pub const KEY_2: TestKey = TestKey {
+    kid: "key-2",
+    private_pem: include_bytes!("../fixtures/key2.pem"),
+    cert_pem: include_str!("../fixtures/key2.crt"),
+};
+
+struct Account {
+    uid: String,
+    password: String,
+    disabled: bool,
+}
+
+struct MockState {
+    accounts: HashMap<String, Account>,
+    /// Refresh token -> uid; tokens are single use, like Firebase rotates them
+    refresh_tokens: HashMap<String, String>,
+    /// Certificates served from the key endpoint
+    published: Vec<&'static TestKey>,
+    /// Key new ID tokens are signed with
+    signing: &'static TestKey,
+    keys_max_age: u64,
+    key_fetches: usize,
+    /// Reply to every sign-in with this error message
+    sign_in_error: Option<String>,
+    /// Reply to sign-in with a success status but a body missing fields
+    malformed_sign_in: bool,
+    issued: usize,
+}
+
+/// Handle on a running mock server
+#[derive(Clone)]
+pub struct MockFirebase {
+    pub url: String,
+    state: Arc<Mutex<MockState>>,
+}
+
+impl MockFirebase {
+    pub async fn start() -> Self {
+        let state = Arc::new(Mutex::new(MockState {
+            accounts: HashMap::new(),
+            refresh_tokens: HashMap::new(),
+            published: vec![&KEY_1],
+            signing: &KEY_1,
+            keys_max_age: 3600,
+            key_fetches: 0,
+            sign_in_error: None,
+            malformed_sign_in: false,
+            issued: 0,
+        }));
+
+        let app = Router::new()
+            .route("/v1/accounts:signInWithPassword", post(sign_in))
+            .route("/v1/token", post(refresh))
+            .route("/keys", get(keys))
+            .with_state(state.clone());
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("Failed to bind mock Firebase server");
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        Self { url, state }
+    }
+
+    pub fn add_account(&self, email: &str, password: &str) {
+        self.state.lock().unwrap().accounts.insert(
+            email.to_string(),
+            Account {
+                uid: format!("uid-{email}"),
+                password: password.to_string(),
+                disabled: false,
+            },
+        );
+    }
+
+    pub fn disable_account(&self, email: &str) {
+        if let Some(account) = self.state.lock().unwrap().accounts.get_mut(email) {
+            account.disabled = true;
+        }
+    }
+
+    pub fn fail_sign_in_with(&self, message: &str) {
+        self.state.lock().unwrap().sign_in_error = Some(message.to_string());
+    }
+
+    pub fn send_malformed_sign_in(&self) {
+        self.state.lock().unwrap().malformed_sign_in = true;
+    }
+
+    /// Publish `keys` and sign new tokens with `signing`, as Google does when
+    /// it rotates keys
+    pub fn rotate_keys(&self, published: Vec<&'static TestKey>, signing: &'static TestKey) {
+        let mut state = self.state.lock().unwrap();
+        state.published = published;
+        state.signing = signing;
+    }
+
+    pub fn set_keys_max_age(&self, secs: u64) {
+        self.state.lock().unwrap().keys_max_age = secs;
+    }
+
+    pub fn key_fetches(&self) -> usize {
+        self.state.lock().unwrap().key_fetches
+    }
+
+    /// A Firebase-shaped ID token for `uid`, signed with `key`
+    pub fn id_token(&self, key: &TestKey, uid: &str) -> String {
+        sign(key, &claims(uid))
+    }
+
+    /// An ID token carrying `claims`, signed with `key`
+    pub fn id_token_with(&self, key: &TestKey, claims: &Claims) -> String {
+        sign(key, claims)
+    }
+
+    /// Backend config pointing every Firebase endpoint at this server
+    pub fn config(&self) -> Config {
+        Config {
+            host: "127.0.0.1".parse().unwrap(),
+            port: 0,
+            cors_origin: "http://localhost:5173".to_string(),
+            trusted_proxies: vec![],
+            database_path: temp_database(),
+            identity_provider: IdentityProviderKind::Firebase,
+            firebase_project_id: PROJECT_ID.to_string(),
+            firebase_api_key: API_KEY.to_string(),
+            firebase_auth_url: self.url.clone(),
+            firebase_token_url: self.url.clone(),
+            firebase_public_keys_url: format!("{}/keys", self.url),
+            firebase_allow_unsigned_tokens: false,
+            local_admin_email: None,
+            local_admin_password: None,
+            publish_interval_secs: 60,
+            health_check_interval_secs: 15,
+            login_rate_limit_per_ip: "20/300".parse().unwrap(),
+            login_rate_limit_per_email: "5/300".parse().unwrap(),
+            refresh_rate_limit_per_ip: "60/300".parse().unwrap(),
+            public_rate_limit_per_ip: "600/60".parse().unwrap(),
+            inquiry_rate_limit_per_ip: "5/3600".parse().unwrap(),
+            rate_limit_persist: false,
+            spam_threshold: 1.0,
+            // Tests submit right after fetching a form token
+            spam_min_submit_secs: 0,
+            spam_form_token_max_age_secs: 86400,
+            spam_form_secret: None,
+            spam_submissions_per_ip: "3/3600".parse().unwrap(),
+            spam_max_links: 2,
+            spam_blocklist: vec![],
+            spam_classifier: false,
+            smtp_host: None,
+            smtp_port: 25,
+            smtp_tls: SmtpTls::None,
+            smtp_username: None,
+            smtp_password: None,
+            email_from: "blog@example.com".to_string(),
+            email_to: None,
+            email_outbox_interval_secs: 30,
+            notify_failed_logins: "10/3600".parse().unwrap(),
+            webhook_delivery_interval_secs: 10,
+            webhook_timeout_secs: 5,
+            webhook_allow_private_urls: false,
+            site_url: "http://localhost:5173".to_string(),
+            public_url: "http://localhost:3000".to_string(),
+            site_title: "Blog".to_string(),
+        }
+    }
+}
+
+/// App state wired up the way `main` does it: Firebase keys are prefetched and
+/// the background key refresher is running
+pub async fn app_state(config: Config) -> AppState {
+    let pool = db::init(&config.database_path).await;
+    let identity = identity::connect(&config, pool.clone()).await;
+    AppState::new(config, pool, identity)
+}
+
+/// App state on a fresh database, its Firebase endpoints served by a mock
+pub async fn setup() -> AppState {
+    setup_with(|_| {}).await
+}
+
+/// [`setup`] with the test config changed by `configure` first
+pub async fn setup_with(configure: impl FnOnce(&mut Config)) -> AppState {
+    let mut config = MockFirebase::start().await.config();
+    configure(&mut config);
+    app_state(config).await
+}
+
+/// Claims Firebase would put in an ID token for `uid`, a verified admin,
+/// valid for an hour
+pub fn claims(uid: &str) -> Claims {
+    Claims {
+        email: Some(format!("{uid}@example.com")),
+        email_verified: true,
+        roles: vec!["admin".to_string()],
+        sub: uid.to_string(),
+        aud: PROJECT_ID.to_string(),
+        iss: format!("https://securetoken.google.com/{PROJECT_ID}"),
+        exp: jsonwebtoken::get_current_timestamp() + 3600,
+    }
+}
+
+/// A request as `AuthorizeLayer` passes it on, with the caller's claims attached
+pub fn as_user<T>(uid: &str, message: T) -> tonic::Request<T> {
+    let mut request = tonic::Request::new(message);
+    request.extensions_mut().insert(claims(uid));
+    request
+}
+
+/// A request from an admin, as `AuthorizeLayer` passes it on
+pub fn as_admin<T>(message: T) -> tonic::Request<T> {
+    as_user("uid-admin", message)
+}
+
+/// Run an ID token through `AuthorizeLayer` as a call to `rpc` of a service
+/// guarded by `permissions`. Returns the `sub` the inner service saw in the
+/// request's claims, or the status code the layer refused with.
+pub async fn authorize(
+    state: &AppState,
+    permissions: Permissions,
+    rpc: &str,
+    id_token: &str,
+) -> Result<String, Code> {
+    let inner = service_fn(|req: http::Request<()>| async move {
+        let claims = req.extensions().get::<Claims>().expect("claims attached");
+        Ok::<_, Infallible>(http::Response::new(claims.sub.clone()))
+    });
+    let request = http::Request::builder()
+        .uri(format!("/test.Service/{rpc}"))
+        .header("cookie", format!("id_token={id_token}"))
+        .body(())
+        .unwrap();
+
+    let response = AuthorizeLayer::new(state.clone(), permissions)
+        .layer(inner)
+        .oneshot(request)
+        .await
+        .unwrap();
+    match response.headers().get("grpc-status") {
+        Some(code) => Err(Code::from_bytes(code.as_bytes())),
+        None => Ok(response.into_body()),
+    }
+}
+
+/// A unary gRPC request for `rpc` of service `S`, with an optional ID token
+pub fn grpc_request<S: NamedService>(
+    rpc: &str,
+    message: impl Message,
+    token: Option<&str>,
+) -> http::Request<Body> {
+    let message = message.encode_to_vec();
+    let mut frame = vec![0];
+    frame.extend_from_slice(&(message.len() as u32).to_be_bytes());
+    frame.extend_from_slice(&message);
+
+    let mut request = http::Request::builder()
+        .method("POST")
+        .uri(format!("/{}/{rpc}", S::NAME))
+        .header("content-type", "application/grpc")
+        .header("x-request-id", format!("req-{rpc}"));
+    if let Some(token) = token {
+        request = request.header("cookie", format!("id_token={token}"));
+    }
+    request
+        .body(Body::new(http_body_util::Full::new(frame.into())))
+        .unwrap()
+}
+
+/// Status code of a gRPC response, from its headers or trailers
+pub async fn grpc_status(response: http::Response<Body>) -> Code {
+    let (parts, body) = response.into_parts();
+    let trailers = body.collect().await.unwrap().trailers().cloned();
+    let code = parts
+        .headers
+        .get("grpc-status")
+        .or_else(|| trailers.as_ref().and_then(|t| t.get("grpc-status")))
+        .expect("grpc-status");
+    Code::from_bytes(code.as_bytes())
+}
+
+fn sign(key: &TestKey, claims: &Claims) -> String {
+    let mut header = Header::new(Algorithm::RS256);
+    header.kid = Some(key.kid.to_string());
+    let encoding_key =
+        EncodingKey::from_rsa_pem(key.private_pem).expect("Invalid test signing key");
+    jsonwebtoken::encode(&header, claims, &encoding_key).expect("Failed to sign test token")
+}
+
+pub fn temp_database() -> String {
+    let path: PathBuf =
+        std::env::temp_dir().join(format!("backend-test-{}.db", uuid::Uuid::new_v4()));
+    format!("sqlite:{}", path.display())
+}
+
+#[derive(Deserialize)]
+struct KeyParam {
+    key: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SignInBody {
+    email: String,
+    password: String,
+    return_secure_token: bool,
+}
+
+#[derive(Deserialize)]
+struct RefreshForm {
+    grant_type: String,
+    refresh_token: String,
+}
+
+fn firebase_error(message: &str) -> Response {
+    let body = json!({ "error": { "code": 400, "message": message } });
+    (StatusCode::BAD_REQUEST, Json(body)).into_response()
+}
+
+/// Sign `uid` in: a fresh ID token and a new refresh token
+fn issue_tokens(state: &mut MockState, uid: &str) -> (String, String) {
+    state.issued += 1;
+    let refresh_token = format!("refresh-{}", state.issued);
+    state
+        .refresh_tokens
+        .insert(refresh_token.clone(), uid.to_string());
+    (sign(state.signing, &claims(uid)), refresh_token)
+}
+
+async fn sign_in(
+    State(state): State<Arc<Mutex<MockState>>>,
+    Query(param): Query<KeyParam>,
+    Json(body): Json<SignInBody>,
+) -> Response {
+    let mut state = state.lock().unwrap();
+    if param.key != API_KEY {
+        return firebase_error("API key not valid. Please pass a valid API key.");
+    }
+    if let Some(message) = &state.sign_in_error {
+        return firebase_error(message);
+    }
+    if state.malformed_sign_in {
+        return Json(json!({ "kind": "identitytoolkit#VerifyPasswordResponse" })).into_response();
+    }
+    assert!(body.return_secure_token, "backend must ask for tokens");
+
+    let uid = match state.accounts.get(&body.email) {
+        None => return firebase_error("EMAIL_NOT_FOUND"),
+        Some(account) if account.password != body.password => {
+            return firebase_error("INVALID_PASSWORD");
+        }
+        Some(account) if account.disabled => {
+            return firebase_error(
+                "USER_DISABLED : The user account has been disabled by an administrator.",
+            );
+        }
+        Some(account) => account.uid.clone(),
+    };
+
+    let (id_token, refresh_token) = issue_tokens(&mut state, &uid);
+    Json(json!({
+        "kind": "identitytoolkit#VerifyPasswordResponse",
+        "localId": uid,
+        "email": body.email,
+        "displayName": "",
+        "idToken": id_token,
+        "registered": true,
+        "refreshToken": refresh_token,
+        "expiresIn": "3600",
+    }))
+    .into_response()
+}
+
+async fn refresh(
+    State(state): State<Arc<Mutex<MockState>>>,
+    Query(param): Query<KeyParam>,
+    Form(form): Form<RefreshForm>,
+) -> Response {
+    let mut state = state.lock().unwrap();
+    if param.key != API_KEY || form.grant_type != "refresh_token" {
+        return firebase_error("INVALID_GRANT_TYPE");
+    }
+    let Some(uid) = state.refresh_tokens.remove(&form.refresh_token) else {
+        return firebase_error("INVALID_REFRESH_TOKEN");
+    };
+
+    let (id_token, refresh_token) = issue_tokens(&mut state, &uid);
+    Json(json!({
+        "expires_in": "3600",
+        "token_type": "Bearer",
+        "refresh_token": refresh_token,
+        "id_token": id_token,
+        "user_id": uid,
+        "project_id": PROJECT_ID,
+    }))
+    .into_response()
+}
+
+async fn keys(State(state): State<Arc<Mutex<MockState>>>) -> Response {
+    let mut state = state.lock().unwrap();
+    state.key_fetches += 1;
+    let certs: HashMap<&str, &str> = state
+        .published
+        .iter()
+        .map(|key| (key.kid, key.cert_pem))
+        .collect();
+    let cache_control = format!("public, max-age={}, must-revalidate", state.keys_max_age);
+    ([(CACHE_CONTROL, cache_control)], Json(certs)).into_response()
+}
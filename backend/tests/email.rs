@@ -0,0 +1,460 @@
+//! Notification emails: queued in the outbox by the events that trigger them
+//! and sent to an in-process SMTP server, with failed sends retried later.
+
+mod common;
+
+use std::sync::{Arc, Mutex};
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use proto::auth::auth_service_server::AuthService;
+use proto::auth::LoginRequest;
+use proto::inquiry::inquiry_service_server::InquiryService;
+use proto::inquiry::{GetFormTokenRequest, SubmitInquiryRequest};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpListener;
+use tonic::Request;
+
+use backend::config::{Config, SmtpTls};
+use backend::dao::email::{OutboxEmail, OutboxStatus};
+use backend::db::{self, DbPool};
+use backend::grpc::{AuthController, InquiryController};
+use backend::repositories::EmailOutboxRepository;
+use backend::services::email::render_message;
+use backend::state::AppState;
+use common::MockFirebase;
+
+/// A message the SMTP server accepted
+#[derive(Debug, Clone)]
+struct Delivery {
+    from: String,
+    to: String,
+    data: String,
+}
+
+/// Handle on a running mock SMTP server
+#[derive(Clone)]
+struct SmtpSink {
+    port: u16,
+    delivered: Arc<Mutex<Vec<Delivery>>>,
+    /// Reply to `RCPT TO` with this instead of accepting the recipient
+    reject_rcpt: Arc<Mutex<Option<String>>>,
+}
+
+impl SmtpSink {
+    async fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("Failed to bind mock SMTP server");
+        let sink = Self {
+            port: listener.local_addr().unwrap().port(),
+            delivered: Arc::default(),
+            reject_rcpt: Arc::default(),
+        };
+
+        let server = sink.clone();
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let server = server.clone();
+                tokio::spawn(async move { server.session(stream).await });
+            }
+        });
+        sink
+    }
+
+    async fn session(&self, stream: tokio::net::TcpStream) -> std::io::Result<()> {
+        let (reader, mut writer) = stream.into_split();
+        let mut lines = BufReader::new(reader).lines();
+        writer.write_all(b"220 mock ESMTP\r\n").await?;
+
+        let (mut from, mut to) = (String::new(), String::new());
+        while let Some(line) = lines.next_line().await? {
+            let reply = if line.starts_with("EHLO") {
+                "250-mock\r\n250 8BITMIME\r\n".to_string()
+            } else if let Some(address) = line.strip_prefix("MAIL FROM:") {
+                from = address.trim_matches(['<', '>']).to_string();
+                "250 OK\r\n".to_string()
+            } else if let Some(address) = line.strip_prefix("RCPT TO:") {
+                match self.reject_rcpt.lock().unwrap().clone() {
+                    Some(reply) => format!("{reply}\r\n"),
+                    None => {
+                        to = address.trim_matches(['<', '>']).to_string();
+                        "250 OK\r\n".to_string()
+                    }
+                }
+            } else if line == "DATA" {
+                writer.write_all(b"354 End data with .\r\n").await?;
+                let mut data = String::new();
+                while let Some(line) = lines.next_line().await? {
+                    if line == "." {
+                        break;
+                    }
+                    data.push_str(line.strip_prefix('.').unwrap_or(&line));
+                    data.push_str("\r\n");
+                }
+                self.delivered.lock().unwrap().push(Delivery {
+                    from: from.clone(),
+                    to: to.clone(),
+                    data,
+                });
+                "250 Queued\r\n".to_string()
+            } else if line == "QUIT" {
+                writer.write_all(b"221 Bye\r\n").await?;
+                return Ok(());
+            } else {
+                "502 Not implemented\r\n".to_string()
+            };
+            writer.write_all(reply.as_bytes()).await?;
+        }
+        Ok(())
+    }
+
+    fn reject_recipients(&self, reply: Option<&str>) {
+        *self.reject_rcpt.lock().unwrap() = reply.map(str::to_string);
+    }
+
+    fn delivered(&self) -> Vec<Delivery> {
+        self.delivered.lock().unwrap().clone()
+    }
+}
+
+struct Setup {
+    firebase: MockFirebase,
+    smtp: SmtpSink,
+    state: AppState,
+    pool: DbPool,
+    outbox: EmailOutboxRepository,
+}
+
+async fn setup(configure: impl FnOnce(&mut Config)) -> Setup {
+    let firebase = MockFirebase::start().await;
+    let smtp = SmtpSink::start().await;
+    let mut config = firebase.config();
+    config.smtp_host = Some("127.0.0.1".to_string());
+    config.smtp_port = smtp.port;
+    config.smtp_tls = SmtpTls::None;
+    config.email_to = Some("owner@example.com".to_string());
+    configure(&mut config);
+
+    let pool = db::init(&config.database_path).await;
+    let outbox = EmailOutboxRepository::new(pool.clone());
+    let state = common::app_state(config).await;
+    Setup {
+        firebase,
+        smtp,
+        state,
+        pool,
+        outbox,
+    }
+}
+
+async fn submit(state: &AppState, message: &str, website: &str) {
+    let controller = InquiryController::new(state.clone());
+    let form_token = controller
+        .get_form_token(Request::new(GetFormTokenRequest {}))
+        .await
+        .unwrap()
+        .into_inner()
+        .form_token;
+    controller
+        .submit_inquiry(Request::new(SubmitInquiryRequest {
+            name: "Ada Lovelace".to_string(),
+            email: "ada@example.com".to_string(),
+            subject: "Analytical engine".to_string(),
+            message: message.to_string(),
+            website: website.to_string(),
+            form_token,
+        }))
+        .await
+        .expect("accepted");
+}
+
+async fn email(outbox: &EmailOutboxRepository, id: i64) -> OutboxEmail {
+    outbox.get(id).await.unwrap().expect("queued email")
+}
+
+/// Decoded bodies of the base64 parts of a delivered message
+fn parts(data: &str) -> Vec<String> {
+    data.split("Content-Transfer-Encoding: base64\r\n\r\n")
+        .skip(1)
+        .map(|part| {
+            let encoded: String = part
+                .lines()
+                .take_while(|line| !line.starts_with("--"))
+                .collect();
+            String::from_utf8(STANDARD.decode(encoded).unwrap()).unwrap()
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn new_inquiries_are_queued_and_sent() {
+    let Setup {
+        smtp,
+        state,
+        outbox,
+        ..
+    } = setup(|_| {}).await;
+
+    submit(
+        &state,
+        "Could it compose music?\n<b>Asking</b> for a friend.",
+        "",
+    )
+    .await;
+    let queued = email(&outbox, 1).await;
+    assert_eq!(queued.event, "new_inquiry");
+    assert_eq!(queued.status, OutboxStatus::Pending);
+    assert_eq!(queued.subject, "New inquiry: Analytical engine");
+    assert!(
+        smtp.delivered().is_empty(),
+        "sent by the outbox, not the request"
+    );
+
+    assert_eq!(state.notifier.deliver_due().await.unwrap(), 1);
+    let sent = email(&outbox, 1).await;
+    assert_eq!(sent.status, OutboxStatus::Sent);
+    assert!(sent.sent_at.is_some());
+
+    let delivered = smtp.delivered();
+    assert_eq!(delivered.len(), 1);
+    assert_eq!(delivered[0].from, "blog@example.com");
+    assert_eq!(delivered[0].to, "owner@example.com");
+    assert!(delivered[0]
+        .data
+        .contains("Subject: New inquiry: Analytical engine\r\n"));
+    assert!(delivered[0].data.contains("multipart/alternative"));
+
+    let parts = parts(&delivered[0].data);
+    assert_eq!(parts.len(), 2);
+    assert!(parts[0].contains("Ada Lovelace <ada@example.com>"));
+    assert!(parts[0].contains("<b>Asking</b> for a friend."));
+    assert!(parts[0].contains("http://localhost:5173/manage"));
+    assert!(parts[1].contains("Could it compose music?<br>&lt;b&gt;Asking&lt;/b&gt;"));
+    assert!(parts[1].contains("mailto:ada@example.com"));
+
+    // Nothing left to send
+    assert_eq!(state.notifier.deliver_due().await.unwrap(), 0);
+    assert_eq!(smtp.delivered().len(), 1);
+}
+
+#[tokio::test]
+async fn quarantined_inquiries_are_not_notified() {
+    let Setup { state, outbox, .. } = setup(|_| {}).await;
+
+    // Filling in the honeypot field quarantines the submission
+    submit(&state, "Cheap watches", "http://spam.example").await;
+    assert!(outbox.get(1).await.unwrap().is_none());
+    assert_eq!(state.notifier.deliver_due().await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn nothing_is_queued_without_a_recipient_or_server() {
+    for configure in [
+        (|config: &mut Config| config.email_to = None) as fn(&mut Config),
+        |config: &mut Config| config.smtp_host = None,
+    ] {
+        let Setup {
+            smtp,
+            state,
+            outbox,
+            ..
+        } = setup(configure).await;
+
+        submit(&state, "Hello", "").await;
+        assert!(outbox.get(1).await.unwrap().is_none());
+        assert_eq!(state.notifier.deliver_due().await.unwrap(), 0);
+        assert!(smtp.delivered().is_empty());
+    }
+}
+
+#[tokio::test]
+async fn failed_sends_are_retried_with_backoff() {
+    let Setup {
+        smtp,
+        state,
+        pool,
+        outbox,
+        ..
+    } = setup(|_| {}).await;
+    smtp.reject_recipients(Some("550 No such user"));
+
+    submit(&state, "Hello", "").await;
+    let queued = email(&outbox, 1).await;
+    assert_eq!(state.notifier.deliver_due().await.unwrap(), 0);
+
+    let failed = email(&outbox, 1).await;
+    assert_eq!(failed.status, OutboxStatus::Pending);
+    assert_eq!(failed.attempts, 1);
+    assert_eq!(
+        failed.last_error.as_deref(),
+        Some("SMTP RCPT TO rejected: 550 No such user")
+    );
+    // Retried in a minute, and not before
+    assert!(failed.next_attempt_at >= queued.next_attempt_at + 60);
+    assert_eq!(state.notifier.deliver_due().await.unwrap(), 0);
+    assert_eq!(email(&outbox, 1).await.attempts, 1);
+
+    // Once due again and the server accepts it, it's sent
+    smtp.reject_recipients(None);
+    rewind(&pool, 1).await;
+    assert_eq!(state.notifier.deliver_due().await.unwrap(), 1);
+    let sent = email(&outbox, 1).await;
+    assert_eq!(sent.status, OutboxStatus::Sent);
+    assert_eq!(sent.last_error, None);
+    assert_eq!(smtp.delivered().len(), 1);
+}
+
+#[tokio::test]
+async fn sends_are_given_up_after_repeated_failures() {
+    let Setup {
+        state,
+        pool,
+        outbox,
+        ..
+    } = setup(|config| {
+        // Nothing listens on the discard port
+        config.smtp_port = 9;
+    })
+    .await;
+
+    submit(&state, "Hello", "").await;
+    let mut backoff = vec![];
+    for _ in 0..8 {
+        assert_eq!(email(&outbox, 1).await.status, OutboxStatus::Pending);
+        let now = chrono::Utc::now().timestamp();
+        assert_eq!(state.notifier.deliver_due().await.unwrap(), 0);
+
+        let failed = email(&outbox, 1).await;
+        assert!(failed
+            .last_error
+            .unwrap()
+            .starts_with("SMTP connection failed"));
+        if failed.status == OutboxStatus::Pending {
+            backoff.push(failed.next_attempt_at - now);
+        }
+        rewind(&pool, 1).await;
+    }
+
+    let failed = email(&outbox, 1).await;
+    assert_eq!(failed.status, OutboxStatus::Failed);
+    assert_eq!(failed.attempts, 8);
+    assert_eq!(state.notifier.deliver_due().await.unwrap(), 0);
+
+    // Doubling from a minute; the last attempt isn't rescheduled
+    assert_eq!(backoff.len(), 7);
+    for (attempt, wait) in backoff.into_iter().enumerate() {
+        let expected = 60 << attempt;
+        assert!((expected..expected + 5).contains(&wait), "{wait}s");
+    }
+}
+
+#[tokio::test]
+async fn credentials_are_not_sent_without_tls() {
+    let Setup {
+        smtp,
+        state,
+        outbox,
+        ..
+    } = setup(|config| {
+        config.smtp_username = Some("owner".to_string());
+        config.smtp_password = Some("secret".to_string());
+    })
+    .await;
+
+    submit(&state, "Hello", "").await;
+    assert_eq!(state.notifier.deliver_due().await.unwrap(), 0);
+    let failed = email(&outbox, 1).await;
+    assert_eq!(failed.status, OutboxStatus::Pending);
+    assert_eq!(
+        failed.last_error.as_deref(),
+        Some("SMTP credentials are only sent over TLS; set SMTP_TLS")
+    );
+    assert!(smtp.delivered().is_empty());
+}
+
+/// Make a queued email due now, as if its backoff had passed
+async fn rewind(pool: &DbPool, id: i64) {
+    sqlx::query("UPDATE email_outbox SET next_attempt_at = 0 WHERE id = ?")
+        .bind(id)
+        .execute(pool)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn repeated_failed_logins_notify_once_per_period() {
+    let Setup {
+        firebase,
+        smtp,
+        state,
+        outbox,
+        ..
+    } = setup(|config| config.notify_failed_logins = "3/3600".parse().unwrap()).await;
+    firebase.add_account("owner@example.com", "correct horse");
+
+    let controller = AuthController::new(state.clone());
+    let login = |password: &str| {
+        let controller = &controller;
+        let request = Request::new(LoginRequest {
+            email: "owner@example.com".to_string(),
+            password: password.to_string(),
+        });
+        async move { controller.login(request).await.is_ok() }
+    };
+
+    assert!(!login("guess 1").await);
+    assert!(!login("guess 2").await);
+    // Successful logins don't count
+    assert!(login("correct horse").await);
+    assert!(outbox.get(1).await.unwrap().is_none());
+
+    assert!(!login("guess 3").await);
+    let alert = email(&outbox, 1).await;
+    assert_eq!(alert.event, "failed_logins");
+    assert_eq!(alert.subject, "3 failed login attempts");
+    assert!(alert
+        .text_body
+        .contains("The latest was for owner@example.com"));
+
+    // Quiet for the rest of the period
+    for i in 4..10 {
+        assert!(!login(&format!("guess {i}")).await);
+    }
+    assert!(outbox.get(2).await.unwrap().is_none());
+
+    assert_eq!(state.notifier.deliver_due().await.unwrap(), 1);
+    assert_eq!(smtp.delivered().len(), 1);
+}
+
+#[test]
+fn messages_encode_headers_and_wrap_bodies() {
+    let message = render_message(
+        "blog@example.com",
+        "owner@example.com",
+        "Nouvelle demande : café\r\nBcc: evil@example.com",
+        &"a".repeat(200),
+        "<p>é</p>",
+        0,
+    );
+
+    let (headers, body) = message.split_once("\r\n\r\n").unwrap();
+    // The line break is dropped, so no header can be injected
+    assert!(!headers.contains("\r\nBcc:"));
+    let subject = headers
+        .split("\r\n")
+        .skip_while(|line| !line.starts_with("Subject:"))
+        .take_while(|line| line.starts_with("Subject:") || line.starts_with(' '))
+        .collect::<Vec<_>>();
+    assert!(subject[0].starts_with("Subject: =?UTF-8?B?"));
+    assert!(subject.iter().all(|line| line.len() <= 78));
+    assert!(headers.contains("Date: Thu, 1 Jan 1970 00:00:00 +0000\r\n"));
+    assert!(headers.contains("Message-ID: <"));
+    assert!(headers.contains("@example.com>\r\n"));
+
+    assert!(body.lines().all(|line| line.len() <= 76));
+    assert_eq!(
+        parts(&message),
+        vec!["a".repeat(200), "<p>é</p>".to_string()]
+    );
+}
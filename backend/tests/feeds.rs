@@ -0,0 +1,271 @@
+//! RSS, Atom and JSON feeds of published posts, with conditional GET.
+
+mod common;
+
+use std::time::Duration;
+
+use axum::body::Body;
+use axum::http::{header, Request, StatusCode};
+use axum::response::Response;
+use serde_json::Value;
+use tower::ServiceExt;
+
+use backend::dao::blog::BlogPostUpdate;
+use backend::state::AppState;
+
+async fn setup() -> AppState {
+    let state = common::setup().await;
+    let blog = &state.blog_service;
+
+    let tags = ["Rust".to_string()];
+    let id = blog
+        .create("Fish & <Chips>", "Tasty \"food\"", "# Hello", &tags, None)
+        .await
+        .unwrap();
+    blog.publish(id, None).await.unwrap();
+    let id = blog
+        .create("Untagged", "Plain", "Text", &[], None)
+        .await
+        .unwrap();
+    blog.publish(id, None).await.unwrap();
+    // Drafts never appear in feeds
+    blog.create("Draft", "", "", &tags, None).await.unwrap();
+
+    state
+}
+
+async fn get(state: &AppState, uri: &str, headers: &[(header::HeaderName, &str)]) -> Response {
+    let mut request = Request::get(uri);
+    for (name, value) in headers {
+        request = request.header(name, *value);
+    }
+    backend::web::router(state.clone())
+        .oneshot(request.body(Body::empty()).unwrap())
+        .await
+        .unwrap()
+}
+
+async fn text(response: Response) -> String {
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    String::from_utf8(bytes.to_vec()).unwrap()
+}
+
+fn content_type(response: &Response) -> &str {
+    response.headers()[header::CONTENT_TYPE].to_str().unwrap()
+}
+
+#[tokio::test]
+async fn rss_lists_published_posts_escaped() {
+    let state = setup().await;
+
+    let response = get(&state, "/feed.xml", &[]).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        content_type(&response),
+        "application/rss+xml; charset=utf-8"
+    );
+    let xml = text(response).await;
+
+    assert!(xml.starts_with(r#"<?xml version="1.0" encoding="UTF-8"?><rss version="2.0""#));
+    assert!(xml.contains("<title>Blog</title><link>http://localhost:5173/blog</link>"));
+    assert!(xml.contains(
+        r#"<atom:link href="http://localhost:3000/feed.xml" rel="self" type="application/rss+xml"/>"#
+    ));
+    assert!(xml.contains("<title>Fish &amp; &lt;Chips&gt;</title>"));
+    assert!(xml.contains("<description>Tasty &quot;food&quot;</description>"));
+    assert!(xml.contains("<link>http://localhost:5173/blog/fish-chips</link>"));
+    assert!(xml.contains(r#"<guid isPermaLink="false">http://localhost:5173/blog?id=1</guid>"#));
+    assert!(xml.contains("<content:encoded>&lt;h1"));
+    assert!(xml.contains("<category>rust</category>"));
+    assert_eq!(xml.matches("<item>").count(), 2);
+    assert!(!xml.contains("Draft"));
+}
+
+#[tokio::test]
+async fn atom_lists_published_posts() {
+    let state = setup().await;
+
+    let response = get(&state, "/atom.xml", &[]).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        content_type(&response),
+        "application/atom+xml; charset=utf-8"
+    );
+    let xml = text(response).await;
+
+    assert!(xml.contains(
+        r#"<feed xmlns="http://www.w3.org/2005/Atom"><id>http://localhost:3000/atom.xml</id>"#
+    ));
+    assert!(xml
+        .contains(r#"<link rel="alternate" type="text/html" href="http://localhost:5173/blog"/>"#));
+    assert!(xml.contains(
+        "<entry><id>http://localhost:5173/blog?id=1</id><title>Fish &amp; &lt;Chips&gt;</title>"
+    ));
+    assert!(xml.contains(r#"<category term="rust"/>"#));
+    assert_eq!(xml.matches("<entry>").count(), 2);
+    assert!(!xml.contains("Draft"));
+}
+
+#[tokio::test]
+async fn json_feed_lists_published_posts() {
+    let state = setup().await;
+
+    let response = get(&state, "/feed.json", &[]).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        content_type(&response),
+        "application/feed+json; charset=utf-8"
+    );
+    let feed: Value = serde_json::from_str(&text(response).await).unwrap();
+
+    assert_eq!(feed["version"], "https://jsonfeed.org/version/1.1");
+    assert_eq!(feed["title"], "Blog");
+    assert_eq!(feed["home_page_url"], "http://localhost:5173/blog");
+    assert_eq!(feed["feed_url"], "http://localhost:3000/feed.json");
+
+    let items = feed["items"].as_array().unwrap();
+    assert_eq!(items.len(), 2);
+    let fish = items
+        .iter()
+        .find(|i| i["id"] == "http://localhost:5173/blog?id=1")
+        .unwrap();
+    assert_eq!(fish["title"], "Fish & <Chips>");
+    assert_eq!(fish["url"], "http://localhost:5173/blog/fish-chips");
+    assert_eq!(fish["tags"], serde_json::json!(["rust"]));
+    assert!(fish["content_html"].as_str().unwrap().starts_with("<h1"));
+    // Untagged posts leave the field out
+    let untagged = items.iter().find(|i| i["title"] == "Untagged").unwrap();
+    assert!(untagged.get("tags").is_none());
+}
+
+#[tokio::test]
+async fn tag_feeds_only_list_tagged_posts() {
+    let state = setup().await;
+
+    let response = get(&state, "/tags/Rust/feed.json", &[]).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let feed: Value = serde_json::from_str(&text(response).await).unwrap();
+    assert_eq!(feed["title"], "Blog - rust");
+    assert_eq!(feed["home_page_url"], "http://localhost:5173/blog?tag=rust");
+    assert_eq!(
+        feed["feed_url"],
+        "http://localhost:3000/tags/rust/feed.json"
+    );
+    assert_eq!(feed["items"].as_array().unwrap().len(), 1);
+
+    let response = get(&state, "/tags/missing/feed.xml", &[]).await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn unchanged_feeds_are_not_modified() {
+    let state = setup().await;
+
+    let response = get(&state, "/feed.xml", &[]).await;
+    let etag = response.headers()[header::ETAG]
+        .to_str()
+        .unwrap()
+        .to_string();
+    let last_modified = response.headers()[header::LAST_MODIFIED]
+        .to_str()
+        .unwrap()
+        .to_string();
+    assert_eq!(
+        response.headers()[header::CACHE_CONTROL],
+        "public, max-age=300"
+    );
+
+    let response = get(&state, "/feed.xml", &[(header::IF_NONE_MATCH, &etag)]).await;
+    assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+    let weak = format!("\"other\", W/{etag}");
+    let response = get(&state, "/feed.xml", &[(header::IF_NONE_MATCH, &weak)]).await;
+    assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+
+    let response = get(
+        &state,
+        "/feed.xml",
+        &[(header::IF_MODIFIED_SINCE, &last_modified)],
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+
+    // A mismatched entity tag wins over a current date
+    let response = get(
+        &state,
+        "/feed.xml",
+        &[
+            (header::IF_NONE_MATCH, "\"other\""),
+            (header::IF_MODIFIED_SINCE, &last_modified),
+        ],
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = get(
+        &state,
+        "/feed.xml",
+        &[(header::IF_MODIFIED_SINCE, "Thu, 01 Jan 1970 00:00:00 GMT")],
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+/// The feed's `Last-Modified` and `ETag`
+async fn validators(state: &AppState) -> (String, String) {
+    let response = get(state, "/feed.xml", &[]).await;
+    let headers = response.headers();
+    (
+        headers[header::LAST_MODIFIED].to_str().unwrap().to_string(),
+        headers[header::ETAG].to_str().unwrap().to_string(),
+    )
+}
+
+/// Wait for the next second, the resolution of `Last-Modified`
+async fn next_second() {
+    tokio::time::sleep(Duration::from_millis(1100)).await;
+}
+
+#[tokio::test]
+async fn last_modified_follows_what_readers_see() {
+    let state = setup().await;
+    let blog = &state.blog_service;
+    next_second().await;
+    let (last_modified, etag) = validators(&state).await;
+
+    // Editing the draft changes nothing readers see
+    next_second().await;
+    let changes = BlogPostUpdate {
+        title: Some("Still a draft"),
+        ..Default::default()
+    };
+    blog.update(3, changes, None).await.unwrap();
+    assert_eq!(validators(&state).await, (last_modified.clone(), etag));
+
+    // Renaming a tag changes the categories of the posts carrying it
+    blog.rename_tag("rust", "rust-lang", None).await.unwrap();
+    let (renamed, etag) = validators(&state).await;
+    assert_ne!(renamed, last_modified);
+    let response = get(
+        &state,
+        "/feed.xml",
+        &[(header::IF_MODIFIED_SINCE, &last_modified)],
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // Taking a post out of view leaves no public post newer than before
+    next_second().await;
+    blog.unpublish(2, false, None).await.unwrap();
+    let (unpublished, unpublished_etag) = validators(&state).await;
+    assert_ne!(unpublished, renamed);
+    assert_ne!(unpublished_etag, etag);
+    let response = get(
+        &state,
+        "/feed.xml",
+        &[(header::IF_MODIFIED_SINCE, &renamed)],
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+}
@@ -0,0 +1,296 @@
+//! `AuthController` and the auth layer end to end, against a mock
+//! Firebase serving sign-in, token refresh and public keys.
+
+mod common;
+
+use std::time::Duration;
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use proto::auth::auth_service_server::AuthService;
+use proto::auth::{LoginRequest, LoginResponse, RefreshTokenRequest};
+use tonic::{Code, Request, Response};
+
+use backend::grpc::{AuthController, BLOG_ADMIN_PERMISSIONS};
+use backend::services::key_refresher::refresh_delay;
+use backend::state::AppState;
+use common::{claims, MockFirebase, KEY_1, KEY_2, PROJECT_ID};
+
+const EMAIL: &str = "admin@example.com";
+const PASSWORD: &str = "correct horse";
+
+async fn setup() -> (MockFirebase, AppState) {
+    let firebase = MockFirebase::start().await;
+    firebase.add_account(EMAIL, PASSWORD);
+    let state = common::app_state(firebase.config()).await;
+    (firebase, state)
+}
+
+async fn login(
+    state: &AppState,
+    email: &str,
+    password: &str,
+) -> Result<Response<LoginResponse>, tonic::Status> {
+    AuthController::new(state.clone())
+        .login(Request::new(LoginRequest {
+            email: email.to_string(),
+            password: password.to_string(),
+        }))
+        .await
+}
+
+/// `name=value` pairs from a response's Set-Cookie entries
+fn cookies<T>(response: &Response<T>) -> Vec<String> {
+    response
+        .metadata()
+        .get_all("set-cookie")
+        .iter()
+        .map(|value| {
+            value
+                .to_str()
+                .unwrap()
+                .split(';')
+                .next()
+                .unwrap()
+                .to_string()
+        })
+        .collect()
+}
+
+fn cookie_value(cookies: &[String], name: &str) -> String {
+    cookies
+        .iter()
+        .find_map(|cookie| cookie.strip_prefix(&format!("{name}=")))
+        .unwrap_or_else(|| panic!("no {name} cookie in {cookies:?}"))
+        .to_string()
+}
+
+/// Run an ID token through the auth layer guarding the admin service
+async fn authorize(state: &AppState, id_token: &str) -> Result<String, Code> {
+    common::authorize(state, BLOG_ADMIN_PERMISSIONS, "GetBlogPosts", id_token).await
+}
+
+/// Unsigned token like the Auth Emulator issues
+fn unsigned_token(claims: &backend::dto::auth::Claims) -> String {
+    let header = URL_SAFE_NO_PAD.encode(r#"{"alg":"none","typ":"JWT"}"#);
+    let payload = URL_SAFE_NO_PAD.encode(serde_json::to_vec(claims).unwrap());
+    format!("{header}.{payload}.")
+}
+
+#[tokio::test]
+async fn login_sets_cookies_with_verifiable_tokens() {
+    let (_firebase, state) = setup().await;
+
+    let response = login(&state, EMAIL, PASSWORD).await.unwrap();
+    let cookies = cookies(&response);
+    let id_token = cookie_value(&cookies, "id_token");
+    assert_eq!(cookie_value(&cookies, "refresh_token"), "refresh-1");
+
+    let max_age = response
+        .metadata()
+        .get("set-cookie")
+        .unwrap()
+        .to_str()
+        .unwrap();
+    assert!(max_age.contains("Max-Age=3600"), "{max_age}");
+
+    assert_eq!(
+        authorize(&state, &id_token).await,
+        Ok(format!("uid-{EMAIL}"))
+    );
+}
+
+#[tokio::test]
+async fn login_maps_firebase_error_messages() {
+    let (firebase, state) = setup().await;
+
+    let status = login(&state, "nobody@example.com", PASSWORD)
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), Code::Unauthenticated);
+    assert_eq!(status.message(), "Invalid credentials");
+
+    let status = login(&state, EMAIL, "wrong").await.unwrap_err();
+    assert_eq!(status.code(), Code::Unauthenticated);
+    assert_eq!(status.message(), "Invalid credentials");
+
+    firebase.disable_account(EMAIL);
+    let status = login(&state, EMAIL, PASSWORD).await.unwrap_err();
+    assert_eq!(status.code(), Code::PermissionDenied);
+    assert_eq!(status.message(), "User disabled");
+
+    firebase.fail_sign_in_with(
+        "TOO_MANY_ATTEMPTS_TRY_LATER : Access to this account has been temporarily disabled due to many failed login attempts.",
+    );
+    let status = login(&state, EMAIL, PASSWORD).await.unwrap_err();
+    assert_eq!(status.code(), Code::ResourceExhausted);
+    assert_eq!(status.message(), "Too many attempts, try later");
+}
+
+#[tokio::test]
+async fn login_reports_malformed_firebase_response() {
+    let (firebase, state) = setup().await;
+    firebase.send_malformed_sign_in();
+
+    let status = login(&state, EMAIL, PASSWORD).await.unwrap_err();
+    assert_eq!(status.code(), Code::Internal);
+    assert_eq!(status.message(), "Authentication service error");
+}
+
+#[tokio::test]
+async fn refresh_rotates_tokens_and_rejects_reuse() {
+    let (_firebase, state) = setup().await;
+    let controller = AuthController::new(state.clone());
+
+    let login = login(&state, EMAIL, PASSWORD).await.unwrap();
+    let refresh_token = cookie_value(&cookies(&login), "refresh_token");
+
+    let refresh = |token: &str| {
+        let mut request = Request::new(RefreshTokenRequest {});
+        request
+            .metadata_mut()
+            .insert("cookie", format!("refresh_token={token}").parse().unwrap());
+        controller.refresh_token(request)
+    };
+
+    let response = refresh(&refresh_token).await.unwrap();
+    let cookies = cookies(&response);
+    assert_eq!(cookie_value(&cookies, "refresh_token"), "refresh-2");
+    let id_token = cookie_value(&cookies, "id_token");
+    assert_eq!(
+        authorize(&state, &id_token).await,
+        Ok(format!("uid-{EMAIL}"))
+    );
+
+    let status = refresh(&refresh_token).await.unwrap_err();
+    assert_eq!(status.code(), Code::Unauthenticated);
+    assert_eq!(status.message(), "Invalid refresh token");
+
+    let status = controller
+        .refresh_token(Request::new(RefreshTokenRequest {}))
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), Code::Unauthenticated);
+    assert_eq!(status.message(), "Missing refresh token");
+}
+
+#[tokio::test]
+async fn unknown_key_id_refetches_rotated_keys() {
+    let (firebase, state) = setup().await;
+    assert_eq!(firebase.key_fetches(), 1);
+
+    let old_token = firebase.id_token(&KEY_1, "uid-old");
+    assert_eq!(
+        authorize(&state, &old_token).await,
+        Ok("uid-old".to_string())
+    );
+
+    firebase.rotate_keys(vec![&KEY_1, &KEY_2], &KEY_2);
+    let login = login(&state, EMAIL, PASSWORD).await.unwrap();
+    let new_token = cookie_value(&cookies(&login), "id_token");
+
+    // The first attempt misses the cache and wakes the key refresher
+    assert_eq!(
+        authorize(&state, &new_token).await,
+        Err(Code::Unauthenticated)
+    );
+
+    let mut result = Err(Code::Unauthenticated);
+    for _ in 0..50 {
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        result = authorize(&state, &new_token).await;
+        if result.is_ok() {
+            break;
+        }
+    }
+    assert_eq!(result, Ok(format!("uid-{EMAIL}")));
+    assert_eq!(firebase.key_fetches(), 2);
+
+    // Tokens signed before the rotation stay valid while Google still
+    // publishes their key
+    assert_eq!(
+        authorize(&state, &old_token).await,
+        Ok("uid-old".to_string())
+    );
+}
+
+#[tokio::test]
+async fn key_cache_follows_cache_control_max_age() {
+    let firebase = MockFirebase::start().await;
+    firebase.set_keys_max_age(21_600);
+    let state = common::app_state(firebase.config()).await;
+
+    let status = state.identity.key_cache_status().await.unwrap();
+    assert_eq!(status.key_count, 1);
+    assert_eq!(status.consecutive_failures, 0);
+    let fetched_at = status.last_refresh_at.unwrap();
+    assert_eq!(status.expires_at, Some(fetched_at + 21_600));
+}
+
+#[test]
+fn keys_are_refreshed_ahead_of_expiry() {
+    let now = 1_700_000_000;
+    assert_eq!(
+        refresh_delay(Some(now + 21_600), now),
+        Duration::from_secs(21_600 - 300)
+    );
+    // No keys cached: fetch right away
+    assert_eq!(refresh_delay(None, now), Duration::ZERO);
+    // Keys that expire within the refresh margin, or already have, are still
+    // kept for a few seconds
+    for expires_at in [now + 300, now + 10, now - 60] {
+        assert_eq!(
+            refresh_delay(Some(expires_at), now),
+            Duration::from_secs(5),
+            "{expires_at}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn short_max_age_does_not_refetch_in_a_loop() {
+    let firebase = MockFirebase::start().await;
+    firebase.set_keys_max_age(1);
+    let _state = common::app_state(firebase.config()).await;
+
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    assert_eq!(firebase.key_fetches(), 1);
+}
+
+#[tokio::test]
+async fn unsigned_tokens_need_the_emulator_flag() {
+    let firebase = MockFirebase::start().await;
+    let token = unsigned_token(&claims("uid-emulator"));
+
+    let state = common::app_state(firebase.config()).await;
+    assert_eq!(authorize(&state, &token).await, Err(Code::Unauthenticated));
+
+    let mut config = firebase.config();
+    config.firebase_allow_unsigned_tokens = true;
+    let state = common::app_state(config).await;
+    assert_eq!(
+        authorize(&state, &token).await,
+        Ok("uid-emulator".to_string())
+    );
+
+    // Signed tokens are still checked against the published keys
+    let signed = firebase.id_token(&KEY_1, "uid-signed");
+    assert_eq!(
+        authorize(&state, &signed).await,
+        Ok("uid-signed".to_string())
+    );
+
+    let mut expired = claims("uid-emulator");
+    expired.exp = jsonwebtoken::get_current_timestamp() - 60;
+    assert_eq!(
+        authorize(&state, &unsigned_token(&expired)).await,
+        Err(Code::Unauthenticated)
+    );
+
+    let mut other_project = claims("uid-emulator");
+    other_project.aud = format!("{PROJECT_ID}-other");
+    assert_eq!(
+        authorize(&state, &unsigned_token(&other_project)).await,
+        Err(Code::Unauthenticated)
+    );
+}
@@ -0,0 +1,85 @@
+//! Per-service `grpc.health.v1.Health` statuses kept up to date from the
+//! database and identity provider.
+
+mod common;
+
+use std::time::Duration;
+
+use tonic::Request;
+use tonic_health::pb::health_check_response::ServingStatus;
+use tonic_health::pb::health_server::Health;
+use tonic_health::pb::HealthCheckRequest;
+use tonic_health::server::{HealthReporter, HealthService};
+
+use backend::db;
+use backend::services::health::spawn_health_monitor;
+use common::{app_state, MockFirebase};
+
+const DATABASE_SERVICES: [&str; 2] = ["blog.BlogService", "inquiry.InquiryService"];
+const ADMIN_SERVICES: [&str; 4] = [
+    "",
+    "blog.BlogAdminService",
+    "inquiry.InquiryAdminService",
+    "webhook.WebhookAdminService",
+];
+
+/// Status of `service`, waiting up to a second for it to become `expected`
+async fn wait_for(health: &HealthService, service: &str, expected: ServingStatus) -> ServingStatus {
+    let mut status = ServingStatus::Unknown;
+    for _ in 0..100 {
+        let request = Request::new(HealthCheckRequest {
+            service: service.to_string(),
+        });
+        if let Ok(response) = health.check(request).await {
+            status = response.into_inner().status();
+            if status == expected {
+                break;
+            }
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    status
+}
+
+#[tokio::test]
+async fn every_service_reports_its_dependencies() {
+    let firebase = MockFirebase::start().await;
+    let config = firebase.config();
+    let pool = db::init(&config.database_path).await;
+    let state = app_state(config).await;
+
+    let reporter = HealthReporter::new();
+    let health = HealthService::from_health_reporter(reporter.clone());
+    spawn_health_monitor(
+        reporter,
+        pool.clone(),
+        state.identity.clone(),
+        Duration::from_millis(20),
+    );
+
+    let services = ADMIN_SERVICES
+        .iter()
+        .chain(&DATABASE_SERVICES)
+        .chain(&["auth.AuthService"]);
+    for service in services {
+        assert_eq!(
+            wait_for(&health, service, ServingStatus::Serving).await,
+            ServingStatus::Serving,
+            "{service}"
+        );
+    }
+
+    // Losing the database takes down everything but logins
+    pool.close().await;
+    for service in ADMIN_SERVICES.iter().chain(&DATABASE_SERVICES) {
+        assert_eq!(
+            wait_for(&health, service, ServingStatus::NotServing).await,
+            ServingStatus::NotServing,
+            "{service}"
+        );
+    }
+    assert_eq!(
+        wait_for(&health, "auth.AuthService", ServingStatus::Serving).await,
+        ServingStatus::Serving
+    );
+}
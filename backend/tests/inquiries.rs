@@ -0,0 +1,173 @@
+//! Contact form submissions through `InquiryController` and the inbox kept by
+//! `InquiryAdminController`.
+
+mod common;
+
+use proto::inquiry::inquiry_admin_service_server::InquiryAdminService;
+use proto::inquiry::inquiry_service_server::InquiryService;
+use proto::inquiry::{
+    ArchiveInquiryRequest, DeleteInquiryRequest, GetFormTokenRequest, GetInquiryRequest, Inquiry,
+    InquiryStatus, ListInquiriesRequest, MarkInquiryHandledRequest, SubmitInquiryRequest,
+};
+use tonic::{Code, Request};
+
+use backend::dto::auth::Claims;
+use backend::grpc::{InquiryAdminController, InquiryController, INQUIRY_ADMIN_PERMISSIONS};
+use backend::state::AppState;
+use common::{as_user, claims, setup, MockFirebase, KEY_1};
+
+fn form(name: &str, email: &str, subject: &str, message: &str) -> SubmitInquiryRequest {
+    SubmitInquiryRequest {
+        name: name.to_string(),
+        email: email.to_string(),
+        subject: subject.to_string(),
+        message: message.to_string(),
+        ..Default::default()
+    }
+}
+
+/// Submit the form the way a browser does, with a token fetched beforehand
+async fn submit(state: &AppState, mut request: SubmitInquiryRequest) -> Result<(), Code> {
+    let controller = InquiryController::new(state.clone());
+    request.form_token = controller
+        .get_form_token(Request::new(GetFormTokenRequest {}))
+        .await
+        .unwrap()
+        .into_inner()
+        .form_token;
+
+    controller
+        .submit_inquiry(Request::new(request))
+        .await
+        .map(|_| ())
+        .map_err(|status| status.code())
+}
+
+async fn list(state: &AppState, status: Option<InquiryStatus>) -> Vec<Inquiry> {
+    let request = ListInquiriesRequest {
+        status: status.map(Into::into),
+        limit: 0,
+        offset: 0,
+    };
+    InquiryAdminController::new(state.clone())
+        .list_inquiries(as_user("uid-editor", request))
+        .await
+        .unwrap()
+        .into_inner()
+        .inquiries
+}
+
+#[tokio::test]
+async fn submissions_are_trimmed_and_checked() {
+    let state = setup().await;
+
+    let submitted = submit(
+        &state,
+        form(" Ada ", "ada@example.com ", "Hello", "  Nice site\n"),
+    )
+    .await;
+    assert_eq!(submitted, Ok(()));
+
+    for invalid in [
+        form("", "ada@example.com", "Hello", "Hi"),
+        form("Ada", "ada@example.com", "   ", "Hi"),
+        form("Ada", "not an email", "Hello", "Hi"),
+        form("Ada", "ada@localhost", "Hello", "Hi"),
+        form("Ada", "ada@example.com", "Hello", &"x".repeat(5001)),
+    ] {
+        assert_eq!(submit(&state, invalid).await, Err(Code::InvalidArgument));
+    }
+
+    let inbox = list(&state, None).await;
+    assert_eq!(inbox.len(), 1);
+    assert_eq!(inbox[0].name, "Ada");
+    assert_eq!(inbox[0].email, "ada@example.com");
+    assert_eq!(inbox[0].message, "Nice site");
+    assert_eq!(inbox[0].status(), InquiryStatus::New);
+}
+
+#[tokio::test]
+async fn inquiries_move_through_the_inbox() {
+    let state = setup().await;
+    let admin = InquiryAdminController::new(state.clone());
+    submit(&state, form("Ada", "ada@example.com", "First", "Hi"))
+        .await
+        .unwrap();
+    submit(&state, form("Bob", "bob@example.com", "Second", "Hi"))
+        .await
+        .unwrap();
+
+    let inbox = list(&state, None).await;
+    assert_eq!(
+        inbox.iter().map(|i| i.subject.as_str()).collect::<Vec<_>>(),
+        ["Second", "First"]
+    );
+    let (second, first) = (inbox[0].id, inbox[1].id);
+
+    admin
+        .mark_inquiry_handled(as_user(
+            "uid-alice",
+            MarkInquiryHandledRequest { id: first },
+        ))
+        .await
+        .unwrap();
+    let handled = admin
+        .get_inquiry(as_user("uid-bob", GetInquiryRequest { id: first }))
+        .await
+        .unwrap()
+        .into_inner()
+        .inquiry
+        .expect("inquiry exists");
+    assert_eq!(handled.status(), InquiryStatus::Handled);
+    assert_eq!(handled.handled_by.as_deref(), Some("uid-alice"));
+    assert!(handled.handled_at.is_some());
+
+    admin
+        .archive_inquiry(as_user("uid-alice", ArchiveInquiryRequest { id: first }))
+        .await
+        .unwrap();
+    // Archiving an already-handled inquiry keeps who handled it
+    assert_eq!(list(&state, None).await.len(), 1);
+    let archived = list(&state, Some(InquiryStatus::Archived)).await;
+    assert_eq!(archived.len(), 1);
+    assert_eq!(archived[0].handled_by.as_deref(), Some("uid-alice"));
+
+    admin
+        .delete_inquiry(as_user("uid-alice", DeleteInquiryRequest { id: second }))
+        .await
+        .unwrap();
+    assert!(list(&state, None).await.is_empty());
+
+    let missing = admin
+        .mark_inquiry_handled(as_user(
+            "uid-alice",
+            MarkInquiryHandledRequest { id: second },
+        ))
+        .await
+        .unwrap_err();
+    assert_eq!(missing.code(), Code::NotFound);
+}
+
+#[tokio::test]
+async fn editors_work_the_inbox_but_only_admins_delete() {
+    let firebase = MockFirebase::start().await;
+    let state = common::app_state(firebase.config()).await;
+    let editor = Claims {
+        roles: vec!["editor".to_string()],
+        ..claims("uid-editor")
+    };
+    let token = firebase.id_token_with(&KEY_1, &editor);
+
+    for (rpc, _) in INQUIRY_ADMIN_PERMISSIONS {
+        let expected = if *rpc == "DeleteInquiry" {
+            Err(Code::PermissionDenied)
+        } else {
+            Ok("uid-editor".to_string())
+        };
+        assert_eq!(
+            common::authorize(&state, INQUIRY_ADMIN_PERMISSIONS, rpc, &token).await,
+            expected,
+            "{rpc}"
+        );
+    }
+}
@@ -0,0 +1,263 @@
+//! The local identity provider: argon2 credentials in SQLite and self-signed
+//! EdDSA ID tokens.
+
+mod common;
+
+use jsonwebtoken::{encode, Algorithm, EncodingKey, Header};
+use ring::rand::SystemRandom;
+use ring::signature::Ed25519KeyPair;
+
+use backend::config::{Config, IdentityProviderKind};
+use backend::db::{self, DbPool};
+use backend::dto::auth::{AuthError, Claims};
+use backend::grpc::BLOG_ADMIN_PERMISSIONS;
+use backend::repositories::LocalAuthRepository;
+use backend::services::identity::IdentityProvider;
+use backend::services::LocalAuthService;
+use backend::state::AppState;
+use common::setup_with;
+
+const EMAIL: &str = "admin@example.com";
+const PASSWORD: &str = "correct horse";
+
+fn local(config: &mut Config) {
+    config.identity_provider = IdentityProviderKind::Local;
+    config.local_admin_email = Some(EMAIL.to_string());
+    config.local_admin_password = Some(PASSWORD.to_string());
+}
+
+async fn setup() -> (AppState, DbPool) {
+    let state = setup_with(local).await;
+    let pool = db::init(&state.config.database_path).await;
+    (state, pool)
+}
+
+/// Sign `claims` with the provider's own key, under `kid`
+async fn sign(pool: &DbPool, kid: Option<&str>, claims: &Claims) -> String {
+    let (own_kid, pkcs8) = LocalAuthRepository::new(pool.clone())
+        .signing_key()
+        .await
+        .unwrap()
+        .expect("signing key created at startup");
+    let mut header = Header::new(Algorithm::EdDSA);
+    header.kid = Some(kid.unwrap_or(&own_kid).to_string());
+    encode(&header, claims, &EncodingKey::from_ed_der(&pkcs8)).unwrap()
+}
+
+#[tokio::test]
+async fn login_checks_the_password() {
+    let (state, _pool) = setup().await;
+
+    let session = state
+        .identity
+        .login(" Admin@Example.com ".to_string(), PASSWORD.to_string())
+        .await
+        .unwrap();
+    assert_eq!(session.email, EMAIL);
+    assert_eq!(session.tokens.expires_in, 3600);
+
+    let claims = state
+        .identity
+        .verify(&session.tokens.id_token)
+        .await
+        .unwrap();
+    assert_eq!(claims.sub, session.uid);
+    assert_eq!(claims.email.as_deref(), Some(EMAIL));
+    assert!(claims.email_verified);
+    assert_eq!(claims.roles, vec!["admin".to_string()]);
+    assert_eq!(
+        common::authorize(
+            &state,
+            BLOG_ADMIN_PERMISSIONS,
+            "DeleteBlogPost",
+            &session.tokens.id_token
+        )
+        .await,
+        Ok(session.uid)
+    );
+
+    for (email, password) in [(EMAIL, "wrong"), ("nobody@example.com", PASSWORD)] {
+        let error = state
+            .identity
+            .login(email.to_string(), password.to_string())
+            .await
+            .err();
+        assert_eq!(error, Some(AuthError::InvalidCredentials), "{email}");
+    }
+}
+
+#[tokio::test]
+async fn refresh_tokens_work_once() {
+    let (state, _pool) = setup().await;
+    let session = state
+        .identity
+        .login(EMAIL.to_string(), PASSWORD.to_string())
+        .await
+        .unwrap();
+
+    let refreshed = state
+        .identity
+        .refresh(&session.tokens.refresh_token)
+        .await
+        .unwrap();
+    assert_ne!(refreshed.refresh_token, session.tokens.refresh_token);
+    state.identity.verify(&refreshed.id_token).await.unwrap();
+
+    assert_eq!(
+        state
+            .identity
+            .refresh(&session.tokens.refresh_token)
+            .await
+            .err(),
+        Some(AuthError::InvalidRefreshToken)
+    );
+    assert_eq!(
+        state.identity.refresh("made-up").await.err(),
+        Some(AuthError::InvalidRefreshToken)
+    );
+    state
+        .identity
+        .refresh(&refreshed.refresh_token)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn disabled_users_are_refused() {
+    let (state, pool) = setup().await;
+    let session = state
+        .identity
+        .login(EMAIL.to_string(), PASSWORD.to_string())
+        .await
+        .unwrap();
+
+    sqlx::query("UPDATE local_users SET disabled = 1")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        state
+            .identity
+            .login(EMAIL.to_string(), PASSWORD.to_string())
+            .await
+            .err(),
+        Some(AuthError::UserDisabled)
+    );
+    assert_eq!(
+        state
+            .identity
+            .refresh(&session.tokens.refresh_token)
+            .await
+            .err(),
+        Some(AuthError::UserDisabled)
+    );
+}
+
+#[tokio::test]
+async fn tokens_must_come_from_this_provider() {
+    let (state, pool) = setup().await;
+    let session = state
+        .identity
+        .login(EMAIL.to_string(), PASSWORD.to_string())
+        .await
+        .unwrap();
+    let claims = state
+        .identity
+        .verify(&session.tokens.id_token)
+        .await
+        .unwrap();
+
+    // Re-signing the same claims verifies, so only the changes below matter
+    let token = sign(&pool, None, &claims).await;
+    state.identity.verify(&token).await.unwrap();
+
+    let other_kid = sign(&pool, Some("other-key"), &claims).await;
+    let other_iss = sign(
+        &pool,
+        None,
+        &Claims {
+            iss: "https://elsewhere.example.com".to_string(),
+            ..claims.clone()
+        },
+    )
+    .await;
+    let other_aud = sign(
+        &pool,
+        None,
+        &Claims {
+            aud: "another-app".to_string(),
+            ..claims.clone()
+        },
+    )
+    .await;
+    for token in [other_kid, other_iss, other_aud] {
+        assert_eq!(
+            state.identity.verify(&token).await.err(),
+            Some(AuthError::InvalidToken)
+        );
+    }
+
+    let expired = sign(
+        &pool,
+        None,
+        &Claims {
+            exp: jsonwebtoken::get_current_timestamp() - 3600,
+            ..claims.clone()
+        },
+    )
+    .await;
+    assert_eq!(
+        state.identity.verify(&expired).await.err(),
+        Some(AuthError::ExpiredToken)
+    );
+
+    // Another key under this provider's kid
+    let pkcs8 = Ed25519KeyPair::generate_pkcs8(&SystemRandom::new()).unwrap();
+    let mut header = Header::new(Algorithm::EdDSA);
+    header.kid = jsonwebtoken::decode_header(&session.tokens.id_token)
+        .unwrap()
+        .kid;
+    let forged = encode(&header, &claims, &EncodingKey::from_ed_der(pkcs8.as_ref())).unwrap();
+    assert_eq!(
+        state.identity.verify(&forged).await.err(),
+        Some(AuthError::InvalidToken)
+    );
+}
+
+#[tokio::test]
+async fn startup_resets_a_changed_admin_password() {
+    let (state, pool) = setup().await;
+    let session = state
+        .identity
+        .login(EMAIL.to_string(), PASSWORD.to_string())
+        .await
+        .unwrap();
+
+    let mut config = Config::clone(&state.config);
+    config.local_admin_password = Some("battery staple".to_string());
+    let restarted = LocalAuthService::init(LocalAuthRepository::new(pool.clone()), &config)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        restarted
+            .login(EMAIL.to_string(), PASSWORD.to_string())
+            .await
+            .err(),
+        Some(AuthError::InvalidCredentials)
+    );
+    let relogin = restarted
+        .login(EMAIL.to_string(), "battery staple".to_string())
+        .await
+        .unwrap();
+    assert_eq!(relogin.uid, session.uid);
+
+    // Same account and signing key, so earlier tokens still verify
+    restarted.verify(&session.tokens.id_token).await.unwrap();
+    let users: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM local_users")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(users, 1);
+}
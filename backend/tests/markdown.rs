@@ -0,0 +1,150 @@
+//! Markdown post bodies rendered to sanitized HTML with a table of contents.
+
+use backend::utils::render_markdown;
+
+#[test]
+fn renders_commonmark_and_gfm() {
+    let rendered = render_markdown(
+        "Some *emphasis* and ~~strike~~.\n\n| a | b |\n|---|---|\n| 1 | 2 |\n\n- [x] done\n",
+    );
+    assert!(
+        rendered.html.contains("<em>emphasis</em>"),
+        "{}",
+        rendered.html
+    );
+    assert!(
+        rendered.html.contains("<del>strike</del>"),
+        "{}",
+        rendered.html
+    );
+    assert!(rendered.html.contains("<table>"), "{}", rendered.html);
+    let input = rendered.html.split("<input").nth(1).unwrap_or_default();
+    let input = &input[..input.find('>').unwrap_or_default()];
+    for attribute in [r#"type="checkbox""#, r#"checked="""#, r#"disabled="""#] {
+        assert!(input.contains(attribute), "{}", rendered.html);
+    }
+}
+
+#[test]
+fn strips_scripts_and_event_handlers() {
+    let rendered = render_markdown(
+        "<script>alert(1)</script>\n\n<img src=\"x.png\" onerror=\"alert(2)\">\n\n[link](javascript:alert(3))\n",
+    );
+    assert!(!rendered.html.contains("<script"), "{}", rendered.html);
+    assert!(!rendered.html.contains("onerror"), "{}", rendered.html);
+    assert!(!rendered.html.contains("javascript:"), "{}", rendered.html);
+}
+
+#[test]
+fn keeps_code_languages() {
+    let rendered = render_markdown("```rust\nfn main() {}\n```\n");
+    assert!(
+        rendered.html.contains(r#"<code class="language-rust">"#),
+        "{}",
+        rendered.html
+    );
+}
+
+#[test]
+fn headings_get_unique_anchors_and_a_toc() {
+    let rendered = render_markdown("# Intro\n\n## Setup `cargo`\n\n## Intro\n\n### !!!\n");
+
+    let toc: Vec<(i32, &str, &str)> = rendered
+        .toc
+        .iter()
+        .map(|entry| (entry.level, entry.anchor.as_str(), entry.title.as_str()))
+        .collect();
+    assert_eq!(
+        toc,
+        vec![
+            (1, "user-content-intro", "Intro"),
+            (2, "user-content-setup-cargo", "Setup cargo"),
+            (2, "user-content-intro-2", "Intro"),
+            (3, "user-content-section", "!!!"),
+        ]
+    );
+    assert!(
+        rendered.html.contains(r#"<h1 id="user-content-intro">"#),
+        "{}",
+        rendered.html
+    );
+    assert!(
+        rendered.html.contains(r#"<h2 id="user-content-intro-2">"#),
+        "{}",
+        rendered.html
+    );
+}
+
+#[test]
+fn author_ids_are_prefixed_and_fragment_links_follow() {
+    let rendered = render_markdown(
+        "<div id=\"comments\">hi</div>\n\nSee [below](#comments) and [intro](#user-content-intro).\n",
+    );
+    assert!(
+        rendered
+            .html
+            .contains(r#"<div id="user-content-comments">"#),
+        "{}",
+        rendered.html
+    );
+    assert!(
+        rendered.html.contains(r##"href="#user-content-comments""##),
+        "{}",
+        rendered.html
+    );
+    assert!(
+        rendered.html.contains(r##"href="#user-content-intro""##),
+        "{}",
+        rendered.html
+    );
+}
+
+#[test]
+fn footnote_links_reach_their_definitions() {
+    let rendered = render_markdown("Claim.[^1]\n\n[^1]: Source.\n");
+    assert!(
+        rendered.html.contains(r##"href="#user-content-1""##),
+        "{}",
+        rendered.html
+    );
+    assert!(
+        rendered.html.contains(r#"id="user-content-1""#),
+        "{}",
+        rendered.html
+    );
+}
+
+#[test]
+fn inputs_are_only_disabled_checkboxes() {
+    let rendered =
+        render_markdown("<input type=\"password\" name=\"pw\">\n\n<input value=\"x\">\n");
+    assert_eq!(
+        rendered.html.matches("<input").count(),
+        2,
+        "{}",
+        rendered.html
+    );
+    assert_eq!(
+        rendered.html.matches(r#"type="checkbox""#).count(),
+        2,
+        "{}",
+        rendered.html
+    );
+    assert_eq!(
+        rendered.html.matches(r#"disabled="""#).count(),
+        2,
+        "{}",
+        rendered.html
+    );
+    assert!(!rendered.html.contains("password"), "{}", rendered.html);
+    assert!(!rendered.html.contains("name="), "{}", rendered.html);
+}
+
+#[test]
+fn reading_time_is_at_least_a_minute() {
+    assert_eq!(render_markdown("").reading_minutes, 1);
+    assert_eq!(render_markdown("just a few words").reading_minutes, 1);
+    assert_eq!(render_markdown(&"word ".repeat(200)).reading_minutes, 1);
+    assert_eq!(render_markdown(&"word ".repeat(201)).reading_minutes, 2);
+    assert_eq!(render_markdown(&"word ".repeat(1000)).reading_minutes, 5);
+}
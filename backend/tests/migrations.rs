@@ -0,0 +1,289 @@
+//! Versioned schema migrations: history, checksums, dry runs, refusals and
+//! adoption of databases from before migrations were versioned.
+
+mod common;
+
+use backend::db::migrations::{self, Migration, MigrationError, MIGRATIONS};
+use backend::db::{self, DbPool};
+use common::temp_database;
+
+async fn empty_database() -> DbPool {
+    db::connect(&temp_database()).await
+}
+
+async fn history(pool: &DbPool) -> Vec<(i64, String, String)> {
+    sqlx::query_as("SELECT version, name, checksum FROM schema_migrations ORDER BY version")
+        .fetch_all(pool)
+        .await
+        .unwrap()
+}
+
+async fn table_exists(pool: &DbPool, name: &str) -> bool {
+    sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM sqlite_master WHERE name = ?)")
+        .bind(name)
+        .fetch_one(pool)
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn fresh_database_gets_every_migration_once() {
+    let pool = empty_database().await;
+
+    let applied = migrations::run(&pool).await.unwrap();
+    assert_eq!(applied.len(), MIGRATIONS.len());
+
+    let recorded = history(&pool).await;
+    let expected: Vec<(i64, String, String)> = MIGRATIONS
+        .iter()
+        .map(|m| (m.version, m.name.to_string(), m.checksum()))
+        .collect();
+    assert_eq!(recorded, expected);
+
+    // The search ranking is part of the schema, not a best-effort extra
+    let rank: String = sqlx::query_scalar("SELECT v FROM blog_posts_fts_config WHERE k = 'rank'")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(rank, "bm25(10.0, 1.0, 1.0)");
+
+    assert!(migrations::run(&pool).await.unwrap().is_empty());
+    assert_eq!(history(&pool).await, expected);
+}
+
+#[tokio::test]
+async fn dry_run_leaves_the_database_untouched() {
+    let pool = empty_database().await;
+
+    let pending = migrations::dry_run(&pool).await.unwrap();
+    let versions: Vec<i64> = pending.iter().map(|m| m.version).collect();
+    assert_eq!(
+        versions,
+        MIGRATIONS.iter().map(|m| m.version).collect::<Vec<_>>()
+    );
+
+    let tables: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM sqlite_master")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(tables, 0);
+
+    migrations::run(&pool).await.unwrap();
+    assert!(migrations::dry_run(&pool).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn edited_migration_is_refused() {
+    let pool = empty_database().await;
+    migrations::run(&pool).await.unwrap();
+
+    sqlx::query("UPDATE schema_migrations SET checksum = 'edited' WHERE version = 3")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let err = migrations::run(&pool).await.unwrap_err();
+    assert!(
+        matches!(err, MigrationError::ChecksumMismatch { version: 3, .. }),
+        "{err}"
+    );
+    assert!(matches!(
+        migrations::dry_run(&pool).await,
+        Err(MigrationError::ChecksumMismatch { version: 3, .. })
+    ));
+}
+
+#[tokio::test]
+async fn newer_database_is_refused() {
+    let pool = empty_database().await;
+    migrations::run(&pool).await.unwrap();
+
+    let latest = MIGRATIONS.last().unwrap().version;
+    sqlx::query("INSERT INTO schema_migrations VALUES (?, 'from_the_future', '', 0)")
+        .bind(latest + 1)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let err = migrations::run(&pool).await.unwrap_err();
+    assert!(
+        matches!(err, MigrationError::NewerDatabase { version, latest: l } if version == latest + 1 && l == latest),
+        "{err}"
+    );
+}
+
+#[tokio::test]
+#[should_panic(expected = "refusing to run an older binary")]
+async fn startup_refuses_newer_database() {
+    let path = temp_database();
+    let pool = db::init(&path).await;
+    sqlx::query("INSERT INTO schema_migrations VALUES (1000, 'from_the_future', '', 0)")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    db::init(&path).await;
+}
+
+#[tokio::test]
+async fn failed_migration_is_rolled_back() {
+    let pool = empty_database().await;
+    let broken = [
+        Migration {
+            version: 1,
+            name: "first",
+            sql: "CREATE TABLE first (id INTEGER PRIMARY KEY);",
+        },
+        Migration {
+            version: 2,
+            name: "second",
+            sql: "CREATE TABLE second (id INTEGER PRIMARY KEY); INSERT INTO missing VALUES (1);",
+        },
+    ];
+
+    let err = migrations::migrate(&pool, &broken, false)
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(err, MigrationError::Failed { version: 2, .. }),
+        "{err}"
+    );
+    assert!(table_exists(&pool, "first").await);
+    assert!(!table_exists(&pool, "second").await);
+    assert_eq!(history(&pool).await.len(), 1);
+
+    let fixed = [
+        broken.into_iter().next().unwrap(),
+        Migration {
+            version: 2,
+            name: "second",
+            sql: "CREATE TABLE second (id INTEGER PRIMARY KEY);",
+        },
+    ];
+    let applied = migrations::migrate(&pool, &fixed, false).await.unwrap();
+    assert_eq!(applied.len(), 1);
+    assert!(table_exists(&pool, "second").await);
+}
+
+/// Schema the first release created, before migrations were versioned
+const FIRST_RELEASE: &str = r#"
+    CREATE TABLE IF NOT EXISTS blog_posts (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        title TEXT NOT NULL,
+        description TEXT NOT NULL,
+        body TEXT NOT NULL,
+        created_at INTEGER NOT NULL,
+        updated_at INTEGER NOT NULL,
+        deleted_at INTEGER
+    );
+    CREATE VIRTUAL TABLE IF NOT EXISTS blog_posts_fts USING fts5(
+        title,
+        description,
+        body,
+        content='blog_posts',
+        content_rowid='id',
+        tokenize='porter'
+    );
+    INSERT INTO blog_posts_fts(blog_posts_fts, rank) VALUES('rank', 'bm25(10.0, 1.0, 1.0)');
+    CREATE TRIGGER IF NOT EXISTS blog_posts_ai AFTER INSERT ON blog_posts BEGIN
+        INSERT INTO blog_posts_fts(rowid, title, description, body) VALUES (new.id, new.title, new.description, new.body);
+    END;
+    CREATE TRIGGER IF NOT EXISTS blog_posts_ad AFTER DELETE ON blog_posts BEGIN
+        INSERT INTO blog_posts_fts(blog_posts_fts, rowid, title, description, body) VALUES ('delete', old.id, old.title, old.description, old.body);
+    END;
+    CREATE TRIGGER IF NOT EXISTS blog_posts_au AFTER UPDATE ON blog_posts BEGIN
+        INSERT INTO blog_posts_fts(blog_posts_fts, rowid, title, description, body) VALUES ('delete', old.id, old.title, old.description, old.body);
+        INSERT INTO blog_posts_fts(rowid, title, description, body) VALUES (new.id, new.title, new.description, new.body);
+    END;
+    CREATE INDEX IF NOT EXISTS idx_blog_posts_deleted_at ON blog_posts(deleted_at);
+"#;
+
+/// Every table, index, trigger and view with the SQL that created it
+async fn schema(pool: &DbPool) -> Vec<(String, String, Option<String>)> {
+    sqlx::query_as("SELECT type, name, sql FROM sqlite_master ORDER BY type, name")
+        .fetch_all(pool)
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn legacy_database_is_adopted() {
+    let pool = empty_database().await;
+    sqlx::raw_sql(FIRST_RELEASE).execute(&pool).await.unwrap();
+    sqlx::raw_sql(
+        r#"
+        INSERT INTO blog_posts (title, description, body, created_at, updated_at)
+        VALUES ('Hello World', 'First post', '# Hi', 100, 200);
+        INSERT INTO blog_posts (title, description, body, created_at, updated_at, deleted_at)
+        VALUES ('Hello World', 'Second post', 'Again', 300, 400, 500);
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let before = schema(&pool).await;
+    let pending = migrations::dry_run(&pool).await.unwrap();
+    assert_eq!(pending.len(), MIGRATIONS.len() - 1);
+    assert_eq!(schema(&pool).await, before);
+
+    // Only the first migration is taken as applied; the rest run as usual
+    let applied = migrations::run(&pool).await.unwrap();
+    let versions: Vec<i64> = applied.iter().map(|m| m.version).collect();
+    let later: Vec<i64> = MIGRATIONS.iter().skip(1).map(|m| m.version).collect();
+    assert_eq!(versions, later);
+    let recorded: Vec<i64> = history(&pool).await.iter().map(|h| h.0).collect();
+    let every: Vec<i64> = MIGRATIONS.iter().map(|m| m.version).collect();
+    assert_eq!(recorded, every);
+
+    let fresh = empty_database().await;
+    migrations::run(&fresh).await.unwrap();
+    assert_eq!(schema(&pool).await, schema(&fresh).await);
+
+    let posts: Vec<(String, String, i64, Option<i64>, String)> = sqlx::query_as(
+        "SELECT slug, status, published_at, deleted_at, body_html FROM blog_posts ORDER BY id",
+    )
+    .fetch_all(&pool)
+    .await
+    .unwrap();
+    assert_eq!(posts.len(), 2);
+    assert_eq!(posts[0].0, "hello-world");
+    assert_eq!(posts[1].0, "hello-world-2");
+    assert!(posts.iter().all(|p| p.1 == "published"));
+    assert_eq!((posts[0].2, posts[0].3), (100, None));
+    assert_eq!((posts[1].2, posts[1].3), (300, Some(500)));
+    assert!(posts[0].4.contains("Hi"));
+
+    let revisions: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM blog_post_revisions")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(revisions, 2);
+
+    for index in ["blog_posts_fts", "blog_posts_terms"] {
+        let matches: i64 = sqlx::query_scalar(&format!(
+            "SELECT COUNT(*) FROM {index} WHERE {index} MATCH 'hello'"
+        ))
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(matches, 2, "{index}");
+    }
+
+    assert!(migrations::run(&pool).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn unrecognized_database_is_refused() {
+    let pool = empty_database().await;
+    sqlx::raw_sql(FIRST_RELEASE).execute(&pool).await.unwrap();
+    sqlx::query("ALTER TABLE blog_posts ADD COLUMN slug TEXT")
+        .execute(&pool)
+        .await
+        .unwrap();
+    let before = schema(&pool).await;
+
+    let err = migrations::run(&pool).await.unwrap_err();
+    assert!(matches!(err, MigrationError::UnrecognizedSchema), "{err}");
+    assert_eq!(schema(&pool).await, before);
+}
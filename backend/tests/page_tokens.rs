@@ -0,0 +1,209 @@
+//! Blog listing page sizes and the keyset page tokens that continue a listing.
+
+mod common;
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+
+use backend::dao::blog::{
+    BlogPostCursor, BlogPostFilter, BlogPostSort, BlogPostVisibility, CursorKey, HighlightMarkers,
+    PageStart,
+};
+use backend::services::blog::{decode_page_token, encode_page_token, PageTokenError};
+use common::setup;
+
+const SORTS: [BlogPostSort; 4] = [
+    BlogPostSort::CreatedAsc,
+    BlogPostSort::CreatedDesc,
+    BlogPostSort::UpdatedAsc,
+    BlogPostSort::UpdatedDesc,
+];
+
+/// An admin listing of every post, unfiltered
+fn every_post() -> BlogPostFilter {
+    BlogPostFilter {
+        query: None,
+        tags: vec![],
+        visibility: BlogPostVisibility::All,
+        status: None,
+        markers: HighlightMarkers::default(),
+    }
+}
+
+#[test]
+fn tokens_round_trip() {
+    for sort in SORTS {
+        let cursor = BlogPostCursor {
+            sort,
+            key: CursorKey::Timestamp(1_700_000_000),
+            id: 42,
+        };
+        let token = encode_page_token(&cursor);
+        assert_eq!(decode_page_token(&token, sort, false), Ok(cursor));
+        // Timestamp sorts continue the same way while searching
+        assert_eq!(decode_page_token(&token, sort, true), Ok(cursor));
+    }
+
+    // Unsearched relevance listings fall back to a timestamp key
+    let cursor = BlogPostCursor {
+        sort: BlogPostSort::Relevance,
+        key: CursorKey::Timestamp(-5),
+        id: 1,
+    };
+    let token = encode_page_token(&cursor);
+    assert_eq!(
+        decode_page_token(&token, BlogPostSort::Relevance, false),
+        Ok(cursor)
+    );
+}
+
+#[test]
+fn ranks_round_trip_exactly() {
+    for rank in [-1.234_567_890_123e-7, -0.0, -12.5, f64::MIN_POSITIVE] {
+        let cursor = BlogPostCursor {
+            sort: BlogPostSort::Relevance,
+            key: CursorKey::Rank(rank),
+            id: 7,
+        };
+        let token = encode_page_token(&cursor);
+        let decoded = decode_page_token(&token, BlogPostSort::Relevance, true).unwrap();
+        let CursorKey::Rank(decoded_rank) = decoded.key else {
+            panic!("expected a rank key, got {decoded:?}");
+        };
+        assert_eq!(decoded_rank.to_bits(), rank.to_bits());
+        assert_eq!(decoded.id, 7);
+    }
+}
+
+#[test]
+fn tokens_are_url_safe() {
+    let cursor = BlogPostCursor {
+        sort: BlogPostSort::Relevance,
+        key: CursorKey::Rank(-3.75),
+        id: i64::MAX,
+    };
+    let token = encode_page_token(&cursor);
+    assert!(
+        token
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'),
+        "{token}"
+    );
+}
+
+#[test]
+fn malformed_tokens_are_rejected() {
+    let encoded = |raw: &str| URL_SAFE_NO_PAD.encode(raw);
+    let tokens = [
+        "not a token!".to_string(),
+        String::new(),
+        URL_SAFE_NO_PAD.encode([0xff, 0xfe, 0x00]),
+        encoded("cd:t100"),
+        encoded("zz:t100:1"),
+        encoded("cd:x100:1"),
+        encoded("cd:tabc:1"),
+        encoded("cd:t100:one"),
+        encoded("r:rnothex:1"),
+    ];
+    for token in tokens {
+        assert_eq!(
+            decode_page_token(&token, BlogPostSort::CreatedDesc, false),
+            Err(PageTokenError::Malformed),
+            "{token}"
+        );
+    }
+}
+
+#[test]
+fn tokens_only_continue_their_own_listing() {
+    let cursor = BlogPostCursor {
+        sort: BlogPostSort::CreatedDesc,
+        key: CursorKey::Timestamp(100),
+        id: 1,
+    };
+    let token = encode_page_token(&cursor);
+    assert_eq!(
+        decode_page_token(&token, BlogPostSort::CreatedAsc, false),
+        Err(PageTokenError::SortMismatch)
+    );
+
+    // A ranked token doesn't continue an unsearched listing, and vice versa
+    let ranked = encode_page_token(&BlogPostCursor {
+        sort: BlogPostSort::Relevance,
+        key: CursorKey::Rank(-1.0),
+        id: 1,
+    });
+    assert_eq!(
+        decode_page_token(&ranked, BlogPostSort::Relevance, false),
+        Err(PageTokenError::SortMismatch)
+    );
+    let unranked = encode_page_token(&BlogPostCursor {
+        sort: BlogPostSort::Relevance,
+        key: CursorKey::Timestamp(100),
+        id: 1,
+    });
+    assert_eq!(
+        decode_page_token(&unranked, BlogPostSort::Relevance, true),
+        Err(PageTokenError::SortMismatch)
+    );
+}
+
+#[tokio::test]
+async fn page_sizes_are_clamped() {
+    let blog = setup().await.blog_service;
+    for i in 0..101 {
+        blog.create(&format!("Post {i}"), "", "", &[], None)
+            .await
+            .unwrap();
+    }
+
+    let list = |limit: i32, start: PageStart| {
+        let blog = blog.clone();
+        async move {
+            blog.list_all(every_post(), limit, start, BlogPostSort::CreatedDesc)
+                .await
+                .unwrap()
+        }
+    };
+
+    let page = list(0, PageStart::Offset(0)).await;
+    assert_eq!(page.posts.len(), 10);
+    assert_eq!(page.total, 101);
+
+    let page = list(i32::MAX, PageStart::Offset(0)).await;
+    assert_eq!(page.posts.len(), 100);
+    assert!(page.next.is_some());
+
+    let page = list(5, PageStart::Offset(-20)).await;
+    assert_eq!(page.posts.len(), 5);
+}
+
+#[tokio::test]
+async fn tokens_page_through_every_post_once() {
+    let blog = setup().await.blog_service;
+    for i in 0..7 {
+        blog.create(&format!("Post {i}"), "", "", &[], None)
+            .await
+            .unwrap();
+    }
+
+    let mut seen = vec![];
+    let mut start = PageStart::Offset(0);
+    loop {
+        let page = blog
+            .list_all(every_post(), 3, start, BlogPostSort::CreatedAsc)
+            .await
+            .unwrap();
+        seen.extend(page.posts.iter().map(|p| p.id));
+        let Some(next) = page.next else { break };
+        let token = encode_page_token(&next);
+        start =
+            PageStart::After(decode_page_token(&token, BlogPostSort::CreatedAsc, false).unwrap());
+    }
+
+    let mut expected = seen.clone();
+    expected.sort();
+    expected.dedup();
+    assert_eq!(seen.len(), 7);
+    assert_eq!(seen, expected);
+}
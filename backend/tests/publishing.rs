@@ -0,0 +1,254 @@
+//! The publication workflow: drafts stay hidden from readers until they're
+//! published, now or on schedule, and unpublishing hides them again.
+
+mod common;
+
+use proto::blog::blog_admin_service_server::BlogAdminService;
+use proto::blog::blog_service_server::BlogService;
+use std::time::Duration;
+
+use proto::blog::{
+    BlogPostStatus, CreateBlogPostRequest, GetBlogPostBySlugRequest, GetBlogPostRequest,
+    GetBlogPostsRequest, ListTagsRequest, PublishBlogPostRequest, ScheduleBlogPostRequest,
+    UnpublishBlogPostRequest,
+};
+use tonic::{Code, Request};
+
+use backend::grpc::{BlogAdminController, BlogController};
+use backend::state::AppState;
+use backend::utils::now_timestamp;
+use common::{as_admin, setup};
+
+async fn create(state: &AppState, title: &str) -> i64 {
+    BlogAdminController::new(state.clone())
+        .create_blog_post(as_admin(CreateBlogPostRequest {
+            title: title.to_string(),
+            description: "About publishing".to_string(),
+            body: "Body".to_string(),
+            tags: vec!["workflow".to_string()],
+        }))
+        .await
+        .unwrap()
+        .into_inner()
+        .id
+}
+
+async fn publish(state: &AppState, id: i64) {
+    BlogAdminController::new(state.clone())
+        .publish_blog_post(as_admin(PublishBlogPostRequest { id }))
+        .await
+        .unwrap();
+}
+
+async fn unpublish(state: &AppState, id: i64, archive: bool) -> Result<(), Code> {
+    BlogAdminController::new(state.clone())
+        .unpublish_blog_post(as_admin(UnpublishBlogPostRequest { id, archive }))
+        .await
+        .map(|_| ())
+        .map_err(|status| status.code())
+}
+
+async fn schedule(state: &AppState, id: i64, publish_at: i64) -> Result<(), Code> {
+    BlogAdminController::new(state.clone())
+        .schedule_blog_post(as_admin(ScheduleBlogPostRequest { id, publish_at }))
+        .await
+        .map(|_| ())
+        .map_err(|status| status.code())
+}
+
+async fn status(state: &AppState, id: i64) -> BlogPostStatus {
+    BlogAdminController::new(state.clone())
+        .get_blog_post(as_admin(GetBlogPostRequest { id }))
+        .await
+        .unwrap()
+        .into_inner()
+        .post
+        .expect("admins see every post")
+        .status()
+}
+
+/// Whether readers can find the post by id, by slug, in the list, in search
+/// results and among the tag counts, each in that order
+async fn public_views(state: &AppState, id: i64) -> [bool; 5] {
+    let controller = BlogController::new(state.clone());
+    let listed = |request: GetBlogPostsRequest| {
+        let controller = &controller;
+        async move {
+            controller
+                .get_blog_posts(Request::new(request))
+                .await
+                .unwrap()
+                .into_inner()
+                .posts
+                .iter()
+                .any(|post| post.id == id)
+        }
+    };
+
+    let by_id = controller
+        .get_blog_post(Request::new(GetBlogPostRequest { id }))
+        .await
+        .unwrap()
+        .into_inner()
+        .post
+        .is_some();
+    let by_slug = controller
+        .get_blog_post_by_slug(Request::new(GetBlogPostBySlugRequest {
+            slug: "hello-readers".to_string(),
+        }))
+        .await
+        .is_ok();
+    let in_list = listed(GetBlogPostsRequest::default()).await;
+    let in_search = listed(GetBlogPostsRequest {
+        query: Some("publishing".to_string()),
+        ..Default::default()
+    })
+    .await;
+    let in_tags = !controller
+        .list_tags(Request::new(ListTagsRequest {}))
+        .await
+        .unwrap()
+        .into_inner()
+        .tags
+        .is_empty();
+
+    [by_id, by_slug, in_list, in_search, in_tags]
+}
+
+const HIDDEN: [bool; 5] = [false; 5];
+const VISIBLE: [bool; 5] = [true; 5];
+
+#[tokio::test]
+async fn drafts_are_hidden_until_published() {
+    let state = setup().await;
+    let id = create(&state, "Hello readers").await;
+    assert_eq!(status(&state, id).await, BlogPostStatus::Draft);
+    assert_eq!(public_views(&state, id).await, HIDDEN);
+
+    publish(&state, id).await;
+    assert_eq!(status(&state, id).await, BlogPostStatus::Published);
+    assert_eq!(public_views(&state, id).await, VISIBLE);
+}
+
+#[tokio::test]
+async fn unpublishing_hides_a_post_again() {
+    let state = setup().await;
+    let id = create(&state, "Hello readers").await;
+
+    publish(&state, id).await;
+    unpublish(&state, id, false).await.unwrap();
+    assert_eq!(status(&state, id).await, BlogPostStatus::Draft);
+    assert_eq!(public_views(&state, id).await, HIDDEN);
+
+    publish(&state, id).await;
+    unpublish(&state, id, true).await.unwrap();
+    assert_eq!(status(&state, id).await, BlogPostStatus::Archived);
+    assert_eq!(public_views(&state, id).await, HIDDEN);
+
+    assert_eq!(unpublish(&state, id + 1, false).await, Err(Code::NotFound));
+}
+
+#[tokio::test]
+async fn schedules_must_be_in_the_future() {
+    let state = setup().await;
+    let id = create(&state, "Hello readers").await;
+    let now = now_timestamp();
+
+    assert_eq!(
+        schedule(&state, id, now - 60).await,
+        Err(Code::InvalidArgument)
+    );
+    assert_eq!(schedule(&state, id, now).await, Err(Code::InvalidArgument));
+    assert_eq!(status(&state, id).await, BlogPostStatus::Draft);
+
+    assert_eq!(
+        schedule(&state, id + 1, now + 60).await,
+        Err(Code::NotFound)
+    );
+}
+
+#[tokio::test]
+async fn scheduled_posts_go_live_when_due() {
+    let state = setup().await;
+    let id = create(&state, "Hello readers").await;
+    let later = create(&state, "Later").await;
+
+    schedule(&state, id, now_timestamp() + 2).await.unwrap();
+    schedule(&state, later, now_timestamp() + 3600)
+        .await
+        .unwrap();
+    assert_eq!(status(&state, id).await, BlogPostStatus::Scheduled);
+    assert_eq!(public_views(&state, id).await, HIDDEN);
+    assert_eq!(
+        state.blog_service.publish_due().await.unwrap(),
+        Vec::<i64>::new()
+    );
+
+    // Let the first post's time come
+    tokio::time::sleep(Duration::from_secs(2)).await;
+
+    assert_eq!(state.blog_service.publish_due().await.unwrap(), vec![id]);
+    assert_eq!(status(&state, id).await, BlogPostStatus::Published);
+    assert_eq!(public_views(&state, id).await, VISIBLE);
+    assert_eq!(status(&state, later).await, BlogPostStatus::Scheduled);
+    assert_eq!(
+        state.blog_service.publish_due().await.unwrap(),
+        Vec::<i64>::new()
+    );
+}
+
+#[tokio::test]
+async fn admin_listing_filters_by_status() {
+    let state = setup().await;
+    let draft = create(&state, "Draft").await;
+    let published = create(&state, "Published").await;
+    publish(&state, published).await;
+    let scheduled = create(&state, "Scheduled").await;
+    schedule(&state, scheduled, now_timestamp() + 3600)
+        .await
+        .unwrap();
+    let archived = create(&state, "Archived").await;
+    publish(&state, archived).await;
+    unpublish(&state, archived, true).await.unwrap();
+
+    let controller = BlogAdminController::new(state.clone());
+    for (status, expected) in [
+        (None, vec![draft, published, scheduled, archived]),
+        (
+            Some(BlogPostStatus::Unspecified),
+            vec![draft, published, scheduled, archived],
+        ),
+        (Some(BlogPostStatus::Draft), vec![draft]),
+        (Some(BlogPostStatus::Published), vec![published]),
+        (Some(BlogPostStatus::Scheduled), vec![scheduled]),
+        (Some(BlogPostStatus::Archived), vec![archived]),
+    ] {
+        let response = controller
+            .get_blog_posts(as_admin(GetBlogPostsRequest {
+                status: status.map(|s| s as i32),
+                sort: proto::blog::get_blog_posts_request::Sort::CreatedAsc as i32,
+                ..Default::default()
+            }))
+            .await
+            .unwrap()
+            .into_inner();
+        let ids: Vec<i64> = response.posts.iter().map(|post| post.id).collect();
+        assert_eq!(ids, expected, "{status:?}");
+        assert_eq!(response.total, expected.len() as i32, "{status:?}");
+    }
+
+    // Readers only ever see published posts
+    let ids: Vec<i64> = BlogController::new(state.clone())
+        .get_blog_posts(Request::new(GetBlogPostsRequest {
+            status: Some(BlogPostStatus::Draft as i32),
+            ..Default::default()
+        }))
+        .await
+        .unwrap()
+        .into_inner()
+        .posts
+        .iter()
+        .map(|post| post.id)
+        .collect();
+    assert_eq!(ids, vec![published]);
+}
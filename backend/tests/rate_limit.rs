@@ -0,0 +1,361 @@
+//! `RateLimitLayer` on the auth and public blog services, the matching REST
+//! limits, the client addresses they count by, and the token buckets behind
+//! them.
+
+mod common;
+
+use std::net::{IpAddr, SocketAddr};
+use std::time::Duration;
+
+use axum::body::Body;
+use axum::http::header::{CONTENT_TYPE, RETRY_AFTER};
+use axum::http::StatusCode;
+use axum::middleware;
+use prost::Message;
+use proto::auth::auth_service_server::AuthServiceServer;
+use proto::auth::LoginRequest;
+use proto::blog::blog_service_server::BlogServiceServer;
+use proto::blog::ListTagsRequest;
+use tonic::transport::server::TcpConnectInfo;
+use tonic::Code;
+use tower::{Layer, Service, ServiceExt};
+
+use backend::api;
+use backend::config::{Config, RateLimit};
+use backend::grpc::{AuthController, BlogController, AUTH_RATE_LIMITS, BLOG_RATE_LIMITS};
+use backend::middleware::{resolve_client_ip, RateLimitLayer};
+use backend::services::RateLimiter;
+use backend::state::AppState;
+use common::MockFirebase;
+
+const EMAIL: &str = "admin@example.com";
+const PASSWORD: &str = "correct horse";
+
+async fn setup(configure: impl FnOnce(&mut Config)) -> (MockFirebase, AppState) {
+    let firebase = MockFirebase::start().await;
+    firebase.add_account(EMAIL, PASSWORD);
+    let mut config = firebase.config();
+    configure(&mut config);
+    let state = common::app_state(config).await;
+    (firebase, state)
+}
+
+fn limit(s: &str) -> RateLimit {
+    s.parse().unwrap()
+}
+
+/// A gRPC request as the server passes it on from a client at `ip`
+fn from_ip<S: tonic::server::NamedService>(
+    ip: &str,
+    rpc: &str,
+    message: impl Message,
+) -> http::Request<tonic::body::Body> {
+    let mut request = common::grpc_request::<S>(rpc, message, None);
+    request.extensions_mut().insert(TcpConnectInfo {
+        local_addr: None,
+        remote_addr: Some(SocketAddr::new(ip.parse::<IpAddr>().unwrap(), 50000)),
+    });
+    request
+}
+
+/// Log in through the rate limit layer; returns the status code and the
+/// `retry-after` metadata, if any
+async fn login(state: &AppState, ip: &str, email: &str) -> (Code, Option<u64>) {
+    let service = RateLimitLayer::new(state.clone(), AUTH_RATE_LIMITS)
+        .layer(AuthServiceServer::new(AuthController::new(state.clone())));
+    let message = LoginRequest {
+        email: email.to_string(),
+        password: PASSWORD.to_string(),
+    };
+    let request = from_ip::<AuthServiceServer<AuthController>>(ip, "Login", message);
+
+    let response = service.oneshot(request).await.unwrap();
+    let retry_after = response
+        .headers()
+        .get("retry-after")
+        .map(|v| v.to_str().unwrap().parse().unwrap());
+    (common::grpc_status(response).await, retry_after)
+}
+
+/// Call the REST API the way `main` serves it, over a connection from `peer`;
+/// returns the status and the `Retry-After` header, if any
+async fn rest(
+    state: &AppState,
+    peer: &str,
+    forwarded_for: Option<&str>,
+    uri: &str,
+    login_email: Option<&str>,
+) -> (StatusCode, Option<u64>) {
+    let router = api::router(state.clone()).layer(middleware::from_fn_with_state(
+        state.clone(),
+        resolve_client_ip,
+    ));
+
+    let mut request = http::Request::builder().uri(uri);
+    if let Some(forwarded_for) = forwarded_for {
+        request = request.header("x-forwarded-for", forwarded_for);
+    }
+    let body = match login_email {
+        Some(email) => {
+            request = request
+                .method("POST")
+                .header(CONTENT_TYPE, "application/json");
+            Body::from(serde_json::json!({ "email": email, "password": PASSWORD }).to_string())
+        }
+        None => Body::empty(),
+    };
+    let mut request = request.body(body).unwrap();
+    request.extensions_mut().insert(TcpConnectInfo {
+        local_addr: None,
+        remote_addr: Some(SocketAddr::new(peer.parse::<IpAddr>().unwrap(), 50000)),
+    });
+
+    let response = router.oneshot(request).await.unwrap();
+    let retry_after = response
+        .headers()
+        .get(RETRY_AFTER)
+        .map(|v| v.to_str().unwrap().parse().unwrap());
+    (response.status(), retry_after)
+}
+
+#[tokio::test]
+async fn login_is_limited_per_email_across_addresses() {
+    let (_firebase, state) = setup(|config| {
+        config.login_rate_limit_per_email = limit("2/3600");
+    })
+    .await;
+
+    assert_eq!(login(&state, "203.0.113.1", EMAIL).await, (Code::Ok, None));
+    assert_eq!(login(&state, "203.0.113.2", EMAIL).await, (Code::Ok, None));
+
+    // Case and surrounding whitespace don't make a different account
+    let (code, retry_after) = login(&state, "203.0.113.3", " Admin@Example.com").await;
+    assert_eq!(code, Code::ResourceExhausted);
+    let retry_after = retry_after.expect("retry-after metadata");
+    assert!((1..=1800).contains(&retry_after), "{retry_after}");
+
+    // Other accounts are unaffected
+    let (code, _) = login(&state, "203.0.113.3", "someone@example.com").await;
+    assert_eq!(code, Code::Unauthenticated);
+}
+
+#[tokio::test]
+async fn login_is_limited_per_client_ip() {
+    let (_firebase, state) = setup(|config| {
+        config.login_rate_limit_per_ip = limit("2/3600");
+    })
+    .await;
+
+    for email in ["a@example.com", "b@example.com"] {
+        assert_eq!(
+            login(&state, "198.51.100.7", email).await.0,
+            Code::Unauthenticated
+        );
+    }
+    assert_eq!(
+        login(&state, "198.51.100.7", EMAIL).await.0,
+        Code::ResourceExhausted
+    );
+    assert_eq!(login(&state, "198.51.100.8", EMAIL).await.0, Code::Ok);
+}
+
+#[tokio::test]
+async fn public_blog_service_is_limited_per_client_ip() {
+    let (_firebase, state) = setup(|config| {
+        config.public_rate_limit_per_ip = limit("3/60");
+    })
+    .await;
+    let mut service = RateLimitLayer::new(state.clone(), BLOG_RATE_LIMITS)
+        .layer(BlogServiceServer::new(BlogController::new(state.clone())));
+
+    let mut codes = vec![];
+    for ip in [
+        "192.0.2.1",
+        "192.0.2.1",
+        "192.0.2.1",
+        "192.0.2.1",
+        "192.0.2.2",
+    ] {
+        let request =
+            from_ip::<BlogServiceServer<BlogController>>(ip, "ListTags", ListTagsRequest {});
+        let response = service.ready().await.unwrap().call(request).await.unwrap();
+        codes.push(common::grpc_status(response).await);
+    }
+    assert_eq!(
+        codes,
+        [
+            Code::Ok,
+            Code::Ok,
+            Code::Ok,
+            Code::ResourceExhausted,
+            Code::Ok
+        ]
+    );
+}
+
+#[tokio::test]
+async fn rest_login_shares_the_per_email_limit() {
+    let (_firebase, state) = setup(|config| {
+        config.login_rate_limit_per_email = limit("2/3600");
+    })
+    .await;
+
+    assert_eq!(login(&state, "203.0.113.1", EMAIL).await, (Code::Ok, None));
+    assert_eq!(
+        rest(&state, "203.0.113.2", None, "/auth/login", Some(EMAIL)).await,
+        (StatusCode::OK, None)
+    );
+
+    let (status, retry_after) = rest(
+        &state,
+        "203.0.113.3",
+        None,
+        "/auth/login",
+        Some(" Admin@Example.com"),
+    )
+    .await;
+    assert_eq!(status, StatusCode::TOO_MANY_REQUESTS);
+    let retry_after = retry_after.expect("Retry-After header");
+    assert!((1..=1800).contains(&retry_after), "{retry_after}");
+    assert_eq!(
+        login(&state, "203.0.113.4", EMAIL).await.0,
+        Code::ResourceExhausted
+    );
+
+    // Other accounts are unaffected
+    let (status, _) = rest(
+        &state,
+        "203.0.113.3",
+        None,
+        "/auth/login",
+        Some("someone@example.com"),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn public_blog_rest_is_limited_per_client_ip() {
+    let (_firebase, state) = setup(|config| {
+        config.public_rate_limit_per_ip = limit("3/60");
+    })
+    .await;
+
+    let mut statuses = vec![];
+    for (ip, uri) in [
+        ("192.0.2.1", "/blog"),
+        ("192.0.2.1", "/blog/1"),
+        ("192.0.2.1", "/blog"),
+        ("192.0.2.1", "/blog/1"),
+        ("192.0.2.2", "/blog"),
+    ] {
+        statuses.push(rest(&state, ip, None, uri, None).await.0);
+    }
+    assert_eq!(
+        statuses,
+        [
+            StatusCode::OK,
+            StatusCode::NOT_FOUND,
+            StatusCode::OK,
+            StatusCode::TOO_MANY_REQUESTS,
+            StatusCode::OK
+        ]
+    );
+
+    // The gRPC BlogService shares the bucket
+    let request =
+        from_ip::<BlogServiceServer<BlogController>>("192.0.2.2", "ListTags", ListTagsRequest {});
+    let service = RateLimitLayer::new(state.clone(), BLOG_RATE_LIMITS)
+        .layer(BlogServiceServer::new(BlogController::new(state.clone())));
+    let response = service.oneshot(request).await.unwrap();
+    assert_eq!(common::grpc_status(response).await, Code::Ok);
+    assert_eq!(
+        rest(&state, "192.0.2.2", None, "/blog", None).await.0,
+        StatusCode::OK
+    );
+    assert_eq!(
+        rest(&state, "192.0.2.2", None, "/blog", None).await.0,
+        StatusCode::TOO_MANY_REQUESTS
+    );
+}
+
+#[tokio::test]
+async fn forwarded_addresses_are_only_believed_from_trusted_proxies() {
+    let (_firebase, state) = setup(|config| {
+        config.public_rate_limit_per_ip = limit("1/60");
+        config.trusted_proxies = vec!["10.0.0.0/8".parse().unwrap()];
+    })
+    .await;
+    let status = |peer: &'static str, forwarded_for: Option<&'static str>| {
+        let state = state.clone();
+        async move { rest(&state, peer, forwarded_for, "/blog", None).await.0 }
+    };
+
+    // Clients behind the proxy get their own buckets
+    assert_eq!(status("10.0.0.1", Some("192.0.2.1")).await, StatusCode::OK);
+    assert_eq!(status("10.0.0.1", Some("192.0.2.2")).await, StatusCode::OK);
+    assert_eq!(
+        status("10.0.0.2", Some("192.0.2.1")).await,
+        StatusCode::TOO_MANY_REQUESTS
+    );
+
+    // Trusted hops are skipped from the right; an address the client sent
+    // itself, left of the one the proxy saw, is ignored
+    assert_eq!(
+        status("10.0.0.1", Some("192.0.2.3, 10.0.0.9")).await,
+        StatusCode::OK
+    );
+    assert_eq!(
+        status("10.0.0.1", Some("192.0.2.3, 198.51.100.1")).await,
+        StatusCode::OK
+    );
+    assert_eq!(
+        status("10.0.0.1", Some("192.0.2.4, 198.51.100.1")).await,
+        StatusCode::TOO_MANY_REQUESTS
+    );
+
+    // Anyone else is counted by the connection's address
+    assert_eq!(
+        status("203.0.113.9", Some("192.0.2.5")).await,
+        StatusCode::OK
+    );
+    assert_eq!(
+        status("203.0.113.9", Some("192.0.2.6")).await,
+        StatusCode::TOO_MANY_REQUESTS
+    );
+    assert_eq!(status("192.0.2.5", None).await, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn buckets_survive_a_restart_when_persisted() {
+    let (_firebase, state) = setup(|config| {
+        config.login_rate_limit_per_email = limit("1/3600");
+        config.rate_limit_persist = true;
+    })
+    .await;
+    assert_eq!(login(&state, "203.0.113.1", EMAIL).await.0, Code::Ok);
+    state.rate_limiter.persist().await.unwrap();
+
+    let restarted = common::app_state(state.config.as_ref().clone()).await;
+    // The client IP's bucket and the email's
+    assert_eq!(restarted.rate_limiter.restore().await.unwrap(), 2);
+    assert_eq!(
+        login(&restarted, "203.0.113.1", EMAIL).await.0,
+        Code::ResourceExhausted
+    );
+}
+
+#[tokio::test]
+async fn refilled_buckets_are_pruned() {
+    let limiter = RateLimiter::new(None);
+    let one_per_second = limit("1/1");
+
+    assert_eq!(limiter.check("test:a", one_per_second), Ok(()));
+    let retry_after = limiter.check("test:a", one_per_second).unwrap_err();
+    assert!(retry_after <= Duration::from_secs(1));
+    assert_eq!(limiter.prune(), 0);
+
+    tokio::time::sleep(Duration::from_millis(1100)).await;
+    assert_eq!(limiter.prune(), 1);
+    assert_eq!(limiter.check("test:a", one_per_second), Ok(()));
+}
@@ -0,0 +1,228 @@
+//! The REST API: cookie and bearer authentication, the editor/admin split on
+//! protected routes, auth error messages and CORS.
+
+mod common;
+
+use axum::body::Body;
+use axum::http::{header, Method, Request, StatusCode};
+use axum::response::Response;
+use serde_json::json;
+use tower::{Layer, ServiceExt};
+
+use backend::dto::auth::Claims;
+use backend::middleware::cors_layer;
+use backend::state::AppState;
+use common::{app_state, claims, MockFirebase, KEY_1};
+
+const FRONTEND: &str = "http://localhost:5173";
+
+enum Auth {
+    None,
+    Cookie(String),
+    Bearer(String),
+}
+
+async fn setup() -> (MockFirebase, AppState) {
+    let firebase = MockFirebase::start().await;
+    let state = app_state(firebase.config()).await;
+    (firebase, state)
+}
+
+fn token(firebase: &MockFirebase, uid: &str, roles: &[&str]) -> String {
+    firebase.id_token_with(
+        &KEY_1,
+        &Claims {
+            roles: roles.iter().map(|role| role.to_string()).collect(),
+            ..claims(uid)
+        },
+    )
+}
+
+async fn call(
+    state: &AppState,
+    method: Method,
+    uri: &str,
+    auth: Auth,
+    body: Option<serde_json::Value>,
+) -> Response {
+    let mut request = Request::builder().method(method).uri(uri);
+    request = match auth {
+        Auth::None => request,
+        Auth::Cookie(token) => request.header(header::COOKIE, format!("id_token={token}")),
+        Auth::Bearer(token) => request.header(header::AUTHORIZATION, format!("Bearer {token}")),
+    };
+    let body = match body {
+        Some(body) => {
+            request = request.header(header::CONTENT_TYPE, "application/json");
+            Body::from(body.to_string())
+        }
+        None => Body::empty(),
+    };
+
+    backend::api::router(state.clone())
+        .oneshot(request.body(body).unwrap())
+        .await
+        .unwrap()
+}
+
+async fn create(state: &AppState, auth: Auth) -> Response {
+    call(
+        state,
+        Method::POST,
+        "/blog",
+        auth,
+        Some(json!({ "title": "Hello", "description": "", "body": "Body" })),
+    )
+    .await
+}
+
+async fn text(response: Response) -> String {
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    String::from_utf8(bytes.to_vec()).unwrap()
+}
+
+#[tokio::test]
+async fn cookie_and_bearer_tokens_both_authenticate() {
+    let (firebase, state) = setup().await;
+    let token = token(&firebase, "uid-admin", &["admin"]);
+
+    let response = create(&state, Auth::Cookie(token.clone())).await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let response = create(&state, Auth::Bearer(token)).await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    for auth in [
+        Auth::None,
+        Auth::Cookie("not-a-jwt".to_string()),
+        Auth::Bearer("not-a-jwt".to_string()),
+    ] {
+        assert_eq!(
+            create(&state, auth).await.status(),
+            StatusCode::UNAUTHORIZED
+        );
+    }
+
+    // Public routes need no token
+    let response = call(&state, Method::GET, "/blog", Auth::None, None).await;
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn editors_write_but_only_admins_delete() {
+    let (firebase, state) = setup().await;
+    let editor = || Auth::Bearer(token(&firebase, "uid-editor", &["editor"]));
+    let admin = || Auth::Bearer(token(&firebase, "uid-admin", &["admin"]));
+    let reader = Auth::Bearer(token(&firebase, "uid-reader", &[]));
+
+    assert_eq!(create(&state, reader).await.status(), StatusCode::FORBIDDEN);
+    let response = create(&state, editor()).await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let created: serde_json::Value = serde_json::from_str(&text(response).await).unwrap();
+    let post = format!("/blog/{}", created["id"]);
+    let publish = format!("{post}/publish");
+    let restore = format!("{post}/restore");
+
+    let response = call(&state, Method::POST, &publish, editor(), None).await;
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    let response = call(&state, Method::DELETE, &post, editor(), None).await;
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    let response = call(&state, Method::DELETE, &post, admin(), None).await;
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    let response = call(&state, Method::POST, &restore, editor(), None).await;
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    let response = call(&state, Method::POST, &restore, admin(), None).await;
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+}
+
+#[tokio::test]
+async fn auth_errors_have_readable_messages() {
+    let (firebase, state) = setup().await;
+    firebase.add_account("admin@example.com", "correct horse");
+
+    let response = call(
+        &state,
+        Method::POST,
+        "/auth/login",
+        Auth::None,
+        Some(json!({ "email": "admin@example.com", "password": "wrong" })),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(text(response).await, "Invalid credentials");
+
+    firebase.disable_account("admin@example.com");
+    let response = call(
+        &state,
+        Method::POST,
+        "/auth/login",
+        Auth::None,
+        Some(json!({ "email": "admin@example.com", "password": "correct horse" })),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    assert_eq!(text(response).await, "User disabled");
+
+    let response = backend::api::router(state.clone())
+        .oneshot(
+            Request::post("/auth/refresh")
+                .header(header::COOKIE, "refresh_token=made-up")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(text(response).await, "Invalid refresh token");
+}
+
+#[tokio::test]
+async fn cors_allows_the_rest_methods_from_the_frontend() {
+    let (_firebase, state) = setup().await;
+    let service = cors_layer(FRONTEND).layer(backend::api::router(state));
+
+    for method in ["GET", "POST", "PATCH", "DELETE"] {
+        let response = service
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method(Method::OPTIONS)
+                    .uri("/blog/1")
+                    .header(header::ORIGIN, FRONTEND)
+                    .header(header::ACCESS_CONTROL_REQUEST_METHOD, method)
+                    .header(header::ACCESS_CONTROL_REQUEST_HEADERS, "authorization")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let headers = response.headers();
+        assert_eq!(headers[header::ACCESS_CONTROL_ALLOW_ORIGIN], FRONTEND);
+        assert_eq!(headers[header::ACCESS_CONTROL_ALLOW_CREDENTIALS], "true");
+        let allowed = headers[header::ACCESS_CONTROL_ALLOW_METHODS]
+            .to_str()
+            .unwrap();
+        assert!(allowed.split(',').any(|m| m.trim() == method), "{allowed}");
+    }
+
+    let response = service
+        .oneshot(
+            Request::builder()
+                .method(Method::OPTIONS)
+                .uri("/blog/1")
+                .header(header::ORIGIN, "https://evil.example.com")
+                .header(header::ACCESS_CONTROL_REQUEST_METHOD, "DELETE")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    // Browsers compare the one allowed origin with their own and refuse
+    assert_eq!(
+        response.headers()[header::ACCESS_CONTROL_ALLOW_ORIGIN],
+        FRONTEND
+    );
+}
@@ -0,0 +1,73 @@
+//! Line-level diffs between blog post revisions.
+
+use backend::services::blog::{diff_lines, DiffLine, DiffOp};
+
+/// Each line as (op, text, old line, new line)
+fn summary(diff: &[DiffLine]) -> Vec<(DiffOp, &str, Option<usize>, Option<usize>)> {
+    diff.iter()
+        .map(|line| (line.op, line.text.as_str(), line.old_line, line.new_line))
+        .collect()
+}
+
+#[test]
+fn identical_texts_are_all_equal() {
+    let diff = diff_lines("one\ntwo\n", "one\ntwo\n");
+    assert_eq!(
+        summary(&diff),
+        vec![
+            (DiffOp::Equal, "one", Some(1), Some(1)),
+            (DiffOp::Equal, "two", Some(2), Some(2)),
+        ]
+    );
+}
+
+#[test]
+fn changed_line_is_a_delete_and_an_insert() {
+    let diff = diff_lines("one\ntwo\nthree\n", "one\n2\nthree\n");
+    assert_eq!(
+        summary(&diff),
+        vec![
+            (DiffOp::Equal, "one", Some(1), Some(1)),
+            (DiffOp::Delete, "two", Some(2), None),
+            (DiffOp::Insert, "2", None, Some(2)),
+            (DiffOp::Equal, "three", Some(3), Some(3)),
+        ]
+    );
+}
+
+#[test]
+fn line_numbers_follow_each_side() {
+    let diff = diff_lines("a\nb\n", "intro\na\nb\noutro\n");
+    assert_eq!(
+        summary(&diff),
+        vec![
+            (DiffOp::Insert, "intro", None, Some(1)),
+            (DiffOp::Equal, "a", Some(1), Some(2)),
+            (DiffOp::Equal, "b", Some(2), Some(3)),
+            (DiffOp::Insert, "outro", None, Some(4)),
+        ]
+    );
+}
+
+#[test]
+fn line_endings_are_stripped() {
+    let diff = diff_lines("windows\r\nlast", "windows\r\nlast\n");
+    let texts: Vec<&str> = diff.iter().map(|line| line.text.as_str()).collect();
+    assert!(texts.iter().all(|text| !text.ends_with(['\r', '\n'])));
+    assert_eq!(diff[0].op, DiffOp::Equal);
+    assert_eq!(diff[0].text, "windows");
+}
+
+#[test]
+fn empty_texts_have_no_lines() {
+    assert!(diff_lines("", "").is_empty());
+
+    let diff = diff_lines("", "new\n");
+    assert_eq!(summary(&diff), vec![(DiffOp::Insert, "new", None, Some(1))]);
+
+    let diff = diff_lines("gone\n", "");
+    assert_eq!(
+        summary(&diff),
+        vec![(DiffOp::Delete, "gone", Some(1), None)]
+    );
+}
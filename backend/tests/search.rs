@@ -0,0 +1,290 @@
+//! Blog search: parsing reader input into FTS5 expressions, excerpts with
+//! marked matches, and suggestions.
+
+mod common;
+
+use backend::dao::blog::{
+    BlogPostFilter, BlogPostSort, BlogPostSummary, BlogPostVisibility, HighlightMarkers, PageStart,
+};
+use backend::db;
+use backend::repositories::BlogRepository;
+use backend::services::blog::{parse_search_query, SearchQueryError};
+use backend::services::BlogService;
+use common::{setup, temp_database};
+
+fn parse(input: &str) -> Option<String> {
+    parse_search_query(input).unwrap()
+}
+
+#[test]
+fn plain_words_must_all_match() {
+    assert_eq!(parse("rust").as_deref(), Some(r#"("rust")"#));
+    assert_eq!(
+        parse("  rust   web ").as_deref(),
+        Some(r#"("rust" AND "web")"#)
+    );
+}
+
+#[test]
+fn quoted_phrases_stay_together() {
+    assert_eq!(
+        parse(r#""exact phrase" rust"#).as_deref(),
+        Some(r#"("exact phrase" AND "rust")"#)
+    );
+    assert_eq!(parse(r#"say"hi""#).as_deref(), Some(r#"("say" AND "hi")"#));
+    assert_eq!(
+        parse_search_query(r#""unclosed phrase"#),
+        Err(SearchQueryError::UnclosedQuote)
+    );
+}
+
+#[test]
+fn operators_are_searched_as_text() {
+    assert_eq!(
+        parse("rust OR NEAR(a b) AND ^c").as_deref(),
+        Some(r#"("rust" AND "OR" AND "NEAR(a" AND "b)" AND "AND" AND "^c")"#)
+    );
+}
+
+#[test]
+fn exclusions_and_prefixes() {
+    assert_eq!(
+        parse("rust -draft -\"old news\"").as_deref(),
+        Some(r#"("rust") NOT "draft" NOT "old news""#)
+    );
+    assert_eq!(parse("rus*").as_deref(), Some(r#"("rus"*)"#));
+    assert_eq!(
+        parse(r#""hello wor"*"#).as_deref(),
+        Some(r#"("hello wor"*)"#)
+    );
+
+    assert_eq!(
+        parse_search_query("-rust"),
+        Err(SearchQueryError::OnlyExclusions)
+    );
+    assert_eq!(
+        parse_search_query("ru*st"),
+        Err(SearchQueryError::MisplacedWildcard("ru*st".to_string()))
+    );
+    assert_eq!(
+        parse_search_query("*"),
+        Err(SearchQueryError::MisplacedWildcard("*".to_string()))
+    );
+}
+
+#[test]
+fn field_prefixes_scope_a_term() {
+    assert_eq!(parse("title:rust").as_deref(), Some(r#"(title : "rust")"#));
+    assert_eq!(
+        parse(r#"BODY:"borrow checker" -description:draft"#).as_deref(),
+        Some(r#"(body : "borrow checker") NOT description : "draft""#)
+    );
+    // The scope carries over a space instead of being dropped
+    assert_eq!(
+        parse("title: rust web").as_deref(),
+        Some(r#"(title : "rust" AND "web")"#)
+    );
+}
+
+#[test]
+fn unknown_prefixes_are_plain_text() {
+    assert_eq!(
+        parse("https://example.com/posts").as_deref(),
+        Some(r#"("https://example.com/posts")"#)
+    );
+    assert_eq!(
+        parse("note: foo").as_deref(),
+        Some(r#"("note:" AND "foo")"#)
+    );
+    assert_eq!(parse("c++:rust").as_deref(), Some(r#"("c++:rust")"#));
+}
+
+#[test]
+fn nothing_searchable_is_no_query() {
+    assert_eq!(parse(""), None);
+    assert_eq!(parse("   "), None);
+    assert_eq!(parse("!!! -- ..."), None);
+    assert_eq!(parse("title:"), None);
+}
+
+#[test]
+fn long_queries_are_rejected() {
+    let words = vec!["word"; 32].join(" ");
+    assert!(parse_search_query(&words).unwrap().is_some());
+
+    let words = vec!["word"; 33].join(" ");
+    assert_eq!(
+        parse_search_query(&words),
+        Err(SearchQueryError::TooManyTerms)
+    );
+}
+
+async fn repo() -> BlogRepository {
+    BlogRepository::new(db::init(&temp_database()).await)
+}
+
+async fn search(
+    repo: &BlogRepository,
+    query: &str,
+    markers: HighlightMarkers,
+) -> Vec<BlogPostSummary> {
+    let filter = BlogPostFilter {
+        query: Some(query.to_string()),
+        tags: vec![],
+        visibility: BlogPostVisibility::All,
+        status: None,
+        markers,
+    };
+    repo.list(&filter, 10, PageStart::Offset(0), BlogPostSort::Relevance)
+        .await
+        .unwrap()
+        .posts
+}
+
+#[tokio::test]
+async fn excerpts_mark_matches() {
+    let repo = repo().await;
+    repo.create(
+        "Learning Rust",
+        "Notes on rust",
+        "Ownership is what makes Rust different.",
+        &[],
+        None,
+    )
+    .await
+    .unwrap();
+
+    let posts = search(&repo, "rust", HighlightMarkers::default()).await;
+    assert_eq!(posts.len(), 1);
+    assert_eq!(
+        posts[0].highlighted_title.as_deref(),
+        Some("Learning <mark>Rust</mark>")
+    );
+    assert_eq!(
+        posts[0].highlighted_description.as_deref(),
+        Some("Notes on <mark>rust</mark>")
+    );
+    assert_eq!(
+        posts[0].snippet.as_deref(),
+        Some("Ownership is what makes <mark>Rust</mark> different.")
+    );
+
+    let markers = HighlightMarkers {
+        open: "[".to_string(),
+        close: "]".to_string(),
+    };
+    let posts = search(&repo, "rust", markers).await;
+    assert_eq!(
+        posts[0].highlighted_title.as_deref(),
+        Some("Learning [Rust]")
+    );
+}
+
+#[tokio::test]
+async fn excerpts_escape_post_html() {
+    let repo = repo().await;
+    repo.create(
+        "<b>Bold</b> & rust",
+        "<img src=x onerror=alert(1)> rust",
+        "Try <script>alert('rust')</script> in \"rust\"",
+        &[],
+        None,
+    )
+    .await
+    .unwrap();
+
+    let posts = search(&repo, "rust", HighlightMarkers::default()).await;
+    let post = &posts[0];
+    assert_eq!(
+        post.highlighted_title.as_deref(),
+        Some("&lt;b&gt;Bold&lt;/b&gt; &amp; <mark>rust</mark>")
+    );
+    assert_eq!(
+        post.highlighted_description.as_deref(),
+        Some("&lt;img src=x onerror=alert(1)&gt; <mark>rust</mark>")
+    );
+    let snippet = post.snippet.as_deref().unwrap();
+    assert!(!snippet.contains("<script"), "{snippet}");
+    assert!(snippet.contains("&lt;script&gt;"), "{snippet}");
+    assert!(snippet.contains("<mark>rust</mark>"), "{snippet}");
+}
+
+#[tokio::test]
+async fn urls_find_posts_that_mention_them() {
+    let repo = repo().await;
+    repo.create(
+        "Links",
+        "",
+        "See https://example.com/posts for more.",
+        &[],
+        None,
+    )
+    .await
+    .unwrap();
+
+    let query = parse("https://example.com/posts").unwrap();
+    let posts = search(&repo, &query, HighlightMarkers::default()).await;
+    assert_eq!(posts.len(), 1);
+}
+
+/// A blog service with one published and one draft post
+async fn suggestions_setup() -> std::sync::Arc<BlogService> {
+    let blog = setup().await.blog_service;
+
+    let published = blog
+        .create("Rust ownership", "", "Borrowing explained", &[], None)
+        .await
+        .unwrap();
+    blog.publish(published, None).await.unwrap();
+    blog.create("Unannounced", "", "Confidential roadmap", &[], None)
+        .await
+        .unwrap();
+
+    blog
+}
+
+#[tokio::test]
+async fn suggestions_complete_and_correct_public_words() {
+    let blog = suggestions_setup().await;
+
+    let suggestions = blog.suggest("rust own", 5).await.unwrap();
+    assert_eq!(suggestions.completions, vec!["rust ownership"]);
+    assert_eq!(suggestions.did_you_mean, None);
+
+    let suggestions = blog.suggest("rsut borowing ", 5).await.unwrap();
+    assert!(suggestions.completions.is_empty());
+    assert_eq!(suggestions.did_you_mean.as_deref(), Some("rust borrowing"));
+
+    // Words only in drafts are never suggested
+    let suggestions = blog.suggest("confidentail", 5).await.unwrap();
+    assert!(suggestions.completions.is_empty());
+    assert_eq!(suggestions.did_you_mean, None);
+    assert!(blog
+        .suggest("conf", 5)
+        .await
+        .unwrap()
+        .completions
+        .is_empty());
+}
+
+#[tokio::test]
+async fn suggestions_are_bounded() {
+    let blog = suggestions_setup().await;
+
+    // Only the last five words are checked for typos
+    let suggestions = blog.suggest("one two three four rsut ", 5).await.unwrap();
+    assert_eq!(
+        suggestions.did_you_mean.as_deref(),
+        Some("one two three four rust")
+    );
+    let suggestions = blog
+        .suggest("rsut one two three four five ", 5)
+        .await
+        .unwrap();
+    assert_eq!(suggestions.did_you_mean, None);
+
+    let long = format!("{} rsut", "word ".repeat(40));
+    let suggestions = blog.suggest(&long, 5).await.unwrap();
+    assert!(suggestions.completions.is_empty());
+    assert_eq!(suggestions.did_you_mean, None);
+}
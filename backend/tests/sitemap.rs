@@ -0,0 +1,117 @@
+//! Sitemap and robots.txt generated from published posts.
+
+mod common;
+
+use std::sync::Arc;
+
+use axum::body::Body;
+use axum::http::{header, Request, StatusCode};
+use axum::response::Response;
+use tower::ServiceExt;
+
+use backend::state::AppState;
+use backend::utils::rfc3339;
+use common::setup;
+
+async fn get(state: &AppState, uri: &str) -> Response {
+    backend::web::router(state.clone())
+        .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+        .await
+        .unwrap()
+}
+
+async fn text(response: Response) -> String {
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    String::from_utf8(bytes.to_vec()).unwrap()
+}
+
+#[tokio::test]
+async fn sitemap_lists_pages_and_published_posts() {
+    let state = setup().await;
+    let blog = &state.blog_service;
+    let id = blog.create("Hello & bye", "", "", &[], None).await.unwrap();
+    blog.publish(id, None).await.unwrap();
+    blog.create("Draft", "", "", &[], None).await.unwrap();
+    let updated_at = blog.get_any(id).await.unwrap().unwrap().updated_at;
+
+    let index = state.sitemap_service.index().await.unwrap();
+    assert!(index.pages.is_empty());
+    assert_eq!(
+        index.sitemap,
+        format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\
+             <urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\
+             <url><loc>http://localhost:5173/</loc></url>\
+             <url><loc>http://localhost:5173/projects</loc></url>\
+             <url><loc>http://localhost:5173/blog</loc></url>\
+             <url><loc>http://localhost:5173/more</loc></url>\
+             <url><loc>http://localhost:5173/blog/hello-bye</loc><lastmod>{}</lastmod></url>\
+             </urlset>",
+            rfc3339(updated_at)
+        )
+    );
+    assert_eq!(index.last_modified, updated_at);
+}
+
+#[tokio::test]
+async fn robots_points_at_the_sitemap() {
+    let state = setup().await;
+
+    let response = get(&state, "/robots.txt").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()[header::CONTENT_TYPE],
+        "text/plain; charset=utf-8"
+    );
+    assert_eq!(
+        text(response).await,
+        "User-agent: *\n\
+         Allow: /sitemap.xml\nAllow: /sitemaps/\n\
+         Allow: /feed.xml\nAllow: /atom.xml\nAllow: /feed.json\nAllow: /tags/\n\
+         Disallow: /\n\n\
+         Sitemap: http://localhost:3000/sitemap.xml\n"
+    );
+}
+
+#[tokio::test]
+async fn sitemap_is_rebuilt_after_post_changes() {
+    let state = setup().await;
+    let blog = &state.blog_service;
+    let sitemaps = &state.sitemap_service;
+
+    let first = sitemaps.index().await.unwrap();
+    assert!(Arc::ptr_eq(&first, &sitemaps.index().await.unwrap()));
+
+    let id = blog.create("New post", "", "", &[], None).await.unwrap();
+    blog.publish(id, None).await.unwrap();
+    let second = sitemaps.index().await.unwrap();
+    assert!(!Arc::ptr_eq(&first, &second));
+    assert!(second.sitemap.contains("/blog/new-post</loc>"));
+
+    blog.delete(id, None).await.unwrap();
+    assert!(!sitemaps.index().await.unwrap().sitemap.contains("new-post"));
+}
+
+#[tokio::test]
+async fn sitemap_routes() {
+    let state = setup().await;
+
+    let response = get(&state, "/sitemap.xml").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()[header::CONTENT_TYPE],
+        "application/xml; charset=utf-8"
+    );
+    assert!(text(response).await.contains("<urlset"));
+
+    // Child sitemaps only exist once the sitemap is split
+    for uri in ["/sitemaps/1.xml", "/sitemaps/0.xml", "/sitemaps/one.xml"] {
+        assert_eq!(
+            get(&state, uri).await.status(),
+            StatusCode::NOT_FOUND,
+            "{uri}"
+        );
+    }
+}
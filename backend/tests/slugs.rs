@@ -0,0 +1,92 @@
+//! Slugs derived from post titles.
+
+mod common;
+
+use backend::dao::blog::{BlogPostUpdate, BlogPostVisibility};
+use backend::db;
+use backend::repositories::BlogRepository;
+use backend::utils::slugify;
+use common::temp_database;
+
+#[test]
+fn lowercases_and_joins_words_with_dashes() {
+    assert_eq!(slugify("Hello World"), "hello-world");
+    assert_eq!(slugify("Rust 2024 Edition"), "rust-2024-edition");
+}
+
+#[test]
+fn collapses_punctuation_and_whitespace() {
+    assert_eq!(
+        slugify("  What's new -- in   v2.0?  "),
+        "what-s-new-in-v2-0"
+    );
+    assert_eq!(slugify("a/b\\c_d"), "a-b-c-d");
+}
+
+#[test]
+fn drops_non_ascii_characters() {
+    assert_eq!(slugify("Café au lait"), "caf-au-lait");
+    assert_eq!(slugify("日本語 post"), "post");
+}
+
+#[test]
+fn nothing_usable_gives_an_empty_slug() {
+    assert_eq!(slugify(""), "");
+    assert_eq!(slugify("!!! ??? ..."), "");
+    assert_eq!(slugify("日本語"), "");
+}
+
+#[test]
+fn long_titles_are_truncated_without_a_trailing_dash() {
+    let title = "word ".repeat(40);
+    let slug = slugify(&title);
+    assert!(slug.len() <= 80, "{slug}");
+    assert!(!slug.ends_with('-'));
+    assert!(slug.starts_with("word-word-"));
+
+    // Cut right after a separator
+    let title = format!("{} tail", "a".repeat(79));
+    assert_eq!(slugify(&title), "a".repeat(79));
+}
+
+async fn repo() -> BlogRepository {
+    BlogRepository::new(db::init(&temp_database()).await)
+}
+
+async fn slug(repo: &BlogRepository, id: i64) -> String {
+    repo.get(id, BlogPostVisibility::All)
+        .await
+        .unwrap()
+        .expect("post exists")
+        .slug
+}
+
+#[tokio::test]
+async fn repeated_titles_get_numbered_slugs() {
+    let repo = repo().await;
+    let first = repo.create("Hello", "", "", &[], None).await.unwrap();
+    let second = repo.create("Hello", "", "", &[], None).await.unwrap();
+    let untitled = repo.create("???", "", "", &[], None).await.unwrap();
+
+    assert_eq!(slug(&repo, first).await, "hello");
+    assert_eq!(slug(&repo, second).await, "hello-2");
+    assert_eq!(slug(&repo, untitled).await, "post");
+}
+
+#[tokio::test]
+async fn old_slugs_keep_resolving_and_are_not_reused() {
+    let repo = repo().await;
+    let id = repo.create("Hello", "", "", &[], None).await.unwrap();
+    let changes = BlogPostUpdate {
+        slug: Some("greetings"),
+        ..Default::default()
+    };
+    repo.update(id, changes, None).await.unwrap();
+
+    assert_eq!(repo.resolve_slug("greetings").await.unwrap(), Some(id));
+    assert_eq!(repo.resolve_slug("hello").await.unwrap(), Some(id));
+
+    // A new post with the old title doesn't take over the old link
+    let other = repo.create("Hello", "", "", &[], None).await.unwrap();
+    assert_eq!(slug(&repo, other).await, "hello-2");
+}
@@ -0,0 +1,264 @@
+//! `SpamFilter` scoring contact form submissions, which quarantines flagged
+//! ones instead of refusing them, and its classifier learning from admin marks.
+
+mod common;
+
+use std::net::SocketAddr;
+
+use proto::inquiry::inquiry_admin_service_server::InquiryAdminService;
+use proto::inquiry::inquiry_service_server::InquiryService;
+use proto::inquiry::{
+    GetFormTokenRequest, Inquiry, InquiryStatus, ListInquiriesRequest, MarkInquirySpamRequest,
+    SubmitInquiryRequest,
+};
+use tonic::transport::server::TcpConnectInfo;
+use tonic::Request;
+
+use backend::grpc::{InquiryAdminController, InquiryController};
+use backend::services::spam::{tokenize, FormTokenError, FormTokens};
+use backend::state::AppState;
+use common::{as_user, setup, setup_with};
+
+fn form(message: &str) -> SubmitInquiryRequest {
+    SubmitInquiryRequest {
+        name: "Ada".to_string(),
+        email: "ada@example.com".to_string(),
+        subject: "Hello".to_string(),
+        message: message.to_string(),
+        ..Default::default()
+    }
+}
+
+async fn form_token(state: &AppState) -> String {
+    InquiryController::new(state.clone())
+        .get_form_token(Request::new(GetFormTokenRequest {}))
+        .await
+        .unwrap()
+        .into_inner()
+        .form_token
+}
+
+/// Submit from `ip` with the form token as given; submissions are accepted
+/// whatever the spam filter makes of them
+async fn submit_as_is(state: &AppState, ip: &str, request: SubmitInquiryRequest) {
+    let mut request = Request::new(request);
+    request.extensions_mut().insert(TcpConnectInfo {
+        local_addr: None,
+        remote_addr: Some(SocketAddr::new(ip.parse().unwrap(), 50000)),
+    });
+    InquiryController::new(state.clone())
+        .submit_inquiry(request)
+        .await
+        .expect("accepted");
+}
+
+/// Submit with a fresh form token, as a person filling in the form would
+async fn submit(state: &AppState, ip: &str, mut request: SubmitInquiryRequest) {
+    request.form_token = form_token(state).await;
+    submit_as_is(state, ip, request).await;
+}
+
+/// The most recent submission, wherever it ended up
+async fn latest(state: &AppState) -> Inquiry {
+    let mut latest = vec![];
+    for status in [InquiryStatus::New, InquiryStatus::Quarantined] {
+        latest.extend(list(state, status).await);
+    }
+    latest
+        .into_iter()
+        .max_by_key(|i| i.id)
+        .expect("a submission")
+}
+
+async fn list(state: &AppState, status: InquiryStatus) -> Vec<Inquiry> {
+    let request = ListInquiriesRequest {
+        status: Some(status.into()),
+        limit: 0,
+        offset: 0,
+    };
+    InquiryAdminController::new(state.clone())
+        .list_inquiries(as_user("uid-editor", request))
+        .await
+        .unwrap()
+        .into_inner()
+        .inquiries
+}
+
+async fn mark_spam(state: &AppState, id: i64, spam: bool) {
+    InquiryAdminController::new(state.clone())
+        .mark_inquiry_spam(as_user("uid-editor", MarkInquirySpamRequest { id, spam }))
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn bots_are_quarantined_instead_of_refused() {
+    let state = setup().await;
+
+    submit(&state, "192.0.2.1", form("Hi there")).await;
+    let person = latest(&state).await;
+    assert_eq!(person.status(), InquiryStatus::New);
+    assert!(person.spam_reasons.is_empty());
+
+    let mut honeypot = form("Hi there");
+    honeypot.website = "https://spam.example".to_string();
+    submit(&state, "192.0.2.2", honeypot).await;
+    let bot = latest(&state).await;
+    assert_eq!(bot.status(), InquiryStatus::Quarantined);
+    assert_eq!(bot.spam_reasons, ["honeypot field filled in"]);
+
+    submit_as_is(&state, "192.0.2.3", form("No token")).await;
+    assert_eq!(latest(&state).await.spam_reasons, ["no form token"]);
+
+    let mut forged = form("Forged token");
+    forged.form_token = format!("1{}", form_token(&state).await);
+    submit_as_is(&state, "192.0.2.4", forged).await;
+    assert_eq!(latest(&state).await.spam_reasons, ["invalid form token"]);
+
+    // Only the person's inquiry reaches the inbox
+    assert_eq!(list(&state, InquiryStatus::New).await.len(), 1);
+    assert_eq!(list(&state, InquiryStatus::Quarantined).await.len(), 3);
+}
+
+#[tokio::test]
+async fn instant_submissions_are_quarantined() {
+    let state = setup_with(|config| config.spam_min_submit_secs = 5).await;
+
+    submit(&state, "192.0.2.1", form("Hi there")).await;
+
+    let inquiry = latest(&state).await;
+    assert_eq!(inquiry.status(), InquiryStatus::Quarantined);
+    assert_eq!(
+        inquiry.spam_reasons,
+        ["submitted 0s after loading the form"]
+    );
+}
+
+#[tokio::test]
+async fn suspicious_traits_add_up() {
+    let state = setup_with(|config| {
+        config.spam_submissions_per_ip = "1/3600".parse().unwrap();
+        config.spam_blocklist = vec!["Casino".to_string()];
+    })
+    .await;
+    let links = "See https://a.example, https://b.example and www.c.example";
+
+    // Too many links alone is only suspicious
+    submit(&state, "198.51.100.1", form(links)).await;
+    let first = latest(&state).await;
+    assert_eq!(first.status(), InquiryStatus::New);
+    assert_eq!(first.spam_reasons, ["3 links"]);
+    assert_eq!(first.spam_score, 0.5);
+
+    // So is a second submission from the same address, but not both
+    submit(&state, "198.51.100.1", form(links)).await;
+    let second = latest(&state).await;
+    assert_eq!(second.status(), InquiryStatus::Quarantined);
+    assert_eq!(
+        second.spam_reasons,
+        ["many submissions from this address", "3 links"]
+    );
+
+    submit(&state, "198.51.100.2", form("Best CASINO bonus")).await;
+    let blocked = latest(&state).await;
+    assert_eq!(blocked.status(), InquiryStatus::Quarantined);
+    assert_eq!(blocked.spam_reasons, ["contains blocked term \"casino\""]);
+}
+
+#[tokio::test]
+async fn classifier_learns_from_admin_marks() {
+    let state = setup_with(|config| config.spam_classifier = true).await;
+
+    for i in 0..5 {
+        submit(&state, "203.0.113.1", form("Cheap pills, buy viagra now")).await;
+        let spam = latest(&state).await;
+        assert_eq!(spam.status(), InquiryStatus::New, "untrained {i}");
+        mark_spam(&state, spam.id, true).await;
+
+        submit(
+            &state,
+            "203.0.113.2",
+            form("Loved your post about Rust lifetimes"),
+        )
+        .await;
+        mark_spam(&state, latest(&state).await.id, false).await;
+    }
+    assert_eq!(list(&state, InquiryStatus::Quarantined).await.len(), 5);
+    assert_eq!(list(&state, InquiryStatus::New).await.len(), 5);
+
+    submit(&state, "203.0.113.3", form("Buy cheap viagra")).await;
+    let spam = latest(&state).await;
+    assert_eq!(spam.status(), InquiryStatus::Quarantined);
+    assert!(spam.spam_reasons[0].starts_with("classifier: "), "{spam:?}");
+
+    submit(
+        &state,
+        "203.0.113.4",
+        form("A question about your Rust post"),
+    )
+    .await;
+    assert_eq!(latest(&state).await.status(), InquiryStatus::New);
+
+    // A false positive goes back to the inbox once marked
+    mark_spam(&state, spam.id, false).await;
+    let released = list(&state, InquiryStatus::New)
+        .await
+        .into_iter()
+        .find(|i| i.id == spam.id)
+        .expect("released to the inbox");
+    assert_eq!(released.marked_spam, Some(false));
+}
+
+#[tokio::test]
+async fn padding_does_not_push_out_telling_words() {
+    let state = setup_with(|config| config.spam_classifier = true).await;
+
+    for _ in 0..5 {
+        submit(&state, "203.0.113.1", form("Cheap pills, buy viagra now")).await;
+        mark_spam(&state, latest(&state).await.id, true).await;
+        submit(
+            &state,
+            "203.0.113.2",
+            form("Loved your post about Rust lifetimes"),
+        )
+        .await;
+        mark_spam(&state, latest(&state).await.id, false).await;
+    }
+
+    // Hundreds of unseen words that sort before the telling ones
+    let padding: Vec<String> = (0..300).map(|i| format!("aaa{i:03}")).collect();
+    let message = format!("Buy cheap viagra {}", padding.join(" "));
+    submit(&state, "203.0.113.3", form(&message)).await;
+    let spam = latest(&state).await;
+    assert_eq!(spam.status(), InquiryStatus::Quarantined);
+    assert!(spam.spam_reasons[0].starts_with("classifier: "), "{spam:?}");
+}
+
+#[test]
+fn tokens_keep_their_order_of_appearance() {
+    assert_eq!(
+        tokenize("Zebra, apple and ZEBRA again; an ox"),
+        ["zebra", "apple", "and", "again"]
+    );
+
+    let text = (0..300).map(|i| format!("w{i:03}")).collect::<Vec<_>>();
+    let tokens = tokenize(&text.join(" "));
+    assert_eq!(tokens.len(), 200);
+    assert_eq!(tokens[0], "w000");
+    assert_eq!(tokens[199], "w199");
+}
+
+#[test]
+fn form_tokens_expire_and_cannot_be_forged() {
+    let tokens = FormTokens::new(Some("secret"), 60);
+    let token = tokens.issue(1_000);
+
+    assert_eq!(tokens.age(&token, 1_010), Ok(10));
+    assert_eq!(tokens.age(&token, 1_061), Err(FormTokenError::Expired));
+    assert_eq!(tokens.age("", 1_010), Err(FormTokenError::Missing));
+
+    let backdated = token.replacen("1000", "900", 1);
+    assert_eq!(tokens.age(&backdated, 1_010), Err(FormTokenError::Invalid));
+    let other_key = FormTokens::new(Some("other"), 60);
+    assert_eq!(other_key.age(&token, 1_010), Err(FormTokenError::Invalid));
+}
@@ -0,0 +1,242 @@
+//! Tag filtering, counts, renames and merges.
+
+mod common;
+
+use proto::blog::blog_admin_service_server::BlogAdminService;
+use proto::blog::blog_service_server::BlogService;
+use proto::blog::get_blog_posts_request::Sort;
+use proto::blog::{
+    CreateBlogPostRequest, GetBlogPostsRequest, ListTagsRequest, MergeTagsRequest,
+    PublishBlogPostRequest, RenameTagRequest, Tag,
+};
+use tonic::{Code, Request};
+
+use backend::grpc::{BlogAdminController, BlogController};
+use backend::state::AppState;
+use common::{as_admin, setup};
+
+async fn create(state: &AppState, title: &str, body: &str, tags: &[&str], publish: bool) -> i64 {
+    let controller = BlogAdminController::new(state.clone());
+    let id = controller
+        .create_blog_post(as_admin(CreateBlogPostRequest {
+            title: title.to_string(),
+            description: String::new(),
+            body: body.to_string(),
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+        }))
+        .await
+        .unwrap()
+        .into_inner()
+        .id;
+    if publish {
+        controller
+            .publish_blog_post(as_admin(PublishBlogPostRequest { id }))
+            .await
+            .unwrap();
+    }
+    id
+}
+
+async fn list(state: &AppState, request: GetBlogPostsRequest) -> Vec<i64> {
+    BlogController::new(state.clone())
+        .get_blog_posts(Request::new(request))
+        .await
+        .unwrap()
+        .into_inner()
+        .posts
+        .iter()
+        .map(|post| post.id)
+        .collect()
+}
+
+async fn tagged(state: &AppState, tags: &[&str]) -> Vec<i64> {
+    list(
+        state,
+        GetBlogPostsRequest {
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            sort: Sort::CreatedAsc as i32,
+            ..Default::default()
+        },
+    )
+    .await
+}
+
+async fn tag_counts(state: &AppState) -> Vec<(String, i32)> {
+    BlogController::new(state.clone())
+        .list_tags(Request::new(ListTagsRequest {}))
+        .await
+        .unwrap()
+        .into_inner()
+        .tags
+        .into_iter()
+        .map(|Tag { name, post_count }| (name, post_count))
+        .collect()
+}
+
+#[tokio::test]
+async fn filter_requires_every_tag() {
+    let state = setup().await;
+    let both = create(&state, "Both", "Body", &["rust", "web"], true).await;
+    let rust = create(&state, "Rust", "Body", &["rust"], true).await;
+    let web = create(&state, "Web", "Body", &["web"], true).await;
+
+    assert_eq!(tagged(&state, &[]).await, vec![both, rust, web]);
+    assert_eq!(tagged(&state, &["rust"]).await, vec![both, rust]);
+    assert_eq!(tagged(&state, &["rust", "web"]).await, vec![both]);
+    assert_eq!(tagged(&state, &["Web", " RUST "]).await, vec![both]);
+    assert_eq!(tagged(&state, &["rust", "rust"]).await, vec![both, rust]);
+    assert_eq!(tagged(&state, &["rust", "go"]).await, Vec::<i64>::new());
+}
+
+#[tokio::test]
+async fn filter_combines_with_query_and_every_sort() {
+    let state = setup().await;
+    let first = create(&state, "SQLite tips", "Indexes", &["db", "howto"], true).await;
+    create(&state, "SQLite news", "Release", &["db"], true).await;
+    create(&state, "Postgres tips", "Vacuum", &["db", "howto"], true).await;
+    let second = create(&state, "More SQLite", "Triggers", &["db", "howto"], true).await;
+    create(
+        &state,
+        "SQLite draft",
+        "Unfinished",
+        &["db", "howto"],
+        false,
+    )
+    .await;
+
+    // Posts created within the same second tie on every sort
+    for sort in [
+        Sort::Relevance,
+        Sort::CreatedAsc,
+        Sort::CreatedDesc,
+        Sort::UpdatedAsc,
+        Sort::UpdatedDesc,
+    ] {
+        let mut ids = list(
+            &state,
+            GetBlogPostsRequest {
+                query: Some("sqlite".to_string()),
+                tags: vec!["howto".to_string(), "db".to_string()],
+                sort: sort as i32,
+                ..Default::default()
+            },
+        )
+        .await;
+        ids.sort();
+        assert_eq!(ids, vec![first, second], "{sort:?}");
+    }
+}
+
+#[tokio::test]
+async fn list_tags_counts_public_posts() {
+    let state = setup().await;
+    create(&state, "One", "Body", &["rust", "web"], true).await;
+    create(&state, "Two", "Body", &["rust"], true).await;
+    create(&state, "Three", "Body", &["async"], true).await;
+    create(&state, "Draft", "Body", &["rust", "draft-only"], false).await;
+
+    assert_eq!(
+        tag_counts(&state).await,
+        vec![
+            ("rust".to_string(), 2),
+            ("async".to_string(), 1),
+            ("web".to_string(), 1),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn renames_keep_posts_and_refuse_collisions() {
+    let state = setup().await;
+    let controller = BlogAdminController::new(state.clone());
+    let id = create(&state, "Post", "Body", &["rust", "web"], true).await;
+
+    let rename = |name: &str, new_name: &str| {
+        as_admin(RenameTagRequest {
+            name: name.to_string(),
+            new_name: new_name.to_string(),
+        })
+    };
+
+    let status = controller
+        .rename_tag(rename("rust", "Web"))
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), Code::AlreadyExists);
+    let status = controller
+        .rename_tag(rename("missing", "other"))
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), Code::NotFound);
+    let status = controller
+        .rename_tag(rename("rust", " "))
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), Code::InvalidArgument);
+
+    controller
+        .rename_tag(rename("rust", "Rust-Lang"))
+        .await
+        .unwrap();
+    assert_eq!(tagged(&state, &["rust"]).await, Vec::<i64>::new());
+    assert_eq!(tagged(&state, &["rust-lang", "web"]).await, vec![id]);
+}
+
+#[tokio::test]
+async fn merges_move_posts_onto_the_target() {
+    let state = setup().await;
+    let controller = BlogAdminController::new(state.clone());
+    let both = create(&state, "Both", "Body", &["js", "javascript"], true).await;
+    let js = create(&state, "JS", "Body", &["js"], true).await;
+    let ecma = create(&state, "Ecma", "Body", &["ecmascript"], true).await;
+
+    let merge = |sources: &[&str], target: &str| {
+        as_admin(MergeTagsRequest {
+            sources: sources.iter().map(|s| s.to_string()).collect(),
+            target: target.to_string(),
+        })
+    };
+
+    let merged = controller
+        .merge_tags(merge(
+            &["JS", "ecmascript", "missing", "javascript"],
+            "javascript",
+        ))
+        .await
+        .unwrap()
+        .into_inner()
+        .merged;
+    assert_eq!(merged, 2);
+    assert_eq!(tagged(&state, &["javascript"]).await, vec![both, js, ecma]);
+    assert_eq!(
+        tag_counts(&state).await,
+        vec![("javascript".to_string(), 3)]
+    );
+
+    // Merging into a new tag creates it
+    controller
+        .merge_tags(merge(&["javascript"], "web"))
+        .await
+        .unwrap();
+    assert_eq!(tag_counts(&state).await, vec![("web".to_string(), 3)]);
+}
+
+#[tokio::test]
+async fn merging_missing_tags_changes_nothing() {
+    let state = setup().await;
+    let controller = BlogAdminController::new(state.clone());
+    create(&state, "Post", "Body", &["rust"], true).await;
+
+    let merged = controller
+        .merge_tags(as_admin(MergeTagsRequest {
+            sources: vec!["missing".to_string(), "gone".to_string()],
+            target: "new".to_string(),
+        }))
+        .await
+        .unwrap()
+        .into_inner()
+        .merged;
+    assert_eq!(merged, 0);
+    assert!(!state.blog_service.tag_exists("new").await.unwrap());
+    assert_eq!(tag_counts(&state).await, vec![("rust".to_string(), 1)]);
+}
@@ -0,0 +1,645 @@
+//! Webhooks: blog content events queued by `BlogService` for the subscribed
+//! endpoints, delivered signed, retried with backoff and logged.
+
+mod common;
+
+use std::sync::{Arc, Mutex};
+
+use axum::extract::State;
+use axum::http::header::LOCATION;
+use axum::http::{HeaderMap, StatusCode};
+use axum::routing::post;
+use axum::Router;
+use proto::webhook::webhook_admin_service_server::WebhookAdminService;
+use proto::webhook::{
+    CreateWebhookRequest, DeleteWebhookRequest, ListWebhookDeliveriesRequest,
+    RedeliverWebhookRequest, UpdateWebhookRequest, WebhookDelivery, WebhookDeliveryStatus,
+    WebhookEvent, WebhookEventList,
+};
+use serde_json::Value;
+use tonic::Code;
+
+use backend::dao::blog::BlogPostUpdate;
+use backend::db::{self, DbPool};
+use backend::dto::auth::Claims;
+use backend::grpc::{WebhookAdminController, WEBHOOK_ADMIN_PERMISSIONS};
+use backend::services::webhook::sign;
+use backend::state::AppState;
+use common::{as_admin, claims, setup_with, MockFirebase, KEY_1};
+
+/// A request the receiver got
+#[derive(Debug, Clone)]
+struct Received {
+    event: String,
+    delivery: String,
+    timestamp: i64,
+    signature: String,
+    body: String,
+}
+
+impl Received {
+    fn json(&self) -> Value {
+        serde_json::from_str(&self.body).unwrap()
+    }
+}
+
+/// Handle on a running webhook endpoint
+#[derive(Clone)]
+struct Receiver {
+    url: String,
+    received: Arc<Mutex<Vec<Received>>>,
+    /// Status every request is answered with
+    status: Arc<Mutex<StatusCode>>,
+}
+
+impl Receiver {
+    async fn start() -> Self {
+        let received = Arc::new(Mutex::new(vec![]));
+        let status = Arc::new(Mutex::new(StatusCode::NO_CONTENT));
+
+        let app = Router::new()
+            .route("/hook", post(receive))
+            .route(
+                "/moved",
+                post(|| async { (StatusCode::TEMPORARY_REDIRECT, [(LOCATION, "/hook")]) }),
+            )
+            .with_state((received.clone(), status.clone()));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("Failed to bind webhook receiver");
+        let url = format!("http://{}/hook", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        Self {
+            url,
+            received,
+            status,
+        }
+    }
+
+    fn respond_with(&self, status: StatusCode) {
+        *self.status.lock().unwrap() = status;
+    }
+
+    fn received(&self) -> Vec<Received> {
+        self.received.lock().unwrap().clone()
+    }
+
+    fn events(&self) -> Vec<String> {
+        self.received().into_iter().map(|r| r.event).collect()
+    }
+}
+
+type ReceiverState = (Arc<Mutex<Vec<Received>>>, Arc<Mutex<StatusCode>>);
+
+async fn receive(
+    State((received, status)): State<ReceiverState>,
+    headers: HeaderMap,
+    body: String,
+) -> StatusCode {
+    let header = |name: &str| {
+        headers
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_default()
+            .to_string()
+    };
+    received.lock().unwrap().push(Received {
+        event: header("x-webhook-event"),
+        delivery: header("x-webhook-delivery"),
+        timestamp: header("x-webhook-timestamp").parse().unwrap_or_default(),
+        signature: header("x-webhook-signature"),
+        body,
+    });
+    *status.lock().unwrap()
+}
+
+/// App state that lets webhooks reach receivers on localhost, and a pool
+/// onto its database
+async fn setup() -> (AppState, DbPool) {
+    let state = setup_with(|config| config.webhook_allow_private_urls = true).await;
+    let pool = db::init(&state.config.database_path).await;
+    (state, pool)
+}
+
+/// Subscribe `url` to `events`, returning the webhook's id and secret
+async fn subscribe(state: &AppState, url: &str, events: &[WebhookEvent]) -> (i64, String) {
+    let response = WebhookAdminController::new(state.clone())
+        .create_webhook(as_admin(CreateWebhookRequest {
+            url: url.to_string(),
+            secret: String::new(),
+            events: events.iter().map(|&e| e.into()).collect(),
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+    (response.webhook.unwrap().id, response.secret)
+}
+
+async fn deliveries(state: &AppState, webhook_id: i64) -> Vec<WebhookDelivery> {
+    WebhookAdminController::new(state.clone())
+        .list_webhook_deliveries(as_admin(ListWebhookDeliveriesRequest {
+            webhook_id,
+            ..Default::default()
+        }))
+        .await
+        .unwrap()
+        .into_inner()
+        .deliveries
+}
+
+/// Make every pending delivery due now, as if its backoff had passed
+async fn rewind(pool: &DbPool) {
+    sqlx::query("UPDATE webhook_deliveries SET next_attempt_at = 0 WHERE status = 'pending'")
+        .execute(pool)
+        .await
+        .unwrap();
+}
+
+async fn create_post(state: &AppState) -> i64 {
+    state
+        .blog_service
+        .create("Hello", "First post", "Body", &[], Some("uid-alice"))
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn content_events_are_delivered_signed() {
+    let (state, _pool) = setup().await;
+    let receiver = Receiver::start().await;
+    let (webhook_id, secret) = subscribe(&state, &receiver.url, &[]).await;
+    assert!(secret.len() >= 32, "a secret is generated");
+
+    let blog = &state.blog_service;
+    let id = create_post(&state).await;
+    let changes = BlogPostUpdate {
+        title: Some("Hello again"),
+        ..Default::default()
+    };
+    blog.update(id, changes, Some("uid-bob")).await.unwrap();
+    blog.publish(id, Some("uid-bob")).await.unwrap();
+    blog.delete(id, Some("uid-carol")).await.unwrap();
+    blog.restore(id, Some("uid-carol")).await.unwrap();
+    // No-op writes aren't events
+    assert!(!blog.restore(id, Some("uid-carol")).await.unwrap());
+
+    assert!(
+        receiver.received().is_empty(),
+        "sent by the queue, not the edit"
+    );
+    assert_eq!(state.webhook_service.deliver_due().await.unwrap(), 5);
+    assert_eq!(
+        receiver.events(),
+        [
+            "post.created",
+            "post.updated",
+            "post.published",
+            "post.deleted",
+            "post.restored"
+        ]
+    );
+
+    for received in receiver.received() {
+        assert_eq!(
+            received.signature,
+            sign(&secret, received.timestamp, &received.body)
+        );
+        assert!(received.signature.starts_with("sha256="));
+        assert_ne!(
+            received.signature,
+            sign("wrong", received.timestamp, &received.body)
+        );
+    }
+
+    let published = receiver.received()[2].json();
+    assert_eq!(published["event"], "post.published");
+    assert_eq!(published["actor"], "uid-bob");
+    assert_eq!(published["post"]["id"], id);
+    assert_eq!(published["post"]["title"], "Hello again");
+    assert_eq!(published["post"]["status"], "published");
+
+    let log = deliveries(&state, webhook_id).await;
+    assert_eq!(log.len(), 5);
+    assert!(log
+        .iter()
+        .all(|d| d.status() == WebhookDeliveryStatus::Delivered));
+    assert!(log.iter().all(|d| d.response_status == Some(204)));
+    // Newest first, and the id matches the header sent
+    assert_eq!(log[0].event(), WebhookEvent::PostRestored);
+    assert_eq!(receiver.received()[4].delivery, log[0].id.to_string());
+
+    assert_eq!(state.webhook_service.deliver_due().await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn scheduled_posts_going_live_are_published_events() {
+    let (state, _pool) = setup().await;
+    let receiver = Receiver::start().await;
+    subscribe(&state, &receiver.url, &[WebhookEvent::PostPublished]).await;
+
+    let id = create_post(&state).await;
+    let blog = &state.blog_service;
+    blog.schedule(id, 1, Some("uid-alice")).await.unwrap();
+    assert_eq!(blog.publish_due().await.unwrap(), [id]);
+
+    state.webhook_service.deliver_due().await.unwrap();
+    assert_eq!(receiver.events(), ["post.published"]);
+    assert_eq!(receiver.received()[0].json()["actor"], Value::Null);
+}
+
+#[tokio::test]
+async fn webhooks_only_get_the_events_they_subscribe_to() {
+    let (state, _pool) = setup().await;
+    let (everything, published, disabled) = (
+        Receiver::start().await,
+        Receiver::start().await,
+        Receiver::start().await,
+    );
+    subscribe(&state, &everything.url, &[]).await;
+    subscribe(&state, &published.url, &[WebhookEvent::PostPublished]).await;
+    let (disabled_id, _) = subscribe(&state, &disabled.url, &[]).await;
+
+    let controller = WebhookAdminController::new(state.clone());
+    controller
+        .update_webhook(as_admin(UpdateWebhookRequest {
+            id: disabled_id,
+            active: Some(false),
+            ..Default::default()
+        }))
+        .await
+        .unwrap();
+
+    let id = create_post(&state).await;
+    state
+        .blog_service
+        .publish(id, Some("uid-alice"))
+        .await
+        .unwrap();
+    state.webhook_service.deliver_due().await.unwrap();
+
+    assert_eq!(everything.events(), ["post.created", "post.published"]);
+    assert_eq!(published.events(), ["post.published"]);
+    assert!(disabled.events().is_empty());
+    assert!(deliveries(&state, disabled_id).await.is_empty());
+
+    // Changing the filter applies to later events
+    controller
+        .update_webhook(as_admin(UpdateWebhookRequest {
+            id: disabled_id,
+            active: Some(true),
+            events: Some(WebhookEventList {
+                events: vec![WebhookEvent::PostDeleted.into()],
+            }),
+            ..Default::default()
+        }))
+        .await
+        .unwrap();
+    state
+        .blog_service
+        .delete(id, Some("uid-alice"))
+        .await
+        .unwrap();
+    state.webhook_service.deliver_due().await.unwrap();
+    assert_eq!(disabled.events(), ["post.deleted"]);
+}
+
+#[tokio::test]
+async fn failed_deliveries_are_retried_then_given_up() {
+    let (state, pool) = setup().await;
+    let receiver = Receiver::start().await;
+    receiver.respond_with(StatusCode::SERVICE_UNAVAILABLE);
+    let (webhook_id, _) = subscribe(&state, &receiver.url, &[]).await;
+
+    create_post(&state).await;
+    let now = chrono::Utc::now().timestamp();
+    assert_eq!(state.webhook_service.deliver_due().await.unwrap(), 0);
+
+    let failed = deliveries(&state, webhook_id).await.remove(0);
+    assert_eq!(failed.status(), WebhookDeliveryStatus::Pending);
+    assert_eq!(failed.attempts, 1);
+    assert_eq!(failed.response_status, Some(503));
+    assert_eq!(
+        failed.last_error.as_deref(),
+        Some("HTTP 503 Service Unavailable")
+    );
+    assert!((now + 30..now + 35).contains(&failed.next_attempt_at));
+
+    // Not retried before it's due
+    assert_eq!(state.webhook_service.deliver_due().await.unwrap(), 0);
+    assert_eq!(receiver.received().len(), 1);
+
+    // Each retry waits twice as long, up to an hour
+    let mut waits = vec![];
+    for _ in 1..10 {
+        rewind(&pool).await;
+        let now = chrono::Utc::now().timestamp();
+        state.webhook_service.deliver_due().await.unwrap();
+        let delivery = deliveries(&state, webhook_id).await.remove(0);
+        if delivery.status() == WebhookDeliveryStatus::Pending {
+            waits.push(delivery.next_attempt_at - now);
+        }
+    }
+    let expected = [60, 120, 240, 480, 960, 1920, 3600, 3600];
+    assert_eq!(waits.len(), expected.len());
+    for (wait, expected) in waits.into_iter().zip(expected) {
+        assert!((expected..expected + 5).contains(&wait), "{wait}s");
+    }
+
+    let given_up = deliveries(&state, webhook_id).await.remove(0);
+    assert_eq!(given_up.status(), WebhookDeliveryStatus::Failed);
+    assert_eq!(given_up.attempts, 10);
+    assert_eq!(receiver.received().len(), 10);
+
+    // Every attempt carries the same delivery id
+    let ids: Vec<_> = receiver
+        .received()
+        .into_iter()
+        .map(|r| r.delivery)
+        .collect();
+    assert!(ids.iter().all(|id| *id == given_up.id.to_string()));
+}
+
+#[tokio::test]
+async fn deliveries_can_be_resent_by_hand() {
+    let (state, _pool) = setup().await;
+    let receiver = Receiver::start().await;
+    receiver.respond_with(StatusCode::INTERNAL_SERVER_ERROR);
+    let (webhook_id, _) = subscribe(&state, &receiver.url, &[]).await;
+    create_post(&state).await;
+    state.webhook_service.deliver_due().await.unwrap();
+    let original = deliveries(&state, webhook_id).await.remove(0);
+
+    // The receiver is fixed and the secret rotated before resending
+    receiver.respond_with(StatusCode::OK);
+    let controller = WebhookAdminController::new(state.clone());
+    controller
+        .update_webhook(as_admin(UpdateWebhookRequest {
+            id: webhook_id,
+            secret: Some("rotated secret".to_string()),
+            ..Default::default()
+        }))
+        .await
+        .unwrap();
+    let redelivery_id = controller
+        .redeliver_webhook(as_admin(RedeliverWebhookRequest {
+            delivery_id: original.id,
+        }))
+        .await
+        .unwrap()
+        .into_inner()
+        .delivery_id;
+    assert_ne!(redelivery_id, original.id);
+
+    // Only the new delivery is due; the original waits for its own retry
+    assert_eq!(state.webhook_service.deliver_due().await.unwrap(), 1);
+    let resent = receiver.received().pop().unwrap();
+    assert_eq!(resent.delivery, redelivery_id.to_string());
+    assert_eq!(resent.body, original.payload);
+    assert_eq!(
+        resent.signature,
+        sign("rotated secret", resent.timestamp, &resent.body)
+    );
+
+    let log = deliveries(&state, webhook_id).await;
+    assert_eq!(log.len(), 2);
+    let redelivery = log.iter().find(|d| d.id == redelivery_id).unwrap();
+    assert_eq!(redelivery.redelivery_of, Some(original.id));
+    assert_eq!(redelivery.status(), WebhookDeliveryStatus::Delivered);
+
+    let missing = controller
+        .redeliver_webhook(as_admin(RedeliverWebhookRequest { delivery_id: 999 }))
+        .await
+        .unwrap_err();
+    assert_eq!(missing.code(), Code::NotFound);
+}
+
+#[tokio::test]
+async fn webhooks_are_validated_and_deleted_with_their_log() {
+    let (state, _pool) = setup().await;
+    let controller = WebhookAdminController::new(state.clone());
+
+    for url in ["", "not a url", "ftp://example.com/hook", "http://"] {
+        let status = controller
+            .create_webhook(as_admin(CreateWebhookRequest {
+                url: url.to_string(),
+                ..Default::default()
+            }))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), Code::InvalidArgument, "{url:?}");
+    }
+
+    let response = controller
+        .create_webhook(as_admin(CreateWebhookRequest {
+            url: " https://example.com/hook ".to_string(),
+            secret: "my secret".to_string(),
+            events: vec![
+                WebhookEvent::PostCreated.into(),
+                WebhookEvent::PostCreated.into(),
+                WebhookEvent::Unspecified.into(),
+            ],
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+    let webhook = response.webhook.unwrap();
+    assert_eq!(webhook.url, "https://example.com/hook");
+    assert_eq!(
+        webhook.events().collect::<Vec<_>>(),
+        [WebhookEvent::PostCreated]
+    );
+    assert!(webhook.active);
+    assert_eq!(webhook.created_by.as_deref(), Some("uid-admin"));
+    assert_eq!(response.secret, "my secret");
+
+    let blank_secret = controller
+        .update_webhook(as_admin(UpdateWebhookRequest {
+            id: webhook.id,
+            secret: Some("  ".to_string()),
+            ..Default::default()
+        }))
+        .await
+        .unwrap_err();
+    assert_eq!(blank_secret.code(), Code::InvalidArgument);
+
+    create_post(&state).await;
+    assert_eq!(deliveries(&state, webhook.id).await.len(), 1);
+
+    controller
+        .delete_webhook(as_admin(DeleteWebhookRequest { id: webhook.id }))
+        .await
+        .unwrap();
+    assert!(deliveries(&state, webhook.id).await.is_empty());
+    let missing = controller
+        .delete_webhook(as_admin(DeleteWebhookRequest { id: webhook.id }))
+        .await
+        .unwrap_err();
+    assert_eq!(missing.code(), Code::NotFound);
+}
+
+#[tokio::test]
+async fn only_admins_manage_webhooks() {
+    let firebase = MockFirebase::start().await;
+    let state = common::app_state(firebase.config()).await;
+    let editor = Claims {
+        roles: vec!["editor".to_string()],
+        ..claims("uid-editor")
+    };
+    let editor_token = firebase.id_token_with(&KEY_1, &editor);
+    let admin_token = firebase.id_token(&KEY_1, "uid-admin");
+
+    for (rpc, _) in WEBHOOK_ADMIN_PERMISSIONS {
+        assert_eq!(
+            common::authorize(&state, WEBHOOK_ADMIN_PERMISSIONS, rpc, &editor_token).await,
+            Err(Code::PermissionDenied),
+            "{rpc}"
+        );
+        assert_eq!(
+            common::authorize(&state, WEBHOOK_ADMIN_PERMISSIONS, rpc, &admin_token).await,
+            Ok("uid-admin".to_string()),
+            "{rpc}"
+        );
+    }
+}
+
+#[test]
+fn signatures_match_a_known_vector() {
+    // HMAC-SHA256 of "1700000000.Hello, World!", as computed by openssl
+    assert_eq!(
+        sign("It's a Secret to Everybody", 1_700_000_000, "Hello, World!"),
+        "sha256=76c83fd0acdf22faed320674fe8e04d528cfe8a17905e720a9611e40677c03b7"
+    );
+}
+
+#[tokio::test]
+async fn private_addresses_are_refused_unless_allowed() {
+    let state = common::setup().await;
+    let controller = WebhookAdminController::new(state.clone());
+
+    for url in [
+        "http://127.0.0.1:8080/hook",
+        "http://10.1.2.3/hook",
+        "http://192.168.0.1/hook",
+        "http://169.254.169.254/latest/meta-data",
+        "http://0.0.0.0/hook",
+        "http://[::1]/hook",
+        "http://[fd00::1]/hook",
+        "http://[::ffff:127.0.0.1]/hook",
+        "http://[64:ff9b::a9fe:a9fe]/hook",
+        "http://[64:ff9b:1::1]/hook",
+        "http://[2002:7f00:1::1]/hook",
+        "http://[2002:c0a8:1::1]/hook",
+        "http://[fec0::1]/hook",
+        "http://[ff02::1]/hook",
+    ] {
+        let status = controller
+            .create_webhook(as_admin(CreateWebhookRequest {
+                url: url.to_string(),
+                ..Default::default()
+            }))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), Code::InvalidArgument, "{url}");
+        assert_eq!(
+            status.message(),
+            "Webhook URL must point at a public address"
+        );
+    }
+
+    // Translated addresses are as public as the IPv4 address they carry
+    for url in [
+        "http://[64:ff9b::808:808]/hook",
+        "http://[2002:808:808::1]/hook",
+    ] {
+        controller
+            .create_webhook(as_admin(CreateWebhookRequest {
+                url: url.to_string(),
+                ..Default::default()
+            }))
+            .await
+            .unwrap();
+    }
+
+    // Host names are checked as they're resolved for each delivery
+    let receiver = Receiver::start().await;
+    let url = receiver.url.replace("127.0.0.1", "localhost");
+    let (webhook_id, _) = subscribe(&state, &url, &[]).await;
+    create_post(&state).await;
+    assert_eq!(state.webhook_service.deliver_due().await.unwrap(), 0);
+    assert!(receiver.received().is_empty());
+    let failed = &deliveries(&state, webhook_id).await[0];
+    assert_eq!(failed.status(), WebhookDeliveryStatus::Pending);
+    assert_eq!(failed.response_status, None);
+    assert!(failed
+        .last_error
+        .as_deref()
+        .unwrap()
+        .starts_with("Request failed"));
+}
+
+#[tokio::test]
+async fn redirects_are_not_followed() {
+    let (state, _pool) = setup().await;
+    let receiver = Receiver::start().await;
+    let url = receiver.url.replace("/hook", "/moved");
+    let (webhook_id, _) = subscribe(&state, &url, &[]).await;
+
+    create_post(&state).await;
+    assert_eq!(state.webhook_service.deliver_due().await.unwrap(), 0);
+    assert!(receiver.received().is_empty());
+    let failed = &deliveries(&state, webhook_id).await[0];
+    assert_eq!(failed.response_status, Some(307));
+    assert_eq!(
+        failed.last_error.as_deref(),
+        Some("HTTP 307 Temporary Redirect")
+    );
+}
+
+#[tokio::test]
+async fn renamed_and_merged_tags_update_their_posts() {
+    let (state, _pool) = setup().await;
+    let receiver = Receiver::start().await;
+    subscribe(&state, &receiver.url, &[WebhookEvent::PostUpdated]).await;
+
+    let blog = &state.blog_service;
+    let rust = blog
+        .create("Rust", "", "", &["rust".to_string()], None)
+        .await
+        .unwrap();
+    let go = blog
+        .create("Go", "", "", &["go".to_string()], None)
+        .await
+        .unwrap();
+    blog.create("Untagged", "", "", &[], None).await.unwrap();
+
+    assert!(blog
+        .rename_tag("rust", "rustlang", Some("uid-bob"))
+        .await
+        .unwrap());
+    assert_eq!(state.webhook_service.deliver_due().await.unwrap(), 1);
+    let renamed = receiver.received()[0].json();
+    assert_eq!(renamed["event"], "post.updated");
+    assert_eq!(renamed["actor"], "uid-bob");
+    assert_eq!(renamed["post"]["id"], rust);
+    assert_eq!(renamed["post"]["tags"], serde_json::json!(["rustlang"]));
+
+    let sources = ["go".to_string(), "rustlang".to_string(), "code".to_string()];
+    assert_eq!(
+        blog.merge_tags(&sources, "code", Some("uid-bob"))
+            .await
+            .unwrap(),
+        2
+    );
+    assert_eq!(state.webhook_service.deliver_due().await.unwrap(), 2);
+    let mut merged: Vec<i64> = receiver.received()[1..]
+        .iter()
+        .map(|r| r.json()["post"]["id"].as_i64().unwrap())
+        .collect();
+    merged.sort_unstable();
+    assert_eq!(merged, [rust, go]);
+
+    // Nothing renamed, nothing sent
+    assert!(!blog.rename_tag("missing", "other", None).await.unwrap());
+    assert_eq!(state.webhook_service.deliver_due().await.unwrap(), 0);
+}
@@ -26,6 +26,27 @@ pub mod blog_service_server {
             tonic::Response<super::GetBlogPostResponse>,
             tonic::Status,
         >;
+        async fn list_tags(
+            &self,
+            request: tonic::Request<super::ListTagsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListTagsResponse>,
+            tonic::Status,
+        >;
+        async fn get_blog_post_by_slug(
+            &self,
+            request: tonic::Request<super::GetBlogPostBySlugRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlogPostBySlugResponse>,
+            tonic::Status,
+        >;
+        async fn suggest_blog_search(
+            &self,
+            request: tonic::Request<super::SuggestBlogSearchRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SuggestBlogSearchResponse>,
+            tonic::Status,
+        >;
     }
     #[derive(Debug)]
     pub struct BlogServiceServer<T> {
@@ -193,6 +214,143 @@ pub mod blog_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/blog.BlogService/ListTags" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListTagsSvc<T: BlogService>(pub Arc<T>);
+                    impl<
+                        T: BlogService,
+                    > tonic::server::UnaryService<super::ListTagsRequest>
+                    for ListTagsSvc<T> {
+                        type Response = super::ListTagsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListTagsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlogService>::list_tags(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListTagsSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blog.BlogService/GetBlogPostBySlug" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetBlogPostBySlugSvc<T: BlogService>(pub Arc<T>);
+                    impl<
+                        T: BlogService,
+                    > tonic::server::UnaryService<super::GetBlogPostBySlugRequest>
+                    for GetBlogPostBySlugSvc<T> {
+                        type Response = super::GetBlogPostBySlugResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetBlogPostBySlugRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlogService>::get_blog_post_by_slug(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetBlogPostBySlugSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blog.BlogService/SuggestBlogSearch" => {
+                    #[allow(non_camel_case_types)]
+                    struct SuggestBlogSearchSvc<T: BlogService>(pub Arc<T>);
+                    impl<
+                        T: BlogService,
+                    > tonic::server::UnaryService<super::SuggestBlogSearchRequest>
+                    for SuggestBlogSearchSvc<T> {
+                        type Response = super::SuggestBlogSearchResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SuggestBlogSearchRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlogService>::suggest_blog_search(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SuggestBlogSearchSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         let mut response = http::Response::new(
@@ -246,6 +404,20 @@ pub mod blog_admin_service_server {
     /// Generated trait containing gRPC methods that should be implemented for use with BlogAdminServiceServer.
     #[async_trait]
     pub trait BlogAdminService: std::marker::Send + std::marker::Sync + 'static {
+        async fn get_blog_posts(
+            &self,
+            request: tonic::Request<super::GetBlogPostsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlogPostsResponse>,
+            tonic::Status,
+        >;
+        async fn get_blog_post(
+            &self,
+            request: tonic::Request<super::GetBlogPostRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlogPostResponse>,
+            tonic::Status,
+        >;
         async fn create_blog_post(
             &self,
             request: tonic::Request<super::CreateBlogPostRequest>,
@@ -274,6 +446,76 @@ pub mod blog_admin_service_server {
             tonic::Response<super::RestoreBlogPostResponse>,
             tonic::Status,
         >;
+        async fn publish_blog_post(
+            &self,
+            request: tonic::Request<super::PublishBlogPostRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PublishBlogPostResponse>,
+            tonic::Status,
+        >;
+        async fn unpublish_blog_post(
+            &self,
+            request: tonic::Request<super::UnpublishBlogPostRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UnpublishBlogPostResponse>,
+            tonic::Status,
+        >;
+        async fn schedule_blog_post(
+            &self,
+            request: tonic::Request<super::ScheduleBlogPostRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ScheduleBlogPostResponse>,
+            tonic::Status,
+        >;
+        async fn list_blog_post_revisions(
+            &self,
+            request: tonic::Request<super::ListBlogPostRevisionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListBlogPostRevisionsResponse>,
+            tonic::Status,
+        >;
+        async fn get_blog_post_revision(
+            &self,
+            request: tonic::Request<super::GetBlogPostRevisionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlogPostRevisionResponse>,
+            tonic::Status,
+        >;
+        async fn diff_blog_post_revisions(
+            &self,
+            request: tonic::Request<super::DiffBlogPostRevisionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DiffBlogPostRevisionsResponse>,
+            tonic::Status,
+        >;
+        async fn revert_blog_post(
+            &self,
+            request: tonic::Request<super::RevertBlogPostRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RevertBlogPostResponse>,
+            tonic::Status,
+        >;
+        async fn rename_tag(
+            &self,
+            request: tonic::Request<super::RenameTagRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RenameTagResponse>,
+            tonic::Status,
+        >;
+        async fn merge_tags(
+            &self,
+            request: tonic::Request<super::MergeTagsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::MergeTagsResponse>,
+            tonic::Status,
+        >;
+        async fn list_audit_events(
+            &self,
+            request: tonic::Request<super::ListAuditEventsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListAuditEventsResponse>,
+            tonic::Status,
+        >;
     }
     #[derive(Debug)]
     pub struct BlogAdminServiceServer<T> {
@@ -351,25 +593,25 @@ pub mod blog_admin_service_server {
         }
         fn call(&mut self, req: http::Request<B>) -> Self::Future {
             match req.uri().path() {
-                "/blog.BlogAdminService/CreateBlogPost" => {
+                "/blog.BlogAdminService/GetBlogPosts" => {
                     #[allow(non_camel_case_types)]
-                    struct CreateBlogPostSvc<T: BlogAdminService>(pub Arc<T>);
+                    struct GetBlogPostsSvc<T: BlogAdminService>(pub Arc<T>);
                     impl<
                         T: BlogAdminService,
-                    > tonic::server::UnaryService<super::CreateBlogPostRequest>
-                    for CreateBlogPostSvc<T> {
-                        type Response = super::CreateBlogPostResponse;
+                    > tonic::server::UnaryService<super::GetBlogPostsRequest>
+                    for GetBlogPostsSvc<T> {
+                        type Response = super::GetBlogPostsResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::CreateBlogPostRequest>,
+                            request: tonic::Request<super::GetBlogPostsRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as BlogAdminService>::create_blog_post(&inner, request)
+                                <T as BlogAdminService>::get_blog_posts(&inner, request)
                                     .await
                             };
                             Box::pin(fut)
@@ -381,7 +623,7 @@ pub mod blog_admin_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = CreateBlogPostSvc(inner);
+                        let method = GetBlogPostsSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -397,25 +639,25 @@ pub mod blog_admin_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/blog.BlogAdminService/UpdateBlogPost" => {
+                "/blog.BlogAdminService/GetBlogPost" => {
                     #[allow(non_camel_case_types)]
-                    struct UpdateBlogPostSvc<T: BlogAdminService>(pub Arc<T>);
+                    struct GetBlogPostSvc<T: BlogAdminService>(pub Arc<T>);
                     impl<
                         T: BlogAdminService,
-                    > tonic::server::UnaryService<super::UpdateBlogPostRequest>
-                    for UpdateBlogPostSvc<T> {
-                        type Response = super::UpdateBlogPostResponse;
+                    > tonic::server::UnaryService<super::GetBlogPostRequest>
+                    for GetBlogPostSvc<T> {
+                        type Response = super::GetBlogPostResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::UpdateBlogPostRequest>,
+                            request: tonic::Request<super::GetBlogPostRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as BlogAdminService>::update_blog_post(&inner, request)
+                                <T as BlogAdminService>::get_blog_post(&inner, request)
                                     .await
                             };
                             Box::pin(fut)
@@ -427,7 +669,7 @@ pub mod blog_admin_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = UpdateBlogPostSvc(inner);
+                        let method = GetBlogPostSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -443,25 +685,25 @@ pub mod blog_admin_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/blog.BlogAdminService/DeleteBlogPost" => {
+                "/blog.BlogAdminService/CreateBlogPost" => {
                     #[allow(non_camel_case_types)]
-                    struct DeleteBlogPostSvc<T: BlogAdminService>(pub Arc<T>);
+                    struct CreateBlogPostSvc<T: BlogAdminService>(pub Arc<T>);
                     impl<
                         T: BlogAdminService,
-                    > tonic::server::UnaryService<super::DeleteBlogPostRequest>
-                    for DeleteBlogPostSvc<T> {
-                        type Response = super::DeleteBlogPostResponse;
+                    > tonic::server::UnaryService<super::CreateBlogPostRequest>
+                    for CreateBlogPostSvc<T> {
+                        type Response = super::CreateBlogPostResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::DeleteBlogPostRequest>,
+                            request: tonic::Request<super::CreateBlogPostRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as BlogAdminService>::delete_blog_post(&inner, request)
+                                <T as BlogAdminService>::create_blog_post(&inner, request)
                                     .await
                             };
                             Box::pin(fut)
@@ -473,7 +715,7 @@ pub mod blog_admin_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = DeleteBlogPostSvc(inner);
+                        let method = CreateBlogPostSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -489,25 +731,25 @@ pub mod blog_admin_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/blog.BlogAdminService/RestoreBlogPost" => {
+                "/blog.BlogAdminService/UpdateBlogPost" => {
                     #[allow(non_camel_case_types)]
-                    struct RestoreBlogPostSvc<T: BlogAdminService>(pub Arc<T>);
+                    struct UpdateBlogPostSvc<T: BlogAdminService>(pub Arc<T>);
                     impl<
                         T: BlogAdminService,
-                    > tonic::server::UnaryService<super::RestoreBlogPostRequest>
-                    for RestoreBlogPostSvc<T> {
-                        type Response = super::RestoreBlogPostResponse;
+                    > tonic::server::UnaryService<super::UpdateBlogPostRequest>
+                    for UpdateBlogPostSvc<T> {
+                        type Response = super::UpdateBlogPostResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::RestoreBlogPostRequest>,
+                            request: tonic::Request<super::UpdateBlogPostRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as BlogAdminService>::restore_blog_post(&inner, request)
+                                <T as BlogAdminService>::update_blog_post(&inner, request)
                                     .await
                             };
                             Box::pin(fut)
@@ -519,7 +761,569 @@ pub mod blog_admin_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = RestoreBlogPostSvc(inner);
+                        let method = UpdateBlogPostSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blog.BlogAdminService/DeleteBlogPost" => {
+                    #[allow(non_camel_case_types)]
+                    struct DeleteBlogPostSvc<T: BlogAdminService>(pub Arc<T>);
+                    impl<
+                        T: BlogAdminService,
+                    > tonic::server::UnaryService<super::DeleteBlogPostRequest>
+                    for DeleteBlogPostSvc<T> {
+                        type Response = super::DeleteBlogPostResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DeleteBlogPostRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlogAdminService>::delete_blog_post(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = DeleteBlogPostSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blog.BlogAdminService/RestoreBlogPost" => {
+                    #[allow(non_camel_case_types)]
+                    struct RestoreBlogPostSvc<T: BlogAdminService>(pub Arc<T>);
+                    impl<
+                        T: BlogAdminService,
+                    > tonic::server::UnaryService<super::RestoreBlogPostRequest>
+                    for RestoreBlogPostSvc<T> {
+                        type Response = super::RestoreBlogPostResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RestoreBlogPostRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlogAdminService>::restore_blog_post(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = RestoreBlogPostSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blog.BlogAdminService/PublishBlogPost" => {
+                    #[allow(non_camel_case_types)]
+                    struct PublishBlogPostSvc<T: BlogAdminService>(pub Arc<T>);
+                    impl<
+                        T: BlogAdminService,
+                    > tonic::server::UnaryService<super::PublishBlogPostRequest>
+                    for PublishBlogPostSvc<T> {
+                        type Response = super::PublishBlogPostResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PublishBlogPostRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlogAdminService>::publish_blog_post(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = PublishBlogPostSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blog.BlogAdminService/UnpublishBlogPost" => {
+                    #[allow(non_camel_case_types)]
+                    struct UnpublishBlogPostSvc<T: BlogAdminService>(pub Arc<T>);
+                    impl<
+                        T: BlogAdminService,
+                    > tonic::server::UnaryService<super::UnpublishBlogPostRequest>
+                    for UnpublishBlogPostSvc<T> {
+                        type Response = super::UnpublishBlogPostResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::UnpublishBlogPostRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlogAdminService>::unpublish_blog_post(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = UnpublishBlogPostSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blog.BlogAdminService/ScheduleBlogPost" => {
+                    #[allow(non_camel_case_types)]
+                    struct ScheduleBlogPostSvc<T: BlogAdminService>(pub Arc<T>);
+                    impl<
+                        T: BlogAdminService,
+                    > tonic::server::UnaryService<super::ScheduleBlogPostRequest>
+                    for ScheduleBlogPostSvc<T> {
+                        type Response = super::ScheduleBlogPostResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ScheduleBlogPostRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlogAdminService>::schedule_blog_post(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ScheduleBlogPostSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blog.BlogAdminService/ListBlogPostRevisions" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListBlogPostRevisionsSvc<T: BlogAdminService>(pub Arc<T>);
+                    impl<
+                        T: BlogAdminService,
+                    > tonic::server::UnaryService<super::ListBlogPostRevisionsRequest>
+                    for ListBlogPostRevisionsSvc<T> {
+                        type Response = super::ListBlogPostRevisionsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListBlogPostRevisionsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlogAdminService>::list_blog_post_revisions(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListBlogPostRevisionsSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blog.BlogAdminService/GetBlogPostRevision" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetBlogPostRevisionSvc<T: BlogAdminService>(pub Arc<T>);
+                    impl<
+                        T: BlogAdminService,
+                    > tonic::server::UnaryService<super::GetBlogPostRevisionRequest>
+                    for GetBlogPostRevisionSvc<T> {
+                        type Response = super::GetBlogPostRevisionResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetBlogPostRevisionRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlogAdminService>::get_blog_post_revision(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetBlogPostRevisionSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blog.BlogAdminService/DiffBlogPostRevisions" => {
+                    #[allow(non_camel_case_types)]
+                    struct DiffBlogPostRevisionsSvc<T: BlogAdminService>(pub Arc<T>);
+                    impl<
+                        T: BlogAdminService,
+                    > tonic::server::UnaryService<super::DiffBlogPostRevisionsRequest>
+                    for DiffBlogPostRevisionsSvc<T> {
+                        type Response = super::DiffBlogPostRevisionsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DiffBlogPostRevisionsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlogAdminService>::diff_blog_post_revisions(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = DiffBlogPostRevisionsSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blog.BlogAdminService/RevertBlogPost" => {
+                    #[allow(non_camel_case_types)]
+                    struct RevertBlogPostSvc<T: BlogAdminService>(pub Arc<T>);
+                    impl<
+                        T: BlogAdminService,
+                    > tonic::server::UnaryService<super::RevertBlogPostRequest>
+                    for RevertBlogPostSvc<T> {
+                        type Response = super::RevertBlogPostResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RevertBlogPostRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlogAdminService>::revert_blog_post(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = RevertBlogPostSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blog.BlogAdminService/RenameTag" => {
+                    #[allow(non_camel_case_types)]
+                    struct RenameTagSvc<T: BlogAdminService>(pub Arc<T>);
+                    impl<
+                        T: BlogAdminService,
+                    > tonic::server::UnaryService<super::RenameTagRequest>
+                    for RenameTagSvc<T> {
+                        type Response = super::RenameTagResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RenameTagRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlogAdminService>::rename_tag(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = RenameTagSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blog.BlogAdminService/MergeTags" => {
+                    #[allow(non_camel_case_types)]
+                    struct MergeTagsSvc<T: BlogAdminService>(pub Arc<T>);
+                    impl<
+                        T: BlogAdminService,
+                    > tonic::server::UnaryService<super::MergeTagsRequest>
+                    for MergeTagsSvc<T> {
+                        type Response = super::MergeTagsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MergeTagsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlogAdminService>::merge_tags(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = MergeTagsSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/blog.BlogAdminService/ListAuditEvents" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListAuditEventsSvc<T: BlogAdminService>(pub Arc<T>);
+                    impl<
+                        T: BlogAdminService,
+                    > tonic::server::UnaryService<super::ListAuditEventsRequest>
+                    for ListAuditEventsSvc<T> {
+                        type Response = super::ListAuditEventsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListAuditEventsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlogAdminService>::list_audit_events(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListAuditEventsSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -0,0 +1,553 @@
+// @generated
+// This file is @generated by prost-build.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Inquiry {
+    #[prost(int64, tag="1")]
+    pub id: i64,
+    #[prost(string, tag="2")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub email: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub subject: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub message: ::prost::alloc::string::String,
+    #[prost(enumeration="InquiryStatus", tag="6")]
+    pub status: i32,
+    #[prost(int64, tag="7")]
+    pub created_at: i64,
+    #[prost(int64, optional, tag="8")]
+    pub handled_at: ::core::option::Option<i64>,
+    /// Firebase `sub` of the admin who marked it handled
+    #[prost(string, optional, tag="9")]
+    pub handled_by: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(int64, optional, tag="10")]
+    pub archived_at: ::core::option::Option<i64>,
+    /// Spam filter score at submission; quarantined from SPAM_THRESHOLD up
+    #[prost(double, tag="11")]
+    pub spam_score: f64,
+    /// Why the spam filter scored it, e.g. "honeypot field filled in"
+    #[prost(string, repeated, tag="12")]
+    pub spam_reasons: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Admin verdict, if any
+    #[prost(bool, optional, tag="13")]
+    pub marked_spam: ::core::option::Option<bool>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SubmitInquiryRequest {
+    #[prost(string, tag="1")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub email: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub subject: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub message: ::prost::alloc::string::String,
+    /// Honeypot: hidden from people by the form, so only bots fill it in
+    #[prost(string, tag="5")]
+    pub website: ::prost::alloc::string::String,
+    /// From GetFormToken
+    #[prost(string, tag="6")]
+    pub form_token: ::prost::alloc::string::String,
+}
+/// Deliberately the same whether or not the inquiry was quarantined
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SubmitInquiryResponse {
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct GetFormTokenRequest {
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct GetFormTokenResponse {
+    #[prost(string, tag="1")]
+    pub form_token: ::prost::alloc::string::String,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ListInquiriesRequest {
+    /// Unset lists the inbox: new and handled inquiries, but not archived or
+    /// quarantined ones
+    #[prost(enumeration="InquiryStatus", optional, tag="1")]
+    pub status: ::core::option::Option<i32>,
+    #[prost(int32, tag="2")]
+    pub limit: i32,
+    #[prost(int32, tag="3")]
+    pub offset: i32,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListInquiriesResponse {
+    #[prost(message, repeated, tag="1")]
+    pub inquiries: ::prost::alloc::vec::Vec<Inquiry>,
+    #[prost(int32, tag="2")]
+    pub total: i32,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct GetInquiryRequest {
+    #[prost(int64, tag="1")]
+    pub id: i64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetInquiryResponse {
+    #[prost(message, optional, tag="1")]
+    pub inquiry: ::core::option::Option<Inquiry>,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct MarkInquiryHandledRequest {
+    #[prost(int64, tag="1")]
+    pub id: i64,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct MarkInquiryHandledResponse {
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ArchiveInquiryRequest {
+    #[prost(int64, tag="1")]
+    pub id: i64,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ArchiveInquiryResponse {
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct MarkInquirySpamRequest {
+    #[prost(int64, tag="1")]
+    pub id: i64,
+    #[prost(bool, tag="2")]
+    pub spam: bool,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct MarkInquirySpamResponse {
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DeleteInquiryRequest {
+    #[prost(int64, tag="1")]
+    pub id: i64,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DeleteInquiryResponse {
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum InquiryStatus {
+    Unspecified = 0,
+    New = 1,
+    Handled = 2,
+    Archived = 3,
+    /// Flagged by the spam filter, or marked as spam by an admin
+    Quarantined = 4,
+}
+impl InquiryStatus {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "INQUIRY_STATUS_UNSPECIFIED",
+            Self::New => "INQUIRY_STATUS_NEW",
+            Self::Handled => "INQUIRY_STATUS_HANDLED",
+            Self::Archived => "INQUIRY_STATUS_ARCHIVED",
+            Self::Quarantined => "INQUIRY_STATUS_QUARANTINED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "INQUIRY_STATUS_UNSPECIFIED" => Some(Self::Unspecified),
+            "INQUIRY_STATUS_NEW" => Some(Self::New),
+            "INQUIRY_STATUS_HANDLED" => Some(Self::Handled),
+            "INQUIRY_STATUS_ARCHIVED" => Some(Self::Archived),
+            "INQUIRY_STATUS_QUARANTINED" => Some(Self::Quarantined),
+            _ => None,
+        }
+    }
+}
+/// Encoded file descriptor set for the `inquiry` package
+pub const FILE_DESCRIPTOR_SET: &[u8] = &[
+    0x0a, 0x99, 0x30, 0x0a, 0x0d, 0x69, 0x6e, 0x71, 0x75, 0x69, 0x72, 0x79, 0x2e, 0x70, 0x72, 0x6f,
+    0x74, 0x6f, 0x12, 0x07, 0x69, 0x6e, 0x71, 0x75, 0x69, 0x72, 0x79, 0x22, 0xda, 0x03, 0x0a, 0x07,
+    0x49, 0x6e, 0x71, 0x75, 0x69, 0x72, 0x79, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20,
+    0x01, 0x28, 0x03, 0x52, 0x02, 0x69, 0x64, 0x12, 0x12, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18,
+    0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x12, 0x14, 0x0a, 0x05, 0x65,
+    0x6d, 0x61, 0x69, 0x6c, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x05, 0x65, 0x6d, 0x61, 0x69,
+    0x6c, 0x12, 0x18, 0x0a, 0x07, 0x73, 0x75, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x18, 0x04, 0x20, 0x01,
+    0x28, 0x09, 0x52, 0x07, 0x73, 0x75, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x12, 0x18, 0x0a, 0x07, 0x6d,
+    0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x6d, 0x65,
+    0x73, 0x73, 0x61, 0x67, 0x65, 0x12, 0x2e, 0x0a, 0x06, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x18,
+    0x06, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x16, 0x2e, 0x69, 0x6e, 0x71, 0x75, 0x69, 0x72, 0x79, 0x2e,
+    0x49, 0x6e, 0x71, 0x75, 0x69, 0x72, 0x79, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x52, 0x06, 0x73,
+    0x74, 0x61, 0x74, 0x75, 0x73, 0x12, 0x1d, 0x0a, 0x0a, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64,
+    0x5f, 0x61, 0x74, 0x18, 0x07, 0x20, 0x01, 0x28, 0x03, 0x52, 0x09, 0x63, 0x72, 0x65, 0x61, 0x74,
+    0x65, 0x64, 0x41, 0x74, 0x12, 0x22, 0x0a, 0x0a, 0x68, 0x61, 0x6e, 0x64, 0x6c, 0x65, 0x64, 0x5f,
+    0x61, 0x74, 0x18, 0x08, 0x20, 0x01, 0x28, 0x03, 0x48, 0x00, 0x52, 0x09, 0x68, 0x61, 0x6e, 0x64,
+    0x6c, 0x65, 0x64, 0x41, 0x74, 0x88, 0x01, 0x01, 0x12, 0x22, 0x0a, 0x0a, 0x68, 0x61, 0x6e, 0x64,
+    0x6c, 0x65, 0x64, 0x5f, 0x62, 0x79, 0x18, 0x09, 0x20, 0x01, 0x28, 0x09, 0x48, 0x01, 0x52, 0x09,
+    0x68, 0x61, 0x6e, 0x64, 0x6c, 0x65, 0x64, 0x42, 0x79, 0x88, 0x01, 0x01, 0x12, 0x24, 0x0a, 0x0b,
+    0x61, 0x72, 0x63, 0x68, 0x69, 0x76, 0x65, 0x64, 0x5f, 0x61, 0x74, 0x18, 0x0a, 0x20, 0x01, 0x28,
+    0x03, 0x48, 0x02, 0x52, 0x0a, 0x61, 0x72, 0x63, 0x68, 0x69, 0x76, 0x65, 0x64, 0x41, 0x74, 0x88,
+    0x01, 0x01, 0x12, 0x1d, 0x0a, 0x0a, 0x73, 0x70, 0x61, 0x6d, 0x5f, 0x73, 0x63, 0x6f, 0x72, 0x65,
+    0x18, 0x0b, 0x20, 0x01, 0x28, 0x01, 0x52, 0x09, 0x73, 0x70, 0x61, 0x6d, 0x53, 0x63, 0x6f, 0x72,
+    0x65, 0x12, 0x21, 0x0a, 0x0c, 0x73, 0x70, 0x61, 0x6d, 0x5f, 0x72, 0x65, 0x61, 0x73, 0x6f, 0x6e,
+    0x73, 0x18, 0x0c, 0x20, 0x03, 0x28, 0x09, 0x52, 0x0b, 0x73, 0x70, 0x61, 0x6d, 0x52, 0x65, 0x61,
+    0x73, 0x6f, 0x6e, 0x73, 0x12, 0x24, 0x0a, 0x0b, 0x6d, 0x61, 0x72, 0x6b, 0x65, 0x64, 0x5f, 0x73,
+    0x70, 0x61, 0x6d, 0x18, 0x0d, 0x20, 0x01, 0x28, 0x08, 0x48, 0x03, 0x52, 0x0a, 0x6d, 0x61, 0x72,
+    0x6b, 0x65, 0x64, 0x53, 0x70, 0x61, 0x6d, 0x88, 0x01, 0x01, 0x42, 0x0d, 0x0a, 0x0b, 0x5f, 0x68,
+    0x61, 0x6e, 0x64, 0x6c, 0x65, 0x64, 0x5f, 0x61, 0x74, 0x42, 0x0d, 0x0a, 0x0b, 0x5f, 0x68, 0x61,
+    0x6e, 0x64, 0x6c, 0x65, 0x64, 0x5f, 0x62, 0x79, 0x42, 0x0e, 0x0a, 0x0c, 0x5f, 0x61, 0x72, 0x63,
+    0x68, 0x69, 0x76, 0x65, 0x64, 0x5f, 0x61, 0x74, 0x42, 0x0e, 0x0a, 0x0c, 0x5f, 0x6d, 0x61, 0x72,
+    0x6b, 0x65, 0x64, 0x5f, 0x73, 0x70, 0x61, 0x6d, 0x22, 0xad, 0x01, 0x0a, 0x14, 0x53, 0x75, 0x62,
+    0x6d, 0x69, 0x74, 0x49, 0x6e, 0x71, 0x75, 0x69, 0x72, 0x79, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73,
+    0x74, 0x12, 0x12, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52,
+    0x04, 0x6e, 0x61, 0x6d, 0x65, 0x12, 0x14, 0x0a, 0x05, 0x65, 0x6d, 0x61, 0x69, 0x6c, 0x18, 0x02,
+    0x20, 0x01, 0x28, 0x09, 0x52, 0x05, 0x65, 0x6d, 0x61, 0x69, 0x6c, 0x12, 0x18, 0x0a, 0x07, 0x73,
+    0x75, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x73, 0x75,
+    0x62, 0x6a, 0x65, 0x63, 0x74, 0x12, 0x18, 0x0a, 0x07, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65,
+    0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x12,
+    0x18, 0x0a, 0x07, 0x77, 0x65, 0x62, 0x73, 0x69, 0x74, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x09,
+    0x52, 0x07, 0x77, 0x65, 0x62, 0x73, 0x69, 0x74, 0x65, 0x12, 0x1d, 0x0a, 0x0a, 0x66, 0x6f, 0x72,
+    0x6d, 0x5f, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18, 0x06, 0x20, 0x01, 0x28, 0x09, 0x52, 0x09, 0x66,
+    0x6f, 0x72, 0x6d, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x22, 0x17, 0x0a, 0x15, 0x53, 0x75, 0x62, 0x6d,
+    0x69, 0x74, 0x49, 0x6e, 0x71, 0x75, 0x69, 0x72, 0x79, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73,
+    0x65, 0x22, 0x15, 0x0a, 0x13, 0x47, 0x65, 0x74, 0x46, 0x6f, 0x72, 0x6d, 0x54, 0x6f, 0x6b, 0x65,
+    0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x22, 0x35, 0x0a, 0x14, 0x47, 0x65, 0x74, 0x46,
+    0x6f, 0x72, 0x6d, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
+    0x12, 0x1d, 0x0a, 0x0a, 0x66, 0x6f, 0x72, 0x6d, 0x5f, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18, 0x01,
+    0x20, 0x01, 0x28, 0x09, 0x52, 0x09, 0x66, 0x6f, 0x72, 0x6d, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x22,
+    0x84, 0x01, 0x0a, 0x14, 0x4c, 0x69, 0x73, 0x74, 0x49, 0x6e, 0x71, 0x75, 0x69, 0x72, 0x69, 0x65,
+    0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x33, 0x0a, 0x06, 0x73, 0x74, 0x61, 0x74,
+    0x75, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x16, 0x2e, 0x69, 0x6e, 0x71, 0x75, 0x69,
+    0x72, 0x79, 0x2e, 0x49, 0x6e, 0x71, 0x75, 0x69, 0x72, 0x79, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73,
+    0x48, 0x00, 0x52, 0x06, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x88, 0x01, 0x01, 0x12, 0x14, 0x0a,
+    0x05, 0x6c, 0x69, 0x6d, 0x69, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x05, 0x52, 0x05, 0x6c, 0x69,
+    0x6d, 0x69, 0x74, 0x12, 0x16, 0x0a, 0x06, 0x6f, 0x66, 0x66, 0x73, 0x65, 0x74, 0x18, 0x03, 0x20,
+    0x01, 0x28, 0x05, 0x52, 0x06, 0x6f, 0x66, 0x66, 0x73, 0x65, 0x74, 0x42, 0x09, 0x0a, 0x07, 0x5f,
+    0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x22, 0x5d, 0x0a, 0x15, 0x4c, 0x69, 0x73, 0x74, 0x49, 0x6e,
+    0x71, 0x75, 0x69, 0x72, 0x69, 0x65, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12,
+    0x2e, 0x0a, 0x09, 0x69, 0x6e, 0x71, 0x75, 0x69, 0x72, 0x69, 0x65, 0x73, 0x18, 0x01, 0x20, 0x03,
+    0x28, 0x0b, 0x32, 0x10, 0x2e, 0x69, 0x6e, 0x71, 0x75, 0x69, 0x72, 0x79, 0x2e, 0x49, 0x6e, 0x71,
+    0x75, 0x69, 0x72, 0x79, 0x52, 0x09, 0x69, 0x6e, 0x71, 0x75, 0x69, 0x72, 0x69, 0x65, 0x73, 0x12,
+    0x14, 0x0a, 0x05, 0x74, 0x6f, 0x74, 0x61, 0x6c, 0x18, 0x02, 0x20, 0x01, 0x28, 0x05, 0x52, 0x05,
+    0x74, 0x6f, 0x74, 0x61, 0x6c, 0x22, 0x23, 0x0a, 0x11, 0x47, 0x65, 0x74, 0x49, 0x6e, 0x71, 0x75,
+    0x69, 0x72, 0x79, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64,
+    0x18, 0x01, 0x20, 0x01, 0x28, 0x03, 0x52, 0x02, 0x69, 0x64, 0x22, 0x51, 0x0a, 0x12, 0x47, 0x65,
+    0x74, 0x49, 0x6e, 0x71, 0x75, 0x69, 0x72, 0x79, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
+    0x12, 0x2f, 0x0a, 0x07, 0x69, 0x6e, 0x71, 0x75, 0x69, 0x72, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28,
+    0x0b, 0x32, 0x10, 0x2e, 0x69, 0x6e, 0x71, 0x75, 0x69, 0x72, 0x79, 0x2e, 0x49, 0x6e, 0x71, 0x75,
+    0x69, 0x72, 0x79, 0x48, 0x00, 0x52, 0x07, 0x69, 0x6e, 0x71, 0x75, 0x69, 0x72, 0x79, 0x88, 0x01,
+    0x01, 0x42, 0x0a, 0x0a, 0x08, 0x5f, 0x69, 0x6e, 0x71, 0x75, 0x69, 0x72, 0x79, 0x22, 0x2b, 0x0a,
+    0x19, 0x4d, 0x61, 0x72, 0x6b, 0x49, 0x6e, 0x71, 0x75, 0x69, 0x72, 0x79, 0x48, 0x61, 0x6e, 0x64,
+    0x6c, 0x65, 0x64, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64,
+    0x18, 0x01, 0x20, 0x01, 0x28, 0x03, 0x52, 0x02, 0x69, 0x64, 0x22, 0x1c, 0x0a, 0x1a, 0x4d, 0x61,
+    0x72, 0x6b, 0x49, 0x6e, 0x71, 0x75, 0x69, 0x72, 0x79, 0x48, 0x61, 0x6e, 0x64, 0x6c, 0x65, 0x64,
+    0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x27, 0x0a, 0x15, 0x41, 0x72, 0x63, 0x68,
+    0x69, 0x76, 0x65, 0x49, 0x6e, 0x71, 0x75, 0x69, 0x72, 0x79, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73,
+    0x74, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x03, 0x52, 0x02, 0x69,
+    0x64, 0x22, 0x18, 0x0a, 0x16, 0x41, 0x72, 0x63, 0x68, 0x69, 0x76, 0x65, 0x49, 0x6e, 0x71, 0x75,
+    0x69, 0x72, 0x79, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x3c, 0x0a, 0x16, 0x4d,
+    0x61, 0x72, 0x6b, 0x49, 0x6e, 0x71, 0x75, 0x69, 0x72, 0x79, 0x53, 0x70, 0x61, 0x6d, 0x52, 0x65,
+    0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28,
+    0x03, 0x52, 0x02, 0x69, 0x64, 0x12, 0x12, 0x0a, 0x04, 0x73, 0x70, 0x61, 0x6d, 0x18, 0x02, 0x20,
+    0x01, 0x28, 0x08, 0x52, 0x04, 0x73, 0x70, 0x61, 0x6d, 0x22, 0x19, 0x0a, 0x17, 0x4d, 0x61, 0x72,
+    0x6b, 0x49, 0x6e, 0x71, 0x75, 0x69, 0x72, 0x79, 0x53, 0x70, 0x61, 0x6d, 0x52, 0x65, 0x73, 0x70,
+    0x6f, 0x6e, 0x73, 0x65, 0x22, 0x26, 0x0a, 0x14, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x49, 0x6e,
+    0x71, 0x75, 0x69, 0x72, 0x79, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x0e, 0x0a, 0x02,
+    0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x03, 0x52, 0x02, 0x69, 0x64, 0x22, 0x17, 0x0a, 0x15,
+    0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x49, 0x6e, 0x71, 0x75, 0x69, 0x72, 0x79, 0x52, 0x65, 0x73,
+    0x70, 0x6f, 0x6e, 0x73, 0x65, 0x2a, 0xa0, 0x01, 0x0a, 0x0d, 0x49, 0x6e, 0x71, 0x75, 0x69, 0x72,
+    0x79, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x12, 0x1e, 0x0a, 0x1a, 0x49, 0x4e, 0x51, 0x55, 0x49,
+    0x52, 0x59, 0x5f, 0x53, 0x54, 0x41, 0x54, 0x55, 0x53, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43,
+    0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x16, 0x0a, 0x12, 0x49, 0x4e, 0x51, 0x55, 0x49,
+    0x52, 0x59, 0x5f, 0x53, 0x54, 0x41, 0x54, 0x55, 0x53, 0x5f, 0x4e, 0x45, 0x57, 0x10, 0x01, 0x12,
+    0x1a, 0x0a, 0x16, 0x49, 0x4e, 0x51, 0x55, 0x49, 0x52, 0x59, 0x5f, 0x53, 0x54, 0x41, 0x54, 0x55,
+    0x53, 0x5f, 0x48, 0x41, 0x4e, 0x44, 0x4c, 0x45, 0x44, 0x10, 0x02, 0x12, 0x1b, 0x0a, 0x17, 0x49,
+    0x4e, 0x51, 0x55, 0x49, 0x52, 0x59, 0x5f, 0x53, 0x54, 0x41, 0x54, 0x55, 0x53, 0x5f, 0x41, 0x52,
+    0x43, 0x48, 0x49, 0x56, 0x45, 0x44, 0x10, 0x03, 0x12, 0x1e, 0x0a, 0x1a, 0x49, 0x4e, 0x51, 0x55,
+    0x49, 0x52, 0x59, 0x5f, 0x53, 0x54, 0x41, 0x54, 0x55, 0x53, 0x5f, 0x51, 0x55, 0x41, 0x52, 0x41,
+    0x4e, 0x54, 0x49, 0x4e, 0x45, 0x44, 0x10, 0x04, 0x32, 0xad, 0x01, 0x0a, 0x0e, 0x49, 0x6e, 0x71,
+    0x75, 0x69, 0x72, 0x79, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x12, 0x4b, 0x0a, 0x0c, 0x47,
+    0x65, 0x74, 0x46, 0x6f, 0x72, 0x6d, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x12, 0x1c, 0x2e, 0x69, 0x6e,
+    0x71, 0x75, 0x69, 0x72, 0x79, 0x2e, 0x47, 0x65, 0x74, 0x46, 0x6f, 0x72, 0x6d, 0x54, 0x6f, 0x6b,
+    0x65, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1d, 0x2e, 0x69, 0x6e, 0x71, 0x75,
+    0x69, 0x72, 0x79, 0x2e, 0x47, 0x65, 0x74, 0x46, 0x6f, 0x72, 0x6d, 0x54, 0x6f, 0x6b, 0x65, 0x6e,
+    0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x4e, 0x0a, 0x0d, 0x53, 0x75, 0x62, 0x6d,
+    0x69, 0x74, 0x49, 0x6e, 0x71, 0x75, 0x69, 0x72, 0x79, 0x12, 0x1d, 0x2e, 0x69, 0x6e, 0x71, 0x75,
+    0x69, 0x72, 0x79, 0x2e, 0x53, 0x75, 0x62, 0x6d, 0x69, 0x74, 0x49, 0x6e, 0x71, 0x75, 0x69, 0x72,
+    0x79, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1e, 0x2e, 0x69, 0x6e, 0x71, 0x75, 0x69,
+    0x72, 0x79, 0x2e, 0x53, 0x75, 0x62, 0x6d, 0x69, 0x74, 0x49, 0x6e, 0x71, 0x75, 0x69, 0x72, 0x79,
+    0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x32, 0x84, 0x04, 0x0a, 0x13, 0x49, 0x6e, 0x71,
+    0x75, 0x69, 0x72, 0x79, 0x41, 0x64, 0x6d, 0x69, 0x6e, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65,
+    0x12, 0x4e, 0x0a, 0x0d, 0x4c, 0x69, 0x73, 0x74, 0x49, 0x6e, 0x71, 0x75, 0x69, 0x72, 0x69, 0x65,
+    0x73, 0x12, 0x1d, 0x2e, 0x69, 0x6e, 0x71, 0x75, 0x69, 0x72, 0x79, 0x2e, 0x4c, 0x69, 0x73, 0x74,
+    0x49, 0x6e, 0x71, 0x75, 0x69, 0x72, 0x69, 0x65, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+    0x1a, 0x1e, 0x2e, 0x69, 0x6e, 0x71, 0x75, 0x69, 0x72, 0x79, 0x2e, 0x4c, 0x69, 0x73, 0x74, 0x49,
+    0x6e, 0x71, 0x75, 0x69, 0x72, 0x69, 0x65, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
+    0x12, 0x45, 0x0a, 0x0a, 0x47, 0x65, 0x74, 0x49, 0x6e, 0x71, 0x75, 0x69, 0x72, 0x79, 0x12, 0x1a,
+    0x2e, 0x69, 0x6e, 0x71, 0x75, 0x69, 0x72, 0x79, 0x2e, 0x47, 0x65, 0x74, 0x49, 0x6e, 0x71, 0x75,
+    0x69, 0x72, 0x79, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1b, 0x2e, 0x69, 0x6e, 0x71,
+    0x75, 0x69, 0x72, 0x79, 0x2e, 0x47, 0x65, 0x74, 0x49, 0x6e, 0x71, 0x75, 0x69, 0x72, 0x79, 0x52,
+    0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x5d, 0x0a, 0x12, 0x4d, 0x61, 0x72, 0x6b, 0x49,
+    0x6e, 0x71, 0x75, 0x69, 0x72, 0x79, 0x48, 0x61, 0x6e, 0x64, 0x6c, 0x65, 0x64, 0x12, 0x22, 0x2e,
+    0x69, 0x6e, 0x71, 0x75, 0x69, 0x72, 0x79, 0x2e, 0x4d, 0x61, 0x72, 0x6b, 0x49, 0x6e, 0x71, 0x75,
+    0x69, 0x72, 0x79, 0x48, 0x61, 0x6e, 0x64, 0x6c, 0x65, 0x64, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73,
+    0x74, 0x1a, 0x23, 0x2e, 0x69, 0x6e, 0x71, 0x75, 0x69, 0x72, 0x79, 0x2e, 0x4d, 0x61, 0x72, 0x6b,
+    0x49, 0x6e, 0x71, 0x75, 0x69, 0x72, 0x79, 0x48, 0x61, 0x6e, 0x64, 0x6c, 0x65, 0x64, 0x52, 0x65,
+    0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x51, 0x0a, 0x0e, 0x41, 0x72, 0x63, 0x68, 0x69, 0x76,
+    0x65, 0x49, 0x6e, 0x71, 0x75, 0x69, 0x72, 0x79, 0x12, 0x1e, 0x2e, 0x69, 0x6e, 0x71, 0x75, 0x69,
+    0x72, 0x79, 0x2e, 0x41, 0x72, 0x63, 0x68, 0x69, 0x76, 0x65, 0x49, 0x6e, 0x71, 0x75, 0x69, 0x72,
+    0x79, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1f, 0x2e, 0x69, 0x6e, 0x71, 0x75, 0x69,
+    0x72, 0x79, 0x2e, 0x41, 0x72, 0x63, 0x68, 0x69, 0x76, 0x65, 0x49, 0x6e, 0x71, 0x75, 0x69, 0x72,
+    0x79, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x54, 0x0a, 0x0f, 0x4d, 0x61, 0x72,
+    0x6b, 0x49, 0x6e, 0x71, 0x75, 0x69, 0x72, 0x79, 0x53, 0x70, 0x61, 0x6d, 0x12, 0x1f, 0x2e, 0x69,
+    0x6e, 0x71, 0x75, 0x69, 0x72, 0x79, 0x2e, 0x4d, 0x61, 0x72, 0x6b, 0x49, 0x6e, 0x71, 0x75, 0x69,
+    0x72, 0x79, 0x53, 0x70, 0x61, 0x6d, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x20, 0x2e,
+    0x69, 0x6e, 0x71, 0x75, 0x69, 0x72, 0x79, 0x2e, 0x4d, 0x61, 0x72, 0x6b, 0x49, 0x6e, 0x71, 0x75,
+    0x69, 0x72, 0x79, 0x53, 0x70, 0x61, 0x6d, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12,
+    0x4e, 0x0a, 0x0d, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x49, 0x6e, 0x71, 0x75, 0x69, 0x72, 0x79,
+    0x12, 0x1d, 0x2e, 0x69, 0x6e, 0x71, 0x75, 0x69, 0x72, 0x79, 0x2e, 0x44, 0x65, 0x6c, 0x65, 0x74,
+    0x65, 0x49, 0x6e, 0x71, 0x75, 0x69, 0x72, 0x79, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a,
+    0x1e, 0x2e, 0x69, 0x6e, 0x71, 0x75, 0x69, 0x72, 0x79, 0x2e, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65,
+    0x49, 0x6e, 0x71, 0x75, 0x69, 0x72, 0x79, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x4a,
+    0xa2, 0x1e, 0x0a, 0x06, 0x12, 0x04, 0x00, 0x00, 0x76, 0x20, 0x0a, 0x08, 0x0a, 0x01, 0x0c, 0x12,
+    0x03, 0x00, 0x00, 0x12, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03, 0x02, 0x00, 0x10, 0x0a, 0x21,
+    0x0a, 0x02, 0x06, 0x00, 0x12, 0x04, 0x05, 0x00, 0x0a, 0x01, 0x1a, 0x15, 0x20, 0x50, 0x75, 0x62,
+    0x6c, 0x69, 0x63, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x61, 0x63, 0x74, 0x20, 0x66, 0x6f, 0x72, 0x6d,
+    0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x06, 0x00, 0x01, 0x12, 0x03, 0x05, 0x08, 0x16, 0x0a, 0x78, 0x0a,
+    0x04, 0x06, 0x00, 0x02, 0x00, 0x12, 0x03, 0x08, 0x02, 0x47, 0x1a, 0x6b, 0x20, 0x54, 0x6f, 0x6b,
+    0x65, 0x6e, 0x20, 0x74, 0x6f, 0x20, 0x73, 0x65, 0x6e, 0x64, 0x20, 0x62, 0x61, 0x63, 0x6b, 0x20,
+    0x77, 0x69, 0x74, 0x68, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x75, 0x62, 0x6d, 0x69, 0x73, 0x73,
+    0x69, 0x6f, 0x6e, 0x3b, 0x20, 0x66, 0x6f, 0x72, 0x6d, 0x73, 0x20, 0x73, 0x75, 0x62, 0x6d, 0x69,
+    0x74, 0x74, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x6f, 0x20, 0x73, 0x6f, 0x6f, 0x6e, 0x20, 0x61, 0x66,
+    0x74, 0x65, 0x72, 0x0a, 0x20, 0x69, 0x74, 0x20, 0x77, 0x61, 0x73, 0x20, 0x69, 0x73, 0x73, 0x75,
+    0x65, 0x64, 0x20, 0x61, 0x72, 0x65, 0x20, 0x74, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x20, 0x61,
+    0x73, 0x20, 0x73, 0x70, 0x61, 0x6d, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x00, 0x01,
+    0x12, 0x03, 0x08, 0x06, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03,
+    0x08, 0x13, 0x26, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x08, 0x31,
+    0x45, 0x0a, 0x0b, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x01, 0x12, 0x03, 0x09, 0x02, 0x4a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x06, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x09, 0x06, 0x13, 0x0a, 0x0c, 0x0a, 0x05,
+    0x06, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x09, 0x14, 0x28, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00,
+    0x02, 0x01, 0x03, 0x12, 0x03, 0x09, 0x33, 0x48, 0x0a, 0x3f, 0x0a, 0x02, 0x06, 0x01, 0x12, 0x04,
+    0x0d, 0x00, 0x17, 0x01, 0x1a, 0x33, 0x20, 0x50, 0x72, 0x6f, 0x74, 0x65, 0x63, 0x74, 0x65, 0x64,
+    0x20, 0x69, 0x6e, 0x71, 0x75, 0x69, 0x72, 0x79, 0x20, 0x69, 0x6e, 0x62, 0x6f, 0x78, 0x20, 0x28,
+    0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x73, 0x20, 0x61, 0x75, 0x74, 0x68, 0x65, 0x6e, 0x74,
+    0x69, 0x63, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x29, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x06, 0x01, 0x01,
+    0x12, 0x03, 0x0d, 0x08, 0x1b, 0x0a, 0x0b, 0x0a, 0x04, 0x06, 0x01, 0x02, 0x00, 0x12, 0x03, 0x0e,
+    0x02, 0x4a, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x0e, 0x06, 0x13,
+    0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x01, 0x02, 0x00, 0x02, 0x12, 0x03, 0x0e, 0x14, 0x28, 0x0a, 0x0c,
+    0x0a, 0x05, 0x06, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x0e, 0x33, 0x48, 0x0a, 0x0b, 0x0a, 0x04,
+    0x06, 0x01, 0x02, 0x01, 0x12, 0x03, 0x0f, 0x02, 0x41, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x01, 0x02,
+    0x01, 0x01, 0x12, 0x03, 0x0f, 0x06, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x01, 0x02, 0x01, 0x02,
+    0x12, 0x03, 0x0f, 0x11, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x01, 0x02, 0x01, 0x03, 0x12, 0x03,
+    0x0f, 0x2d, 0x3f, 0x0a, 0x0b, 0x0a, 0x04, 0x06, 0x01, 0x02, 0x02, 0x12, 0x03, 0x10, 0x02, 0x59,
+    0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x01, 0x02, 0x02, 0x01, 0x12, 0x03, 0x10, 0x06, 0x18, 0x0a, 0x0c,
+    0x0a, 0x05, 0x06, 0x01, 0x02, 0x02, 0x02, 0x12, 0x03, 0x10, 0x19, 0x32, 0x0a, 0x0c, 0x0a, 0x05,
+    0x06, 0x01, 0x02, 0x02, 0x03, 0x12, 0x03, 0x10, 0x3d, 0x57, 0x0a, 0x0b, 0x0a, 0x04, 0x06, 0x01,
+    0x02, 0x03, 0x12, 0x03, 0x11, 0x02, 0x4d, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x01, 0x02, 0x03, 0x01,
+    0x12, 0x03, 0x11, 0x06, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x01, 0x02, 0x03, 0x02, 0x12, 0x03,
+    0x11, 0x15, 0x2a, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x01, 0x02, 0x03, 0x03, 0x12, 0x03, 0x11, 0x35,
+    0x4b, 0x0a, 0x79, 0x0a, 0x04, 0x06, 0x01, 0x02, 0x04, 0x12, 0x03, 0x14, 0x02, 0x50, 0x1a, 0x6c,
+    0x20, 0x51, 0x75, 0x61, 0x72, 0x61, 0x6e, 0x74, 0x69, 0x6e, 0x65, 0x20, 0x61, 0x6e, 0x20, 0x69,
+    0x6e, 0x71, 0x75, 0x69, 0x72, 0x79, 0x20, 0x61, 0x73, 0x20, 0x73, 0x70, 0x61, 0x6d, 0x2c, 0x20,
+    0x6f, 0x72, 0x20, 0x72, 0x65, 0x6c, 0x65, 0x61, 0x73, 0x65, 0x20, 0x69, 0x74, 0x20, 0x74, 0x6f,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x69, 0x6e, 0x62, 0x6f, 0x78, 0x2c, 0x20, 0x61, 0x6e, 0x64, 0x20,
+    0x74, 0x72, 0x61, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x0a, 0x20, 0x73, 0x70, 0x61, 0x6d, 0x20,
+    0x63, 0x6c, 0x61, 0x73, 0x73, 0x69, 0x66, 0x69, 0x65, 0x72, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x76, 0x65, 0x72, 0x64, 0x69, 0x63, 0x74, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x06, 0x01, 0x02, 0x04, 0x01, 0x12, 0x03, 0x14, 0x06, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x01,
+    0x02, 0x04, 0x02, 0x12, 0x03, 0x14, 0x16, 0x2c, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x01, 0x02, 0x04,
+    0x03, 0x12, 0x03, 0x14, 0x37, 0x4e, 0x0a, 0x2e, 0x0a, 0x04, 0x06, 0x01, 0x02, 0x05, 0x12, 0x03,
+    0x16, 0x02, 0x4a, 0x1a, 0x21, 0x20, 0x50, 0x65, 0x72, 0x6d, 0x61, 0x6e, 0x65, 0x6e, 0x74, 0x6c,
+    0x79, 0x20, 0x72, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x69, 0x6e,
+    0x71, 0x75, 0x69, 0x72, 0x79, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x01, 0x02, 0x05, 0x01, 0x12,
+    0x03, 0x16, 0x06, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x01, 0x02, 0x05, 0x02, 0x12, 0x03, 0x16,
+    0x14, 0x28, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x01, 0x02, 0x05, 0x03, 0x12, 0x03, 0x16, 0x33, 0x48,
+    0x0a, 0x0a, 0x0a, 0x02, 0x05, 0x00, 0x12, 0x04, 0x19, 0x00, 0x20, 0x01, 0x0a, 0x0a, 0x0a, 0x03,
+    0x05, 0x00, 0x01, 0x12, 0x03, 0x19, 0x05, 0x12, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x00,
+    0x12, 0x03, 0x1a, 0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03,
+    0x1a, 0x02, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x1a, 0x1f,
+    0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x01, 0x12, 0x03, 0x1b, 0x02, 0x19, 0x0a, 0x0c,
+    0x0a, 0x05, 0x05, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x1b, 0x02, 0x14, 0x0a, 0x0c, 0x0a, 0x05,
+    0x05, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x1b, 0x17, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00,
+    0x02, 0x02, 0x12, 0x03, 0x1c, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x02, 0x01,
+    0x12, 0x03, 0x1c, 0x02, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03,
+    0x1c, 0x1b, 0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x03, 0x12, 0x03, 0x1d, 0x02, 0x1e,
+    0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x1d, 0x02, 0x19, 0x0a, 0x0c,
+    0x0a, 0x05, 0x05, 0x00, 0x02, 0x03, 0x02, 0x12, 0x03, 0x1d, 0x1c, 0x1d, 0x0a, 0x48, 0x0a, 0x04,
+    0x05, 0x00, 0x02, 0x04, 0x12, 0x03, 0x1f, 0x02, 0x21, 0x1a, 0x3b, 0x20, 0x46, 0x6c, 0x61, 0x67,
+    0x67, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x70, 0x61, 0x6d, 0x20,
+    0x66, 0x69, 0x6c, 0x74, 0x65, 0x72, 0x2c, 0x20, 0x6f, 0x72, 0x20, 0x6d, 0x61, 0x72, 0x6b, 0x65,
+    0x64, 0x20, 0x61, 0x73, 0x20, 0x73, 0x70, 0x61, 0x6d, 0x20, 0x62, 0x79, 0x20, 0x61, 0x6e, 0x20,
+    0x61, 0x64, 0x6d, 0x69, 0x6e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x04, 0x01, 0x12,
+    0x03, 0x1f, 0x02, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x04, 0x02, 0x12, 0x03, 0x1f,
+    0x1f, 0x20, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x22, 0x00, 0x34, 0x01, 0x0a, 0x0a,
+    0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x22, 0x08, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00,
+    0x02, 0x00, 0x12, 0x03, 0x23, 0x02, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x05,
+    0x12, 0x03, 0x23, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03,
+    0x23, 0x08, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x23, 0x0d,
+    0x0e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x24, 0x02, 0x12, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x05, 0x12, 0x03, 0x24, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x24, 0x09, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
+    0x02, 0x01, 0x03, 0x12, 0x03, 0x24, 0x10, 0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x02,
+    0x12, 0x03, 0x25, 0x02, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x05, 0x12, 0x03,
+    0x25, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x25, 0x09,
+    0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x03, 0x12, 0x03, 0x25, 0x11, 0x12, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x03, 0x12, 0x03, 0x26, 0x02, 0x15, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x00, 0x02, 0x03, 0x05, 0x12, 0x03, 0x26, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
+    0x02, 0x03, 0x01, 0x12, 0x03, 0x26, 0x09, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03,
+    0x03, 0x12, 0x03, 0x26, 0x13, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x04, 0x12, 0x03,
+    0x27, 0x02, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x05, 0x12, 0x03, 0x27, 0x02,
+    0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x01, 0x12, 0x03, 0x27, 0x09, 0x10, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x03, 0x12, 0x03, 0x27, 0x13, 0x14, 0x0a, 0x0b, 0x0a,
+    0x04, 0x04, 0x00, 0x02, 0x05, 0x12, 0x03, 0x28, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
+    0x02, 0x05, 0x06, 0x12, 0x03, 0x28, 0x02, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x05,
+    0x01, 0x12, 0x03, 0x28, 0x10, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x05, 0x03, 0x12,
+    0x03, 0x28, 0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x06, 0x12, 0x03, 0x29, 0x02,
+    0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x06, 0x05, 0x12, 0x03, 0x29, 0x02, 0x07, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x06, 0x01, 0x12, 0x03, 0x29, 0x08, 0x12, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x06, 0x03, 0x12, 0x03, 0x29, 0x15, 0x16, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x00, 0x02, 0x07, 0x12, 0x03, 0x2a, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x07,
+    0x04, 0x12, 0x03, 0x2a, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x07, 0x05, 0x12,
+    0x03, 0x2a, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x07, 0x01, 0x12, 0x03, 0x2a,
+    0x11, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x07, 0x03, 0x12, 0x03, 0x2a, 0x1e, 0x1f,
+    0x0a, 0x40, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x08, 0x12, 0x03, 0x2c, 0x02, 0x21, 0x1a, 0x33, 0x20,
+    0x46, 0x69, 0x72, 0x65, 0x62, 0x61, 0x73, 0x65, 0x20, 0x60, 0x73, 0x75, 0x62, 0x60, 0x20, 0x6f,
+    0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x64, 0x6d, 0x69, 0x6e, 0x20, 0x77, 0x68, 0x6f, 0x20,
+    0x6d, 0x61, 0x72, 0x6b, 0x65, 0x64, 0x20, 0x69, 0x74, 0x20, 0x68, 0x61, 0x6e, 0x64, 0x6c, 0x65,
+    0x64, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x08, 0x04, 0x12, 0x03, 0x2c, 0x02, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x08, 0x05, 0x12, 0x03, 0x2c, 0x0b, 0x11, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x00, 0x02, 0x08, 0x01, 0x12, 0x03, 0x2c, 0x12, 0x1c, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x00, 0x02, 0x08, 0x03, 0x12, 0x03, 0x2c, 0x1f, 0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00,
+    0x02, 0x09, 0x12, 0x03, 0x2d, 0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x09, 0x04,
+    0x12, 0x03, 0x2d, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x09, 0x05, 0x12, 0x03,
+    0x2d, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x09, 0x01, 0x12, 0x03, 0x2d, 0x11,
+    0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x09, 0x03, 0x12, 0x03, 0x2d, 0x1f, 0x21, 0x0a,
+    0x52, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x0a, 0x12, 0x03, 0x2f, 0x02, 0x19, 0x1a, 0x45, 0x20, 0x53,
+    0x70, 0x61, 0x6d, 0x20, 0x66, 0x69, 0x6c, 0x74, 0x65, 0x72, 0x20, 0x73, 0x63, 0x6f, 0x72, 0x65,
+    0x20, 0x61, 0x74, 0x20, 0x73, 0x75, 0x62, 0x6d, 0x69, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x3b, 0x20,
+    0x71, 0x75, 0x61, 0x72, 0x61, 0x6e, 0x74, 0x69, 0x6e, 0x65, 0x64, 0x20, 0x66, 0x72, 0x6f, 0x6d,
+    0x20, 0x53, 0x50, 0x41, 0x4d, 0x5f, 0x54, 0x48, 0x52, 0x45, 0x53, 0x48, 0x4f, 0x4c, 0x44, 0x20,
+    0x75, 0x70, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x0a, 0x05, 0x12, 0x03, 0x2f, 0x02,
+    0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x0a, 0x01, 0x12, 0x03, 0x2f, 0x09, 0x13, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x0a, 0x03, 0x12, 0x03, 0x2f, 0x16, 0x18, 0x0a, 0x4d, 0x0a,
+    0x04, 0x04, 0x00, 0x02, 0x0b, 0x12, 0x03, 0x31, 0x02, 0x24, 0x1a, 0x40, 0x20, 0x57, 0x68, 0x79,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x70, 0x61, 0x6d, 0x20, 0x66, 0x69, 0x6c, 0x74, 0x65, 0x72,
+    0x20, 0x73, 0x63, 0x6f, 0x72, 0x65, 0x64, 0x20, 0x69, 0x74, 0x2c, 0x20, 0x65, 0x2e, 0x67, 0x2e,
+    0x20, 0x22, 0x68, 0x6f, 0x6e, 0x65, 0x79, 0x70, 0x6f, 0x74, 0x20, 0x66, 0x69, 0x65, 0x6c, 0x64,
+    0x20, 0x66, 0x69, 0x6c, 0x6c, 0x65, 0x64, 0x20, 0x69, 0x6e, 0x22, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x00, 0x02, 0x0b, 0x04, 0x12, 0x03, 0x31, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
+    0x02, 0x0b, 0x05, 0x12, 0x03, 0x31, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x0b,
+    0x01, 0x12, 0x03, 0x31, 0x12, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x0b, 0x03, 0x12,
+    0x03, 0x31, 0x21, 0x23, 0x0a, 0x24, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x0c, 0x12, 0x03, 0x33, 0x02,
+    0x21, 0x1a, 0x17, 0x20, 0x41, 0x64, 0x6d, 0x69, 0x6e, 0x20, 0x76, 0x65, 0x72, 0x64, 0x69, 0x63,
+    0x74, 0x2c, 0x20, 0x69, 0x66, 0x20, 0x61, 0x6e, 0x79, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
+    0x02, 0x0c, 0x04, 0x12, 0x03, 0x33, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x0c,
+    0x05, 0x12, 0x03, 0x33, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x0c, 0x01, 0x12,
+    0x03, 0x33, 0x10, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x0c, 0x03, 0x12, 0x03, 0x33,
+    0x1e, 0x20, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x36, 0x00, 0x3f, 0x01, 0x0a, 0x0a,
+    0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x36, 0x08, 0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01,
+    0x02, 0x00, 0x12, 0x03, 0x37, 0x02, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x05,
+    0x12, 0x03, 0x37, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03,
+    0x37, 0x09, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x37, 0x10,
+    0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12, 0x03, 0x38, 0x02, 0x13, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x05, 0x12, 0x03, 0x38, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x38, 0x09, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x01, 0x03, 0x12, 0x03, 0x38, 0x11, 0x12, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x02,
+    0x12, 0x03, 0x39, 0x02, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x05, 0x12, 0x03,
+    0x39, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x01, 0x12, 0x03, 0x39, 0x09,
+    0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x03, 0x12, 0x03, 0x39, 0x13, 0x14, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x03, 0x12, 0x03, 0x3a, 0x02, 0x15, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x01, 0x02, 0x03, 0x05, 0x12, 0x03, 0x3a, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x03, 0x01, 0x12, 0x03, 0x3a, 0x09, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03,
+    0x03, 0x12, 0x03, 0x3a, 0x13, 0x14, 0x0a, 0x50, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x04, 0x12, 0x03,
+    0x3c, 0x02, 0x15, 0x1a, 0x43, 0x20, 0x48, 0x6f, 0x6e, 0x65, 0x79, 0x70, 0x6f, 0x74, 0x3a, 0x20,
+    0x68, 0x69, 0x64, 0x64, 0x65, 0x6e, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x70, 0x65, 0x6f, 0x70,
+    0x6c, 0x65, 0x20, 0x62, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x66, 0x6f, 0x72, 0x6d, 0x2c, 0x20,
+    0x73, 0x6f, 0x20, 0x6f, 0x6e, 0x6c, 0x79, 0x20, 0x62, 0x6f, 0x74, 0x73, 0x20, 0x66, 0x69, 0x6c,
+    0x6c, 0x20, 0x69, 0x74, 0x20, 0x69, 0x6e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x04,
+    0x05, 0x12, 0x03, 0x3c, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x04, 0x01, 0x12,
+    0x03, 0x3c, 0x09, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x04, 0x03, 0x12, 0x03, 0x3c,
+    0x13, 0x14, 0x0a, 0x20, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x05, 0x12, 0x03, 0x3e, 0x02, 0x18, 0x1a,
+    0x13, 0x20, 0x46, 0x72, 0x6f, 0x6d, 0x20, 0x47, 0x65, 0x74, 0x46, 0x6f, 0x72, 0x6d, 0x54, 0x6f,
+    0x6b, 0x65, 0x6e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x05, 0x05, 0x12, 0x03, 0x3e,
+    0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x05, 0x01, 0x12, 0x03, 0x3e, 0x09, 0x13,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x05, 0x03, 0x12, 0x03, 0x3e, 0x16, 0x17, 0x0a, 0x4d,
+    0x0a, 0x02, 0x04, 0x02, 0x12, 0x03, 0x42, 0x00, 0x20, 0x1a, 0x42, 0x20, 0x44, 0x65, 0x6c, 0x69,
+    0x62, 0x65, 0x72, 0x61, 0x74, 0x65, 0x6c, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x61, 0x6d,
+    0x65, 0x20, 0x77, 0x68, 0x65, 0x74, 0x68, 0x65, 0x72, 0x20, 0x6f, 0x72, 0x20, 0x6e, 0x6f, 0x74,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x69, 0x6e, 0x71, 0x75, 0x69, 0x72, 0x79, 0x20, 0x77, 0x61, 0x73,
+    0x20, 0x71, 0x75, 0x61, 0x72, 0x61, 0x6e, 0x74, 0x69, 0x6e, 0x65, 0x64, 0x0a, 0x0a, 0x0a, 0x0a,
+    0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x42, 0x08, 0x1d, 0x0a, 0x09, 0x0a, 0x02, 0x04, 0x03, 0x12,
+    0x03, 0x44, 0x00, 0x1e, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01, 0x12, 0x03, 0x44, 0x08, 0x1b,
+    0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x04, 0x12, 0x04, 0x46, 0x00, 0x48, 0x01, 0x0a, 0x0a, 0x0a, 0x03,
+    0x04, 0x04, 0x01, 0x12, 0x03, 0x46, 0x08, 0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x00,
+    0x12, 0x03, 0x47, 0x02, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x05, 0x12, 0x03,
+    0x47, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x01, 0x12, 0x03, 0x47, 0x09,
+    0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x03, 0x12, 0x03, 0x47, 0x16, 0x17, 0x0a,
+    0x0a, 0x0a, 0x02, 0x04, 0x05, 0x12, 0x04, 0x4a, 0x00, 0x50, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04,
+    0x05, 0x01, 0x12, 0x03, 0x4a, 0x08, 0x1c, 0x0a, 0x66, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x00, 0x12,
+    0x03, 0x4d, 0x02, 0x24, 0x1a, 0x59, 0x20, 0x55, 0x6e, 0x73, 0x65, 0x74, 0x20, 0x6c, 0x69, 0x73,
+    0x74, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x69, 0x6e, 0x62, 0x6f, 0x78, 0x3a, 0x20, 0x6e, 0x65,
+    0x77, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x68, 0x61, 0x6e, 0x64, 0x6c, 0x65, 0x64, 0x20, 0x69, 0x6e,
+    0x71, 0x75, 0x69, 0x72, 0x69, 0x65, 0x73, 0x2c, 0x20, 0x62, 0x75, 0x74, 0x20, 0x6e, 0x6f, 0x74,
+    0x20, 0x61, 0x72, 0x63, 0x68, 0x69, 0x76, 0x65, 0x64, 0x20, 0x6f, 0x72, 0x0a, 0x20, 0x71, 0x75,
+    0x61, 0x72, 0x61, 0x6e, 0x74, 0x69, 0x6e, 0x65, 0x64, 0x20, 0x6f, 0x6e, 0x65, 0x73, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x04, 0x12, 0x03, 0x4d, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x05, 0x02, 0x00, 0x06, 0x12, 0x03, 0x4d, 0x0b, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x05, 0x02, 0x00, 0x01, 0x12, 0x03, 0x4d, 0x19, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02,
+    0x00, 0x03, 0x12, 0x03, 0x4d, 0x22, 0x23, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x01, 0x12,
+    0x03, 0x4e, 0x02, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x05, 0x12, 0x03, 0x4e,
+    0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x01, 0x12, 0x03, 0x4e, 0x08, 0x0d,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x03, 0x12, 0x03, 0x4e, 0x10, 0x11, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x05, 0x02, 0x02, 0x12, 0x03, 0x4f, 0x02, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x05, 0x02, 0x02, 0x05, 0x12, 0x03, 0x4f, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02,
+    0x02, 0x01, 0x12, 0x03, 0x4f, 0x08, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x03,
+    0x12, 0x03, 0x4f, 0x11, 0x12, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x06, 0x12, 0x04, 0x52, 0x00, 0x55,
+    0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x06, 0x01, 0x12, 0x03, 0x52, 0x08, 0x1d, 0x0a, 0x0b, 0x0a,
+    0x04, 0x04, 0x06, 0x02, 0x00, 0x12, 0x03, 0x53, 0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06,
+    0x02, 0x00, 0x04, 0x12, 0x03, 0x53, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00,
+    0x06, 0x12, 0x03, 0x53, 0x0b, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x01, 0x12,
+    0x03, 0x53, 0x13, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x03, 0x12, 0x03, 0x53,
+    0x1f, 0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x01, 0x12, 0x03, 0x54, 0x02, 0x12, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x05, 0x12, 0x03, 0x54, 0x02, 0x07, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x06, 0x02, 0x01, 0x01, 0x12, 0x03, 0x54, 0x08, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x06, 0x02, 0x01, 0x03, 0x12, 0x03, 0x54, 0x10, 0x11, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x07, 0x12,
+    0x04, 0x57, 0x00, 0x59, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x07, 0x01, 0x12, 0x03, 0x57, 0x08,
+    0x19, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x00, 0x12, 0x03, 0x58, 0x02, 0x0f, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x05, 0x12, 0x03, 0x58, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x07, 0x02, 0x00, 0x01, 0x12, 0x03, 0x58, 0x08, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07,
+    0x02, 0x00, 0x03, 0x12, 0x03, 0x58, 0x0d, 0x0e, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x08, 0x12, 0x04,
+    0x5b, 0x00, 0x5d, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x08, 0x01, 0x12, 0x03, 0x5b, 0x08, 0x1a,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x00, 0x12, 0x03, 0x5c, 0x02, 0x1f, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x08, 0x02, 0x00, 0x04, 0x12, 0x03, 0x5c, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x08, 0x02, 0x00, 0x06, 0x12, 0x03, 0x5c, 0x0b, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02,
+    0x00, 0x01, 0x12, 0x03, 0x5c, 0x13, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x03,
+    0x12, 0x03, 0x5c, 0x1d, 0x1e, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x09, 0x12, 0x04, 0x5f, 0x00, 0x61,
+    0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x09, 0x01, 0x12, 0x03, 0x5f, 0x08, 0x21, 0x0a, 0x0b, 0x0a,
+    0x04, 0x04, 0x09, 0x02, 0x00, 0x12, 0x03, 0x60, 0x02, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09,
+    0x02, 0x00, 0x05, 0x12, 0x03, 0x60, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00,
+    0x01, 0x12, 0x03, 0x60, 0x08, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x03, 0x12,
+    0x03, 0x60, 0x0d, 0x0e, 0x0a, 0x09, 0x0a, 0x02, 0x04, 0x0a, 0x12, 0x03, 0x63, 0x00, 0x25, 0x0a,
+    0x0a, 0x0a, 0x03, 0x04, 0x0a, 0x01, 0x12, 0x03, 0x63, 0x08, 0x22, 0x0a, 0x0a, 0x0a, 0x02, 0x04,
+    0x0b, 0x12, 0x04, 0x65, 0x00, 0x67, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0b, 0x01, 0x12, 0x03,
+    0x65, 0x08, 0x1d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x00, 0x12, 0x03, 0x66, 0x02, 0x0f,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x05, 0x12, 0x03, 0x66, 0x02, 0x07, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x01, 0x12, 0x03, 0x66, 0x08, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x0b, 0x02, 0x00, 0x03, 0x12, 0x03, 0x66, 0x0d, 0x0e, 0x0a, 0x09, 0x0a, 0x02, 0x04, 0x0c,
+    0x12, 0x03, 0x69, 0x00, 0x21, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0c, 0x01, 0x12, 0x03, 0x69, 0x08,
+    0x1e, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x0d, 0x12, 0x04, 0x6b, 0x00, 0x6e, 0x01, 0x0a, 0x0a, 0x0a,
+    0x03, 0x04, 0x0d, 0x01, 0x12, 0x03, 0x6b, 0x08, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0d, 0x02,
+    0x00, 0x12, 0x03, 0x6c, 0x02, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x05, 0x12,
+    0x03, 0x6c, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x01, 0x12, 0x03, 0x6c,
+    0x08, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x03, 0x12, 0x03, 0x6c, 0x0d, 0x0e,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x01, 0x12, 0x03, 0x6d, 0x02, 0x10, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x0d, 0x02, 0x01, 0x05, 0x12, 0x03, 0x6d, 0x02, 0x06, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x0d, 0x02, 0x01, 0x01, 0x12, 0x03, 0x6d, 0x07, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02,
+    0x01, 0x03, 0x12, 0x03, 0x6d, 0x0e, 0x0f, 0x0a, 0x09, 0x0a, 0x02, 0x04, 0x0e, 0x12, 0x03, 0x70,
+    0x00, 0x22, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0e, 0x01, 0x12, 0x03, 0x70, 0x08, 0x1f, 0x0a, 0x0a,
+    0x0a, 0x02, 0x04, 0x0f, 0x12, 0x04, 0x72, 0x00, 0x74, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0f,
+    0x01, 0x12, 0x03, 0x72, 0x08, 0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x00, 0x12, 0x03,
+    0x73, 0x02, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x05, 0x12, 0x03, 0x73, 0x02,
+    0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x01, 0x12, 0x03, 0x73, 0x08, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x03, 0x12, 0x03, 0x73, 0x0d, 0x0e, 0x0a, 0x09, 0x0a,
+    0x02, 0x04, 0x10, 0x12, 0x03, 0x76, 0x00, 0x20, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x10, 0x01, 0x12,
+    0x03, 0x76, 0x08, 0x1d, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33,
+];
+include!("inquiry.tonic.rs");
+// @@protoc_insertion_point(module)
\ No newline at end of file
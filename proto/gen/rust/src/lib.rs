@@ -5,3 +5,11 @@ pub mod auth {
 pub mod blog {
     include!("../blog/blog.rs");
 }
+
+pub mod inquiry {
+    include!("../inquiry/inquiry.rs");
+}
+
+pub mod webhook {
+    include!("../webhook/webhook.rs");
+}
@@ -10,7 +10,7 @@ import type { Message } from "@bufbuild/protobuf";
  * Describes the file blog.proto.
  */
 export const file_blog: GenFile = /*@__PURE__*/
-  fileDesc("CgpibG9nLnByb3RvEgRibG9nIt0BChNHZXRCbG9nUG9zdHNSZXF1ZXN0EhIKBXF1ZXJ5GAEgASgJSACIAQESDQoFbGltaXQYAiABKAUSDgoGb2Zmc2V0GAMgASgFEiwKBHNvcnQYBCABKA4yHi5ibG9nLkdldEJsb2dQb3N0c1JlcXVlc3QuU29ydCJbCgRTb3J0Eg0KCVJFTEVWQU5DRRAAEg8KC0NSRUFURURfQVNDEAESEAoMQ1JFQVRFRF9ERVNDEAISDwoLVVBEQVRFRF9BU0MQAxIQCgxVUERBVEVEX0RFU0MQBEIICgZfcXVlcnkiSwoUR2V0QmxvZ1Bvc3RzUmVzcG9uc2USJAoFcG9zdHMYASADKAsyFS5ibG9nLkJsb2dQb3N0U3VtbWFyeRINCgV0b3RhbBgCIAEoBSJpCg9CbG9nUG9zdFN1bW1hcnkSCgoCaWQYASABKAMSDQoFdGl0bGUYAiABKAkSEwoLZGVzY3JpcHRpb24YAyABKAkSEgoKY3JlYXRlZF9hdBgEIAEoAxISCgp1cGRhdGVkX2F0GAUgASgDIiAKEkdldEJsb2dQb3N0UmVxdWVzdBIKCgJpZBgBIAEoAyIzChNHZXRCbG9nUG9zdFJlc3BvbnNlEhwKBHBvc3QYASABKAsyDi5ibG9nLkJsb2dQb3N0InAKCEJsb2dQb3N0EgoKAmlkGAEgASgDEg0KBXRpdGxlGAIgASgJEhMKC2Rlc2NyaXB0aW9uGAMgASgJEgwKBGJvZHkYBCABKAkSEgoKY3JlYXRlZF9hdBgFIAEoAxISCgp1cGRhdGVkX2F0GAYgASgDIkkKFUNyZWF0ZUJsb2dQb3N0UmVxdWVzdBINCgV0aXRsZRgBIAEoCRITCgtkZXNjcmlwdGlvbhgCIAEoCRIMCgRib2R5GAMgASgJIiQKFkNyZWF0ZUJsb2dQb3N0UmVzcG9uc2USCgoCaWQYASABKAMihwEKFVVwZGF0ZUJsb2dQb3N0UmVxdWVzdBIKCgJpZBgBIAEoAxISCgV0aXRsZRgCIAEoCUgAiAEBEhgKC2Rlc2NyaXB0aW9uGAMgASgJSAGIAQESEQoEYm9keRgEIAEoCUgCiAEBQggKBl90aXRsZUIOCgxfZGVzY3JpcHRpb25CBwoFX2JvZHkiGAoWVXBkYXRlQmxvZ1Bvc3RSZXNwb25zZSIjChVEZWxldGVCbG9nUG9zdFJlcXVlc3QSCgoCaWQYASABKAMiGAoWRGVsZXRlQmxvZ1Bvc3RSZXNwb25zZSIkChZSZXN0b3JlQmxvZ1Bvc3RSZXF1ZXN0EgoKAmlkGAEgASgDIhkKF1Jlc3RvcmVCbG9nUG9zdFJlc3BvbnNlMpgBCgtCbG9nU2VydmljZRJFCgxHZXRCbG9nUG9zdHMSGS5ibG9nLkdldEJsb2dQb3N0c1JlcXVlc3QaGi5ibG9nLkdldEJsb2dQb3N0c1Jlc3BvbnNlEkIKC0dldEJsb2dQb3N0EhguYmxvZy5HZXRCbG9nUG9zdFJlcXVlc3QaGS5ibG9nLkdldEJsb2dQb3N0UmVzcG9uc2UyyQIKEEJsb2dBZG1pblNlcnZpY2USSwoOQ3JlYXRlQmxvZ1Bvc3QSGy5ibG9nLkNyZWF0ZUJsb2dQb3N0UmVxdWVzdBocLmJsb2cuQ3JlYXRlQmxvZ1Bvc3RSZXNwb25zZRJLCg5VcGRhdGVCbG9nUG9zdBIbLmJsb2cuVXBkYXRlQmxvZ1Bvc3RSZXF1ZXN0GhwuYmxvZy5VcGRhdGVCbG9nUG9zdFJlc3BvbnNlEksKDkRlbGV0ZUJsb2dQb3N0EhsuYmxvZy5EZWxldGVCbG9nUG9zdFJlcXVlc3QaHC5ibG9nLkRlbGV0ZUJsb2dQb3N0UmVzcG9uc2USTgoPUmVzdG9yZUJsb2dQb3N0EhwuYmxvZy5SZXN0b3JlQmxvZ1Bvc3RSZXF1ZXN0Gh0uYmxvZy5SZXN0b3JlQmxvZ1Bvc3RSZXNwb25zZWIGcHJvdG8z");
+  fileDesc("CgpibG9nLnByb3RvEgRibG9nIqsDChNHZXRCbG9nUG9zdHNSZXF1ZXN0EhIKBXF1ZXJ5GAEgASgJSACIAQESDQoFbGltaXQYAiABKAUSDgoGb2Zmc2V0GAMgASgFEiwKBHNvcnQYBCABKA4yHi5ibG9nLkdldEJsb2dQb3N0c1JlcXVlc3QuU29ydBIpCgZzdGF0dXMYBSABKA4yFC5ibG9nLkJsb2dQb3N0U3RhdHVzSAGIAQESDAoEdGFncxgGIAMoCRIbCg5oaWdobGlnaHRfb3BlbhgHIAEoCUgCiAEBEhwKD2hpZ2hsaWdodF9jbG9zZRgIIAEoCUgDiAEBEhcKCnBhZ2VfdG9rZW4YCSABKAlIBIgBASJbCgRTb3J0Eg0KCVJFTEVWQU5DRRAAEg8KC0NSRUFURURfQVNDEAESEAoMQ1JFQVRFRF9ERVNDEAISDwoLVVBEQVRFRF9BU0MQAxIQCgxVUERBVEVEX0RFU0MQBEIICgZfcXVlcnlCCQoHX3N0YXR1c0IRCg9faGlnaGxpZ2h0X29wZW5CEgoQX2hpZ2hsaWdodF9jbG9zZUINCgtfcGFnZV90b2tlbiJ9ChRHZXRCbG9nUG9zdHNSZXNwb25zZRIkCgVwb3N0cxgBIAMoCzIVLmJsb2cuQmxvZ1Bvc3RTdW1tYXJ5Eg0KBXRvdGFsGAIgASgFEhwKD25leHRfcGFnZV90b2tlbhgDIAEoCUgAiAEBQhIKEF9uZXh0X3BhZ2VfdG9rZW4i8QIKD0Jsb2dQb3N0U3VtbWFyeRIKCgJpZBgBIAEoAxINCgV0aXRsZRgCIAEoCRITCgtkZXNjcmlwdGlvbhgDIAEoCRISCgpjcmVhdGVkX2F0GAQgASgDEhIKCnVwZGF0ZWRfYXQYBSABKAMSJAoGc3RhdHVzGAYgASgOMhQuYmxvZy5CbG9nUG9zdFN0YXR1cxIZCgxwdWJsaXNoZWRfYXQYByABKANIAIgBARIMCgR0YWdzGAggAygJEgwKBHNsdWcYCSABKAkSFAoHc25pcHBldBgKIAEoCUgBiAEBEh4KEWhpZ2hsaWdodGVkX3RpdGxlGAsgASgJSAKIAQESJAoXaGlnaGxpZ2h0ZWRfZGVzY3JpcHRpb24YDCABKAlIA4gBAUIPCg1fcHVibGlzaGVkX2F0QgoKCF9zbmlwcGV0QhQKEl9oaWdobGlnaHRlZF90aXRsZUIaChhfaGlnaGxpZ2h0ZWRfZGVzY3JpcHRpb24iOAoYU3VnZ2VzdEJsb2dTZWFyY2hSZXF1ZXN0Eg0KBXF1ZXJ5GAEgASgJEg0KBWxpbWl0GAIgASgFIlwKGVN1Z2dlc3RCbG9nU2VhcmNoUmVzcG9uc2USEwoLY29tcGxldGlvbnMYASADKAkSGQoMZGlkX3lvdV9tZWFuGAIgASgJSACIAQFCDwoNX2RpZF95b3VfbWVhbiIgChJHZXRCbG9nUG9zdFJlcXVlc3QSCgoCaWQYASABKAMiMwoTR2V0QmxvZ1Bvc3RSZXNwb25zZRIcCgRwb3N0GAEgASgLMg4uYmxvZy5CbG9nUG9zdCIoChhHZXRCbG9nUG9zdEJ5U2x1Z1JlcXVlc3QSDAoEc2x1ZxgBIAEoCSJjChlHZXRCbG9nUG9zdEJ5U2x1Z1Jlc3BvbnNlEhwKBHBvc3QYASABKAsyDi5ibG9nLkJsb2dQb3N0EhgKC3JlZGlyZWN0X3RvGAIgASgJSACIAQFCDgoMX3JlZGlyZWN0X3RvIscDCghCbG9nUG9zdBIKCgJpZBgBIAEoAxINCgV0aXRsZRgCIAEoCRITCgtkZXNjcmlwdGlvbhgDIAEoCRIMCgRib2R5GAQgASgJEhIKCmNyZWF0ZWRfYXQYBSABKAMSEgoKdXBkYXRlZF9hdBgGIAEoAxIkCgZzdGF0dXMYByABKA4yFC5ibG9nLkJsb2dQb3N0U3RhdHVzEhkKDHB1Ymxpc2hlZF9hdBgIIAEoA0gAiAEBEgwKBHRhZ3MYCSADKAkSDAoEc2x1ZxgKIAEoCRIRCglib2R5X2h0bWwYCyABKAkSGwoDdG9jGAwgAygLMg4uYmxvZy5Ub2NFbnRyeRIXCg9yZWFkaW5nX21pbnV0ZXMYDSABKAUSFwoKZGVsZXRlZF9hdBgOIAEoA0gBiAEBEhcKCmNyZWF0ZWRfYnkYDyABKAlIAogBARIXCgp1cGRhdGVkX2J5GBAgASgJSAOIAQESFwoKZGVsZXRlZF9ieRgRIAEoCUgEiAEBQg8KDV9wdWJsaXNoZWRfYXRCDQoLX2RlbGV0ZWRfYXRCDQoLX2NyZWF0ZWRfYnlCDQoLX3VwZGF0ZWRfYnlCDQoLX2RlbGV0ZWRfYnkiOAoIVG9jRW50cnkSDQoFbGV2ZWwYASABKAUSDgoGYW5jaG9yGAIgASgJEg0KBXRpdGxlGAMgASgJIlcKFUNyZWF0ZUJsb2dQb3N0UmVxdWVzdBINCgV0aXRsZRgBIAEoCRITCgtkZXNjcmlwdGlvbhgCIAEoCRIMCgRib2R5GAMgASgJEgwKBHRhZ3MYBCADKAkiJAoWQ3JlYXRlQmxvZ1Bvc3RSZXNwb25zZRIKCgJpZBgBIAEoAyLAAQoVVXBkYXRlQmxvZ1Bvc3RSZXF1ZXN0EgoKAmlkGAEgASgDEhIKBXRpdGxlGAIgASgJSACIAQESGAoLZGVzY3JpcHRpb24YAyABKAlIAYgBARIRCgRib2R5GAQgASgJSAKIAQESGwoEdGFncxgFIAEoCzINLmJsb2cuVGFnTGlzdBIRCgRzbHVnGAYgASgJSAOIAQFCCAoGX3RpdGxlQg4KDF9kZXNjcmlwdGlvbkIHCgVfYm9keUIHCgVfc2x1ZyIXCgdUYWdMaXN0EgwKBHRhZ3MYASADKAkiGAoWVXBkYXRlQmxvZ1Bvc3RSZXNwb25zZSIjChVEZWxldGVCbG9nUG9zdFJlcXVlc3QSCgoCaWQYASABKAMiGAoWRGVsZXRlQmxvZ1Bvc3RSZXNwb25zZSIkChZSZXN0b3JlQmxvZ1Bvc3RSZXF1ZXN0EgoKAmlkGAEgASgDIhkKF1Jlc3RvcmVCbG9nUG9zdFJlc3BvbnNlIiQKFlB1Ymxpc2hCbG9nUG9zdFJlcXVlc3QSCgoCaWQYASABKAMiGQoXUHVibGlzaEJsb2dQb3N0UmVzcG9uc2UiNwoYVW5wdWJsaXNoQmxvZ1Bvc3RSZXF1ZXN0EgoKAmlkGAEgASgDEg8KB2FyY2hpdmUYAiABKAgiGwoZVW5wdWJsaXNoQmxvZ1Bvc3RSZXNwb25zZSI5ChdTY2hlZHVsZUJsb2dQb3N0UmVxdWVzdBIKCgJpZBgBIAEoAxISCgpwdWJsaXNoX2F0GAIgASgDIhoKGFNjaGVkdWxlQmxvZ1Bvc3RSZXNwb25zZSKHAQoXQmxvZ1Bvc3RSZXZpc2lvblN1bW1hcnkSDwoHcG9zdF9pZBgBIAEoAxIQCghyZXZpc2lvbhgCIAEoBRINCgV0aXRsZRgDIAEoCRIXCgphdXRob3Jfc3ViGAQgASgJSACIAQESEgoKY3JlYXRlZF9hdBgFIAEoA0INCgtfYXV0aG9yX3N1YiKjAQoQQmxvZ1Bvc3RSZXZpc2lvbhIPCgdwb3N0X2lkGAEgASgDEhAKCHJldmlzaW9uGAIgASgFEg0KBXRpdGxlGAMgASgJEhMKC2Rlc2NyaXB0aW9uGAQgASgJEgwKBGJvZHkYBSABKAkSFwoKYXV0aG9yX3N1YhgGIAEoCUgAiAEBEhIKCmNyZWF0ZWRfYXQYByABKANCDQoLX2F1dGhvcl9zdWIiTgocTGlzdEJsb2dQb3N0UmV2aXNpb25zUmVxdWVzdBIPCgdwb3N0X2lkGAEgASgDEg0KBWxpbWl0GAIgASgFEg4KBm9mZnNldBgDIAEoBSJgCh1MaXN0QmxvZ1Bvc3RSZXZpc2lvbnNSZXNwb25zZRIwCglyZXZpc2lvbnMYASADKAsyHS5ibG9nLkJsb2dQb3N0UmV2aXNpb25TdW1tYXJ5Eg0KBXRvdGFsGAIgASgFIj8KGkdldEJsb2dQb3N0UmV2aXNpb25SZXF1ZXN0Eg8KB3Bvc3RfaWQYASABKAMSEAoIcmV2aXNpb24YAiABKAUiRwobR2V0QmxvZ1Bvc3RSZXZpc2lvblJlc3BvbnNlEigKCHJldmlzaW9uGAEgASgLMhYuYmxvZy5CbG9nUG9zdFJldmlzaW9uIlsKHERpZmZCbG9nUG9zdFJldmlzaW9uc1JlcXVlc3QSDwoHcG9zdF9pZBgBIAEoAxIVCg1mcm9tX3JldmlzaW9uGAIgASgFEhMKC3RvX3JldmlzaW9uGAMgASgFIqgBCghEaWZmTGluZRIdCgJvcBgBIAEoDjIRLmJsb2cuRGlmZkxpbmUuT3ASDAoEdGV4dBgCIAEoCRIVCghvbGRfbGluZRgDIAEoBUgAiAEBEhUKCG5ld19saW5lGAQgASgFSAGIAQEiJwoCT3ASCQoFRVFVQUwQABIKCgZJTlNFUlQQARIKCgZERUxFVEUQAkILCglfb2xkX2xpbmVCCwoJX25ld19saW5lIj4KHURpZmZCbG9nUG9zdFJldmlzaW9uc1Jlc3BvbnNlEh0KBWxpbmVzGAEgAygLMg4uYmxvZy5EaWZmTGluZSI6ChVSZXZlcnRCbG9nUG9zdFJlcXVlc3QSDwoHcG9zdF9pZBgBIAEoAxIQCghyZXZpc2lvbhgCIAEoBSIqChZSZXZlcnRCbG9nUG9zdFJlc3BvbnNlEhAKCHJldmlzaW9uGAEgASgFIicKA1RhZxIMCgRuYW1lGAEgASgJEhIKCnBvc3RfY291bnQYAiABKAUiEQoPTGlzdFRhZ3NSZXF1ZXN0IisKEExpc3RUYWdzUmVzcG9uc2USFwoEdGFncxgBIAMoCzIJLmJsb2cuVGFnIjIKEFJlbmFtZVRhZ1JlcXVlc3QSDAoEbmFtZRgBIAEoCRIQCghuZXdfbmFtZRgCIAEoCSITChFSZW5hbWVUYWdSZXNwb25zZSIzChBNZXJnZVRhZ3NSZXF1ZXN0Eg8KB3NvdXJjZXMYASADKAkSDgoGdGFyZ2V0GAIgASgJIiMKEU1lcmdlVGFnc1Jlc3BvbnNlEg4KBm1lcmdlZBgBIAEoBSKmAgoKQXVkaXRFdmVudBIKCgJpZBgBIAEoAxISCgpjcmVhdGVkX2F0GAIgASgDEhYKCWFjdG9yX3N1YhgDIAEoCUgAiAEBEhgKC2FjdG9yX2VtYWlsGAQgASgJSAGIAQESDgoGYWN0aW9uGAUgASgJEhYKCXRhcmdldF9pZBgGIAEoA0gCiAEBEhMKC3N0YXR1c19jb2RlGAcgASgFEhcKCnJlcXVlc3RfaWQYCCABKAlIA4gBARIWCgljbGllbnRfaXAYCSABKAlIBIgBARIPCgdjaGFuZ2VzGAogASgJQgwKCl9hY3Rvcl9zdWJCDgoMX2FjdG9yX2VtYWlsQgwKCl90YXJnZXRfaWRCDQoLX3JlcXVlc3RfaWRCDAoKX2NsaWVudF9pcCKxAQoWTGlzdEF1ZGl0RXZlbnRzUmVxdWVzdBISCgVhY3RvchgBIAEoCUgAiAEBEhMKBmFjdGlvbhgCIAEoCUgBiAEBEhIKBXNpbmNlGAMgASgDSAKIAQESEgoFdW50aWwYBCABKANIA4gBARINCgVsaW1pdBgFIAEoBRIOCgZvZmZzZXQYBiABKAVCCAoGX2FjdG9yQgkKB19hY3Rpb25CCAoGX3NpbmNlQggKBl91bnRpbCJKChdMaXN0QXVkaXRFdmVudHNSZXNwb25zZRIgCgZldmVudHMYASADKAsyEC5ibG9nLkF1ZGl0RXZlbnQSDQoFdG90YWwYAiABKAUqrQEKDkJsb2dQb3N0U3RhdHVzEiAKHEJMT0dfUE9TVF9TVEFUVVNfVU5TUEVDSUZJRUQQABIaChZCTE9HX1BPU1RfU1RBVFVTX0RSQUZUEAESHgoaQkxPR19QT1NUX1NUQVRVU19TQ0hFRFVMRUQQAhIeChpCTE9HX1BPU1RfU1RBVFVTX1BVQkxJU0hFRBADEh0KGUJMT0dfUE9TVF9TVEFUVVNfQVJDSElWRUQQBDL/AgoLQmxvZ1NlcnZpY2USRQoMR2V0QmxvZ1Bvc3RzEhkuYmxvZy5HZXRCbG9nUG9zdHNSZXF1ZXN0GhouYmxvZy5HZXRCbG9nUG9zdHNSZXNwb25zZRJCCgtHZXRCbG9nUG9zdBIYLmJsb2cuR2V0QmxvZ1Bvc3RSZXF1ZXN0GhkuYmxvZy5HZXRCbG9nUG9zdFJlc3BvbnNlEjkKCExpc3RUYWdzEhUuYmxvZy5MaXN0VGFnc1JlcXVlc3QaFi5ibG9nLkxpc3RUYWdzUmVzcG9uc2USVAoRR2V0QmxvZ1Bvc3RCeVNsdWcSHi5ibG9nLkdldEJsb2dQb3N0QnlTbHVnUmVxdWVzdBofLmJsb2cuR2V0QmxvZ1Bvc3RCeVNsdWdSZXNwb25zZRJUChFTdWdnZXN0QmxvZ1NlYXJjaBIeLmJsb2cuU3VnZ2VzdEJsb2dTZWFyY2hSZXF1ZXN0Gh8uYmxvZy5TdWdnZXN0QmxvZ1NlYXJjaFJlc3BvbnNlMoYKChBCbG9nQWRtaW5TZXJ2aWNlEkUKDEdldEJsb2dQb3N0cxIZLmJsb2cuR2V0QmxvZ1Bvc3RzUmVxdWVzdBoaLmJsb2cuR2V0QmxvZ1Bvc3RzUmVzcG9uc2USQgoLR2V0QmxvZ1Bvc3QSGC5ibG9nLkdldEJsb2dQb3N0UmVxdWVzdBoZLmJsb2cuR2V0QmxvZ1Bvc3RSZXNwb25zZRJLCg5DcmVhdGVCbG9nUG9zdBIbLmJsb2cuQ3JlYXRlQmxvZ1Bvc3RSZXF1ZXN0GhwuYmxvZy5DcmVhdGVCbG9nUG9zdFJlc3BvbnNlEksKDlVwZGF0ZUJsb2dQb3N0EhsuYmxvZy5VcGRhdGVCbG9nUG9zdFJlcXVlc3QaHC5ibG9nLlVwZGF0ZUJsb2dQb3N0UmVzcG9uc2USSwoORGVsZXRlQmxvZ1Bvc3QSGy5ibG9nLkRlbGV0ZUJsb2dQb3N0UmVxdWVzdBocLmJsb2cuRGVsZXRlQmxvZ1Bvc3RSZXNwb25zZRJOCg9SZXN0b3JlQmxvZ1Bvc3QSHC5ibG9nLlJlc3RvcmVCbG9nUG9zdFJlcXVlc3QaHS5ibG9nLlJlc3RvcmVCbG9nUG9zdFJlc3BvbnNlEk4KD1B1Ymxpc2hCbG9nUG9zdBIcLmJsb2cuUHVibGlzaEJsb2dQb3N0UmVxdWVzdBodLmJsb2cuUHVibGlzaEJsb2dQb3N0UmVzcG9uc2USVAoRVW5wdWJsaXNoQmxvZ1Bvc3QSHi5ibG9nLlVucHVibGlzaEJsb2dQb3N0UmVxdWVzdBofLmJsb2cuVW5wdWJsaXNoQmxvZ1Bvc3RSZXNwb25zZRJRChBTY2hlZHVsZUJsb2dQb3N0Eh0uYmxvZy5TY2hlZHVsZUJsb2dQb3N0UmVxdWVzdBoeLmJsb2cuU2NoZWR1bGVCbG9nUG9zdFJlc3BvbnNlEmAKFUxpc3RCbG9nUG9zdFJldmlzaW9ucxIiLmJsb2cuTGlzdEJsb2dQb3N0UmV2aXNpb25zUmVxdWVzdBojLmJsb2cuTGlzdEJsb2dQb3N0UmV2aXNpb25zUmVzcG9uc2USWgoTR2V0QmxvZ1Bvc3RSZXZpc2lvbhIgLmJsb2cuR2V0QmxvZ1Bvc3RSZXZpc2lvblJlcXVlc3QaIS5ibG9nLkdldEJsb2dQb3N0UmV2aXNpb25SZXNwb25zZRJgChVEaWZmQmxvZ1Bvc3RSZXZpc2lvbnMSIi5ibG9nLkRpZmZCbG9nUG9zdFJldmlzaW9uc1JlcXVlc3QaIy5ibG9nLkRpZmZCbG9nUG9zdFJldmlzaW9uc1Jlc3BvbnNlEksKDlJldmVydEJsb2dQb3N0EhsuYmxvZy5SZXZlcnRCbG9nUG9zdFJlcXVlc3QaHC5ibG9nLlJldmVydEJsb2dQb3N0UmVzcG9uc2USPAoJUmVuYW1lVGFnEhYuYmxvZy5SZW5hbWVUYWdSZXF1ZXN0GhcuYmxvZy5SZW5hbWVUYWdSZXNwb25zZRI8CglNZXJnZVRhZ3MSFi5ibG9nLk1lcmdlVGFnc1JlcXVlc3QaFy5ibG9nLk1lcmdlVGFnc1Jlc3BvbnNlEk4KD0xpc3RBdWRpdEV2ZW50cxIcLmJsb2cuTGlzdEF1ZGl0RXZlbnRzUmVxdWVzdBodLmJsb2cuTGlzdEF1ZGl0RXZlbnRzUmVzcG9uc2ViBnByb3RvMw");
 
 /**
  * @generated from message blog.GetBlogPostsRequest
@@ -22,6 +22,8 @@ export type GetBlogPostsRequest = Message<"blog.GetBlogPostsRequest"> & {
   query?: string;
 
   /**
+   * Posts per page (default 10, at most 100)
+   *
    * @generated from field: int32 limit = 2;
    */
   limit: number;
@@ -35,6 +37,40 @@ export type GetBlogPostsRequest = Message<"blog.GetBlogPostsRequest"> & {
    * @generated from field: blog.GetBlogPostsRequest.Sort sort = 4;
    */
   sort: GetBlogPostsRequest_Sort;
+
+  /**
+   * Only posts in this status; admin listings only, ignored by BlogService
+   *
+   * @generated from field: optional blog.BlogPostStatus status = 5;
+   */
+  status?: BlogPostStatus;
+
+  /**
+   * Only posts carrying every one of these tags
+   *
+   * @generated from field: repeated string tags = 6;
+   */
+  tags: string[];
+
+  /**
+   * Wrapped around matched terms in search excerpts; default to <mark> and </mark>
+   *
+   * @generated from field: optional string highlight_open = 7;
+   */
+  highlightOpen?: string;
+
+  /**
+   * @generated from field: optional string highlight_close = 8;
+   */
+  highlightClose?: string;
+
+  /**
+   * next_page_token from a previous response with the same query, tags, status
+   * and sort. Takes precedence over offset.
+   *
+   * @generated from field: optional string page_token = 9;
+   */
+  pageToken?: string;
 };
 
 /**
@@ -93,6 +129,13 @@ export type GetBlogPostsResponse = Message<"blog.GetBlogPostsResponse"> & {
    * @generated from field: int32 total = 2;
    */
   total: number;
+
+  /**
+   * Set when more posts follow; pass it back as page_token to get them
+   *
+   * @generated from field: optional string next_page_token = 3;
+   */
+  nextPageToken?: string;
 };
 
 /**
@@ -130,6 +173,44 @@ export type BlogPostSummary = Message<"blog.BlogPostSummary"> & {
    * @generated from field: int64 updated_at = 5;
    */
   updatedAt: bigint;
+
+  /**
+   * @generated from field: blog.BlogPostStatus status = 6;
+   */
+  status: BlogPostStatus;
+
+  /**
+   * @generated from field: optional int64 published_at = 7;
+   */
+  publishedAt?: bigint;
+
+  /**
+   * @generated from field: repeated string tags = 8;
+   */
+  tags: string[];
+
+  /**
+   * @generated from field: string slug = 9;
+   */
+  slug: string;
+
+  /**
+   * Search excerpts, only set when the request has a query. The text is
+   * HTML-escaped; only the highlight markers are inserted as given.
+   *
+   * @generated from field: optional string snippet = 10;
+   */
+  snippet?: string;
+
+  /**
+   * @generated from field: optional string highlighted_title = 11;
+   */
+  highlightedTitle?: string;
+
+  /**
+   * @generated from field: optional string highlighted_description = 12;
+   */
+  highlightedDescription?: string;
 };
 
 /**
@@ -139,6 +220,60 @@ export type BlogPostSummary = Message<"blog.BlogPostSummary"> & {
 export const BlogPostSummarySchema: GenMessage<BlogPostSummary> = /*@__PURE__*/
   messageDesc(file_blog, 2);
 
+/**
+ * @generated from message blog.SuggestBlogSearchRequest
+ */
+export type SuggestBlogSearchRequest = Message<"blog.SuggestBlogSearchRequest"> & {
+  /**
+   * What the reader has typed so far; the last word is completed unless the
+   * query ends with a space. Only the last 5 words are checked for typos, and
+   * queries over 200 characters get no suggestions.
+   *
+   * @generated from field: string query = 1;
+   */
+  query: string;
+
+  /**
+   * Maximum number of completions (default 5, at most 20)
+   *
+   * @generated from field: int32 limit = 2;
+   */
+  limit: number;
+};
+
+/**
+ * Describes the message blog.SuggestBlogSearchRequest.
+ * Use `create(SuggestBlogSearchRequestSchema)` to create a new message.
+ */
+export const SuggestBlogSearchRequestSchema: GenMessage<SuggestBlogSearchRequest> = /*@__PURE__*/
+  messageDesc(file_blog, 3);
+
+/**
+ * @generated from message blog.SuggestBlogSearchResponse
+ */
+export type SuggestBlogSearchResponse = Message<"blog.SuggestBlogSearchResponse"> & {
+  /**
+   * Full queries with the last word completed, best match first
+   *
+   * @generated from field: repeated string completions = 1;
+   */
+  completions: string[];
+
+  /**
+   * The query with misspelled words corrected, if any were found
+   *
+   * @generated from field: optional string did_you_mean = 2;
+   */
+  didYouMean?: string;
+};
+
+/**
+ * Describes the message blog.SuggestBlogSearchResponse.
+ * Use `create(SuggestBlogSearchResponseSchema)` to create a new message.
+ */
+export const SuggestBlogSearchResponseSchema: GenMessage<SuggestBlogSearchResponse> = /*@__PURE__*/
+  messageDesc(file_blog, 4);
+
 /**
  * @generated from message blog.GetBlogPostRequest
  */
@@ -154,7 +289,7 @@ export type GetBlogPostRequest = Message<"blog.GetBlogPostRequest"> & {
  * Use `create(GetBlogPostRequestSchema)` to create a new message.
  */
 export const GetBlogPostRequestSchema: GenMessage<GetBlogPostRequest> = /*@__PURE__*/
-  messageDesc(file_blog, 3);
+  messageDesc(file_blog, 5);
 
 /**
  * @generated from message blog.GetBlogPostResponse
@@ -171,7 +306,48 @@ export type GetBlogPostResponse = Message<"blog.GetBlogPostResponse"> & {
  * Use `create(GetBlogPostResponseSchema)` to create a new message.
  */
 export const GetBlogPostResponseSchema: GenMessage<GetBlogPostResponse> = /*@__PURE__*/
-  messageDesc(file_blog, 4);
+  messageDesc(file_blog, 6);
+
+/**
+ * @generated from message blog.GetBlogPostBySlugRequest
+ */
+export type GetBlogPostBySlugRequest = Message<"blog.GetBlogPostBySlugRequest"> & {
+  /**
+   * @generated from field: string slug = 1;
+   */
+  slug: string;
+};
+
+/**
+ * Describes the message blog.GetBlogPostBySlugRequest.
+ * Use `create(GetBlogPostBySlugRequestSchema)` to create a new message.
+ */
+export const GetBlogPostBySlugRequestSchema: GenMessage<GetBlogPostBySlugRequest> = /*@__PURE__*/
+  messageDesc(file_blog, 7);
+
+/**
+ * @generated from message blog.GetBlogPostBySlugResponse
+ */
+export type GetBlogPostBySlugResponse = Message<"blog.GetBlogPostBySlugResponse"> & {
+  /**
+   * @generated from field: blog.BlogPost post = 1;
+   */
+  post?: BlogPost;
+
+  /**
+   * Set when the requested slug is one the post used before being renamed
+   *
+   * @generated from field: optional string redirect_to = 2;
+   */
+  redirectTo?: string;
+};
+
+/**
+ * Describes the message blog.GetBlogPostBySlugResponse.
+ * Use `create(GetBlogPostBySlugResponseSchema)` to create a new message.
+ */
+export const GetBlogPostBySlugResponseSchema: GenMessage<GetBlogPostBySlugResponse> = /*@__PURE__*/
+  messageDesc(file_blog, 8);
 
 /**
  * @generated from message blog.BlogPost
@@ -206,6 +382,66 @@ export type BlogPost = Message<"blog.BlogPost"> & {
    * @generated from field: int64 updated_at = 6;
    */
   updatedAt: bigint;
+
+  /**
+   * @generated from field: blog.BlogPostStatus status = 7;
+   */
+  status: BlogPostStatus;
+
+  /**
+   * @generated from field: optional int64 published_at = 8;
+   */
+  publishedAt?: bigint;
+
+  /**
+   * @generated from field: repeated string tags = 9;
+   */
+  tags: string[];
+
+  /**
+   * @generated from field: string slug = 10;
+   */
+  slug: string;
+
+  /**
+   * Sanitized HTML rendered from body (CommonMark with GFM extensions)
+   *
+   * @generated from field: string body_html = 11;
+   */
+  bodyHtml: string;
+
+  /**
+   * @generated from field: repeated blog.TocEntry toc = 12;
+   */
+  toc: TocEntry[];
+
+  /**
+   * @generated from field: int32 reading_minutes = 13;
+   */
+  readingMinutes: number;
+
+  /**
+   * Admin views only: when the post was soft-deleted, and the `sub` of who
+   * created, last changed and deleted it
+   *
+   * @generated from field: optional int64 deleted_at = 14;
+   */
+  deletedAt?: bigint;
+
+  /**
+   * @generated from field: optional string created_by = 15;
+   */
+  createdBy?: string;
+
+  /**
+   * @generated from field: optional string updated_by = 16;
+   */
+  updatedBy?: string;
+
+  /**
+   * @generated from field: optional string deleted_by = 17;
+   */
+  deletedBy?: string;
 };
 
 /**
@@ -213,7 +449,36 @@ export type BlogPost = Message<"blog.BlogPost"> & {
  * Use `create(BlogPostSchema)` to create a new message.
  */
 export const BlogPostSchema: GenMessage<BlogPost> = /*@__PURE__*/
-  messageDesc(file_blog, 5);
+  messageDesc(file_blog, 9);
+
+/**
+ * A heading in the rendered body; anchor matches the heading's id attribute
+ *
+ * @generated from message blog.TocEntry
+ */
+export type TocEntry = Message<"blog.TocEntry"> & {
+  /**
+   * @generated from field: int32 level = 1;
+   */
+  level: number;
+
+  /**
+   * @generated from field: string anchor = 2;
+   */
+  anchor: string;
+
+  /**
+   * @generated from field: string title = 3;
+   */
+  title: string;
+};
+
+/**
+ * Describes the message blog.TocEntry.
+ * Use `create(TocEntrySchema)` to create a new message.
+ */
+export const TocEntrySchema: GenMessage<TocEntry> = /*@__PURE__*/
+  messageDesc(file_blog, 10);
 
 /**
  * @generated from message blog.CreateBlogPostRequest
@@ -233,6 +498,11 @@ export type CreateBlogPostRequest = Message<"blog.CreateBlogPostRequest"> & {
    * @generated from field: string body = 3;
    */
   body: string;
+
+  /**
+   * @generated from field: repeated string tags = 4;
+   */
+  tags: string[];
 };
 
 /**
@@ -240,7 +510,7 @@ export type CreateBlogPostRequest = Message<"blog.CreateBlogPostRequest"> & {
  * Use `create(CreateBlogPostRequestSchema)` to create a new message.
  */
 export const CreateBlogPostRequestSchema: GenMessage<CreateBlogPostRequest> = /*@__PURE__*/
-  messageDesc(file_blog, 6);
+  messageDesc(file_blog, 11);
 
 /**
  * @generated from message blog.CreateBlogPostResponse
@@ -257,7 +527,7 @@ export type CreateBlogPostResponse = Message<"blog.CreateBlogPostResponse"> & {
  * Use `create(CreateBlogPostResponseSchema)` to create a new message.
  */
 export const CreateBlogPostResponseSchema: GenMessage<CreateBlogPostResponse> = /*@__PURE__*/
-  messageDesc(file_blog, 7);
+  messageDesc(file_blog, 12);
 
 /**
  * @generated from message blog.UpdateBlogPostRequest
@@ -282,6 +552,20 @@ export type UpdateBlogPostRequest = Message<"blog.UpdateBlogPostRequest"> & {
    * @generated from field: optional string body = 4;
    */
   body?: string;
+
+  /**
+   * Replaces the post's tags when set; leaves them unchanged when unset
+   *
+   * @generated from field: blog.TagList tags = 5;
+   */
+  tags?: TagList;
+
+  /**
+   * Renames the post's slug; the old slug keeps resolving via GetBlogPostBySlug
+   *
+   * @generated from field: optional string slug = 6;
+   */
+  slug?: string;
 };
 
 /**
@@ -289,7 +573,24 @@ export type UpdateBlogPostRequest = Message<"blog.UpdateBlogPostRequest"> & {
  * Use `create(UpdateBlogPostRequestSchema)` to create a new message.
  */
 export const UpdateBlogPostRequestSchema: GenMessage<UpdateBlogPostRequest> = /*@__PURE__*/
-  messageDesc(file_blog, 8);
+  messageDesc(file_blog, 13);
+
+/**
+ * @generated from message blog.TagList
+ */
+export type TagList = Message<"blog.TagList"> & {
+  /**
+   * @generated from field: repeated string tags = 1;
+   */
+  tags: string[];
+};
+
+/**
+ * Describes the message blog.TagList.
+ * Use `create(TagListSchema)` to create a new message.
+ */
+export const TagListSchema: GenMessage<TagList> = /*@__PURE__*/
+  messageDesc(file_blog, 14);
 
 /**
  * @generated from message blog.UpdateBlogPostResponse
@@ -302,7 +603,7 @@ export type UpdateBlogPostResponse = Message<"blog.UpdateBlogPostResponse"> & {
  * Use `create(UpdateBlogPostResponseSchema)` to create a new message.
  */
 export const UpdateBlogPostResponseSchema: GenMessage<UpdateBlogPostResponse> = /*@__PURE__*/
-  messageDesc(file_blog, 9);
+  messageDesc(file_blog, 15);
 
 /**
  * @generated from message blog.DeleteBlogPostRequest
@@ -319,7 +620,7 @@ export type DeleteBlogPostRequest = Message<"blog.DeleteBlogPostRequest"> & {
  * Use `create(DeleteBlogPostRequestSchema)` to create a new message.
  */
 export const DeleteBlogPostRequestSchema: GenMessage<DeleteBlogPostRequest> = /*@__PURE__*/
-  messageDesc(file_blog, 10);
+  messageDesc(file_blog, 16);
 
 /**
  * @generated from message blog.DeleteBlogPostResponse
@@ -332,7 +633,7 @@ export type DeleteBlogPostResponse = Message<"blog.DeleteBlogPostResponse"> & {
  * Use `create(DeleteBlogPostResponseSchema)` to create a new message.
  */
 export const DeleteBlogPostResponseSchema: GenMessage<DeleteBlogPostResponse> = /*@__PURE__*/
-  messageDesc(file_blog, 11);
+  messageDesc(file_blog, 17);
 
 /**
  * @generated from message blog.RestoreBlogPostRequest
@@ -349,7 +650,7 @@ export type RestoreBlogPostRequest = Message<"blog.RestoreBlogPostRequest"> & {
  * Use `create(RestoreBlogPostRequestSchema)` to create a new message.
  */
 export const RestoreBlogPostRequestSchema: GenMessage<RestoreBlogPostRequest> = /*@__PURE__*/
-  messageDesc(file_blog, 12);
+  messageDesc(file_blog, 18);
 
 /**
  * @generated from message blog.RestoreBlogPostResponse
@@ -362,71 +663,934 @@ export type RestoreBlogPostResponse = Message<"blog.RestoreBlogPostResponse"> &
  * Use `create(RestoreBlogPostResponseSchema)` to create a new message.
  */
 export const RestoreBlogPostResponseSchema: GenMessage<RestoreBlogPostResponse> = /*@__PURE__*/
-  messageDesc(file_blog, 13);
+  messageDesc(file_blog, 19);
 
 /**
- * Public blog service for read operations
- *
- * @generated from service blog.BlogService
+ * @generated from message blog.PublishBlogPostRequest
  */
-export const BlogService: GenService<{
-  /**
-   * @generated from rpc blog.BlogService.GetBlogPosts
-   */
-  getBlogPosts: {
-    methodKind: "unary";
-    input: typeof GetBlogPostsRequestSchema;
-    output: typeof GetBlogPostsResponseSchema;
-  },
+export type PublishBlogPostRequest = Message<"blog.PublishBlogPostRequest"> & {
   /**
-   * @generated from rpc blog.BlogService.GetBlogPost
+   * @generated from field: int64 id = 1;
    */
-  getBlogPost: {
-    methodKind: "unary";
-    input: typeof GetBlogPostRequestSchema;
-    output: typeof GetBlogPostResponseSchema;
-  },
-}> = /*@__PURE__*/
-  serviceDesc(file_blog, 0);
+  id: bigint;
+};
 
 /**
- * Protected blog admin service for write operations (requires authentication)
- *
- * @generated from service blog.BlogAdminService
+ * Describes the message blog.PublishBlogPostRequest.
+ * Use `create(PublishBlogPostRequestSchema)` to create a new message.
  */
-export const BlogAdminService: GenService<{
+export const PublishBlogPostRequestSchema: GenMessage<PublishBlogPostRequest> = /*@__PURE__*/
+  messageDesc(file_blog, 20);
+
+/**
+ * @generated from message blog.PublishBlogPostResponse
+ */
+export type PublishBlogPostResponse = Message<"blog.PublishBlogPostResponse"> & {
+};
+
+/**
+ * Describes the message blog.PublishBlogPostResponse.
+ * Use `create(PublishBlogPostResponseSchema)` to create a new message.
+ */
+export const PublishBlogPostResponseSchema: GenMessage<PublishBlogPostResponse> = /*@__PURE__*/
+  messageDesc(file_blog, 21);
+
+/**
+ * @generated from message blog.UnpublishBlogPostRequest
+ */
+export type UnpublishBlogPostRequest = Message<"blog.UnpublishBlogPostRequest"> & {
   /**
-   * @generated from rpc blog.BlogAdminService.CreateBlogPost
+   * @generated from field: int64 id = 1;
    */
-  createBlogPost: {
-    methodKind: "unary";
-    input: typeof CreateBlogPostRequestSchema;
-    output: typeof CreateBlogPostResponseSchema;
-  },
+  id: bigint;
+
   /**
-   * @generated from rpc blog.BlogAdminService.UpdateBlogPost
+   * Move the post to archived instead of back to draft
+   *
+   * @generated from field: bool archive = 2;
    */
-  updateBlogPost: {
-    methodKind: "unary";
-    input: typeof UpdateBlogPostRequestSchema;
-    output: typeof UpdateBlogPostResponseSchema;
-  },
+  archive: boolean;
+};
+
+/**
+ * Describes the message blog.UnpublishBlogPostRequest.
+ * Use `create(UnpublishBlogPostRequestSchema)` to create a new message.
+ */
+export const UnpublishBlogPostRequestSchema: GenMessage<UnpublishBlogPostRequest> = /*@__PURE__*/
+  messageDesc(file_blog, 22);
+
+/**
+ * @generated from message blog.UnpublishBlogPostResponse
+ */
+export type UnpublishBlogPostResponse = Message<"blog.UnpublishBlogPostResponse"> & {
+};
+
+/**
+ * Describes the message blog.UnpublishBlogPostResponse.
+ * Use `create(UnpublishBlogPostResponseSchema)` to create a new message.
+ */
+export const UnpublishBlogPostResponseSchema: GenMessage<UnpublishBlogPostResponse> = /*@__PURE__*/
+  messageDesc(file_blog, 23);
+
+/**
+ * @generated from message blog.ScheduleBlogPostRequest
+ */
+export type ScheduleBlogPostRequest = Message<"blog.ScheduleBlogPostRequest"> & {
   /**
-   * @generated from rpc blog.BlogAdminService.DeleteBlogPost
+   * @generated from field: int64 id = 1;
    */
-  deleteBlogPost: {
-    methodKind: "unary";
-    input: typeof DeleteBlogPostRequestSchema;
-    output: typeof DeleteBlogPostResponseSchema;
-  },
+  id: bigint;
+
   /**
-   * @generated from rpc blog.BlogAdminService.RestoreBlogPost
+   * Unix timestamp (seconds) at which the post goes public
+   *
+   * @generated from field: int64 publish_at = 2;
    */
-  restoreBlogPost: {
-    methodKind: "unary";
+  publishAt: bigint;
+};
+
+/**
+ * Describes the message blog.ScheduleBlogPostRequest.
+ * Use `create(ScheduleBlogPostRequestSchema)` to create a new message.
+ */
+export const ScheduleBlogPostRequestSchema: GenMessage<ScheduleBlogPostRequest> = /*@__PURE__*/
+  messageDesc(file_blog, 24);
+
+/**
+ * @generated from message blog.ScheduleBlogPostResponse
+ */
+export type ScheduleBlogPostResponse = Message<"blog.ScheduleBlogPostResponse"> & {
+};
+
+/**
+ * Describes the message blog.ScheduleBlogPostResponse.
+ * Use `create(ScheduleBlogPostResponseSchema)` to create a new message.
+ */
+export const ScheduleBlogPostResponseSchema: GenMessage<ScheduleBlogPostResponse> = /*@__PURE__*/
+  messageDesc(file_blog, 25);
+
+/**
+ * @generated from message blog.BlogPostRevisionSummary
+ */
+export type BlogPostRevisionSummary = Message<"blog.BlogPostRevisionSummary"> & {
+  /**
+   * @generated from field: int64 post_id = 1;
+   */
+  postId: bigint;
+
+  /**
+   * @generated from field: int32 revision = 2;
+   */
+  revision: number;
+
+  /**
+   * @generated from field: string title = 3;
+   */
+  title: string;
+
+  /**
+   * Firebase `sub` of the admin who made the change, if known
+   *
+   * @generated from field: optional string author_sub = 4;
+   */
+  authorSub?: string;
+
+  /**
+   * @generated from field: int64 created_at = 5;
+   */
+  createdAt: bigint;
+};
+
+/**
+ * Describes the message blog.BlogPostRevisionSummary.
+ * Use `create(BlogPostRevisionSummarySchema)` to create a new message.
+ */
+export const BlogPostRevisionSummarySchema: GenMessage<BlogPostRevisionSummary> = /*@__PURE__*/
+  messageDesc(file_blog, 26);
+
+/**
+ * @generated from message blog.BlogPostRevision
+ */
+export type BlogPostRevision = Message<"blog.BlogPostRevision"> & {
+  /**
+   * @generated from field: int64 post_id = 1;
+   */
+  postId: bigint;
+
+  /**
+   * @generated from field: int32 revision = 2;
+   */
+  revision: number;
+
+  /**
+   * @generated from field: string title = 3;
+   */
+  title: string;
+
+  /**
+   * @generated from field: string description = 4;
+   */
+  description: string;
+
+  /**
+   * @generated from field: string body = 5;
+   */
+  body: string;
+
+  /**
+   * @generated from field: optional string author_sub = 6;
+   */
+  authorSub?: string;
+
+  /**
+   * @generated from field: int64 created_at = 7;
+   */
+  createdAt: bigint;
+};
+
+/**
+ * Describes the message blog.BlogPostRevision.
+ * Use `create(BlogPostRevisionSchema)` to create a new message.
+ */
+export const BlogPostRevisionSchema: GenMessage<BlogPostRevision> = /*@__PURE__*/
+  messageDesc(file_blog, 27);
+
+/**
+ * @generated from message blog.ListBlogPostRevisionsRequest
+ */
+export type ListBlogPostRevisionsRequest = Message<"blog.ListBlogPostRevisionsRequest"> & {
+  /**
+   * @generated from field: int64 post_id = 1;
+   */
+  postId: bigint;
+
+  /**
+   * Revisions per page (default 20, at most 100)
+   *
+   * @generated from field: int32 limit = 2;
+   */
+  limit: number;
+
+  /**
+   * @generated from field: int32 offset = 3;
+   */
+  offset: number;
+};
+
+/**
+ * Describes the message blog.ListBlogPostRevisionsRequest.
+ * Use `create(ListBlogPostRevisionsRequestSchema)` to create a new message.
+ */
+export const ListBlogPostRevisionsRequestSchema: GenMessage<ListBlogPostRevisionsRequest> = /*@__PURE__*/
+  messageDesc(file_blog, 28);
+
+/**
+ * @generated from message blog.ListBlogPostRevisionsResponse
+ */
+export type ListBlogPostRevisionsResponse = Message<"blog.ListBlogPostRevisionsResponse"> & {
+  /**
+   * @generated from field: repeated blog.BlogPostRevisionSummary revisions = 1;
+   */
+  revisions: BlogPostRevisionSummary[];
+
+  /**
+   * @generated from field: int32 total = 2;
+   */
+  total: number;
+};
+
+/**
+ * Describes the message blog.ListBlogPostRevisionsResponse.
+ * Use `create(ListBlogPostRevisionsResponseSchema)` to create a new message.
+ */
+export const ListBlogPostRevisionsResponseSchema: GenMessage<ListBlogPostRevisionsResponse> = /*@__PURE__*/
+  messageDesc(file_blog, 29);
+
+/**
+ * @generated from message blog.GetBlogPostRevisionRequest
+ */
+export type GetBlogPostRevisionRequest = Message<"blog.GetBlogPostRevisionRequest"> & {
+  /**
+   * @generated from field: int64 post_id = 1;
+   */
+  postId: bigint;
+
+  /**
+   * @generated from field: int32 revision = 2;
+   */
+  revision: number;
+};
+
+/**
+ * Describes the message blog.GetBlogPostRevisionRequest.
+ * Use `create(GetBlogPostRevisionRequestSchema)` to create a new message.
+ */
+export const GetBlogPostRevisionRequestSchema: GenMessage<GetBlogPostRevisionRequest> = /*@__PURE__*/
+  messageDesc(file_blog, 30);
+
+/**
+ * @generated from message blog.GetBlogPostRevisionResponse
+ */
+export type GetBlogPostRevisionResponse = Message<"blog.GetBlogPostRevisionResponse"> & {
+  /**
+   * @generated from field: blog.BlogPostRevision revision = 1;
+   */
+  revision?: BlogPostRevision;
+};
+
+/**
+ * Describes the message blog.GetBlogPostRevisionResponse.
+ * Use `create(GetBlogPostRevisionResponseSchema)` to create a new message.
+ */
+export const GetBlogPostRevisionResponseSchema: GenMessage<GetBlogPostRevisionResponse> = /*@__PURE__*/
+  messageDesc(file_blog, 31);
+
+/**
+ * @generated from message blog.DiffBlogPostRevisionsRequest
+ */
+export type DiffBlogPostRevisionsRequest = Message<"blog.DiffBlogPostRevisionsRequest"> & {
+  /**
+   * @generated from field: int64 post_id = 1;
+   */
+  postId: bigint;
+
+  /**
+   * @generated from field: int32 from_revision = 2;
+   */
+  fromRevision: number;
+
+  /**
+   * @generated from field: int32 to_revision = 3;
+   */
+  toRevision: number;
+};
+
+/**
+ * Describes the message blog.DiffBlogPostRevisionsRequest.
+ * Use `create(DiffBlogPostRevisionsRequestSchema)` to create a new message.
+ */
+export const DiffBlogPostRevisionsRequestSchema: GenMessage<DiffBlogPostRevisionsRequest> = /*@__PURE__*/
+  messageDesc(file_blog, 32);
+
+/**
+ * @generated from message blog.DiffLine
+ */
+export type DiffLine = Message<"blog.DiffLine"> & {
+  /**
+   * @generated from field: blog.DiffLine.Op op = 1;
+   */
+  op: DiffLine_Op;
+
+  /**
+   * @generated from field: string text = 2;
+   */
+  text: string;
+
+  /**
+   * 1-based line numbers; unset on the side the line doesn't exist on
+   *
+   * @generated from field: optional int32 old_line = 3;
+   */
+  oldLine?: number;
+
+  /**
+   * @generated from field: optional int32 new_line = 4;
+   */
+  newLine?: number;
+};
+
+/**
+ * Describes the message blog.DiffLine.
+ * Use `create(DiffLineSchema)` to create a new message.
+ */
+export const DiffLineSchema: GenMessage<DiffLine> = /*@__PURE__*/
+  messageDesc(file_blog, 33);
+
+/**
+ * @generated from enum blog.DiffLine.Op
+ */
+export enum DiffLine_Op {
+  /**
+   * @generated from enum value: EQUAL = 0;
+   */
+  EQUAL = 0,
+
+  /**
+   * @generated from enum value: INSERT = 1;
+   */
+  INSERT = 1,
+
+  /**
+   * @generated from enum value: DELETE = 2;
+   */
+  DELETE = 2,
+}
+
+/**
+ * Describes the enum blog.DiffLine.Op.
+ */
+export const DiffLine_OpSchema: GenEnum<DiffLine_Op> = /*@__PURE__*/
+  enumDesc(file_blog, 33, 0);
+
+/**
+ * @generated from message blog.DiffBlogPostRevisionsResponse
+ */
+export type DiffBlogPostRevisionsResponse = Message<"blog.DiffBlogPostRevisionsResponse"> & {
+  /**
+   * Line-level diff of the body from `from_revision` to `to_revision`
+   *
+   * @generated from field: repeated blog.DiffLine lines = 1;
+   */
+  lines: DiffLine[];
+};
+
+/**
+ * Describes the message blog.DiffBlogPostRevisionsResponse.
+ * Use `create(DiffBlogPostRevisionsResponseSchema)` to create a new message.
+ */
+export const DiffBlogPostRevisionsResponseSchema: GenMessage<DiffBlogPostRevisionsResponse> = /*@__PURE__*/
+  messageDesc(file_blog, 34);
+
+/**
+ * @generated from message blog.RevertBlogPostRequest
+ */
+export type RevertBlogPostRequest = Message<"blog.RevertBlogPostRequest"> & {
+  /**
+   * @generated from field: int64 post_id = 1;
+   */
+  postId: bigint;
+
+  /**
+   * @generated from field: int32 revision = 2;
+   */
+  revision: number;
+};
+
+/**
+ * Describes the message blog.RevertBlogPostRequest.
+ * Use `create(RevertBlogPostRequestSchema)` to create a new message.
+ */
+export const RevertBlogPostRequestSchema: GenMessage<RevertBlogPostRequest> = /*@__PURE__*/
+  messageDesc(file_blog, 35);
+
+/**
+ * @generated from message blog.RevertBlogPostResponse
+ */
+export type RevertBlogPostResponse = Message<"blog.RevertBlogPostResponse"> & {
+  /**
+   * The new revision recording the rollback
+   *
+   * @generated from field: int32 revision = 1;
+   */
+  revision: number;
+};
+
+/**
+ * Describes the message blog.RevertBlogPostResponse.
+ * Use `create(RevertBlogPostResponseSchema)` to create a new message.
+ */
+export const RevertBlogPostResponseSchema: GenMessage<RevertBlogPostResponse> = /*@__PURE__*/
+  messageDesc(file_blog, 36);
+
+/**
+ * @generated from message blog.Tag
+ */
+export type Tag = Message<"blog.Tag"> & {
+  /**
+   * @generated from field: string name = 1;
+   */
+  name: string;
+
+  /**
+   * @generated from field: int32 post_count = 2;
+   */
+  postCount: number;
+};
+
+/**
+ * Describes the message blog.Tag.
+ * Use `create(TagSchema)` to create a new message.
+ */
+export const TagSchema: GenMessage<Tag> = /*@__PURE__*/
+  messageDesc(file_blog, 37);
+
+/**
+ * @generated from message blog.ListTagsRequest
+ */
+export type ListTagsRequest = Message<"blog.ListTagsRequest"> & {
+};
+
+/**
+ * Describes the message blog.ListTagsRequest.
+ * Use `create(ListTagsRequestSchema)` to create a new message.
+ */
+export const ListTagsRequestSchema: GenMessage<ListTagsRequest> = /*@__PURE__*/
+  messageDesc(file_blog, 38);
+
+/**
+ * @generated from message blog.ListTagsResponse
+ */
+export type ListTagsResponse = Message<"blog.ListTagsResponse"> & {
+  /**
+   * @generated from field: repeated blog.Tag tags = 1;
+   */
+  tags: Tag[];
+};
+
+/**
+ * Describes the message blog.ListTagsResponse.
+ * Use `create(ListTagsResponseSchema)` to create a new message.
+ */
+export const ListTagsResponseSchema: GenMessage<ListTagsResponse> = /*@__PURE__*/
+  messageDesc(file_blog, 39);
+
+/**
+ * @generated from message blog.RenameTagRequest
+ */
+export type RenameTagRequest = Message<"blog.RenameTagRequest"> & {
+  /**
+   * @generated from field: string name = 1;
+   */
+  name: string;
+
+  /**
+   * @generated from field: string new_name = 2;
+   */
+  newName: string;
+};
+
+/**
+ * Describes the message blog.RenameTagRequest.
+ * Use `create(RenameTagRequestSchema)` to create a new message.
+ */
+export const RenameTagRequestSchema: GenMessage<RenameTagRequest> = /*@__PURE__*/
+  messageDesc(file_blog, 40);
+
+/**
+ * @generated from message blog.RenameTagResponse
+ */
+export type RenameTagResponse = Message<"blog.RenameTagResponse"> & {
+};
+
+/**
+ * Describes the message blog.RenameTagResponse.
+ * Use `create(RenameTagResponseSchema)` to create a new message.
+ */
+export const RenameTagResponseSchema: GenMessage<RenameTagResponse> = /*@__PURE__*/
+  messageDesc(file_blog, 41);
+
+/**
+ * @generated from message blog.MergeTagsRequest
+ */
+export type MergeTagsRequest = Message<"blog.MergeTagsRequest"> & {
+  /**
+   * Tags to fold into `target`; they are removed afterwards
+   *
+   * @generated from field: repeated string sources = 1;
+   */
+  sources: string[];
+
+  /**
+   * @generated from field: string target = 2;
+   */
+  target: string;
+};
+
+/**
+ * Describes the message blog.MergeTagsRequest.
+ * Use `create(MergeTagsRequestSchema)` to create a new message.
+ */
+export const MergeTagsRequestSchema: GenMessage<MergeTagsRequest> = /*@__PURE__*/
+  messageDesc(file_blog, 42);
+
+/**
+ * @generated from message blog.MergeTagsResponse
+ */
+export type MergeTagsResponse = Message<"blog.MergeTagsResponse"> & {
+  /**
+   * Number of source tags that existed and were merged
+   *
+   * @generated from field: int32 merged = 1;
+   */
+  merged: number;
+};
+
+/**
+ * Describes the message blog.MergeTagsResponse.
+ * Use `create(MergeTagsResponseSchema)` to create a new message.
+ */
+export const MergeTagsResponseSchema: GenMessage<MergeTagsResponse> = /*@__PURE__*/
+  messageDesc(file_blog, 43);
+
+/**
+ * @generated from message blog.AuditEvent
+ */
+export type AuditEvent = Message<"blog.AuditEvent"> & {
+  /**
+   * @generated from field: int64 id = 1;
+   */
+  id: bigint;
+
+  /**
+   * @generated from field: int64 created_at = 2;
+   */
+  createdAt: bigint;
+
+  /**
+   * Firebase `sub` and email of the caller; login attempts only have the email
+   *
+   * @generated from field: optional string actor_sub = 3;
+   */
+  actorSub?: string;
+
+  /**
+   * @generated from field: optional string actor_email = 4;
+   */
+  actorEmail?: string;
+
+  /**
+   * RPC method name, e.g. DeleteBlogPost. REST calls are recorded under the
+   * name of the RPC that does the same thing.
+   *
+   * @generated from field: string action = 5;
+   */
+  action: string;
+
+  /**
+   * Post the RPC acted on, if any
+   *
+   * @generated from field: optional int64 target_id = 6;
+   */
+  targetId?: bigint;
+
+  /**
+   * gRPC status code the RPC finished with; 0 is OK. For REST calls, the code
+   * matching the HTTP status.
+   *
+   * @generated from field: int32 status_code = 7;
+   */
+  statusCode: number;
+
+  /**
+   * @generated from field: optional string request_id = 8;
+   */
+  requestId?: string;
+
+  /**
+   * @generated from field: optional string client_ip = 9;
+   */
+  clientIp?: string;
+
+  /**
+   * JSON object summarizing the fields the request set
+   *
+   * @generated from field: string changes = 10;
+   */
+  changes: string;
+};
+
+/**
+ * Describes the message blog.AuditEvent.
+ * Use `create(AuditEventSchema)` to create a new message.
+ */
+export const AuditEventSchema: GenMessage<AuditEvent> = /*@__PURE__*/
+  messageDesc(file_blog, 44);
+
+/**
+ * @generated from message blog.ListAuditEventsRequest
+ */
+export type ListAuditEventsRequest = Message<"blog.ListAuditEventsRequest"> & {
+  /**
+   * Matches either the caller's `sub` or email
+   *
+   * @generated from field: optional string actor = 1;
+   */
+  actor?: string;
+
+  /**
+   * RPC method name
+   *
+   * @generated from field: optional string action = 2;
+   */
+  action?: string;
+
+  /**
+   * Unix timestamps (seconds), inclusive
+   *
+   * @generated from field: optional int64 since = 3;
+   */
+  since?: bigint;
+
+  /**
+   * @generated from field: optional int64 until = 4;
+   */
+  until?: bigint;
+
+  /**
+   * @generated from field: int32 limit = 5;
+   */
+  limit: number;
+
+  /**
+   * @generated from field: int32 offset = 6;
+   */
+  offset: number;
+};
+
+/**
+ * Describes the message blog.ListAuditEventsRequest.
+ * Use `create(ListAuditEventsRequestSchema)` to create a new message.
+ */
+export const ListAuditEventsRequestSchema: GenMessage<ListAuditEventsRequest> = /*@__PURE__*/
+  messageDesc(file_blog, 45);
+
+/**
+ * @generated from message blog.ListAuditEventsResponse
+ */
+export type ListAuditEventsResponse = Message<"blog.ListAuditEventsResponse"> & {
+  /**
+   * @generated from field: repeated blog.AuditEvent events = 1;
+   */
+  events: AuditEvent[];
+
+  /**
+   * @generated from field: int32 total = 2;
+   */
+  total: number;
+};
+
+/**
+ * Describes the message blog.ListAuditEventsResponse.
+ * Use `create(ListAuditEventsResponseSchema)` to create a new message.
+ */
+export const ListAuditEventsResponseSchema: GenMessage<ListAuditEventsResponse> = /*@__PURE__*/
+  messageDesc(file_blog, 46);
+
+/**
+ * @generated from enum blog.BlogPostStatus
+ */
+export enum BlogPostStatus {
+  /**
+   * @generated from enum value: BLOG_POST_STATUS_UNSPECIFIED = 0;
+   */
+  UNSPECIFIED = 0,
+
+  /**
+   * @generated from enum value: BLOG_POST_STATUS_DRAFT = 1;
+   */
+  DRAFT = 1,
+
+  /**
+   * @generated from enum value: BLOG_POST_STATUS_SCHEDULED = 2;
+   */
+  SCHEDULED = 2,
+
+  /**
+   * @generated from enum value: BLOG_POST_STATUS_PUBLISHED = 3;
+   */
+  PUBLISHED = 3,
+
+  /**
+   * @generated from enum value: BLOG_POST_STATUS_ARCHIVED = 4;
+   */
+  ARCHIVED = 4,
+}
+
+/**
+ * Describes the enum blog.BlogPostStatus.
+ */
+export const BlogPostStatusSchema: GenEnum<BlogPostStatus> = /*@__PURE__*/
+  enumDesc(file_blog, 0);
+
+/**
+ * Public blog service for read operations
+ *
+ * @generated from service blog.BlogService
+ */
+export const BlogService: GenService<{
+  /**
+   * @generated from rpc blog.BlogService.GetBlogPosts
+   */
+  getBlogPosts: {
+    methodKind: "unary";
+    input: typeof GetBlogPostsRequestSchema;
+    output: typeof GetBlogPostsResponseSchema;
+  },
+  /**
+   * @generated from rpc blog.BlogService.GetBlogPost
+   */
+  getBlogPost: {
+    methodKind: "unary";
+    input: typeof GetBlogPostRequestSchema;
+    output: typeof GetBlogPostResponseSchema;
+  },
+  /**
+   * @generated from rpc blog.BlogService.ListTags
+   */
+  listTags: {
+    methodKind: "unary";
+    input: typeof ListTagsRequestSchema;
+    output: typeof ListTagsResponseSchema;
+  },
+  /**
+   * @generated from rpc blog.BlogService.GetBlogPostBySlug
+   */
+  getBlogPostBySlug: {
+    methodKind: "unary";
+    input: typeof GetBlogPostBySlugRequestSchema;
+    output: typeof GetBlogPostBySlugResponseSchema;
+  },
+  /**
+   * @generated from rpc blog.BlogService.SuggestBlogSearch
+   */
+  suggestBlogSearch: {
+    methodKind: "unary";
+    input: typeof SuggestBlogSearchRequestSchema;
+    output: typeof SuggestBlogSearchResponseSchema;
+  },
+}> = /*@__PURE__*/
+  serviceDesc(file_blog, 0);
+
+/**
+ * Protected blog admin service for write operations (requires authentication)
+ *
+ * @generated from service blog.BlogAdminService
+ */
+export const BlogAdminService: GenService<{
+  /**
+   * Admin reads include drafts, scheduled and archived posts
+   *
+   * @generated from rpc blog.BlogAdminService.GetBlogPosts
+   */
+  getBlogPosts: {
+    methodKind: "unary";
+    input: typeof GetBlogPostsRequestSchema;
+    output: typeof GetBlogPostsResponseSchema;
+  },
+  /**
+   * Also returns soft-deleted posts, so they can be inspected before restoring
+   *
+   * @generated from rpc blog.BlogAdminService.GetBlogPost
+   */
+  getBlogPost: {
+    methodKind: "unary";
+    input: typeof GetBlogPostRequestSchema;
+    output: typeof GetBlogPostResponseSchema;
+  },
+  /**
+   * @generated from rpc blog.BlogAdminService.CreateBlogPost
+   */
+  createBlogPost: {
+    methodKind: "unary";
+    input: typeof CreateBlogPostRequestSchema;
+    output: typeof CreateBlogPostResponseSchema;
+  },
+  /**
+   * @generated from rpc blog.BlogAdminService.UpdateBlogPost
+   */
+  updateBlogPost: {
+    methodKind: "unary";
+    input: typeof UpdateBlogPostRequestSchema;
+    output: typeof UpdateBlogPostResponseSchema;
+  },
+  /**
+   * @generated from rpc blog.BlogAdminService.DeleteBlogPost
+   */
+  deleteBlogPost: {
+    methodKind: "unary";
+    input: typeof DeleteBlogPostRequestSchema;
+    output: typeof DeleteBlogPostResponseSchema;
+  },
+  /**
+   * @generated from rpc blog.BlogAdminService.RestoreBlogPost
+   */
+  restoreBlogPost: {
+    methodKind: "unary";
     input: typeof RestoreBlogPostRequestSchema;
     output: typeof RestoreBlogPostResponseSchema;
   },
+  /**
+   * @generated from rpc blog.BlogAdminService.PublishBlogPost
+   */
+  publishBlogPost: {
+    methodKind: "unary";
+    input: typeof PublishBlogPostRequestSchema;
+    output: typeof PublishBlogPostResponseSchema;
+  },
+  /**
+   * @generated from rpc blog.BlogAdminService.UnpublishBlogPost
+   */
+  unpublishBlogPost: {
+    methodKind: "unary";
+    input: typeof UnpublishBlogPostRequestSchema;
+    output: typeof UnpublishBlogPostResponseSchema;
+  },
+  /**
+   * @generated from rpc blog.BlogAdminService.ScheduleBlogPost
+   */
+  scheduleBlogPost: {
+    methodKind: "unary";
+    input: typeof ScheduleBlogPostRequestSchema;
+    output: typeof ScheduleBlogPostResponseSchema;
+  },
+  /**
+   * @generated from rpc blog.BlogAdminService.ListBlogPostRevisions
+   */
+  listBlogPostRevisions: {
+    methodKind: "unary";
+    input: typeof ListBlogPostRevisionsRequestSchema;
+    output: typeof ListBlogPostRevisionsResponseSchema;
+  },
+  /**
+   * @generated from rpc blog.BlogAdminService.GetBlogPostRevision
+   */
+  getBlogPostRevision: {
+    methodKind: "unary";
+    input: typeof GetBlogPostRevisionRequestSchema;
+    output: typeof GetBlogPostRevisionResponseSchema;
+  },
+  /**
+   * @generated from rpc blog.BlogAdminService.DiffBlogPostRevisions
+   */
+  diffBlogPostRevisions: {
+    methodKind: "unary";
+    input: typeof DiffBlogPostRevisionsRequestSchema;
+    output: typeof DiffBlogPostRevisionsResponseSchema;
+  },
+  /**
+   * @generated from rpc blog.BlogAdminService.RevertBlogPost
+   */
+  revertBlogPost: {
+    methodKind: "unary";
+    input: typeof RevertBlogPostRequestSchema;
+    output: typeof RevertBlogPostResponseSchema;
+  },
+  /**
+   * @generated from rpc blog.BlogAdminService.RenameTag
+   */
+  renameTag: {
+    methodKind: "unary";
+    input: typeof RenameTagRequestSchema;
+    output: typeof RenameTagResponseSchema;
+  },
+  /**
+   * @generated from rpc blog.BlogAdminService.MergeTags
+   */
+  mergeTags: {
+    methodKind: "unary";
+    input: typeof MergeTagsRequestSchema;
+    output: typeof MergeTagsResponseSchema;
+  },
+  /**
+   * Mutating admin RPCs and login attempts, newest first
+   *
+   * @generated from rpc blog.BlogAdminService.ListAuditEvents
+   */
+  listAuditEvents: {
+    methodKind: "unary";
+    input: typeof ListAuditEventsRequestSchema;
+    output: typeof ListAuditEventsResponseSchema;
+  },
 }> = /*@__PURE__*/
   serviceDesc(file_blog, 1);
 
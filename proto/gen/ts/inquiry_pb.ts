@@ -0,0 +1,529 @@
+// @generated by protoc-gen-es v2.10.2 with parameter "target=ts"
+// @generated from file inquiry.proto (package inquiry, syntax proto3)
+/* eslint-disable */
+
+import type { GenEnum, GenFile, GenMessage, GenService } from "@bufbuild/protobuf/codegenv2";
+import { enumDesc, fileDesc, messageDesc, serviceDesc } from "@bufbuild/protobuf/codegenv2";
+import type { Message } from "@bufbuild/protobuf";
+
+/**
+ * Describes the file inquiry.proto.
+ */
+export const file_inquiry: GenFile = /*@__PURE__*/
+  fileDesc("Cg1pbnF1aXJ5LnByb3RvEgdpbnF1aXJ5It4CCgdJbnF1aXJ5EgoKAmlkGAEgASgDEgwKBG5hbWUYAiABKAkSDQoFZW1haWwYAyABKAkSDwoHc3ViamVjdBgEIAEoCRIPCgdtZXNzYWdlGAUgASgJEiYKBnN0YXR1cxgGIAEoDjIWLmlucXVpcnkuSW5xdWlyeVN0YXR1cxISCgpjcmVhdGVkX2F0GAcgASgDEhcKCmhhbmRsZWRfYXQYCCABKANIAIgBARIXCgpoYW5kbGVkX2J5GAkgASgJSAGIAQESGAoLYXJjaGl2ZWRfYXQYCiABKANIAogBARISCgpzcGFtX3Njb3JlGAsgASgBEhQKDHNwYW1fcmVhc29ucxgMIAMoCRIYCgttYXJrZWRfc3BhbRgNIAEoCEgDiAEBQg0KC19oYW5kbGVkX2F0Qg0KC19oYW5kbGVkX2J5Qg4KDF9hcmNoaXZlZF9hdEIOCgxfbWFya2VkX3NwYW0iegoUU3VibWl0SW5xdWlyeVJlcXVlc3QSDAoEbmFtZRgBIAEoCRINCgVlbWFpbBgCIAEoCRIPCgdzdWJqZWN0GAMgASgJEg8KB21lc3NhZ2UYBCABKAkSDwoHd2Vic2l0ZRgFIAEoCRISCgpmb3JtX3Rva2VuGAYgASgJIhcKFVN1Ym1pdElucXVpcnlSZXNwb25zZSIVChNHZXRGb3JtVG9rZW5SZXF1ZXN0IioKFEdldEZvcm1Ub2tlblJlc3BvbnNlEhIKCmZvcm1fdG9rZW4YASABKAkibQoUTGlzdElucXVpcmllc1JlcXVlc3QSKwoGc3RhdHVzGAEgASgOMhYuaW5xdWlyeS5JbnF1aXJ5U3RhdHVzSACIAQESDQoFbGltaXQYAiABKAUSDgoGb2Zmc2V0GAMgASgFQgkKB19zdGF0dXMiSwoVTGlzdElucXVpcmllc1Jlc3BvbnNlEiMKCWlucXVpcmllcxgBIAMoCzIQLmlucXVpcnkuSW5xdWlyeRINCgV0b3RhbBgCIAEoBSIfChFHZXRJbnF1aXJ5UmVxdWVzdBIKCgJpZBgBIAEoAyJIChJHZXRJbnF1aXJ5UmVzcG9uc2USJgoHaW5xdWlyeRgBIAEoCzIQLmlucXVpcnkuSW5xdWlyeUgAiAEBQgoKCF9pbnF1aXJ5IicKGU1hcmtJbnF1aXJ5SGFuZGxlZFJlcXVlc3QSCgoCaWQYASABKAMiHAoaTWFya0lucXVpcnlIYW5kbGVkUmVzcG9uc2UiIwoVQXJjaGl2ZUlucXVpcnlSZXF1ZXN0EgoKAmlkGAEgASgDIhgKFkFyY2hpdmVJbnF1aXJ5UmVzcG9uc2UiMgoWTWFya0lucXVpcnlTcGFtUmVxdWVzdBIKCgJpZBgBIAEoAxIMCgRzcGFtGAIgASgIIhkKF01hcmtJbnF1aXJ5U3BhbVJlc3BvbnNlIiIKFERlbGV0ZUlucXVpcnlSZXF1ZXN0EgoKAmlkGAEgASgDIhcKFURlbGV0ZUlucXVpcnlSZXNwb25zZSqgAQoNSW5xdWlyeVN0YXR1cxIeChpJTlFVSVJZX1NUQVRVU19VTlNQRUNJRklFRBAAEhYKEklOUVVJUllfU1RBVFVTX05FVxABEhoKFklOUVVJUllfU1RBVFVTX0hBTkRMRUQQAhIbChdJTlFVSVJZX1NUQVRVU19BUkNISVZFRBADEh4KGklOUVVJUllfU1RBVFVTX1FVQVJBTlRJTkVEEAQyrQEKDklucXVpcnlTZXJ2aWNlEksKDEdldEZvcm1Ub2tlbhIcLmlucXVpcnkuR2V0Rm9ybVRva2VuUmVxdWVzdBodLmlucXVpcnkuR2V0Rm9ybVRva2VuUmVzcG9uc2USTgoNU3VibWl0SW5xdWlyeRIdLmlucXVpcnkuU3VibWl0SW5xdWlyeVJlcXVlc3QaHi5pbnF1aXJ5LlN1Ym1pdElucXVpcnlSZXNwb25zZTKEBAoTSW5xdWlyeUFkbWluU2VydmljZRJOCg1MaXN0SW5xdWlyaWVzEh0uaW5xdWlyeS5MaXN0SW5xdWlyaWVzUmVxdWVzdBoeLmlucXVpcnkuTGlzdElucXVpcmllc1Jlc3BvbnNlEkUKCkdldElucXVpcnkSGi5pbnF1aXJ5LkdldElucXVpcnlSZXF1ZXN0GhsuaW5xdWlyeS5HZXRJbnF1aXJ5UmVzcG9uc2USXQoSTWFya0lucXVpcnlIYW5kbGVkEiIuaW5xdWlyeS5NYXJrSW5xdWlyeUhhbmRsZWRSZXF1ZXN0GiMuaW5xdWlyeS5NYXJrSW5xdWlyeUhhbmRsZWRSZXNwb25zZRJRCg5BcmNoaXZlSW5xdWlyeRIeLmlucXVpcnkuQXJjaGl2ZUlucXVpcnlSZXF1ZXN0Gh8uaW5xdWlyeS5BcmNoaXZlSW5xdWlyeVJlc3BvbnNlElQKD01hcmtJbnF1aXJ5U3BhbRIfLmlucXVpcnkuTWFya0lucXVpcnlTcGFtUmVxdWVzdBogLmlucXVpcnkuTWFya0lucXVpcnlTcGFtUmVzcG9uc2USTgoNRGVsZXRlSW5xdWlyeRIdLmlucXVpcnkuRGVsZXRlSW5xdWlyeVJlcXVlc3QaHi5pbnF1aXJ5LkRlbGV0ZUlucXVpcnlSZXNwb25zZWIGcHJvdG8z");
+
+/**
+ * @generated from message inquiry.Inquiry
+ */
+export type Inquiry = Message<"inquiry.Inquiry"> & {
+  /**
+   * @generated from field: int64 id = 1;
+   */
+  id: bigint;
+
+  /**
+   * @generated from field: string name = 2;
+   */
+  name: string;
+
+  /**
+   * @generated from field: string email = 3;
+   */
+  email: string;
+
+  /**
+   * @generated from field: string subject = 4;
+   */
+  subject: string;
+
+  /**
+   * @generated from field: string message = 5;
+   */
+  message: string;
+
+  /**
+   * @generated from field: inquiry.InquiryStatus status = 6;
+   */
+  status: InquiryStatus;
+
+  /**
+   * @generated from field: int64 created_at = 7;
+   */
+  createdAt: bigint;
+
+  /**
+   * @generated from field: optional int64 handled_at = 8;
+   */
+  handledAt?: bigint;
+
+  /**
+   * Firebase `sub` of the admin who marked it handled
+   *
+   * @generated from field: optional string handled_by = 9;
+   */
+  handledBy?: string;
+
+  /**
+   * @generated from field: optional int64 archived_at = 10;
+   */
+  archivedAt?: bigint;
+
+  /**
+   * Spam filter score at submission; quarantined from SPAM_THRESHOLD up
+   *
+   * @generated from field: double spam_score = 11;
+   */
+  spamScore: number;
+
+  /**
+   * Why the spam filter scored it, e.g. "honeypot field filled in"
+   *
+   * @generated from field: repeated string spam_reasons = 12;
+   */
+  spamReasons: string[];
+
+  /**
+   * Admin verdict, if any
+   *
+   * @generated from field: optional bool marked_spam = 13;
+   */
+  markedSpam?: boolean;
+};
+
+/**
+ * Describes the message inquiry.Inquiry.
+ * Use `create(InquirySchema)` to create a new message.
+ */
+export const InquirySchema: GenMessage<Inquiry> = /*@__PURE__*/
+  messageDesc(file_inquiry, 0);
+
+/**
+ * @generated from message inquiry.SubmitInquiryRequest
+ */
+export type SubmitInquiryRequest = Message<"inquiry.SubmitInquiryRequest"> & {
+  /**
+   * @generated from field: string name = 1;
+   */
+  name: string;
+
+  /**
+   * @generated from field: string email = 2;
+   */
+  email: string;
+
+  /**
+   * @generated from field: string subject = 3;
+   */
+  subject: string;
+
+  /**
+   * @generated from field: string message = 4;
+   */
+  message: string;
+
+  /**
+   * Honeypot: hidden from people by the form, so only bots fill it in
+   *
+   * @generated from field: string website = 5;
+   */
+  website: string;
+
+  /**
+   * From GetFormToken
+   *
+   * @generated from field: string form_token = 6;
+   */
+  formToken: string;
+};
+
+/**
+ * Describes the message inquiry.SubmitInquiryRequest.
+ * Use `create(SubmitInquiryRequestSchema)` to create a new message.
+ */
+export const SubmitInquiryRequestSchema: GenMessage<SubmitInquiryRequest> = /*@__PURE__*/
+  messageDesc(file_inquiry, 1);
+
+/**
+ * Deliberately the same whether or not the inquiry was quarantined
+ *
+ * @generated from message inquiry.SubmitInquiryResponse
+ */
+export type SubmitInquiryResponse = Message<"inquiry.SubmitInquiryResponse"> & {
+};
+
+/**
+ * Describes the message inquiry.SubmitInquiryResponse.
+ * Use `create(SubmitInquiryResponseSchema)` to create a new message.
+ */
+export const SubmitInquiryResponseSchema: GenMessage<SubmitInquiryResponse> = /*@__PURE__*/
+  messageDesc(file_inquiry, 2);
+
+/**
+ * @generated from message inquiry.GetFormTokenRequest
+ */
+export type GetFormTokenRequest = Message<"inquiry.GetFormTokenRequest"> & {
+};
+
+/**
+ * Describes the message inquiry.GetFormTokenRequest.
+ * Use `create(GetFormTokenRequestSchema)` to create a new message.
+ */
+export const GetFormTokenRequestSchema: GenMessage<GetFormTokenRequest> = /*@__PURE__*/
+  messageDesc(file_inquiry, 3);
+
+/**
+ * @generated from message inquiry.GetFormTokenResponse
+ */
+export type GetFormTokenResponse = Message<"inquiry.GetFormTokenResponse"> & {
+  /**
+   * @generated from field: string form_token = 1;
+   */
+  formToken: string;
+};
+
+/**
+ * Describes the message inquiry.GetFormTokenResponse.
+ * Use `create(GetFormTokenResponseSchema)` to create a new message.
+ */
+export const GetFormTokenResponseSchema: GenMessage<GetFormTokenResponse> = /*@__PURE__*/
+  messageDesc(file_inquiry, 4);
+
+/**
+ * @generated from message inquiry.ListInquiriesRequest
+ */
+export type ListInquiriesRequest = Message<"inquiry.ListInquiriesRequest"> & {
+  /**
+   * Unset lists the inbox: new and handled inquiries, but not archived or
+   * quarantined ones
+   *
+   * @generated from field: optional inquiry.InquiryStatus status = 1;
+   */
+  status?: InquiryStatus;
+
+  /**
+   * @generated from field: int32 limit = 2;
+   */
+  limit: number;
+
+  /**
+   * @generated from field: int32 offset = 3;
+   */
+  offset: number;
+};
+
+/**
+ * Describes the message inquiry.ListInquiriesRequest.
+ * Use `create(ListInquiriesRequestSchema)` to create a new message.
+ */
+export const ListInquiriesRequestSchema: GenMessage<ListInquiriesRequest> = /*@__PURE__*/
+  messageDesc(file_inquiry, 5);
+
+/**
+ * @generated from message inquiry.ListInquiriesResponse
+ */
+export type ListInquiriesResponse = Message<"inquiry.ListInquiriesResponse"> & {
+  /**
+   * @generated from field: repeated inquiry.Inquiry inquiries = 1;
+   */
+  inquiries: Inquiry[];
+
+  /**
+   * @generated from field: int32 total = 2;
+   */
+  total: number;
+};
+
+/**
+ * Describes the message inquiry.ListInquiriesResponse.
+ * Use `create(ListInquiriesResponseSchema)` to create a new message.
+ */
+export const ListInquiriesResponseSchema: GenMessage<ListInquiriesResponse> = /*@__PURE__*/
+  messageDesc(file_inquiry, 6);
+
+/**
+ * @generated from message inquiry.GetInquiryRequest
+ */
+export type GetInquiryRequest = Message<"inquiry.GetInquiryRequest"> & {
+  /**
+   * @generated from field: int64 id = 1;
+   */
+  id: bigint;
+};
+
+/**
+ * Describes the message inquiry.GetInquiryRequest.
+ * Use `create(GetInquiryRequestSchema)` to create a new message.
+ */
+export const GetInquiryRequestSchema: GenMessage<GetInquiryRequest> = /*@__PURE__*/
+  messageDesc(file_inquiry, 7);
+
+/**
+ * @generated from message inquiry.GetInquiryResponse
+ */
+export type GetInquiryResponse = Message<"inquiry.GetInquiryResponse"> & {
+  /**
+   * @generated from field: optional inquiry.Inquiry inquiry = 1;
+   */
+  inquiry?: Inquiry;
+};
+
+/**
+ * Describes the message inquiry.GetInquiryResponse.
+ * Use `create(GetInquiryResponseSchema)` to create a new message.
+ */
+export const GetInquiryResponseSchema: GenMessage<GetInquiryResponse> = /*@__PURE__*/
+  messageDesc(file_inquiry, 8);
+
+/**
+ * @generated from message inquiry.MarkInquiryHandledRequest
+ */
+export type MarkInquiryHandledRequest = Message<"inquiry.MarkInquiryHandledRequest"> & {
+  /**
+   * @generated from field: int64 id = 1;
+   */
+  id: bigint;
+};
+
+/**
+ * Describes the message inquiry.MarkInquiryHandledRequest.
+ * Use `create(MarkInquiryHandledRequestSchema)` to create a new message.
+ */
+export const MarkInquiryHandledRequestSchema: GenMessage<MarkInquiryHandledRequest> = /*@__PURE__*/
+  messageDesc(file_inquiry, 9);
+
+/**
+ * @generated from message inquiry.MarkInquiryHandledResponse
+ */
+export type MarkInquiryHandledResponse = Message<"inquiry.MarkInquiryHandledResponse"> & {
+};
+
+/**
+ * Describes the message inquiry.MarkInquiryHandledResponse.
+ * Use `create(MarkInquiryHandledResponseSchema)` to create a new message.
+ */
+export const MarkInquiryHandledResponseSchema: GenMessage<MarkInquiryHandledResponse> = /*@__PURE__*/
+  messageDesc(file_inquiry, 10);
+
+/**
+ * @generated from message inquiry.ArchiveInquiryRequest
+ */
+export type ArchiveInquiryRequest = Message<"inquiry.ArchiveInquiryRequest"> & {
+  /**
+   * @generated from field: int64 id = 1;
+   */
+  id: bigint;
+};
+
+/**
+ * Describes the message inquiry.ArchiveInquiryRequest.
+ * Use `create(ArchiveInquiryRequestSchema)` to create a new message.
+ */
+export const ArchiveInquiryRequestSchema: GenMessage<ArchiveInquiryRequest> = /*@__PURE__*/
+  messageDesc(file_inquiry, 11);
+
+/**
+ * @generated from message inquiry.ArchiveInquiryResponse
+ */
+export type ArchiveInquiryResponse = Message<"inquiry.ArchiveInquiryResponse"> & {
+};
+
+/**
+ * Describes the message inquiry.ArchiveInquiryResponse.
+ * Use `create(ArchiveInquiryResponseSchema)` to create a new message.
+ */
+export const ArchiveInquiryResponseSchema: GenMessage<ArchiveInquiryResponse> = /*@__PURE__*/
+  messageDesc(file_inquiry, 12);
+
+/**
+ * @generated from message inquiry.MarkInquirySpamRequest
+ */
+export type MarkInquirySpamRequest = Message<"inquiry.MarkInquirySpamRequest"> & {
+  /**
+   * @generated from field: int64 id = 1;
+   */
+  id: bigint;
+
+  /**
+   * @generated from field: bool spam = 2;
+   */
+  spam: boolean;
+};
+
+/**
+ * Describes the message inquiry.MarkInquirySpamRequest.
+ * Use `create(MarkInquirySpamRequestSchema)` to create a new message.
+ */
+export const MarkInquirySpamRequestSchema: GenMessage<MarkInquirySpamRequest> = /*@__PURE__*/
+  messageDesc(file_inquiry, 13);
+
+/**
+ * @generated from message inquiry.MarkInquirySpamResponse
+ */
+export type MarkInquirySpamResponse = Message<"inquiry.MarkInquirySpamResponse"> & {
+};
+
+/**
+ * Describes the message inquiry.MarkInquirySpamResponse.
+ * Use `create(MarkInquirySpamResponseSchema)` to create a new message.
+ */
+export const MarkInquirySpamResponseSchema: GenMessage<MarkInquirySpamResponse> = /*@__PURE__*/
+  messageDesc(file_inquiry, 14);
+
+/**
+ * @generated from message inquiry.DeleteInquiryRequest
+ */
+export type DeleteInquiryRequest = Message<"inquiry.DeleteInquiryRequest"> & {
+  /**
+   * @generated from field: int64 id = 1;
+   */
+  id: bigint;
+};
+
+/**
+ * Describes the message inquiry.DeleteInquiryRequest.
+ * Use `create(DeleteInquiryRequestSchema)` to create a new message.
+ */
+export const DeleteInquiryRequestSchema: GenMessage<DeleteInquiryRequest> = /*@__PURE__*/
+  messageDesc(file_inquiry, 15);
+
+/**
+ * @generated from message inquiry.DeleteInquiryResponse
+ */
+export type DeleteInquiryResponse = Message<"inquiry.DeleteInquiryResponse"> & {
+};
+
+/**
+ * Describes the message inquiry.DeleteInquiryResponse.
+ * Use `create(DeleteInquiryResponseSchema)` to create a new message.
+ */
+export const DeleteInquiryResponseSchema: GenMessage<DeleteInquiryResponse> = /*@__PURE__*/
+  messageDesc(file_inquiry, 16);
+
+/**
+ * @generated from enum inquiry.InquiryStatus
+ */
+export enum InquiryStatus {
+  /**
+   * @generated from enum value: INQUIRY_STATUS_UNSPECIFIED = 0;
+   */
+  UNSPECIFIED = 0,
+
+  /**
+   * @generated from enum value: INQUIRY_STATUS_NEW = 1;
+   */
+  NEW = 1,
+
+  /**
+   * @generated from enum value: INQUIRY_STATUS_HANDLED = 2;
+   */
+  HANDLED = 2,
+
+  /**
+   * @generated from enum value: INQUIRY_STATUS_ARCHIVED = 3;
+   */
+  ARCHIVED = 3,
+
+  /**
+   * Flagged by the spam filter, or marked as spam by an admin
+   *
+   * @generated from enum value: INQUIRY_STATUS_QUARANTINED = 4;
+   */
+  QUARANTINED = 4,
+}
+
+/**
+ * Describes the enum inquiry.InquiryStatus.
+ */
+export const InquiryStatusSchema: GenEnum<InquiryStatus> = /*@__PURE__*/
+  enumDesc(file_inquiry, 0);
+
+/**
+ * Public contact form
+ *
+ * @generated from service inquiry.InquiryService
+ */
+export const InquiryService: GenService<{
+  /**
+   * Token to send back with the submission; forms submitted too soon after
+   * it was issued are treated as spam
+   *
+   * @generated from rpc inquiry.InquiryService.GetFormToken
+   */
+  getFormToken: {
+    methodKind: "unary";
+    input: typeof GetFormTokenRequestSchema;
+    output: typeof GetFormTokenResponseSchema;
+  },
+  /**
+   * @generated from rpc inquiry.InquiryService.SubmitInquiry
+   */
+  submitInquiry: {
+    methodKind: "unary";
+    input: typeof SubmitInquiryRequestSchema;
+    output: typeof SubmitInquiryResponseSchema;
+  },
+}> = /*@__PURE__*/
+  serviceDesc(file_inquiry, 0);
+
+/**
+ * Protected inquiry inbox (requires authentication)
+ *
+ * @generated from service inquiry.InquiryAdminService
+ */
+export const InquiryAdminService: GenService<{
+  /**
+   * @generated from rpc inquiry.InquiryAdminService.ListInquiries
+   */
+  listInquiries: {
+    methodKind: "unary";
+    input: typeof ListInquiriesRequestSchema;
+    output: typeof ListInquiriesResponseSchema;
+  },
+  /**
+   * @generated from rpc inquiry.InquiryAdminService.GetInquiry
+   */
+  getInquiry: {
+    methodKind: "unary";
+    input: typeof GetInquiryRequestSchema;
+    output: typeof GetInquiryResponseSchema;
+  },
+  /**
+   * @generated from rpc inquiry.InquiryAdminService.MarkInquiryHandled
+   */
+  markInquiryHandled: {
+    methodKind: "unary";
+    input: typeof MarkInquiryHandledRequestSchema;
+    output: typeof MarkInquiryHandledResponseSchema;
+  },
+  /**
+   * @generated from rpc inquiry.InquiryAdminService.ArchiveInquiry
+   */
+  archiveInquiry: {
+    methodKind: "unary";
+    input: typeof ArchiveInquiryRequestSchema;
+    output: typeof ArchiveInquiryResponseSchema;
+  },
+  /**
+   * Quarantine an inquiry as spam, or release it to the inbox, and train the
+   * spam classifier with the verdict
+   *
+   * @generated from rpc inquiry.InquiryAdminService.MarkInquirySpam
+   */
+  markInquirySpam: {
+    methodKind: "unary";
+    input: typeof MarkInquirySpamRequestSchema;
+    output: typeof MarkInquirySpamResponseSchema;
+  },
+  /**
+   * Permanently removes the inquiry
+   *
+   * @generated from rpc inquiry.InquiryAdminService.DeleteInquiry
+   */
+  deleteInquiry: {
+    methodKind: "unary";
+    input: typeof DeleteInquiryRequestSchema;
+    output: typeof DeleteInquiryResponseSchema;
+  },
+}> = /*@__PURE__*/
+  serviceDesc(file_inquiry, 1);
+
@@ -0,0 +1,623 @@
+// @generated by protoc-gen-es v2.10.2 with parameter "target=ts"
+// @generated from file webhook.proto (package webhook, syntax proto3)
+/* eslint-disable */
+
+import type { GenEnum, GenFile, GenMessage, GenService } from "@bufbuild/protobuf/codegenv2";
+import { enumDesc, fileDesc, messageDesc, serviceDesc } from "@bufbuild/protobuf/codegenv2";
+import type { Message } from "@bufbuild/protobuf";
+
+/**
+ * Describes the file webhook.proto.
+ */
+export const file_webhook: GenFile = /*@__PURE__*/
+  fileDesc("Cg13ZWJob29rLnByb3RvEgd3ZWJob29rIqkBCgdXZWJob29rEgoKAmlkGAEgASgDEgsKA3VybBgCIAEoCRIlCgZldmVudHMYAyADKA4yFS53ZWJob29rLldlYmhvb2tFdmVudBIOCgZhY3RpdmUYBCABKAgSEgoKY3JlYXRlZF9hdBgFIAEoAxISCgp1cGRhdGVkX2F0GAYgASgDEhcKCmNyZWF0ZWRfYnkYByABKAlIAIgBAUINCgtfY3JlYXRlZF9ieSKLAwoPV2ViaG9va0RlbGl2ZXJ5EgoKAmlkGAEgASgDEhIKCndlYmhvb2tfaWQYAiABKAMSJAoFZXZlbnQYAyABKA4yFS53ZWJob29rLldlYmhvb2tFdmVudBIPCgdwYXlsb2FkGAQgASgJEi4KBnN0YXR1cxgFIAEoDjIeLndlYmhvb2suV2ViaG9va0RlbGl2ZXJ5U3RhdHVzEhAKCGF0dGVtcHRzGAYgASgFEhcKD25leHRfYXR0ZW1wdF9hdBgHIAEoAxIcCg9yZXNwb25zZV9zdGF0dXMYCCABKAVIAIgBARIXCgpsYXN0X2Vycm9yGAkgASgJSAGIAQESEgoKY3JlYXRlZF9hdBgKIAEoAxIZCgxkZWxpdmVyZWRfYXQYCyABKANIAogBARIaCg1yZWRlbGl2ZXJ5X29mGAwgASgDSAOIAQFCEgoQX3Jlc3BvbnNlX3N0YXR1c0INCgtfbGFzdF9lcnJvckIPCg1fZGVsaXZlcmVkX2F0QhAKDl9yZWRlbGl2ZXJ5X29mIhUKE0xpc3RXZWJob29rc1JlcXVlc3QiOgoUTGlzdFdlYmhvb2tzUmVzcG9uc2USIgoId2ViaG9va3MYASADKAsyEC53ZWJob29rLldlYmhvb2siHwoRR2V0V2ViaG9va1JlcXVlc3QSCgoCaWQYASABKAMiSAoSR2V0V2ViaG9va1Jlc3BvbnNlEiYKB3dlYmhvb2sYASABKAsyEC53ZWJob29rLldlYmhvb2tIAIgBAUIKCghfd2ViaG9vayJaChRDcmVhdGVXZWJob29rUmVxdWVzdBILCgN1cmwYASABKAkSDgoGc2VjcmV0GAIgASgJEiUKBmV2ZW50cxgDIAMoDjIVLndlYmhvb2suV2ViaG9va0V2ZW50IkoKFUNyZWF0ZVdlYmhvb2tSZXNwb25zZRIhCgd3ZWJob29rGAEgASgLMhAud2ViaG9vay5XZWJob29rEg4KBnNlY3JldBgCIAEoCSI5ChBXZWJob29rRXZlbnRMaXN0EiUKBmV2ZW50cxgBIAMoDjIVLndlYmhvb2suV2ViaG9va0V2ZW50IqcBChRVcGRhdGVXZWJob29rUmVxdWVzdBIKCgJpZBgBIAEoAxIQCgN1cmwYAiABKAlIAIgBARITCgZzZWNyZXQYAyABKAlIAYgBARIpCgZldmVudHMYBCABKAsyGS53ZWJob29rLldlYmhvb2tFdmVudExpc3QSEwoGYWN0aXZlGAUgASgISAKIAQFCBgoEX3VybEIJCgdfc2VjcmV0QgkKB19hY3RpdmUiFwoVVXBkYXRlV2ViaG9va1Jlc3BvbnNlIiIKFERlbGV0ZVdlYmhvb2tSZXF1ZXN0EgoKAmlkGAEgASgDIhcKFURlbGV0ZVdlYmhvb2tSZXNwb25zZSKRAQocTGlzdFdlYmhvb2tEZWxpdmVyaWVzUmVxdWVzdBISCgp3ZWJob29rX2lkGAEgASgDEjMKBnN0YXR1cxgCIAEoDjIeLndlYmhvb2suV2ViaG9va0RlbGl2ZXJ5U3RhdHVzSACIAQESDQoFbGltaXQYAyABKAUSDgoGb2Zmc2V0GAQgASgFQgkKB19zdGF0dXMiXAodTGlzdFdlYmhvb2tEZWxpdmVyaWVzUmVzcG9uc2USLAoKZGVsaXZlcmllcxgBIAMoCzIYLndlYmhvb2suV2ViaG9va0RlbGl2ZXJ5Eg0KBXRvdGFsGAIgASgFIi4KF1JlZGVsaXZlcldlYmhvb2tSZXF1ZXN0EhMKC2RlbGl2ZXJ5X2lkGAEgASgDIi8KGFJlZGVsaXZlcldlYmhvb2tSZXNwb25zZRITCgtkZWxpdmVyeV9pZBgBIAEoAyrQAQoMV2ViaG9va0V2ZW50Eh0KGVdFQkhPT0tfRVZFTlRfVU5TUEVDSUZJRUQQABIeChpXRUJIT09LX0VWRU5UX1BPU1RfQ1JFQVRFRBABEh4KGldFQkhPT0tfRVZFTlRfUE9TVF9VUERBVEVEEAISHgoaV0VCSE9PS19FVkVOVF9QT1NUX0RFTEVURUQQAxIfChtXRUJIT09LX0VWRU5UX1BPU1RfUkVTVE9SRUQQBBIgChxXRUJIT09LX0VWRU5UX1BPU1RfUFVCTElTSEVEEAUqsAEKFVdlYmhvb2tEZWxpdmVyeVN0YXR1cxInCiNXRUJIT09LX0RFTElWRVJZX1NUQVRVU19VTlNQRUNJRklFRBAAEiMKH1dFQkhPT0tfREVMSVZFUllfU1RBVFVTX1BFTkRJTkcQARIlCiFXRUJIT09LX0RFTElWRVJZX1NUQVRVU19ERUxJVkVSRUQQAhIiCh5XRUJIT09LX0RFTElWRVJZX1NUQVRVU19GQUlMRUQQAzLaBAoTV2ViaG9va0FkbWluU2VydmljZRJLCgxMaXN0V2ViaG9va3MSHC53ZWJob29rLkxpc3RXZWJob29rc1JlcXVlc3QaHS53ZWJob29rLkxpc3RXZWJob29rc1Jlc3BvbnNlEkUKCkdldFdlYmhvb2sSGi53ZWJob29rLkdldFdlYmhvb2tSZXF1ZXN0Ghsud2ViaG9vay5HZXRXZWJob29rUmVzcG9uc2USTgoNQ3JlYXRlV2ViaG9vaxIdLndlYmhvb2suQ3JlYXRlV2ViaG9va1JlcXVlc3QaHi53ZWJob29rLkNyZWF0ZVdlYmhvb2tSZXNwb25zZRJOCg1VcGRhdGVXZWJob29rEh0ud2ViaG9vay5VcGRhdGVXZWJob29rUmVxdWVzdBoeLndlYmhvb2suVXBkYXRlV2ViaG9va1Jlc3BvbnNlEk4KDURlbGV0ZVdlYmhvb2sSHS53ZWJob29rLkRlbGV0ZVdlYmhvb2tSZXF1ZXN0Gh4ud2ViaG9vay5EZWxldGVXZWJob29rUmVzcG9uc2USZgoVTGlzdFdlYmhvb2tEZWxpdmVyaWVzEiUud2ViaG9vay5MaXN0V2ViaG9va0RlbGl2ZXJpZXNSZXF1ZXN0GiYud2ViaG9vay5MaXN0V2ViaG9va0RlbGl2ZXJpZXNSZXNwb25zZRJXChBSZWRlbGl2ZXJXZWJob29rEiAud2ViaG9vay5SZWRlbGl2ZXJXZWJob29rUmVxdWVzdBohLndlYmhvb2suUmVkZWxpdmVyV2ViaG9va1Jlc3BvbnNlYgZwcm90bzM");
+
+/**
+ * @generated from message webhook.Webhook
+ */
+export type Webhook = Message<"webhook.Webhook"> & {
+  /**
+   * @generated from field: int64 id = 1;
+   */
+  id: bigint;
+
+  /**
+   * @generated from field: string url = 2;
+   */
+  url: string;
+
+  /**
+   * Events delivered to it; empty means every event
+   *
+   * @generated from field: repeated webhook.WebhookEvent events = 3;
+   */
+  events: WebhookEvent[];
+
+  /**
+   * Inactive webhooks get no new deliveries
+   *
+   * @generated from field: bool active = 4;
+   */
+  active: boolean;
+
+  /**
+   * @generated from field: int64 created_at = 5;
+   */
+  createdAt: bigint;
+
+  /**
+   * @generated from field: int64 updated_at = 6;
+   */
+  updatedAt: bigint;
+
+  /**
+   * Firebase `sub` of the admin who created it
+   *
+   * @generated from field: optional string created_by = 7;
+   */
+  createdBy?: string;
+};
+
+/**
+ * Describes the message webhook.Webhook.
+ * Use `create(WebhookSchema)` to create a new message.
+ */
+export const WebhookSchema: GenMessage<Webhook> = /*@__PURE__*/
+  messageDesc(file_webhook, 0);
+
+/**
+ * @generated from message webhook.WebhookDelivery
+ */
+export type WebhookDelivery = Message<"webhook.WebhookDelivery"> & {
+  /**
+   * @generated from field: int64 id = 1;
+   */
+  id: bigint;
+
+  /**
+   * @generated from field: int64 webhook_id = 2;
+   */
+  webhookId: bigint;
+
+  /**
+   * @generated from field: webhook.WebhookEvent event = 3;
+   */
+  event: WebhookEvent;
+
+  /**
+   * JSON body sent
+   *
+   * @generated from field: string payload = 4;
+   */
+  payload: string;
+
+  /**
+   * @generated from field: webhook.WebhookDeliveryStatus status = 5;
+   */
+  status: WebhookDeliveryStatus;
+
+  /**
+   * @generated from field: int32 attempts = 6;
+   */
+  attempts: number;
+
+  /**
+   * When the next attempt is due, while pending
+   *
+   * @generated from field: int64 next_attempt_at = 7;
+   */
+  nextAttemptAt: bigint;
+
+  /**
+   * HTTP status of the last response, if there was one
+   *
+   * @generated from field: optional int32 response_status = 8;
+   */
+  responseStatus?: number;
+
+  /**
+   * @generated from field: optional string last_error = 9;
+   */
+  lastError?: string;
+
+  /**
+   * @generated from field: int64 created_at = 10;
+   */
+  createdAt: bigint;
+
+  /**
+   * @generated from field: optional int64 delivered_at = 11;
+   */
+  deliveredAt?: bigint;
+
+  /**
+   * Delivery whose payload this one resends
+   *
+   * @generated from field: optional int64 redelivery_of = 12;
+   */
+  redeliveryOf?: bigint;
+};
+
+/**
+ * Describes the message webhook.WebhookDelivery.
+ * Use `create(WebhookDeliverySchema)` to create a new message.
+ */
+export const WebhookDeliverySchema: GenMessage<WebhookDelivery> = /*@__PURE__*/
+  messageDesc(file_webhook, 1);
+
+/**
+ * @generated from message webhook.ListWebhooksRequest
+ */
+export type ListWebhooksRequest = Message<"webhook.ListWebhooksRequest"> & {
+};
+
+/**
+ * Describes the message webhook.ListWebhooksRequest.
+ * Use `create(ListWebhooksRequestSchema)` to create a new message.
+ */
+export const ListWebhooksRequestSchema: GenMessage<ListWebhooksRequest> = /*@__PURE__*/
+  messageDesc(file_webhook, 2);
+
+/**
+ * @generated from message webhook.ListWebhooksResponse
+ */
+export type ListWebhooksResponse = Message<"webhook.ListWebhooksResponse"> & {
+  /**
+   * @generated from field: repeated webhook.Webhook webhooks = 1;
+   */
+  webhooks: Webhook[];
+};
+
+/**
+ * Describes the message webhook.ListWebhooksResponse.
+ * Use `create(ListWebhooksResponseSchema)` to create a new message.
+ */
+export const ListWebhooksResponseSchema: GenMessage<ListWebhooksResponse> = /*@__PURE__*/
+  messageDesc(file_webhook, 3);
+
+/**
+ * @generated from message webhook.GetWebhookRequest
+ */
+export type GetWebhookRequest = Message<"webhook.GetWebhookRequest"> & {
+  /**
+   * @generated from field: int64 id = 1;
+   */
+  id: bigint;
+};
+
+/**
+ * Describes the message webhook.GetWebhookRequest.
+ * Use `create(GetWebhookRequestSchema)` to create a new message.
+ */
+export const GetWebhookRequestSchema: GenMessage<GetWebhookRequest> = /*@__PURE__*/
+  messageDesc(file_webhook, 4);
+
+/**
+ * @generated from message webhook.GetWebhookResponse
+ */
+export type GetWebhookResponse = Message<"webhook.GetWebhookResponse"> & {
+  /**
+   * @generated from field: optional webhook.Webhook webhook = 1;
+   */
+  webhook?: Webhook;
+};
+
+/**
+ * Describes the message webhook.GetWebhookResponse.
+ * Use `create(GetWebhookResponseSchema)` to create a new message.
+ */
+export const GetWebhookResponseSchema: GenMessage<GetWebhookResponse> = /*@__PURE__*/
+  messageDesc(file_webhook, 5);
+
+/**
+ * @generated from message webhook.CreateWebhookRequest
+ */
+export type CreateWebhookRequest = Message<"webhook.CreateWebhookRequest"> & {
+  /**
+   * http or https URL
+   *
+   * @generated from field: string url = 1;
+   */
+  url: string;
+
+  /**
+   * Key for the payload signatures; one is generated if empty
+   *
+   * @generated from field: string secret = 2;
+   */
+  secret: string;
+
+  /**
+   * Empty subscribes to every event
+   *
+   * @generated from field: repeated webhook.WebhookEvent events = 3;
+   */
+  events: WebhookEvent[];
+};
+
+/**
+ * Describes the message webhook.CreateWebhookRequest.
+ * Use `create(CreateWebhookRequestSchema)` to create a new message.
+ */
+export const CreateWebhookRequestSchema: GenMessage<CreateWebhookRequest> = /*@__PURE__*/
+  messageDesc(file_webhook, 6);
+
+/**
+ * @generated from message webhook.CreateWebhookResponse
+ */
+export type CreateWebhookResponse = Message<"webhook.CreateWebhookResponse"> & {
+  /**
+   * @generated from field: webhook.Webhook webhook = 1;
+   */
+  webhook?: Webhook;
+
+  /**
+   * Only returned here; keep it to verify signatures
+   *
+   * @generated from field: string secret = 2;
+   */
+  secret: string;
+};
+
+/**
+ * Describes the message webhook.CreateWebhookResponse.
+ * Use `create(CreateWebhookResponseSchema)` to create a new message.
+ */
+export const CreateWebhookResponseSchema: GenMessage<CreateWebhookResponse> = /*@__PURE__*/
+  messageDesc(file_webhook, 7);
+
+/**
+ * @generated from message webhook.WebhookEventList
+ */
+export type WebhookEventList = Message<"webhook.WebhookEventList"> & {
+  /**
+   * @generated from field: repeated webhook.WebhookEvent events = 1;
+   */
+  events: WebhookEvent[];
+};
+
+/**
+ * Describes the message webhook.WebhookEventList.
+ * Use `create(WebhookEventListSchema)` to create a new message.
+ */
+export const WebhookEventListSchema: GenMessage<WebhookEventList> = /*@__PURE__*/
+  messageDesc(file_webhook, 8);
+
+/**
+ * @generated from message webhook.UpdateWebhookRequest
+ */
+export type UpdateWebhookRequest = Message<"webhook.UpdateWebhookRequest"> & {
+  /**
+   * @generated from field: int64 id = 1;
+   */
+  id: bigint;
+
+  /**
+   * @generated from field: optional string url = 2;
+   */
+  url?: string;
+
+  /**
+   * Replaces the secret when set
+   *
+   * @generated from field: optional string secret = 3;
+   */
+  secret?: string;
+
+  /**
+   * Replaces the event filter when set; leaves it unchanged when unset
+   *
+   * @generated from field: webhook.WebhookEventList events = 4;
+   */
+  events?: WebhookEventList;
+
+  /**
+   * @generated from field: optional bool active = 5;
+   */
+  active?: boolean;
+};
+
+/**
+ * Describes the message webhook.UpdateWebhookRequest.
+ * Use `create(UpdateWebhookRequestSchema)` to create a new message.
+ */
+export const UpdateWebhookRequestSchema: GenMessage<UpdateWebhookRequest> = /*@__PURE__*/
+  messageDesc(file_webhook, 9);
+
+/**
+ * @generated from message webhook.UpdateWebhookResponse
+ */
+export type UpdateWebhookResponse = Message<"webhook.UpdateWebhookResponse"> & {
+};
+
+/**
+ * Describes the message webhook.UpdateWebhookResponse.
+ * Use `create(UpdateWebhookResponseSchema)` to create a new message.
+ */
+export const UpdateWebhookResponseSchema: GenMessage<UpdateWebhookResponse> = /*@__PURE__*/
+  messageDesc(file_webhook, 10);
+
+/**
+ * @generated from message webhook.DeleteWebhookRequest
+ */
+export type DeleteWebhookRequest = Message<"webhook.DeleteWebhookRequest"> & {
+  /**
+   * @generated from field: int64 id = 1;
+   */
+  id: bigint;
+};
+
+/**
+ * Describes the message webhook.DeleteWebhookRequest.
+ * Use `create(DeleteWebhookRequestSchema)` to create a new message.
+ */
+export const DeleteWebhookRequestSchema: GenMessage<DeleteWebhookRequest> = /*@__PURE__*/
+  messageDesc(file_webhook, 11);
+
+/**
+ * @generated from message webhook.DeleteWebhookResponse
+ */
+export type DeleteWebhookResponse = Message<"webhook.DeleteWebhookResponse"> & {
+};
+
+/**
+ * Describes the message webhook.DeleteWebhookResponse.
+ * Use `create(DeleteWebhookResponseSchema)` to create a new message.
+ */
+export const DeleteWebhookResponseSchema: GenMessage<DeleteWebhookResponse> = /*@__PURE__*/
+  messageDesc(file_webhook, 12);
+
+/**
+ * @generated from message webhook.ListWebhookDeliveriesRequest
+ */
+export type ListWebhookDeliveriesRequest = Message<"webhook.ListWebhookDeliveriesRequest"> & {
+  /**
+   * @generated from field: int64 webhook_id = 1;
+   */
+  webhookId: bigint;
+
+  /**
+   * @generated from field: optional webhook.WebhookDeliveryStatus status = 2;
+   */
+  status?: WebhookDeliveryStatus;
+
+  /**
+   * @generated from field: int32 limit = 3;
+   */
+  limit: number;
+
+  /**
+   * @generated from field: int32 offset = 4;
+   */
+  offset: number;
+};
+
+/**
+ * Describes the message webhook.ListWebhookDeliveriesRequest.
+ * Use `create(ListWebhookDeliveriesRequestSchema)` to create a new message.
+ */
+export const ListWebhookDeliveriesRequestSchema: GenMessage<ListWebhookDeliveriesRequest> = /*@__PURE__*/
+  messageDesc(file_webhook, 13);
+
+/**
+ * @generated from message webhook.ListWebhookDeliveriesResponse
+ */
+export type ListWebhookDeliveriesResponse = Message<"webhook.ListWebhookDeliveriesResponse"> & {
+  /**
+   * @generated from field: repeated webhook.WebhookDelivery deliveries = 1;
+   */
+  deliveries: WebhookDelivery[];
+
+  /**
+   * @generated from field: int32 total = 2;
+   */
+  total: number;
+};
+
+/**
+ * Describes the message webhook.ListWebhookDeliveriesResponse.
+ * Use `create(ListWebhookDeliveriesResponseSchema)` to create a new message.
+ */
+export const ListWebhookDeliveriesResponseSchema: GenMessage<ListWebhookDeliveriesResponse> = /*@__PURE__*/
+  messageDesc(file_webhook, 14);
+
+/**
+ * @generated from message webhook.RedeliverWebhookRequest
+ */
+export type RedeliverWebhookRequest = Message<"webhook.RedeliverWebhookRequest"> & {
+  /**
+   * Delivery to resend
+   *
+   * @generated from field: int64 delivery_id = 1;
+   */
+  deliveryId: bigint;
+};
+
+/**
+ * Describes the message webhook.RedeliverWebhookRequest.
+ * Use `create(RedeliverWebhookRequestSchema)` to create a new message.
+ */
+export const RedeliverWebhookRequestSchema: GenMessage<RedeliverWebhookRequest> = /*@__PURE__*/
+  messageDesc(file_webhook, 15);
+
+/**
+ * @generated from message webhook.RedeliverWebhookResponse
+ */
+export type RedeliverWebhookResponse = Message<"webhook.RedeliverWebhookResponse"> & {
+  /**
+   * Id of the new delivery
+   *
+   * @generated from field: int64 delivery_id = 1;
+   */
+  deliveryId: bigint;
+};
+
+/**
+ * Describes the message webhook.RedeliverWebhookResponse.
+ * Use `create(RedeliverWebhookResponseSchema)` to create a new message.
+ */
+export const RedeliverWebhookResponseSchema: GenMessage<RedeliverWebhookResponse> = /*@__PURE__*/
+  messageDesc(file_webhook, 16);
+
+/**
+ * @generated from enum webhook.WebhookEvent
+ */
+export enum WebhookEvent {
+  /**
+   * @generated from enum value: WEBHOOK_EVENT_UNSPECIFIED = 0;
+   */
+  UNSPECIFIED = 0,
+
+  /**
+   * @generated from enum value: WEBHOOK_EVENT_POST_CREATED = 1;
+   */
+  POST_CREATED = 1,
+
+  /**
+   * Edited, reverted to an earlier revision, unpublished or scheduled, or one
+   * of its tags renamed or merged into another
+   *
+   * @generated from enum value: WEBHOOK_EVENT_POST_UPDATED = 2;
+   */
+  POST_UPDATED = 2,
+
+  /**
+   * @generated from enum value: WEBHOOK_EVENT_POST_DELETED = 3;
+   */
+  POST_DELETED = 3,
+
+  /**
+   * @generated from enum value: WEBHOOK_EVENT_POST_RESTORED = 4;
+   */
+  POST_RESTORED = 4,
+
+  /**
+   * Published by an admin, or by the scheduler once its time came
+   *
+   * @generated from enum value: WEBHOOK_EVENT_POST_PUBLISHED = 5;
+   */
+  POST_PUBLISHED = 5,
+}
+
+/**
+ * Describes the enum webhook.WebhookEvent.
+ */
+export const WebhookEventSchema: GenEnum<WebhookEvent> = /*@__PURE__*/
+  enumDesc(file_webhook, 0);
+
+/**
+ * @generated from enum webhook.WebhookDeliveryStatus
+ */
+export enum WebhookDeliveryStatus {
+  /**
+   * @generated from enum value: WEBHOOK_DELIVERY_STATUS_UNSPECIFIED = 0;
+   */
+  UNSPECIFIED = 0,
+
+  /**
+   * Waiting for its first attempt or a retry
+   *
+   * @generated from enum value: WEBHOOK_DELIVERY_STATUS_PENDING = 1;
+   */
+  PENDING = 1,
+
+  /**
+   * @generated from enum value: WEBHOOK_DELIVERY_STATUS_DELIVERED = 2;
+   */
+  DELIVERED = 2,
+
+  /**
+   * Gave up after too many attempts
+   *
+   * @generated from enum value: WEBHOOK_DELIVERY_STATUS_FAILED = 3;
+   */
+  FAILED = 3,
+}
+
+/**
+ * Describes the enum webhook.WebhookDeliveryStatus.
+ */
+export const WebhookDeliveryStatusSchema: GenEnum<WebhookDeliveryStatus> = /*@__PURE__*/
+  enumDesc(file_webhook, 1);
+
+/**
+ * Outgoing webhooks for blog content events (requires authentication).
+ *
+ * Each delivery is a POST of a JSON payload with these headers:
+ *   X-Webhook-Event: the event, e.g. post.published
+ *   X-Webhook-Delivery: delivery id, the same across retries
+ *   X-Webhook-Timestamp: Unix timestamp (seconds) of the attempt
+ *   X-Webhook-Signature: sha256=<hex HMAC-SHA256 of "<timestamp>.<body>"
+ *     keyed with the webhook's secret>
+ * Any 2xx response counts as delivered; anything else, redirects included, is
+ * retried with exponential backoff. URLs must resolve to public addresses
+ * unless the server sets WEBHOOK_ALLOW_PRIVATE_URLS.
+ *
+ * @generated from service webhook.WebhookAdminService
+ */
+export const WebhookAdminService: GenService<{
+  /**
+   * @generated from rpc webhook.WebhookAdminService.ListWebhooks
+   */
+  listWebhooks: {
+    methodKind: "unary";
+    input: typeof ListWebhooksRequestSchema;
+    output: typeof ListWebhooksResponseSchema;
+  },
+  /**
+   * @generated from rpc webhook.WebhookAdminService.GetWebhook
+   */
+  getWebhook: {
+    methodKind: "unary";
+    input: typeof GetWebhookRequestSchema;
+    output: typeof GetWebhookResponseSchema;
+  },
+  /**
+   * @generated from rpc webhook.WebhookAdminService.CreateWebhook
+   */
+  createWebhook: {
+    methodKind: "unary";
+    input: typeof CreateWebhookRequestSchema;
+    output: typeof CreateWebhookResponseSchema;
+  },
+  /**
+   * @generated from rpc webhook.WebhookAdminService.UpdateWebhook
+   */
+  updateWebhook: {
+    methodKind: "unary";
+    input: typeof UpdateWebhookRequestSchema;
+    output: typeof UpdateWebhookResponseSchema;
+  },
+  /**
+   * Also removes its delivery log
+   *
+   * @generated from rpc webhook.WebhookAdminService.DeleteWebhook
+   */
+  deleteWebhook: {
+    methodKind: "unary";
+    input: typeof DeleteWebhookRequestSchema;
+    output: typeof DeleteWebhookResponseSchema;
+  },
+  /**
+   * Deliveries of a webhook, newest first
+   *
+   * @generated from rpc webhook.WebhookAdminService.ListWebhookDeliveries
+   */
+  listWebhookDeliveries: {
+    methodKind: "unary";
+    input: typeof ListWebhookDeliveriesRequestSchema;
+    output: typeof ListWebhookDeliveriesResponseSchema;
+  },
+  /**
+   * Send a past delivery's payload again, as a new delivery
+   *
+   * @generated from rpc webhook.WebhookAdminService.RedeliverWebhook
+   */
+  redeliverWebhook: {
+    methodKind: "unary";
+    input: typeof RedeliverWebhookRequestSchema;
+    output: typeof RedeliverWebhookResponseSchema;
+  },
+}> = /*@__PURE__*/
+  serviceDesc(file_webhook, 0);
+